@@ -21,7 +21,7 @@ impl<T> Cursor<T> {
     /// # Examples
     ///
     /// ```
-    /// use ark_std::io::Cursor;
+    /// use fina_common::io::Cursor;
     ///
     /// let buff = Cursor::new(Vec::new());
     /// # fn force_inference(_: &Cursor<Vec<u8>>) {}
@@ -36,7 +36,7 @@ impl<T> Cursor<T> {
     /// # Examples
     ///
     /// ```
-    /// use ark_std::io::Cursor;
+    /// use fina_common::io::Cursor;
     ///
     /// let buff = Cursor::new(Vec::new());
     /// # fn force_inference(_: &Cursor<Vec<u8>>) {}
@@ -53,7 +53,7 @@ impl<T> Cursor<T> {
     /// # Examples
     ///
     /// ```
-    /// use ark_std::io::Cursor;
+    /// use fina_common::io::Cursor;
     ///
     /// let buff = Cursor::new(Vec::new());
     /// # fn force_inference(_: &Cursor<Vec<u8>>) {}
@@ -73,7 +73,7 @@ impl<T> Cursor<T> {
     /// # Examples
     ///
     /// ```
-    /// use ark_std::io::Cursor;
+    /// use fina_common::io::Cursor;
     ///
     /// let mut buff = Cursor::new(Vec::new());
     /// # fn force_inference(_: &Cursor<Vec<u8>>) {}
@@ -95,7 +95,7 @@ impl<T> Cursor<T> {
     /// # Examples
     ///
     /// ```
-    /// use ark_std::io::Cursor;
+    /// use fina_common::io::Cursor;
     ///
     /// let mut buff = Cursor::new(vec![1, 2, 3, 4, 5]);
     ///
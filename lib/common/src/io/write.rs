@@ -44,7 +44,7 @@ impl<W: Write + ?Sized> Write for &mut W {
 impl Write for &mut [u8] {
     fn write(&mut self, data: &[u8]) -> Result<usize> {
         let amt = cmp::min(data.len(), self.len());
-        let (a, b) = mem::replace(self, &mut []).split_at_mut(amt);
+        let (a, b) = mem::take(self).split_at_mut(amt);
         a.copy_from_slice(&data[..amt]);
         *self = b;
         Ok(amt)
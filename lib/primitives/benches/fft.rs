@@ -0,0 +1,28 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use fina_common::{UniformRand, test_rng};
+use fina_primitives::{
+    field::FpBN256 as Fr,
+    polynomial::domain::{EvaluationDomain, Radix2EvaluationDomain},
+};
+
+/// Compares FFTs which compute their twiddle factors with FFTs reusing
+/// precomputed [`Radix2Twiddles`](fina_primitives::polynomial::domain::Radix2Twiddles).
+fn twiddles(c: &mut Criterion) {
+    let mut group = c.benchmark_group("twiddles");
+    let rng = &mut test_rng();
+    for log_size in [8, 12, 16] {
+        let domain = Radix2EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+        let coeffs: Vec<_> = (0..1 << log_size).map(|_| Fr::rand(rng)).collect();
+        let twiddles = domain.precompute_twiddles();
+        group.bench_with_input(BenchmarkId::new("uncached", log_size), &coeffs, |b, coeffs| {
+            b.iter(|| domain.fft_in_place(&mut coeffs.clone()))
+        });
+        group.bench_with_input(BenchmarkId::new("cached", log_size), &coeffs, |b, coeffs| {
+            b.iter(|| domain.fft_in_place_with_twiddles(&mut coeffs.clone(), &twiddles))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, twiddles);
+criterion_main!(benches);
@@ -0,0 +1,49 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use fina_common::{UniformRand, test_rng};
+use fina_primitives::curve::{
+    instances::bn254::{Fr, G1Projective},
+    scalar_mul::fixed_base::FixedBase,
+    traits::PrimeGroup,
+};
+
+/// Precomputes [`FixedBase`] tables and multiplies `n` scalars with them,
+/// for several window sizes, to choose [`FixedBase::window_size`].
+///
+/// The constant-time table scans make each multiplication cost about
+/// `2^w * bits / w` conditional selections on top of `bits / w` additions,
+/// so the best window size stays small however many scalars there are.
+fn window_size(c: &mut Criterion) {
+    let mut group = c.benchmark_group("window_size");
+    group.sample_size(10);
+    let rng = &mut test_rng();
+    let base = G1Projective::generator();
+    for num_scalars in [64, 1024, 8192] {
+        let scalars: Vec<_> = (0..num_scalars).map(|_| Fr::rand(rng)).collect();
+        for window in 2..=8 {
+            let id = BenchmarkId::new(format!("n = {num_scalars}"), window);
+            group.bench_with_input(id, &scalars, |b, scalars| {
+                b.iter(|| FixedBase::new(&base, window).batch_mul(scalars))
+            });
+        }
+    }
+    group.finish();
+}
+
+/// Multiplies one scalar with precomputed [`FixedBase`] tables, i.e. the cost
+/// per scalar once the precomputation is amortized.
+fn mul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mul");
+    let rng = &mut test_rng();
+    let base = G1Projective::generator();
+    let scalar = Fr::rand(rng);
+    for window in 2..=12 {
+        let table = FixedBase::new(&base, window);
+        group.bench_with_input(BenchmarkId::from_parameter(window), &scalar, |b, scalar| {
+            b.iter(|| table.mul(scalar))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, window_size, mul);
+criterion_main!(benches);
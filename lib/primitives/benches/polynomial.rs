@@ -0,0 +1,36 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use fina_common::test_rng;
+use fina_primitives::{
+    field::FpBN256 as Fr,
+    polynomial::{
+        traits::DenseUVPolynomial,
+        univariate::{DenseOrSparsePolynomial, DensePolynomial},
+    },
+};
+
+/// Compares long division with Newton iteration for dividends of degree `2d`
+/// and divisors of degree `d`, to place the threshold at which
+/// [`DensePolynomial::divide_with_q_and_r`] switches from one to the other.
+fn division(c: &mut Criterion) {
+    let mut group = c.benchmark_group("division");
+    let rng = &mut test_rng();
+    for divisor_degree in [128, 256, 384, 512, 1024, 2048] {
+        let dividend = DensePolynomial::<Fr>::rand(2 * divisor_degree, rng);
+        let divisor = DensePolynomial::<Fr>::rand(divisor_degree, rng);
+
+        group.bench_with_input(BenchmarkId::new("long", divisor_degree), &divisor, |b, divisor| {
+            let dividend = DenseOrSparsePolynomial::from(&dividend);
+            let divisor = DenseOrSparsePolynomial::from(divisor);
+            b.iter(|| dividend.naive_divide_with_q_and_r(&divisor))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("newton", divisor_degree),
+            &divisor,
+            |b, divisor| b.iter(|| dividend.fast_divide_with_q_and_r(divisor)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, division);
+criterion_main!(benches);
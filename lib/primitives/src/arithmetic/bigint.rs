@@ -1,4 +1,4 @@
-use crate::bits::BitIteratorBE;
+use crate::bits::{BitIteratorBE, BitIteratorLE};
 use alloc::vec::Vec;
 use core::{
     fmt::{Debug, Display},
@@ -23,6 +23,7 @@ pub trait BigInteger:
     + Sized
     + Sync
     + Zeroize
+    + AsRef<[u64]>
     + From<u128>
     + From<u64>
     + From<u32>
@@ -45,6 +46,7 @@ pub trait BigInteger:
     + Shl<u32, Output = Self>
     + ShlAssign<u32>
     + BitIteratorBE
+    + BitIteratorLE
 {
     /// Number of `usize` limbs representing `Self`.
     const NUM_LIMBS: usize;
@@ -69,7 +71,7 @@ pub trait BigInteger:
     /// # Examples
     ///
     /// ```
-    /// use primitives::arithmetic::{bigint::BigInteger, uint::U64};
+    /// use fina_primitives::arithmetic::{U64, bigint::BigInteger};
     ///
     /// let mut one = U64::from(1u64);
     /// assert!(one.is_odd());
@@ -81,7 +83,7 @@ pub trait BigInteger:
     /// # Examples
     ///
     /// ```
-    /// use primitives::arithmetic::{bigint::BigInteger, uint::U64};
+    /// use fina_primitives::arithmetic::{U64, bigint::BigInteger};
     ///
     /// let mut two = U64::from(2u64);
     /// assert!(two.is_even());
@@ -93,7 +95,7 @@ pub trait BigInteger:
     /// # Examples
     ///
     /// ```
-    /// use primitives::arithmetic::{bigint::BigInteger, uint::U64};
+    /// use fina_primitives::arithmetic::{U64, bigint::BigInteger};
     ///
     /// let mut zero = U64::from(0u64);
     /// assert!(zero.is_zero());
@@ -106,7 +108,7 @@ pub trait BigInteger:
     ///
     /// # Examples
     /// ```
-    /// use primitives::arithmetic::{bigint::BigInteger, uint::U64};
+    /// use fina_primitives::arithmetic::{U64, bigint::BigInteger};
     ///
     /// let zero = U64::from(0u64);
     /// assert_eq!(zero.num_bits(), 1);
@@ -124,7 +126,7 @@ pub trait BigInteger:
     /// # Examples
     ///
     /// ```
-    /// use primitives::arithmetic::{bigint::BigInteger, uint::U64};
+    /// use fina_primitives::arithmetic::{U64, bigint::BigInteger};
     ///
     /// let mut one = U64::from(1u64);
     /// assert!(one.get_bit(0));
@@ -5,7 +5,7 @@
 #![allow(clippy::cast_possible_truncation)]
 #![allow(clippy::cast_lossless)]
 
-use crate::bits::BitIteratorBE;
+use crate::bits::{BitIteratorBE, BitIteratorLE};
 use num_traits::ConstOne;
 
 /// A single limb of a big integer represented by 64-bits.
@@ -25,6 +25,12 @@ impl BitIteratorBE for &[Limb] {
     }
 }
 
+impl BitIteratorLE for &[Limb] {
+    fn bit_le_iter(self) -> impl Iterator<Item = bool> {
+        self.iter().copied().flat_map(Limb::bit_le_iter)
+    }
+}
+
 /// Multiply two [`Limb`]'s and return widened result.
 #[inline(always)]
 #[must_use]
@@ -20,7 +20,7 @@ macro_rules! impl_ct_from_primitive {
             #[doc = stringify!($int)]
             #[doc = "integer (constant)."]
             #[must_use]
-            #[allow(clippy::cast_lossless)]
+            #[allow(clippy::cast_lossless, trivial_numeric_casts)]
             pub const fn $func_name(val: $int) -> Self {
                 assert!(N >= 1, "number of limbs must be greater than zero");
                 let mut repr = Self { limbs: [0u64; N] };
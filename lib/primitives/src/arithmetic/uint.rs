@@ -8,7 +8,7 @@ use crate::{
         limb,
         limb::{Limb, Limbs},
     },
-    bits::BitIteratorBE,
+    bits::{BitIteratorBE, BitIteratorLE},
     ct_for, ct_for_unroll6, ct_rev_for,
 };
 use alloc::vec::Vec;
@@ -167,6 +167,19 @@ impl<const N: usize> Uint<N> {
         !self.ct_eq(rhs)
     }
 
+    /// Returns `rhs` if `choice` is set, and `self` otherwise, without
+    /// branching on `choice` (constant).
+    #[must_use]
+    #[inline(always)]
+    pub const fn ct_select(&self, rhs: &Self, choice: bool) -> Self {
+        let mask = (choice as Limb).wrapping_neg();
+        let mut result = *self;
+        ct_for!((i in 0..N) {
+            result.limbs[i] ^= mask & (self.limbs[i] ^ rhs.limbs[i]);
+        });
+        result
+    }
+
     /// Return the minimum number of bits needed to encode this number.
     ///
     /// One bit is necessary to encode zero.
@@ -730,6 +743,12 @@ impl<const N: usize> BitIteratorBE for Uint<N> {
     }
 }
 
+impl<const N: usize> BitIteratorLE for Uint<N> {
+    fn bit_le_iter(self) -> impl Iterator<Item = bool> {
+        self.into_limbs().into_iter().flat_map(Limb::bit_le_iter)
+    }
+}
+
 /// Parse a number from a string in a given radix.
 ///
 /// This implementation can be slow on big numbers and possibly fail constant
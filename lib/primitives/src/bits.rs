@@ -1,3 +1,5 @@
+use alloc::{vec, vec::Vec};
+
 pub trait BitIteratorBE: Sized {
     fn bit_be_iter(self) -> impl Iterator<Item = bool>;
     fn bit_be_trimmed_iter(self) -> impl Iterator<Item = bool> {
@@ -22,6 +24,158 @@ impl_bit_iter_be!(u64);
 impl_bit_iter_be!(u128);
 impl_bit_iter_be!(usize);
 
+pub trait BitIteratorLE: Sized {
+    fn bit_le_iter(self) -> impl Iterator<Item = bool>;
+    fn bit_le_trimmed_iter(self) -> impl Iterator<Item = bool> {
+        let mut bits: Vec<bool> = self.bit_le_iter().collect();
+        while bits.last() == Some(&false) {
+            bits.pop();
+        }
+        bits.into_iter()
+    }
+}
+
+macro_rules! impl_bit_iter_le {
+    ($int:ty) => {
+        impl BitIteratorLE for $int {
+            fn bit_le_iter(self) -> impl Iterator<Item = bool> {
+                (0..<$int>::BITS).map(move |i| self & (1 << i) != 0)
+            }
+        }
+    };
+}
+
+impl_bit_iter_le!(u8);
+impl_bit_iter_le!(u16);
+impl_bit_iter_le!(u32);
+impl_bit_iter_le!(u64);
+impl_bit_iter_le!(u128);
+impl_bit_iter_le!(usize);
+
+/// Computes the [width-`w` non-adjacent form] of `scalar`, as little-endian
+/// digits.
+///
+/// Every non-zero digit is odd and lies in `(-2^(w - 1), 2^(w - 1))`, and
+/// any `w` consecutive digits contain at most one non-zero digit.
+///
+/// # Panics
+///
+/// * If `w` is not in `2..=16`.
+///
+/// [width-`w` non-adjacent form]: https://en.wikipedia.org/wiki/Elliptic_curve_point_multiplication#w-ary_non-adjacent_form_(wNAF)_method
+pub fn to_wnaf(scalar: impl BitIteratorLE, w: usize) -> Vec<i64> {
+    assert!((2..=16).contains(&w), "window size should be in 2..=16");
+
+    // One extra limb absorbs the carry of the top digit.
+    let mut limbs = vec![0u64];
+    for (i, bit) in scalar.bit_le_trimmed_iter().enumerate() {
+        if i / 64 + 1 >= limbs.len() {
+            limbs.push(0);
+        }
+        limbs[i / 64] |= u64::from(bit) << (i % 64);
+    }
+
+    let width = 1i64 << w;
+    let mut digits = Vec::with_capacity(limbs.len() * 64);
+    while limbs.iter().any(|&limb| limb != 0) {
+        let digit = if limbs[0] & 1 == 1 {
+            // `k mods 2^w`, centered around zero.
+            let digit = (limbs[0] & (width as u64 - 1)) as i64;
+            let digit = if digit >= width / 2 { digit - width } else { digit };
+            if digit > 0 {
+                sub_small(&mut limbs, digit.unsigned_abs());
+            } else {
+                add_small(&mut limbs, digit.unsigned_abs());
+            }
+            digit
+        } else {
+            0
+        };
+        digits.push(digit);
+        shr1(&mut limbs);
+    }
+    digits
+}
+
+/// Computes the [non-adjacent form] of `scalar`, i.e. its [width-2
+/// NAF](to_wnaf), as little-endian digits in `{-1, 0, 1}`.
+///
+/// [non-adjacent form]: https://en.wikipedia.org/wiki/Non-adjacent_form
+pub fn to_naf(scalar: impl BitIteratorLE) -> Vec<i64> {
+    to_wnaf(scalar, 2)
+}
+
+/// Recodes `scalar | 1` into exactly `⌈num_bits / w⌉` little-endian
+/// signed digits of radix `2^w`, all of them odd and in
+/// `[-(2^w - 1), 2^w - 1]`, following [Joye and Tunstall].
+///
+/// Since no digit is zero and the number of digits only depends on
+/// `num_bits`, a scalar multiplication processing these digits performs the
+/// same sequence of group operations for every scalar. The lowest bit of the
+/// scalar is forced to one, so even scalars must be corrected by the caller.
+///
+/// `scalar` is expected to have at most `num_bits` bits.
+///
+/// # Panics
+///
+/// * If `w` is not in `1..=16`.
+///
+/// [Joye and Tunstall]: https://www.iacr.org/archive/ches2009/57470333/57470333.pdf
+pub fn to_signed_window(scalar: impl BitIteratorLE, w: usize, num_bits: usize) -> Vec<i64> {
+    assert!((1..=16).contains(&w), "window size should be in 1..=16");
+
+    let num_digits = num_bits.div_ceil(w).max(1);
+    let mut bits: Vec<bool> = scalar.bit_le_iter().take(num_digits * w).collect();
+    bits.resize(num_digits * w + 1, false);
+    let window = |i: usize| -> i64 {
+        // Bits `i * w + 1..=i * w + w`, above the (forced) lowest bit.
+        (1..=w).map(|t| i64::from(bits[i * w + t]) << t).sum()
+    };
+
+    // Each digit is `(k mod 2^(w + 1)) - 2^w`, where the lowest bit of `k` is
+    // one, and the remaining scalar `(k >> w) | 1` is odd again.
+    let mut digits: Vec<i64> = (0..num_digits - 1).map(|i| 1 + window(i) - (1 << w)).collect();
+    // The top digit is the remaining scalar, `(k >> ((n - 1) * w)) | 1`.
+    digits.push(1 + window(num_digits - 1));
+    digits
+}
+
+/// Subtracts a small value from a little-endian big integer.
+fn sub_small(limbs: &mut [u64], value: u64) {
+    let mut borrow = value;
+    for limb in limbs {
+        let (res, overflow) = limb.overflowing_sub(borrow);
+        *limb = res;
+        borrow = u64::from(overflow);
+        if borrow == 0 {
+            break;
+        }
+    }
+}
+
+/// Adds a small value to a little-endian big integer.
+fn add_small(limbs: &mut [u64], value: u64) {
+    let mut carry = value;
+    for limb in limbs {
+        let (res, overflow) = limb.overflowing_add(carry);
+        *limb = res;
+        carry = u64::from(overflow);
+        if carry == 0 {
+            break;
+        }
+    }
+}
+
+/// Shifts a little-endian big integer right by one bit.
+fn shr1(limbs: &mut [u64]) {
+    let mut high = 0;
+    for limb in limbs.iter_mut().rev() {
+        let low = *limb & 1;
+        *limb = (*limb >> 1) | (high << 63);
+        high = low;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use num_traits::ConstOne;
@@ -82,4 +236,59 @@ mod tests {
         edge_case!(u128);
         edge_case!(usize);
     }
+
+    #[test]
+    fn le_is_reversed_be() {
+        proptest!(|(value: u64)| {
+            let mut be: Vec<bool> = value.bit_be_iter().collect();
+            be.reverse();
+            prop_assert_eq!(value.bit_le_iter().collect::<Vec<_>>(), be);
+
+            let trimmed: Vec<bool> = value.bit_le_trimmed_iter().collect();
+            prop_assert_eq!(trimmed.len(), 64 - value.leading_zeros() as usize);
+
+            let limbs = [value, !value];
+            let bits: Vec<bool> = limbs.as_slice().bit_le_iter().collect();
+            prop_assert_eq!(&bits[..64], value.bit_le_iter().collect::<Vec<_>>());
+        });
+    }
+
+    fn recompose(digits: &[i64], radix_bits: usize) -> i128 {
+        digits.iter().rev().fold(0i128, |acc, d| (acc << radix_bits) + i128::from(*d))
+    }
+
+    #[test]
+    fn wnaf_recomposes() {
+        proptest!(|(value: u64, w in 2usize..10)| {
+            let digits = to_wnaf(value, w);
+            prop_assert_eq!(recompose(&digits, 1), i128::from(value));
+            prop_assert!(digits.last().is_none_or(|d| *d != 0));
+
+            for (i, digit) in digits.iter().enumerate().filter(|(_, d)| **d != 0) {
+                prop_assert!(digit % 2 != 0 && digit.unsigned_abs() < 1 << (w - 1));
+                let next = &digits[i + 1..digits.len().min(i + w)];
+                prop_assert!(next.iter().all(|d| *d == 0), "digits too close at {}", i);
+            }
+        });
+    }
+
+    #[test]
+    fn naf_known_values() {
+        assert!(to_naf(0u64).is_empty());
+        assert_eq!(to_naf(7u64), vec![-1, 0, 0, 1]);
+        assert_eq!(to_naf(u64::MAX), [vec![-1], vec![0; 63], vec![1]].concat());
+        assert_eq!(to_wnaf(u128::MAX, 4).len(), 129);
+    }
+
+    #[test]
+    fn signed_window_recomposes() {
+        proptest!(|(value: u64, w in 1usize..10)| {
+            let digits = to_signed_window(value, w, 64);
+            prop_assert_eq!(digits.len(), 64usize.div_ceil(w));
+            prop_assert_eq!(recompose(&digits, w), i128::from(value | 1));
+            for digit in &digits {
+                prop_assert!(digit % 2 != 0 && digit.unsigned_abs() < 1 << w);
+            }
+        });
+    }
 }
@@ -0,0 +1,119 @@
+//! The [Elligator 2] map, for Montgomery curves and the twisted Edwards
+//! curves birationally equivalent to them.
+//!
+//! [Elligator 2]: https://datatracker.ietf.org/doc/html/rfc9380#section-6.7.1
+use super::{HashToCurveError, MapToCurve, sgn0};
+use crate::{
+    curve::{
+        montgomery::{self, MontCurveConfig},
+        te::{self, TECurveConfig},
+    },
+    field::traits::Field,
+};
+use core::marker::PhantomData;
+use num_traits::{One, Zero};
+
+/// Parameters of the Elligator 2 map.
+///
+/// The map is computed on the Montgomery curve `Self`, with coefficients
+/// `J = a` and `K = b`, and its output is sent to
+/// [`MontCurveConfig::TECurveConfig`] by the rational map
+/// `(s, t) -> (c * s / t, (s - 1) / (s + 1))`, where `c` is
+/// [`Self::RATIONAL_MAP_SCALE`].
+pub trait Elligator2Config: MontCurveConfig {
+    /// Non-square `Z` of the map.
+    const ZETA: Self::BaseField;
+
+    /// Factor `c` of the rational map to the twisted Edwards curve.
+    ///
+    /// Defaults to [`MontCurveConfig::TE_X_SCALE`]. Only its sign can be
+    /// changed, e.g. to match the `sgn0(c) = 0` convention of RFC 9380 for
+    /// edwards25519.
+    const RATIONAL_MAP_SCALE: Self::BaseField = <Self as MontCurveConfig>::TE_X_SCALE;
+}
+
+/// The Elligator 2 map to the twisted Edwards curve equivalent to the
+/// Montgomery curve `P`.
+pub struct Elligator2Map<P: Elligator2Config>(PhantomData<fn() -> P>);
+
+impl<P: Elligator2Config> Elligator2Map<P> {
+    /// Maps `u` to the Montgomery curve `P`, following
+    /// [RFC 9380, section 6.7.1](https://datatracker.ietf.org/doc/html/rfc9380#section-6.7.1),
+    /// in variable time.
+    ///
+    /// # Errors
+    ///
+    /// * If `Z` is a square, so that no candidate lies on the curve.
+    pub fn map_to_montgomery(
+        u: P::BaseField,
+    ) -> Result<montgomery::affine::Affine<P>, HashToCurveError> {
+        let k_inv = P::COEFF_B
+            .inverse()
+            .ok_or(HashToCurveError::UnsupportedCurve("b should be nonzero"))?;
+        let j_over_k = P::COEFF_A * k_inv;
+        let one_over_k2 = k_inv.square();
+
+        // Right-hand side of `y² = x³ + (J / K) * x² + x / K²`.
+        let g = |x: P::BaseField| ((x + j_over_k) * x + one_over_k2) * x;
+
+        let x1 = (P::BaseField::one() + P::ZETA * u.square())
+            .inverse()
+            .map_or(-j_over_k, |tv1| -j_over_k * tv1);
+        let gx1 = g(x1);
+
+        // `y` is the root of sign 1 for `x1`, and of sign 0 for `x2`.
+        let (x, y) = match gx1.sqrt() {
+            Some(y1) => (x1, if sgn0(&y1) { y1 } else { -y1 }),
+            None => {
+                let x2 = -x1 - j_over_k;
+                let y2 = g(x2).sqrt().ok_or(HashToCurveError::MapToCurve(
+                    "neither g(x1) nor g(x2) is square, Z is invalid",
+                ))?;
+                (x2, if sgn0(&y2) { -y2 } else { y2 })
+            },
+        };
+
+        Ok(montgomery::affine::Affine::new_unchecked(x * P::COEFF_B, y * P::COEFF_B))
+    }
+}
+
+impl<P: Elligator2Config> MapToCurve<te::projective::Projective<P::TECurveConfig>>
+    for Elligator2Map<P>
+where
+    P::TECurveConfig: TECurveConfig,
+{
+    fn check_parameters() -> Result<(), HashToCurveError> {
+        if P::COEFF_B.is_zero() {
+            return Err(HashToCurveError::UnsupportedCurve("b should be nonzero"));
+        }
+        if P::ZETA.is_square() {
+            return Err(HashToCurveError::UnsupportedCurve("Z should be a non-square"));
+        }
+        if P::RATIONAL_MAP_SCALE.square() != P::TE_X_SCALE.square() {
+            return Err(HashToCurveError::UnsupportedCurve(
+                "the rational map scale should be TE_X_SCALE up to sign",
+            ));
+        }
+        Ok(())
+    }
+
+    fn map_to_curve(
+        u: P::BaseField,
+    ) -> Result<te::affine::Affine<P::TECurveConfig>, HashToCurveError> {
+        let point = Self::map_to_montgomery(u)?;
+        let (s, t) = (point.x, point.y);
+
+        // The exceptional points of the rational map are sent to the
+        // identity, as in
+        // [RFC 9380, appendix D.1](https://datatracker.ietf.org/doc/html/rfc9380#appendix-D.1).
+        let (Some(t_inv), Some(s_plus_one_inv)) =
+            (t.inverse(), (s + P::BaseField::one()).inverse())
+        else {
+            return Ok(te::affine::Affine::identity());
+        };
+
+        let x = P::RATIONAL_MAP_SCALE * s * t_inv;
+        let y = (s - P::BaseField::one()) * s_plus_one_inv;
+        Ok(te::affine::Affine::new_unchecked(x, y))
+    }
+}
@@ -0,0 +1,137 @@
+//! This module provides hashing of byte strings to elliptic curve points,
+//! following [RFC 9380].
+//!
+//! A [`MapToCurve`] deterministically maps field elements to curve points,
+//! and [`MapToCurveBasedHasher`] composes it with a [`HashToField`] to obtain
+//! the `hash_to_curve` and `encode_to_curve` functions of the RFC.
+//!
+//! [RFC 9380]: https://datatracker.ietf.org/doc/html/rfc9380
+pub mod elligator2;
+pub mod svdw;
+pub mod swu;
+pub mod wb;
+
+use super::traits::{AffineRepr, CurveGroup};
+use crate::{
+    arithmetic::bigint::BigInteger,
+    field::{
+        hashers::HashToField,
+        traits::{Field, PrimeField},
+    },
+};
+use core::{fmt, marker::PhantomData};
+use num_traits::Zero;
+
+/// Errors of hashing to a curve.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HashToCurveError {
+    /// The parameters of the map are not valid for the curve.
+    UnsupportedCurve(&'static str),
+    /// A field element could not be mapped to the curve.
+    MapToCurve(&'static str),
+}
+
+impl core::error::Error for HashToCurveError {}
+
+impl fmt::Display for HashToCurveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedCurve(reason) => write!(f, "unsupported curve: {reason}"),
+            Self::MapToCurve(reason) => write!(f, "map to curve failed: {reason}"),
+        }
+    }
+}
+
+/// Deterministic map from elements of the base field to points of the
+/// curve `T`.
+pub trait MapToCurve<T: CurveGroup>: Sized {
+    /// Checks that the parameters of the map are valid for the curve.
+    ///
+    /// # Errors
+    ///
+    /// * If the map cannot be used with the curve.
+    fn check_parameters() -> Result<(), HashToCurveError>;
+
+    /// Maps `element` to a point of the curve, which need not be in the
+    /// prime-order subgroup.
+    ///
+    /// # Errors
+    ///
+    /// * If `element` cannot be mapped, e.g. when the parameters are invalid.
+    fn map_to_curve(element: T::BaseField) -> Result<T::Affine, HashToCurveError>;
+}
+
+/// Hashes byte strings to points of the prime-order subgroup of the curve
+/// `T`.
+pub trait HashToCurve<T: CurveGroup>: Sized {
+    /// Creates a hasher with the domain separation tag `domain`.
+    ///
+    /// # Errors
+    ///
+    /// * If the underlying map cannot be used with the curve.
+    fn new(domain: &[u8]) -> Result<Self, HashToCurveError>;
+
+    /// The `hash_to_curve` function, whose output is indistinguishable from
+    /// a random oracle.
+    ///
+    /// # Errors
+    ///
+    /// * If a hashed field element cannot be mapped to the curve.
+    fn hash(&self, msg: &[u8]) -> Result<T::Affine, HashToCurveError>;
+
+    /// The `encode_to_curve` function, whose output is not uniformly
+    /// distributed, but which is roughly twice as fast as [`Self::hash`].
+    ///
+    /// # Errors
+    ///
+    /// * If the hashed field element cannot be mapped to the curve.
+    fn encode(&self, msg: &[u8]) -> Result<T::Affine, HashToCurveError>;
+}
+
+/// [`HashToCurve`] built from a [`HashToField`] `H2F` and a [`MapToCurve`]
+/// `M2C`, as specified by
+/// [RFC 9380, section 3](https://datatracker.ietf.org/doc/html/rfc9380#section-3).
+pub struct MapToCurveBasedHasher<T, H2F, M2C> {
+    field_hasher: H2F,
+    _params: PhantomData<(T, M2C)>,
+}
+
+impl<T, H2F, M2C> HashToCurve<T> for MapToCurveBasedHasher<T, H2F, M2C>
+where
+    T: CurveGroup,
+    H2F: HashToField<T::BaseField>,
+    M2C: MapToCurve<T>,
+{
+    fn new(domain: &[u8]) -> Result<Self, HashToCurveError> {
+        M2C::check_parameters()?;
+        Ok(Self { field_hasher: H2F::new(domain), _params: PhantomData })
+    }
+
+    fn hash(&self, msg: &[u8]) -> Result<T::Affine, HashToCurveError> {
+        let [u0, u1] = self.field_hasher.hash_to_field::<2>(msg);
+        let q0 = M2C::map_to_curve(u0)?;
+        let q1 = M2C::map_to_curve(u1)?;
+        let r: T::Affine = (q0 + q1).into();
+        Ok(r.clear_cofactor())
+    }
+
+    fn encode(&self, msg: &[u8]) -> Result<T::Affine, HashToCurveError> {
+        let [u] = self.field_hasher.hash_to_field::<1>(msg);
+        Ok(M2C::map_to_curve(u)?.clear_cofactor())
+    }
+}
+
+/// The `sgn0` function of
+/// [RFC 9380, section 4.1](https://datatracker.ietf.org/doc/html/rfc9380#section-4.1),
+/// i.e. the parity of the first nonzero coefficient of `element` over the
+/// base prime field.
+pub fn sgn0<F: Field>(element: &F) -> bool {
+    let mut sign = false;
+    let mut zero = true;
+    for coeff in element.to_base_prime_field_elements() {
+        let sign_i = coeff.into_bigint().is_odd();
+        sign |= zero && sign_i;
+        zero &= coeff.is_zero();
+    }
+    sign
+}
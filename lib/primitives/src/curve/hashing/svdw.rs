@@ -0,0 +1,112 @@
+//! The [Shallue-van de Woestijne] map, for any short Weierstrass curve, and
+//! in particular for curves with `a = 0` such as BN254.
+//!
+//! [Shallue-van de Woestijne]: https://datatracker.ietf.org/doc/html/rfc9380#section-6.6.1
+use super::{HashToCurveError, MapToCurve, sgn0};
+use crate::{
+    curve::sw::{SWCurveConfig, affine::Affine, projective::Projective},
+    field::traits::Field,
+};
+use core::marker::PhantomData;
+use num_traits::Zero;
+
+/// Parameters of the Shallue-van de Woestijne map.
+///
+/// The constants `c1`, `c2`, `c3` and `c4` of
+/// [RFC 9380, appendix F.1](https://datatracker.ietf.org/doc/html/rfc9380#appendix-F.1)
+/// are precomputed from `Z`, and checked against it by
+/// [`MapToCurve::check_parameters`].
+pub trait SvdWConfig: SWCurveConfig {
+    /// Constant `Z` of the map, such that `g(Z) != 0`,
+    /// `-(3 * Z² + 4 * a) / (4 * g(Z))` is a nonzero square, and either
+    /// `g(Z)` or `g(-Z / 2)` is square, where `g(x) = x³ + a * x + b`.
+    const ZETA: Self::BaseField;
+    /// Constant `c1 = g(Z)`.
+    const C1: Self::BaseField;
+    /// Constant `c2 = -Z / 2`.
+    const C2: Self::BaseField;
+    /// Constant `c3 = sqrt(-g(Z) * (3 * Z² + 4 * a))`, with `sgn0(c3) = 0`.
+    const C3: Self::BaseField;
+    /// Constant `c4 = -4 * g(Z) / (3 * Z² + 4 * a)`.
+    const C4: Self::BaseField;
+}
+
+/// The Shallue-van de Woestijne map to the curve `P`.
+pub struct SvdWMap<P: SvdWConfig>(PhantomData<fn() -> P>);
+
+impl<P: SvdWConfig> SvdWMap<P> {
+    /// Evaluates the right-hand side `x³ + a * x + b` of the curve equation.
+    fn g(x: P::BaseField) -> P::BaseField {
+        P::add_b((x.square() + P::COEFF_A) * x)
+    }
+
+    /// Computes the constants `[c1, c2, c3, c4]` from `Z`, or `None` if `Z`
+    /// does not satisfy the requirements.
+    fn constants() -> Option<[P::BaseField; 4]> {
+        let z = P::ZETA;
+        let gz = Self::g(z);
+        let two = P::BaseField::from(2u8);
+        let four = P::BaseField::from(4u8);
+
+        // `3 * Z² + 4 * a`.
+        let h = z.square() * P::BaseField::from(3u8) + P::COEFF_A * four;
+        if gz.is_zero() || h.is_zero() {
+            return None;
+        }
+        if !gz.is_square() && !Self::g(-z / two).is_square() {
+            return None;
+        }
+
+        let c1 = gz;
+        let c2 = -z / two;
+        let mut c3 = (-gz * h).sqrt()?;
+        if sgn0(&c3) {
+            c3 = -c3;
+        }
+        let c4 = -gz * four / h;
+        Some([c1, c2, c3, c4])
+    }
+}
+
+impl<P: SvdWConfig> MapToCurve<Projective<P>> for SvdWMap<P> {
+    fn check_parameters() -> Result<(), HashToCurveError> {
+        let constants = Self::constants().ok_or(HashToCurveError::UnsupportedCurve(
+            "Z does not satisfy the SvdW requirements",
+        ))?;
+        if constants != [P::C1, P::C2, P::C3, P::C4] {
+            return Err(HashToCurveError::UnsupportedCurve("c1, c2, c3 and c4 do not match Z"));
+        }
+        Ok(())
+    }
+
+    /// Follows the straight-line description of
+    /// [RFC 9380, appendix F.1](https://datatracker.ietf.org/doc/html/rfc9380#appendix-F.1),
+    /// in variable time.
+    fn map_to_curve(u: P::BaseField) -> Result<Affine<P>, HashToCurveError> {
+        let (c1, c2, c3, c4) = (P::C1, P::C2, P::C3, P::C4);
+
+        let tv1 = u.square() * c1;
+        let tv2 = P::BaseField::ONE + tv1;
+        let tv1 = P::BaseField::ONE - tv1;
+        let tv3 = (tv1 * tv2).inverse().unwrap_or_default();
+        let tv4 = u * tv1 * tv3 * c3;
+
+        // Out of the three candidates, the first `x` with `g(x)` square is
+        // used. The third one always is, by choice of `Z`.
+        let x1 = c2 - tv4;
+        let x2 = c2 + tv4;
+        let x3 = (tv2.square() * tv3).square() * c4 + P::ZETA;
+
+        let (x, mut y) =
+            [x1, x2, x3]
+                .into_iter()
+                .find_map(|x| Self::g(x).sqrt().map(|y| (x, y)))
+                .ok_or(HashToCurveError::MapToCurve("none of the candidates is on the curve"))?;
+
+        if sgn0(&u) != sgn0(&y) {
+            y = -y;
+        }
+
+        Ok(Affine::new_unchecked(x, y))
+    }
+}
@@ -0,0 +1,85 @@
+//! The [Simplified Shallue-van de Woestijne-Ulas] map, for short Weierstrass
+//! curves with `a * b != 0`.
+//!
+//! [Simplified Shallue-van de Woestijne-Ulas]: https://datatracker.ietf.org/doc/html/rfc9380#section-6.6.2
+use super::{HashToCurveError, MapToCurve, sgn0};
+use crate::{
+    curve::sw::{SWCurveConfig, affine::Affine, projective::Projective},
+    field::traits::Field,
+};
+use core::marker::PhantomData;
+use num_traits::{One, Zero};
+
+/// Parameters of the Simplified SWU map.
+pub trait SWUConfig: SWCurveConfig {
+    /// Non-square `Z` of the map, such that `g(b / (Z * a))` is square and
+    /// `g(x) - y` is irreducible, where `g(x) = x³ + a * x + b`.
+    const ZETA: Self::BaseField;
+}
+
+/// The Simplified SWU map to the curve `P`.
+pub struct SWUMap<P: SWUConfig>(PhantomData<fn() -> P>);
+
+impl<P: SWUConfig> SWUMap<P> {
+    /// Evaluates the right-hand side `x³ + a * x + b` of the curve equation.
+    fn g(x: P::BaseField) -> P::BaseField {
+        P::add_b((x.square() + P::COEFF_A) * x)
+    }
+}
+
+impl<P: SWUConfig> MapToCurve<Projective<P>> for SWUMap<P> {
+    fn check_parameters() -> Result<(), HashToCurveError> {
+        if P::COEFF_A.is_zero() || P::COEFF_B.is_zero() {
+            return Err(HashToCurveError::UnsupportedCurve(
+                "Simplified SWU requires a * b != 0, use an isogeny map",
+            ));
+        }
+        if P::ZETA.is_square() {
+            return Err(HashToCurveError::UnsupportedCurve("Z should be a non-square"));
+        }
+        let x = P::COEFF_B / (P::ZETA * P::COEFF_A);
+        if !Self::g(x).is_square() {
+            return Err(HashToCurveError::UnsupportedCurve("g(b / (Z * a)) should be a square"));
+        }
+        Ok(())
+    }
+
+    /// Follows the straight-line description of
+    /// [RFC 9380, section 6.6.2](https://datatracker.ietf.org/doc/html/rfc9380#section-6.6.2),
+    /// in variable time.
+    fn map_to_curve(u: P::BaseField) -> Result<Affine<P>, HashToCurveError> {
+        let a = P::COEFF_A;
+        let b = P::COEFF_B;
+        let z = P::ZETA;
+
+        // `tv1 = inv0(Z² * u⁴ + Z * u²)`.
+        let zu2 = z * u.square();
+        let tv1 = (zu2.square() + zu2).inverse();
+
+        // `x1 = (-b / a) * (1 + tv1)`, or `b / (Z * a)` in the exceptional
+        // case `tv1 = 0`.
+        let x1 = match tv1 {
+            Some(tv1) => -b / a * (P::BaseField::one() + tv1),
+            None => b / (z * a),
+        };
+        let gx1 = Self::g(x1);
+
+        // Either `g(x1)` or `g(x2)`, with `x2 = Z * u² * x1`, is square.
+        let (x, mut y) = match gx1.sqrt() {
+            Some(y1) => (x1, y1),
+            None => {
+                let x2 = zu2 * x1;
+                let y2 = Self::g(x2).sqrt().ok_or(HashToCurveError::MapToCurve(
+                    "neither g(x1) nor g(x2) is square, Z is invalid",
+                ))?;
+                (x2, y2)
+            },
+        };
+
+        if sgn0(&u) != sgn0(&y) {
+            y = -y;
+        }
+
+        Ok(Affine::new_unchecked(x, y))
+    }
+}
@@ -0,0 +1,119 @@
+//! The [Wahby-Boneh] map, composing the Simplified SWU map to an isogenous
+//! curve with an isogeny, for short Weierstrass curves with `a * b = 0`.
+//!
+//! [Wahby-Boneh]: https://eprint.iacr.org/2019/403
+use super::{
+    HashToCurveError, MapToCurve,
+    swu::{SWUConfig, SWUMap},
+};
+use crate::{
+    curve::{
+        sw::{SWCurveConfig, affine::Affine, projective::Projective},
+        traits::AffineRepr,
+    },
+    field::traits::Field,
+};
+use core::marker::PhantomData;
+
+/// Rational map `(x, y) -> (x_num(x) / x_den(x), y * y_num(x) / y_den(x))`
+/// from the curve `Domain` to the curve `Codomain`.
+///
+/// Polynomials are given by their coefficients in increasing degree order,
+/// as in
+/// [RFC 9380, appendix E](https://datatracker.ietf.org/doc/html/rfc9380#appendix-E).
+pub struct IsogenyMap<
+    'a,
+    Domain: SWCurveConfig,
+    Codomain: SWCurveConfig<BaseField = Domain::BaseField>,
+> {
+    /// Coefficients of `x_num`.
+    pub x_map_numerator: &'a [Domain::BaseField],
+    /// Coefficients of `x_den`.
+    pub x_map_denominator: &'a [Domain::BaseField],
+    /// Coefficients of `y_num`.
+    pub y_map_numerator: &'a [Domain::BaseField],
+    /// Coefficients of `y_den`.
+    pub y_map_denominator: &'a [Domain::BaseField],
+    _curves: PhantomData<fn(Domain) -> Codomain>,
+}
+
+impl<'a, Domain, Codomain> IsogenyMap<'a, Domain, Codomain>
+where
+    Domain: SWCurveConfig,
+    Codomain: SWCurveConfig<BaseField = Domain::BaseField>,
+{
+    /// Creates the rational map from the coefficients of its polynomials.
+    #[must_use]
+    pub const fn new(
+        x_map_numerator: &'a [Domain::BaseField],
+        x_map_denominator: &'a [Domain::BaseField],
+        y_map_numerator: &'a [Domain::BaseField],
+        y_map_denominator: &'a [Domain::BaseField],
+    ) -> Self {
+        Self {
+            x_map_numerator,
+            x_map_denominator,
+            y_map_numerator,
+            y_map_denominator,
+            _curves: PhantomData,
+        }
+    }
+
+    /// Maps `point` to the codomain curve.
+    ///
+    /// Points cancelling a denominator, i.e. those of the kernel, are mapped
+    /// to the identity.
+    pub fn apply(&self, point: Affine<Domain>) -> Affine<Codomain> {
+        if point.infinity {
+            return Affine::identity();
+        }
+
+        let x_den = evaluate(self.x_map_denominator, point.x);
+        let y_den = evaluate(self.y_map_denominator, point.x);
+        let (Some(x_den_inv), Some(y_den_inv)) = (x_den.inverse(), y_den.inverse()) else {
+            return Affine::identity();
+        };
+
+        let x = evaluate(self.x_map_numerator, point.x) * x_den_inv;
+        let y = point.y * evaluate(self.y_map_numerator, point.x) * y_den_inv;
+        Affine::new_unchecked(x, y)
+    }
+}
+
+/// Evaluates the polynomial with coefficients `coeffs` at `x`, with Horner's
+/// rule.
+fn evaluate<F: Field>(coeffs: &[F], x: F) -> F {
+    coeffs.iter().rev().fold(F::zero(), |acc, coeff| acc * x + coeff)
+}
+
+/// Parameters of the Wahby-Boneh map.
+pub trait WBConfig: SWCurveConfig {
+    /// Curve isogenous to `Self`, with `a * b != 0`, on which the Simplified
+    /// SWU map is computed.
+    type IsogenousCurve: SWUConfig<BaseField = Self::BaseField>;
+
+    /// Isogeny from [`Self::IsogenousCurve`] to `Self`.
+    const ISOGENY_MAP: IsogenyMap<'static, Self::IsogenousCurve, Self>;
+}
+
+/// The Wahby-Boneh map to the curve `P`.
+pub struct WBMap<P: WBConfig>(PhantomData<fn() -> P>);
+
+impl<P: WBConfig> MapToCurve<Projective<P>> for WBMap<P> {
+    fn check_parameters() -> Result<(), HashToCurveError> {
+        SWUMap::<P::IsogenousCurve>::check_parameters()?;
+
+        let image = P::ISOGENY_MAP.apply(P::IsogenousCurve::GENERATOR);
+        if image.is_zero() || !image.is_on_curve() {
+            return Err(HashToCurveError::UnsupportedCurve(
+                "the isogeny should map the isogenous curve to the curve",
+            ));
+        }
+        Ok(())
+    }
+
+    fn map_to_curve(u: P::BaseField) -> Result<Affine<P>, HashToCurveError> {
+        let point = SWUMap::<P::IsogenousCurve>::map_to_curve(u)?;
+        Ok(P::ISOGENY_MAP.apply(point))
+    }
+}
@@ -0,0 +1,197 @@
+//! [Bandersnatch] twisted Edwards curve, defined over the scalar field of
+//! BLS12-381.
+//!
+//! The curve equation is `-5 * x² + y² = 1 + d * x² * y²`, with cofactor `4`.
+//! Bandersnatch has an efficiently computable endomorphism of degree 2, and
+//! its [`Decaf`] group removes the cofactor.
+//!
+//! [Bandersnatch]: https://eprint.iacr.org/2021/1152
+use crate::{
+    arithmetic::U256,
+    curve::{
+        te::{
+            TECurveConfig,
+            affine::Affine,
+            decaf::{Decaf, DecafConfig},
+            projective::Projective,
+        },
+        traits::CurveConfig,
+    },
+    field::{Fp256, FpBLS12, FpParams, LIMBS_256, traits::AdditiveGroup},
+    fp_from_hex, fp_from_num, from_num,
+};
+
+/// Base field of the Bandersnatch curve, i.e. the scalar field of BLS12-381.
+pub type Fq = FpBLS12;
+
+/// Scalar field of the Bandersnatch prime-order subgroup.
+pub type Fr = Fp256<FrParam>;
+/// Parameters of the Bandersnatch scalar field.
+pub struct FrParam;
+
+impl FpParams<LIMBS_256> for FrParam {
+    const GENERATOR: Fr = fp_from_num!("7");
+    const MODULUS: U256 =
+        from_num!("13108968793781547619861935127046491459309155893440570251786403306729687672801");
+    const ONE: Fr = fp_from_num!("1");
+    const TWO_ADICITY: u32 = 5;
+    const TWO_ADIC_ROOT_OF_UNITY: Fr =
+        fp_from_hex!("19470B7EFE802F9B36B6675F52C7008234BB3E0CB7ED22AEC65A62A1234BD960");
+    const ZERO: Fr = fp_from_num!("0");
+}
+
+/// Affine point of the Bandersnatch curve.
+pub type EdwardsAffine = Affine<BandersnatchConfig>;
+/// Extended projective point of the Bandersnatch curve.
+pub type EdwardsProjective = Projective<BandersnatchConfig>;
+
+/// Bandersnatch curve configuration.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct BandersnatchConfig;
+
+impl CurveConfig for BandersnatchConfig {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = &[4];
+    const COFACTOR_INV: Fr = fp_from_num!(
+        "9831726595336160714896451345284868594481866920080427688839802480047265754601"
+    );
+}
+
+impl TECurveConfig for BandersnatchConfig {
+    /// `a = -5`
+    const COEFF_A: Fq = fp_from_num!(
+        "52435875175126190479447740508185965837690552500527637822603658699938581184508"
+    );
+    const COEFF_D: Fq = fp_from_num!(
+        "45022363124591815672509500913686876175488063829319466900776701791074614335719"
+    );
+    const GENERATOR: EdwardsAffine = Affine::new_unchecked(GENERATOR_X, GENERATOR_Y);
+
+    /// Multiplication by `a` is computed as `-(4 * elem + elem)`.
+    #[inline(always)]
+    fn mul_by_a(elem: Fq) -> Fq {
+        -(elem.double().double() + elem)
+    }
+}
+
+/// Element of the Decaf group built on Bandersnatch.
+pub type BandersnatchDecaf = Decaf<BandersnatchConfig>;
+
+impl DecafConfig for BandersnatchConfig {
+    const ZETA: Fq = fp_from_num!("5");
+}
+
+/// `x` coordinate of the generator of the Bandersnatch prime-order subgroup.
+pub const GENERATOR_X: Fq =
+    fp_from_num!("18886178867200960497001835917649091219057080094937609519140440539760939937304");
+
+/// `y` coordinate of the generator of the Bandersnatch prime-order subgroup.
+pub const GENERATOR_Y: Fq =
+    fp_from_num!("19188667384257783945677642223292697773471335439753913231509108946878080696678");
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        curve::traits::{AffineRepr, CurveGroup, PrimeGroup},
+        field::traits::PrimeField,
+        test_utils::from_hex,
+    };
+    use num_traits::Zero;
+    use sha2::{Digest, Sha512};
+
+    #[test]
+    fn generator() {
+        let g = EdwardsAffine::generator();
+        assert!(g.is_on_curve());
+        assert!(g.is_in_correct_subgroup_assuming_on_curve());
+        assert!(g.mul_bigint(Fr::MODULUS).is_zero());
+    }
+
+    #[test]
+    fn double_generator() {
+        let expected = EdwardsAffine::new(
+            fp_from_num!(
+                "21829743261194590194992413705867576097158323059182896808782966767024601242412"
+            ),
+            fp_from_num!(
+                "19075870567762384361343718229920461045746972450262741916171739040424605531019"
+            ),
+        );
+        assert_eq!(EdwardsAffine::generator().mul_bigint(2u32).into_affine(), expected);
+    }
+
+    /// Encodings of `0 * B, ..., 15 * B` in [`BandersnatchDecaf`], computed
+    /// with an independent model of the formulae of
+    /// [`decaf`](crate::curve::te::decaf).
+    #[test]
+    fn decaf_multiples_of_generator() {
+        let encodings = [
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "d0d6b711a262e7f4c5d6a47f6957c5b1d64291a19b80ff6d87a446cef6a7d707",
+            "6e483384e648a5d8c08c090f2f7c45f18c96623780b535fefc56c273705cc925",
+            "6c1fa31c8880bcb31ec269f36c89475e9ceae921e4c4810037bf898524906346",
+            "5e32f3ba98f2829eb5b4c2f5cc57082b51e1042e064985ea6be6bd805bc6354b",
+            "86e80a7038f765e7b980c0529069eb18c0ff171c86ec6cc54e3e5af024cdc75f",
+            "72402e8c7ad5d996c7cc1cfe7236c29163452a4907fcc93ced76e882ecb1b23b",
+            "d0a4ebaf96841c0c5143fc3a842a0e68ee6c78712073dfc55acea9f3029f6d45",
+            "d2b4a6ef66fd2131fe58b9b66832e588b2ffd1f0b4e7aa22350ff1ebc9dbd42f",
+            "9a74018129c1c3e6916ddbb531a8cebd90f36e0b9ebd45d528d99f7596f9b60f",
+            "6cd0d93ef56a8b7093a1ac6c68e06ea25a5c3d904de79fcf8be8afef7f6e635d",
+            "924a0858d331a6f225635a3c19bcca688b6d15f3d7b4a384368ac5c1cf8af424",
+            "043b4ca1717a629a54f421a9ad4aa636aac4416c0ed60f294a1cd802eb37ef13",
+            "90399c083e7277d83caaa30f698389908dbf3a31725073285678aae321851e4a",
+            "e0b0d080168d1a4eceb7ad425585b2fb2006d7f558c3106000658aad1946300b",
+            "30edc137972bcbdea5f27bca32351a0b0beea627f711e3e3ae0e9f62184d245b",
+        ];
+
+        let mut p = BandersnatchDecaf::zero();
+        for encoding in encodings {
+            let bytes = from_hex(encoding);
+            assert_eq!(p.encode(), bytes);
+            assert_eq!(BandersnatchDecaf::decode(&bytes), Some(p));
+            p += BandersnatchDecaf::generator();
+        }
+    }
+
+    #[test]
+    fn decaf_invalid_encodings() {
+        let encodings = [
+            // The modulus.
+            "01000000fffffffffe5bfeff02a4bd5305d8a10908d83933487d9d2953a7ed73",
+            // `s = 1`, which is negative.
+            "0100000000000000000000000000000000000000000000000000000000000000",
+            // `s = 8`, for which `t²` is not a square.
+            "0800000000000000000000000000000000000000000000000000000000000000",
+        ];
+        for encoding in encodings {
+            assert_eq!(BandersnatchDecaf::decode(&from_hex(encoding)), None, "{encoding}");
+        }
+        assert_eq!(BandersnatchDecaf::decode(&[0u8; 31]), None);
+        assert_eq!(BandersnatchDecaf::decode(&[0u8; 33]), None);
+    }
+
+    #[test]
+    fn decaf_from_uniform_bytes() {
+        let cases = [
+            (
+                "Decaf is a prime-order group",
+                "7c938d86a62b7104a07ba78c44a507fb2ffd50893d32b5b39f677614ed445031",
+            ),
+            (
+                "built on top of a curve with cofactor 4",
+                "c8377258aa6bd4da656657c2bd876a296df2141e26c33c3c152b779aeacb146a",
+            ),
+            (
+                "such as Bandersnatch.",
+                "f2870a8fb796a5bd5a333ea19ca9a42bfb8a2a23f7ca5936914d7e439081dd5d",
+            ),
+        ];
+        for (label, expected) in cases {
+            let p = BandersnatchDecaf::from_uniform_bytes(&Sha512::digest(label));
+            assert_eq!(p.encode(), from_hex(expected), "{label}");
+        }
+    }
+}
@@ -0,0 +1,942 @@
+//! The [BLS12-381] pairing-friendly curve.
+//!
+//! The curve equation is `y² = x³ + 4` over a 381-bit prime field, with
+//! cofactor `0x396c8c005555e1568c00aaab0000aaab`. G2 lives on its sextic twist
+//! over [`Fq2`], and the [`Bls12_381`] pairing maps into [`Fq12`].
+//!
+//! [BLS12-381]: https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-pairing-friendly-curves-11#section-4.2.1
+use crate::{
+    arithmetic::{U256, U384},
+    bits::BitIteratorBE,
+    curve::{
+        hashing::{
+            swu::SWUConfig,
+            wb::{IsogenyMap, WBConfig},
+        },
+        pairing::{
+            TwistType,
+            bls12::{Bls12, Bls12Config},
+        },
+        scalar_mul::{gls::GLSConfig, glv::GLVConfig},
+        sw::{
+            SWCurveConfig, affine::Affine, complete::CompleteFormulas, projective::Projective,
+            sw_double_and_add_affine,
+        },
+        traits::CurveConfig,
+    },
+    field::{
+        Fp384, FpBLS12, FpParams, LIMBS_384,
+        fp2::{Fp2, Fp2Config},
+        fp6::{Fp6, Fp6Config},
+        fp12::{Fp12, Fp12Config},
+        traits::AdditiveGroup,
+    },
+    fp_from_hex, fp_from_num, from_num,
+};
+
+/// Absolute value of the parameter `x = -0xd201000000010000` of the curve.
+pub const X: u64 = 0xd201_0000_0001_0000;
+
+/// Base field of the BLS12-381 curve.
+pub type Fq = Fp384<FqParam>;
+/// Parameters of the BLS12-381 base field.
+pub struct FqParam;
+
+impl FpParams<LIMBS_384> for FqParam {
+    const GENERATOR: Fq = fp_from_num!("2");
+    const MODULUS: U384 = from_num!(
+        "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787"
+    );
+    const ONE: Fq = fp_from_num!("1");
+    const TWO_ADICITY: u32 = 1;
+    const TWO_ADIC_ROOT_OF_UNITY: Fq = fp_from_hex!(
+        "1A0111EA397FE69A4B1BA7B6434BACD764774B84F38512BF6730D2A0F6B0F6241EABFFFEB153FFFFB9FEFFFFFFFFAAAA"
+    );
+    const ZERO: Fq = fp_from_num!("0");
+}
+
+/// Scalar field of the BLS12-381 curve.
+pub type Fr = FpBLS12;
+
+/// Affine point of the BLS12-381 G1 group.
+pub type G1Affine = Affine<G1Config>;
+/// Jacobian projective point of the BLS12-381 G1 group.
+pub type G1Projective = Projective<G1Config>;
+
+/// BLS12-381 G1 curve configuration.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct G1Config;
+
+impl CurveConfig for G1Config {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = &[0x8c00_aaab_0000_aaab, 0x396c_8c00_5555_e156];
+    const COFACTOR_INV: Fr = fp_from_num!(
+        "52435875175126190458656871551744051925719901746859129887267498875565241663483"
+    );
+}
+
+impl SWCurveConfig for G1Config {
+    const COEFF_A: Fq = fp_from_num!("0");
+    const COEFF_B: Fq = fp_from_num!("4");
+    const COMPLETE_FORMULAS: CompleteFormulas = CompleteFormulas::AIsZero;
+    const GENERATOR: G1Affine = Affine::new_unchecked(GENERATOR_X, GENERATOR_Y);
+
+    /// Checks that `φ(P) = -x² * P`, following [Scott].
+    ///
+    /// [Scott]: https://eprint.iacr.org/2021/1130.pdf
+    fn is_in_correct_subgroup_assuming_on_curve(item: &G1Affine) -> bool {
+        let x_square = u128::from(X) * u128::from(X);
+        -sw_double_and_add_affine(item, x_square) == Self::endomorphism_affine(item)
+    }
+
+    /// Multiplies by the effective cofactor `1 - x`, following
+    /// [RFC 9380, section 8.8.1](https://datatracker.ietf.org/doc/html/rfc9380#section-8.8.1).
+    fn clear_cofactor(item: &G1Affine) -> G1Affine {
+        sw_double_and_add_affine(item, X + 1).into()
+    }
+
+    fn mul_projective(base: &G1Projective, scalar: impl BitIteratorBE) -> G1Projective {
+        Self::glv_mul_bits(base, scalar)
+    }
+
+    fn mul_affine(base: &G1Affine, scalar: impl BitIteratorBE) -> G1Projective {
+        Self::glv_mul_bits(&(*base).into(), scalar)
+    }
+
+    fn msm_unchecked(bases: &[G1Affine], scalars: &[Fr]) -> G1Projective {
+        Self::glv_msm(bases, scalars)
+    }
+}
+
+impl GLVConfig for G1Config {
+    const ENDO_COEFF: Fq = fp_from_num!(
+        "793479390729215512621379701633421447060886740281060493010456487427281649075476305620758731620350"
+    );
+    const LAMBDA: Fr = fp_from_num!(
+        "52435875175126190479447740508185965837461563690374988244538805122978187051009"
+    );
+    const SCALAR_DECOMP_COEFFS: [(bool, U256); 4] = [
+        (true, from_num!("1")),
+        (false, from_num!("228988810152649578064853576960394133503")),
+        (true, from_num!("228988810152649578064853576960394133504")),
+        (true, from_num!("1")),
+    ];
+}
+
+impl WBConfig for G1Config {
+    type IsogenousCurve = G1IsoConfig;
+
+    const ISOGENY_MAP: IsogenyMap<'static, G1IsoConfig, Self> = IsogenyMap::new(
+        &ISOGENY_X_NUMERATOR,
+        &ISOGENY_X_DENOMINATOR,
+        &ISOGENY_Y_NUMERATOR,
+        &ISOGENY_Y_DENOMINATOR,
+    );
+}
+
+/// Curve `y² = x³ + a' * x + b'`, 11-isogenous to the BLS12-381 G1 curve, on
+/// which the Simplified SWU map of the `BLS12381G1_XMD:SHA-256_SSWU_*` suites
+/// of [RFC 9380, section 8.8.1](https://datatracker.ietf.org/doc/html/rfc9380#section-8.8.1)
+/// is computed.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct G1IsoConfig;
+
+impl CurveConfig for G1IsoConfig {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = G1Config::COFACTOR;
+    const COFACTOR_INV: Fr = G1Config::COFACTOR_INV;
+}
+
+impl SWCurveConfig for G1IsoConfig {
+    const COEFF_A: Fq = fp_from_hex!(
+        "144698A3B8E9433D693A02C96D4982B0EA985383EE66A8D8E8981AEFD881AC98936F8DA0E0F97F5CF428082D584C1D"
+    );
+    const COEFF_B: Fq = fp_from_hex!(
+        "12E2908D11688030018B12E8753EEE3B2016C1F0F24F4070A0B9C14FCEF35EF55A23215A316CEAA5D1CC48E98E172BE0"
+    );
+    /// Cofactor multiple of the point of smallest `x` coordinate, with even
+    /// `y` coordinate.
+    const GENERATOR: Affine<Self> = Affine::new_unchecked(
+        fp_from_hex!(
+            "AE5FD41616A4D7225698C83A6BD8880D0A7B5B45070830FE9C097D75E91D909D8E862ACE53FE2DFDA0E6009E76185D3"
+        ),
+        fp_from_hex!(
+            "10E004CD9E2B3FCA404F7E9FB1686A812F98AE73FE3640FF8E853E4BE07A5E3EC7B4096342C7B934470372506B7F44E0"
+        ),
+    );
+}
+
+impl SWUConfig for G1IsoConfig {
+    const ZETA: Fq = fp_from_num!("11");
+}
+
+/// Coefficients of `x_num` of the isogeny map.
+const ISOGENY_X_NUMERATOR: [Fq; 12] = [
+    fp_from_hex!(
+        "11A05F2B1E833340B809101DD99815856B303E88A2D7005FF2627B56CDB4E2C85610C2D5F2E62D6EAEAC1662734649B7"
+    ),
+    fp_from_hex!(
+        "17294ED3E943AB2F0588BAB22147A81C7C17E75B2F6A8417F565E33C70D1E86B4838F2A6F318C356E834EEF1B3CB83BB"
+    ),
+    fp_from_hex!(
+        "D54005DB97678EC1D1048C5D10A9A1BCE032473295983E56878E501EC68E25C958C3E3D2A09729FE0179F9DAC9EDCB0"
+    ),
+    fp_from_hex!(
+        "1778E7166FCC6DB74E0609D307E55412D7F5E4656A8DBF25F1B33289F1B330835336E25CE3107193C5B388641D9B6861"
+    ),
+    fp_from_hex!(
+        "E99726A3199F4436642B4B3E4118E5499DB995A1257FB3F086EEB65982FAC18985A286F301E77C451154CE9AC8895D9"
+    ),
+    fp_from_hex!(
+        "1630C3250D7313FF01D1201BF7A74AB5DB3CB17DD952799B9ED3AB9097E68F90A0870D2DCAE73D19CD13C1C66F652983"
+    ),
+    fp_from_hex!(
+        "D6ED6553FE44D296A3726C38AE652BFB11586264F0F8CE19008E218F9C86B2A8DA25128C1052ECADDD7F225A139ED84"
+    ),
+    fp_from_hex!(
+        "17B81E7701ABDBE2E8743884D1117E53356DE5AB275B4DB1A682C62EF0F2753339B7C8F8C8F475AF9CCB5618E3F0C88E"
+    ),
+    fp_from_hex!(
+        "80D3CF1F9A78FC47B90B33563BE990DC43B756CE79F5574A2C596C928C5D1DE4FA295F296B74E956D71986A8497E317"
+    ),
+    fp_from_hex!(
+        "169B1F8E1BCFA7C42E0C37515D138F22DD2ECB803A0C5C99676314BAF4BB1B7FA3190B2EDC0327797F241067BE390C9E"
+    ),
+    fp_from_hex!(
+        "10321DA079CE07E272D8EC09D2565B0DFA7DCCDDE6787F96D50AF36003B14866F69B771F8C285DECCA67DF3F1605FB7B"
+    ),
+    fp_from_hex!(
+        "6E08C248E260E70BD1E962381EDEE3D31D79D7E22C837BC23C0BF1BC24C6B68C24B1B80B64D391FA9C8BA2E8BA2D229"
+    ),
+];
+
+/// Coefficients of `x_den` of the isogeny map.
+const ISOGENY_X_DENOMINATOR: [Fq; 11] = [
+    fp_from_hex!(
+        "8CA8D548CFF19AE18B2E62F4BD3FA6F01D5EF4BA35B48BA9C9588617FC8AC62B558D681BE343DF8993CF9FA40D21B1C"
+    ),
+    fp_from_hex!(
+        "12561A5DEB559C4348B4711298E536367041E8CA0CF0800C0126C2588C48BF5713DAA8846CB026E9E5C8276EC82B3BFF"
+    ),
+    fp_from_hex!(
+        "B2962FE57A3225E8137E629BFF2991F6F89416F5A718CD1FCA64E00B11ACEACD6A3D0967C94FEDCFCC239BA5CB83E19"
+    ),
+    fp_from_hex!(
+        "3425581A58AE2FEC83AAFEF7C40EB545B08243F16B1655154CCA8ABC28D6FD04976D5243EECF5C4130DE8938DC62CD8"
+    ),
+    fp_from_hex!(
+        "13A8E162022914A80A6F1D5F43E7A07DFFDFC759A12062BB8D6B44E833B306DA9BD29BA81F35781D539D395B3532A21E"
+    ),
+    fp_from_hex!(
+        "E7355F8E4E667B955390F7F0506C6E9395735E9CE9CAD4D0A43BCEF24B8982F7400D24BC4228F11C02DF9A29F6304A5"
+    ),
+    fp_from_hex!(
+        "772CAACF16936190F3E0C63E0596721570F5799AF53A1894E2E073062AEDE9CEA73B3538F0DE06CEC2574496EE84A3A"
+    ),
+    fp_from_hex!(
+        "14A7AC2A9D64A8B230B3F5B074CF01996E7F63C21BCA68A81996E1CDF9822C580FA5B9489D11E2D311F7D99BBDCC5A5E"
+    ),
+    fp_from_hex!(
+        "A10ECF6ADA54F825E920B3DAFC7A3CCE07F8D1D7161366B74100DA67F39883503826692ABBA43704776EC3A79A1D641"
+    ),
+    fp_from_hex!(
+        "95FC13AB9E92AD4476D6E3EB3A56680F682B4EE96F7D03776DF533978F31C1593174E4B4B7865002D6384D168ECDD0A"
+    ),
+    fp_from_hex!("1"),
+];
+
+/// Coefficients of `y_num` of the isogeny map.
+const ISOGENY_Y_NUMERATOR: [Fq; 16] = [
+    fp_from_hex!(
+        "90D97C81BA24EE0259D1F094980DCFA11AD138E48A869522B52AF6C956543D3CD0C7AEE9B3BA3C2BE9845719707BB33"
+    ),
+    fp_from_hex!(
+        "134996A104EE5811D51036D776FB46831223E96C254F383D0F906343EB67AD34D6C56711962FA8BFE097E75A2E41C696"
+    ),
+    fp_from_hex!(
+        "CC786BAA966E66F4A384C86A3B49942552E2D658A31CE2C344BE4B91400DA7D26D521628B00523B8DFE240C72DE1F6"
+    ),
+    fp_from_hex!(
+        "1F86376E8981C217898751AD8746757D42AA7B90EEB791C09E4A3EC03251CF9DE405ABA9EC61DECA6355C77B0E5F4CB"
+    ),
+    fp_from_hex!(
+        "8CC03FDEFE0FF135CAF4FE2A21529C4195536FBE3CE50B879833FD221351ADC2EE7F8DC099040A841B6DAECF2E8FEDB"
+    ),
+    fp_from_hex!(
+        "16603FCA40634B6A2211E11DB8F0A6A074A7D0D4AFADB7BD76505C3D3AD5544E203F6326C95A807299B23AB13633A5F0"
+    ),
+    fp_from_hex!(
+        "4AB0B9BCFAC1BBCB2C977D027796B3CE75BB8CA2BE184CB5231413C4D634F3747A87AC2460F415EC961F8855FE9D6F2"
+    ),
+    fp_from_hex!(
+        "987C8D5333AB86FDE9926BD2CA6C674170A05BFE3BDD81FFD038DA6C26C842642F64550FEDFE935A15E4CA31870FB29"
+    ),
+    fp_from_hex!(
+        "9FC4018BD96684BE88C9E221E4DA1BB8F3ABD16679DC26C1E8B6E6A1F20CABE69D65201C78607A360370E577BDBA587"
+    ),
+    fp_from_hex!(
+        "E1BBA7A1186BDB5223ABDE7ADA14A23C42A0CA7915AF6FE06985E7ED1E4D43B9B3F7055DD4EBA6F2BAFAAEBCA731C30"
+    ),
+    fp_from_hex!(
+        "19713E47937CD1BE0DFD0B8F1D43FB93CD2FCBCB6CAF493FD1183E416389E61031BF3A5CCE3FBAFCE813711AD011C132"
+    ),
+    fp_from_hex!(
+        "18B46A908F36F6DEB918C143FED2EDCC523559B8AAF0C2462E6BFE7F911F643249D9CDF41B44D606CE07C8A4D0074D8E"
+    ),
+    fp_from_hex!(
+        "B182CAC101B9399D155096004F53F447AA7B12A3426B08EC02710E807B4633F06C851C1919211F20D4C04F00B971EF8"
+    ),
+    fp_from_hex!(
+        "245A394AD1ECA9B72FC00AE7BE315DC757B3B080D4C158013E6632D3C40659CC6CF90AD1C232A6442D9D3F5DB980133"
+    ),
+    fp_from_hex!(
+        "5C129645E44CF1102A159F748C4A3FC5E673D81D7E86568D9AB0F5D396A7CE46BA1049B6579AFB7866B1E715475224B"
+    ),
+    fp_from_hex!(
+        "15E6BE4E990F03CE4EA50B3B42DF2EB5CB181D8F84965A3957ADD4FA95AF01B2B665027EFEC01C7704B456BE69C8B604"
+    ),
+];
+
+/// Coefficients of `y_den` of the isogeny map.
+const ISOGENY_Y_DENOMINATOR: [Fq; 16] = [
+    fp_from_hex!(
+        "16112C4C3A9C98B252181140FAD0EAE9601A6DE578980BE6EEC3232B5BE72E7A07F3688EF60C206D01479253B03663C1"
+    ),
+    fp_from_hex!(
+        "1962D75C2381201E1A0CBD6C43C348B885C84FF731C4D59CA4A10356F453E01F78A4260763529E3532F6102C2E49A03D"
+    ),
+    fp_from_hex!(
+        "58DF3306640DA276FAAAE7D6E8EB15778C4855551AE7F310C35A5DD279CD2ECA6757CD636F96F891E2538B53DBF67F2"
+    ),
+    fp_from_hex!(
+        "16B7D288798E5395F20D23BF89EDB4D1D115C5DBDDBCD30E123DA489E726AF41727364F2C28297ADA8D26D98445F5416"
+    ),
+    fp_from_hex!(
+        "BE0E079545F43E4B00CC912F8228DDCC6D19C9F0F69BBB0542EDA0FC9DEC916A20B15DC0FD2EDEDDA39142311A5001D"
+    ),
+    fp_from_hex!(
+        "8D9E5297186DB2D9FB266EAAC783182B70152C65550D881C5ECD87B6F0F5A6449F38DB9DFA9CCE202C6477FAAF9B7AC"
+    ),
+    fp_from_hex!(
+        "166007C08A99DB2FC3BA8734ACE9824B5EECFDFA8D0CF8EF5DD365BC400A0051D5FA9C01A58B1FB93D1A1399126A775C"
+    ),
+    fp_from_hex!(
+        "16A3EF08BE3EA7EA03BCDDFABBA6FF6EE5A4375EFA1F4FD7FEB34FD206357132B920F5B00801DEE460EE415A15812ED9"
+    ),
+    fp_from_hex!(
+        "1866C8ED336C61231A1BE54FD1D74CC4F9FB0CE4C6AF5920ABC5750C4BF39B4852CFE2F7BB9248836B233D9D55535D4A"
+    ),
+    fp_from_hex!(
+        "167A55CDA70A6E1CEA820597D94A84903216F763E13D87BB5308592E7EA7D4FBC7385EA3D529B35E346EF48BB8913F55"
+    ),
+    fp_from_hex!(
+        "4D2F259EEA405BD48F010A01AD2911D9C6DD039BB61A6290E591B36E636A5C871A5C29F4F83060400F8B49CBA8F6AA8"
+    ),
+    fp_from_hex!(
+        "ACCBB67481D033FF5852C1E48C50C477F94FF8AEFCE42D28C0F9A88CEA7913516F968986F7EBBEA9684B529E2561092"
+    ),
+    fp_from_hex!(
+        "AD6B9514C767FE3C3613144B45F1496543346D98ADF02267D5CEEF9A00D9B8693000763E3B90AC11E99B138573345CC"
+    ),
+    fp_from_hex!(
+        "2660400EB2E4F3B628BDD0D53CD76F2BF565B94E72927C1CB748DF27942480E420517BD8714CC80D1FADC1326ED06F7"
+    ),
+    fp_from_hex!(
+        "E0FA1D816DDC03E6B24255E0D7819C171C40F65E273B853324EFCD6356CAA205CA2F570F13497804415473A1D634B8F"
+    ),
+    fp_from_hex!("1"),
+];
+
+/// `x` coordinate of the generator of the BLS12-381 G1 group.
+pub const GENERATOR_X: Fq = fp_from_hex!(
+    "17F1D3A73197D7942695638C4FA9AC0FC3688C4F9774B905A14E3A3F171BAC586C55E83FF97A1AEFFB3AF00ADB22C6BB"
+);
+
+/// `y` coordinate of the generator of the BLS12-381 G1 group.
+pub const GENERATOR_Y: Fq = fp_from_hex!(
+    "08B3F481E3AAA0F1A09E30ED741D8AE4FCF5E095D5D00AF600DB18CB2C04B3EDD03CC744A2888AE40CAA232946C5E7E1"
+);
+
+/// Quadratic extension `Fq[u] / (u² + 1)` of the BLS12-381 base field.
+pub type Fq2 = Fp2<Fq2Config>;
+/// Parameters of the BLS12-381 quadratic extension.
+pub struct Fq2Config;
+
+impl Fp2Config for Fq2Config {
+    type Fp = Fq;
+
+    const FROBENIUS_COEFF_FP2_C1: &'static [Fq] = &[
+        fp_from_num!("1"),
+        fp_from_num!(
+            "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559786"
+        ),
+    ];
+    const NONRESIDUE: Fq = fp_from_num!(
+        "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559786"
+    );
+
+    #[inline(always)]
+    fn mul_fp_by_nonresidue_in_place(fe: &mut Fq) -> &mut Fq {
+        fe.neg_in_place()
+    }
+}
+
+/// Cubic extension `Fq2[v] / (v³ - (1 + u))` of the BLS12-381 base field.
+pub type Fq6 = Fp6<Fq6Config>;
+/// Parameters of the BLS12-381 sextic extension.
+pub struct Fq6Config;
+
+impl Fp6Config for Fq6Config {
+    type Fp2Config = Fq2Config;
+
+    const FROBENIUS_COEFF_FP6_C1: &'static [Fq2] = &[
+        Fq2::new(fp_from_num!("1"), fp_from_num!("0")),
+        Fq2::new(
+            fp_from_num!("0"),
+            fp_from_num!(
+                "4002409555221667392624310435006688643935503118305586438271171395842971157480381377015405980053539358417135540939436"
+            ),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "793479390729215512621379701633421447060886740281060493010456487427281649075476305620758731620350"
+            ),
+            fp_from_num!("0"),
+        ),
+        Fq2::new(fp_from_num!("0"), fp_from_num!("1")),
+        Fq2::new(
+            fp_from_num!(
+                "4002409555221667392624310435006688643935503118305586438271171395842971157480381377015405980053539358417135540939436"
+            ),
+            fp_from_num!("0"),
+        ),
+        Fq2::new(
+            fp_from_num!("0"),
+            fp_from_num!(
+                "793479390729215512621379701633421447060886740281060493010456487427281649075476305620758731620350"
+            ),
+        ),
+    ];
+    const FROBENIUS_COEFF_FP6_C2: &'static [Fq2] = &[
+        Fq2::new(fp_from_num!("1"), fp_from_num!("0")),
+        Fq2::new(
+            fp_from_num!(
+                "4002409555221667392624310435006688643935503118305586438271171395842971157480381377015405980053539358417135540939437"
+            ),
+            fp_from_num!("0"),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "4002409555221667392624310435006688643935503118305586438271171395842971157480381377015405980053539358417135540939436"
+            ),
+            fp_from_num!("0"),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559786"
+            ),
+            fp_from_num!("0"),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "793479390729215512621379701633421447060886740281060493010456487427281649075476305620758731620350"
+            ),
+            fp_from_num!("0"),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "793479390729215512621379701633421447060886740281060493010456487427281649075476305620758731620351"
+            ),
+            fp_from_num!("0"),
+        ),
+    ];
+    const NONRESIDUE: Fq2 = Fq2::new(fp_from_num!("1"), fp_from_num!("1"));
+
+    /// Multiplies `fe` by `1 + u`, with additions only.
+    #[inline(always)]
+    fn mul_fp2_by_nonresidue_in_place(fe: &mut Fq2) -> &mut Fq2 {
+        *fe = Fq2::new(fe.c0 - fe.c1, fe.c0 + fe.c1);
+        fe
+    }
+}
+
+/// Quadratic extension `Fq6[w] / (w² - v)` of the BLS12-381 base field, the
+/// target field of the pairing.
+pub type Fq12 = Fp12<Fq12Config>;
+/// Parameters of the BLS12-381 dodecic extension.
+pub struct Fq12Config;
+
+impl Fp12Config for Fq12Config {
+    type Fp6Config = Fq6Config;
+
+    const FROBENIUS_COEFF_FP12_C1: &'static [Fq2] = &[
+        Fq2::new(fp_from_num!("1"), fp_from_num!("0")),
+        Fq2::new(
+            fp_from_num!(
+                "3850754370037169011952147076051364057158807420970682438676050522613628423219637725072182697113062777891589506424760"
+            ),
+            fp_from_num!(
+                "151655185184498381465642749684540099398075398968325446656007613510403227271200139370504932015952886146304766135027"
+            ),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "793479390729215512621379701633421447060886740281060493010456487427281649075476305620758731620351"
+            ),
+            fp_from_num!("0"),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "2973677408986561043442465346520108879172042883009249989176415018091420807192182638567116318576472649347015917690530"
+            ),
+            fp_from_num!(
+                "1028732146235106349975324479215795277384839936929757896155643118032610843298655225875571310552543014690878354869257"
+            ),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "793479390729215512621379701633421447060886740281060493010456487427281649075476305620758731620350"
+            ),
+            fp_from_num!("0"),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "3125332594171059424908108096204648978570118281977575435832422631601824034463382777937621250592425535493320683825557"
+            ),
+            fp_from_num!(
+                "877076961050607968509681729531255177986764537961432449499635504522207616027455086505066378536590128544573588734230"
+            ),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559786"
+            ),
+            fp_from_num!("0"),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "151655185184498381465642749684540099398075398968325446656007613510403227271200139370504932015952886146304766135027"
+            ),
+            fp_from_num!(
+                "3850754370037169011952147076051364057158807420970682438676050522613628423219637725072182697113062777891589506424760"
+            ),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "4002409555221667392624310435006688643935503118305586438271171395842971157480381377015405980053539358417135540939436"
+            ),
+            fp_from_num!("0"),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "1028732146235106349975324479215795277384839936929757896155643118032610843298655225875571310552543014690878354869257"
+            ),
+            fp_from_num!(
+                "2973677408986561043442465346520108879172042883009249989176415018091420807192182638567116318576472649347015917690530"
+            ),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "4002409555221667392624310435006688643935503118305586438271171395842971157480381377015405980053539358417135540939437"
+            ),
+            fp_from_num!("0"),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "877076961050607968509681729531255177986764537961432449499635504522207616027455086505066378536590128544573588734230"
+            ),
+            fp_from_num!(
+                "3125332594171059424908108096204648978570118281977575435832422631601824034463382777937621250592425535493320683825557"
+            ),
+        ),
+    ];
+}
+
+/// Affine point of the BLS12-381 G2 group.
+pub type G2Affine = Affine<G2Config>;
+/// Jacobian projective point of the BLS12-381 G2 group.
+pub type G2Projective = Projective<G2Config>;
+
+/// BLS12-381 G2 curve configuration, on the sextic twist
+/// `y² = x³ + 4 * (1 + u)` over [`Fq2`].
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct G2Config;
+
+impl CurveConfig for G2Config {
+    type BaseField = Fq2;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = &[
+        0xcf1c_38e3_1c72_38e5,
+        0x1616_ec6e_786f_0c70,
+        0x2153_7e29_3a66_91ae,
+        0xa628_f1cb_4d9e_82ef,
+        0xa68a_205b_2e5a_7ddf,
+        0xcd91_de45_4708_5aba,
+        0x091d_5079_2876_a202,
+        0x05d5_43a9_5414_e7f1,
+    ];
+    const COFACTOR_INV: Fr = fp_from_num!(
+        "26652489039290660355457965112010883481355318854675681319708643586776743290055"
+    );
+}
+
+impl SWCurveConfig for G2Config {
+    const COEFF_A: Fq2 = Fq2::ZERO;
+    const COEFF_B: Fq2 = Fq2::new(fp_from_num!("4"), fp_from_num!("4"));
+    const COMPLETE_FORMULAS: CompleteFormulas = CompleteFormulas::AIsZero;
+    const GENERATOR: G2Affine = Affine::new_unchecked(G2_GENERATOR_X, G2_GENERATOR_Y);
+
+    /// Checks that `ψ(P) = x * P`, following [Scott].
+    ///
+    /// [Scott]: https://eprint.iacr.org/2021/1130.pdf
+    fn is_in_correct_subgroup_assuming_on_curve(item: &G2Affine) -> bool {
+        -sw_double_and_add_affine(item, X) == Self::psi_affine(item)
+    }
+
+    fn mul_projective(base: &G2Projective, scalar: impl BitIteratorBE) -> G2Projective {
+        Self::gls_mul_bits(base, scalar)
+    }
+
+    fn mul_affine(base: &G2Affine, scalar: impl BitIteratorBE) -> G2Projective {
+        Self::gls_mul_bits(&(*base).into(), scalar)
+    }
+}
+
+impl GLSConfig for G2Config {
+    const PSI_COEFF_X: Fq2 = Fq2::new(
+        fp_from_num!("0"),
+        fp_from_num!(
+            "4002409555221667392624310435006688643935503118305586438271171395842971157480381377015405980053539358417135540939437"
+        ),
+    );
+    const PSI_COEFF_Y: Fq2 = Fq2::new(
+        fp_from_num!(
+            "2973677408986561043442465346520108879172042883009249989176415018091420807192182638567116318576472649347015917690530"
+        ),
+        fp_from_num!(
+            "1028732146235106349975324479215795277384839936929757896155643118032610843298655225875571310552543014690878354869257"
+        ),
+    );
+    const PSI_EIGENVALUE: u128 = X as u128;
+    const PSI_EIGENVALUE_IS_NEGATIVE: bool = true;
+}
+
+/// `x` coordinate of the generator of the BLS12-381 G2 group.
+pub const G2_GENERATOR_X: Fq2 = Fq2::new(
+    fp_from_num!(
+        "352701069587466618187139116011060144890029952792775240219908644239793785735715026873347600343865175952761926303160"
+    ),
+    fp_from_num!(
+        "3059144344244213709971259814753781636986470325476647558659373206291635324768958432433509563104347017837885763365758"
+    ),
+);
+
+/// `y` coordinate of the generator of the BLS12-381 G2 group.
+pub const G2_GENERATOR_Y: Fq2 = Fq2::new(
+    fp_from_num!(
+        "1985150602287291935568054521177171638300868978215655730859378665066344726373823718423869104263333984641494340347905"
+    ),
+    fp_from_num!(
+        "927553665492332455747201965776037880757740193453592970025027978793976877002675564980949289727957565575433344219582"
+    ),
+);
+
+/// Optimal Ate pairing over BLS12-381.
+pub type Bls12_381 = Bls12<Config>;
+
+/// BLS12-381 pairing configuration.
+pub struct Config;
+
+impl Bls12Config for Config {
+    type Fp = Fq;
+    type Fp12Config = Fq12Config;
+    type Fp2Config = Fq2Config;
+    type Fp6Config = Fq6Config;
+    type G1Config = G1Config;
+    type G2Config = G2Config;
+
+    const TWIST_TYPE: TwistType = TwistType::M;
+    const X: &'static [u64] = &[X];
+    const X_IS_NEGATIVE: bool = true;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        arithmetic::uint::from_str_hex,
+        curve::{
+            hashing::{HashToCurve, MapToCurve, MapToCurveBasedHasher, wb::WBMap},
+            sw::validation::validate_curve_config,
+            traits::{AffineRepr, CurveGroup, Pairing},
+        },
+        field::{
+            hashers::{DefaultFieldHasher, HashToField, expander::ExpanderXmd, test_messages},
+            traits::PrimeField,
+        },
+    };
+    use num_traits::Zero;
+    use sha2::Sha256;
+
+    type Hasher = MapToCurveBasedHasher<
+        G1Projective,
+        DefaultFieldHasher<ExpanderXmd<Sha256>>,
+        WBMap<G1Config>,
+    >;
+
+    fn point(x: &str, y: &str) -> G1Affine {
+        G1Affine::new(Fq::new(from_str_hex(x)), Fq::new(from_str_hex(y)))
+    }
+
+    #[test]
+    fn generator() {
+        let g = G1Affine::generator();
+        assert!(g.is_on_curve());
+        assert!(g.is_in_correct_subgroup_assuming_on_curve());
+        assert!(sw_double_and_add_affine(&g, Fr::MODULUS).is_zero());
+    }
+
+    #[test]
+    fn config() {
+        assert_eq!(validate_curve_config::<G1Config>(), Ok(()));
+        assert_eq!(validate_curve_config::<G1IsoConfig>(), Ok(()));
+        assert_eq!(validate_curve_config::<G2Config>(), Ok(()));
+    }
+
+    #[test]
+    fn double_generator() {
+        let expected = G1Affine::new(
+            fp_from_hex!(
+                "0572CBEA904D67468808C8EB50A9450C9721DB309128012543902D0AC358A62AE28F75BB8F1C7C42C39A8C5529BF0F4E"
+            ),
+            fp_from_hex!(
+                "166A9D8CABC673A322FDA673779D8E3822BA3ECB8670E461F73BB9021D5FD76A4C56D9D4CD16BD1BBA86881979749D28"
+            ),
+        );
+        assert_eq!(G1Affine::generator().mul_bigint(2u32).into_affine(), expected);
+    }
+
+    #[test]
+    fn subgroup_check_and_cofactor_clearing() {
+        // `(4, y)` is on the curve, but not in the prime-order subgroup.
+        let p = G1Affine::new_unchecked(
+            fp_from_num!("4"),
+            fp_from_hex!(
+                "0A989BADD40D6212B33CFFC3F3763E9BC760F988C9926B26DA9DD85E928483446346B8ED00E1DE5D5EA93E354ABE706C"
+            ),
+        );
+        assert!(p.is_on_curve());
+        assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+        assert!(!sw_double_and_add_affine(&p, Fr::MODULUS).is_zero());
+
+        let cleared = p.clear_cofactor();
+        assert!(cleared.is_in_correct_subgroup_assuming_on_curve());
+        assert!(sw_double_and_add_affine(&cleared, Fr::MODULUS).is_zero());
+        assert!(!cleared.is_zero());
+    }
+
+    #[test]
+    fn isogenous_curve() {
+        let g = Affine::<G1IsoConfig>::generator();
+        assert!(g.is_on_curve());
+        assert!(sw_double_and_add_affine(&g, Fr::MODULUS).is_zero());
+        assert!(WBMap::<G1Config>::check_parameters().is_ok());
+
+        // The isogeny is a group homomorphism, onto the prime-order subgroup
+        // for points of the prime-order subgroup.
+        let image = G1Config::ISOGENY_MAP.apply(g);
+        let double = G1Config::ISOGENY_MAP.apply(sw_double_and_add_affine(&g, 2u32).into());
+        assert!(image.is_on_curve());
+        assert!(image.is_in_correct_subgroup_assuming_on_curve());
+        assert_eq!(double, sw_double_and_add_affine(&image, 2u32).into_affine());
+    }
+
+    /// `BLS12381G1_XMD:SHA-256_SSWU_RO_` test vectors of
+    /// [RFC 9380, appendix J.9.1](https://datatracker.ietf.org/doc/html/rfc9380#appendix-J.9.1).
+    #[test]
+    fn hash_to_curve() {
+        let expected = [
+            (
+                "52926ADD2207B76CA4FA57A8734416C8DC95E24501772C814278700EED6D1E4E8CF62D9C09DB0FAC349612B759E79A1",
+                "8BA738453BFED09CB546DBB0783DBB3A5F1F566ED67BB6BE0E8C67E2E81A4CC68EE29813BB7994998F3EAE0C9C6A265",
+            ),
+            (
+                "3567BC5EF9C690C2AB2ECDF6A96EF1C139CC0B2F284DCA0A9A7943388A49A3AEE664BA5379A7655D3C68900BE2F6903",
+                "B9C15F3FE6E5CF4211F346271D7B01C8F3B28BE689C8429C85B67AF215533311F0B8DFAAA154FA6B88176C229F2885D",
+            ),
+            (
+                "11E0B079DEA29A68F0383EE94FED1B940995272407E3BB916BBF268C263DDD57A6A27200A784CBC248E84F357CE82D98",
+                "3A87AE2CAF14E8EE52E51FA2ED8EEFE80F02457004BA4D486D6AA1F517C0889501DC7413753F9599B099EBCBBD2D709",
+            ),
+            (
+                "15F68EAA693B95CCB85215DC65FA81038D69629F70AEEE0D0F677CF22285E7BF58D7CB86EEFE8F2E9BC3F8CB84FAC488",
+                "1807A1D50C29F430B8CAFC4F8638DFEEADF51211E1602A5F184443076715F91BB90A48BA1E370EDCE6AE1062F5E6DD38",
+            ),
+            (
+                "82AABAE8B7DEDB0E78AEB619AD3BFD9277A2F77BA7FAD20EF6AABDC6C31D19BA5A6D12283553294C1825C4B3CA2DCFE",
+                "5B84AE5A942248EEA39E1D91030458C40153F3B654AB7872D779AD1E942856A20C438E8D99BC8ABFBF74729CE1F7AC8",
+            ),
+        ];
+
+        let hasher = Hasher::new(b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_").unwrap();
+        for (msg, (x, y)) in test_messages().iter().zip(expected) {
+            let p = hasher.hash(msg).unwrap();
+            assert!(p.is_in_correct_subgroup_assuming_on_curve());
+            assert_eq!(p, point(x, y));
+        }
+    }
+
+    /// `BLS12381G1_XMD:SHA-256_SSWU_NU_` test vectors of
+    /// [RFC 9380, appendix J.9.2](https://datatracker.ietf.org/doc/html/rfc9380#appendix-J.9.2).
+    #[test]
+    fn encode_to_curve() {
+        let expected = [
+            (
+                "184BB665C37FF561A89EC2122DD343F20E0F4CBCAEC84E3C3052EA81D1834E192C426074B02ED3DCA4E7676CE4CE48BA",
+                "4407B8D35AF4DACC809927071FC0405218F1401A6D15AF775810E4E460064BCC9468BEEBA82FDC751BE70476C888BF3",
+            ),
+            (
+                "9769F3AB59BFD551D53A5F846B9984C59B97D6842B20A2C565BAA167945E3D026A3755B6345DF8EC7E6ACB6868AE6D",
+                "1532C00CF61AA3D0CE3E5AA20C3B531A2ABD2C770A790A2613818303C6B830FFC0ECF6C357AF3317B9575C567F11CD2C",
+            ),
+            (
+                "1974DBB8E6B5D20B84DF7E625E2FBFECB2CDB5F77D5EAE5FB2955E5CE7313CAE8364BC2FFF520A6C25619739C6BDCB6A",
+                "15F9897E11C6441EAA676DE141C8D83C37AAB8667173CBE1DFD6DE74D11861B961DCCEBCD9D289AC633455DFCC7013A3",
+            ),
+            (
+                "A7A047C4A8397B3446450642C2AC64D7239B61872C9AE7A59707A8F4F950F101E766AFE58223B3BFF3A19A7F754027C",
+                "1383AEBBA1E4327CCFF7CF9912BDA0DBC77DE048B71EF8C8A81111D71DC33C5E3AA6EDEE9CF6F5FE525D50CC50B77CC9",
+            ),
+            (
+                "E7A16A975904F131682EDBB03D9560D3E48214C9986BD50417A77108D13DC957500EDF96462A3D01E62DC6CD468EF11",
+                "AE89E677711D05C30A48D6D75E76CA9FB70FE06C6DD6FF988683D89CCDE29AC7D46C53BB97A59B1901ABF1DB66052DB",
+            ),
+        ];
+
+        let hasher = Hasher::new(b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_NU_").unwrap();
+        for (msg, (x, y)) in test_messages().iter().zip(expected) {
+            assert_eq!(hasher.encode(msg).unwrap(), point(x, y));
+        }
+    }
+
+    /// `u` values of the `BLS12381G2_XMD:SHA-256_SSWU_RO_` test vectors of
+    /// [RFC 9380, appendix J.10.1](https://datatracker.ietf.org/doc/html/rfc9380#appendix-J.10.1),
+    /// i.e. `hash_to_field` over [`Fq2`], as `[u0.c0, u0.c1, u1.c0, u1.c1]`.
+    #[test]
+    fn hash_to_field_fq2() {
+        let u = [
+            [
+                "03DBC2CCE174E91BA93CBB08F26B917F98194A2EA08D1CCE75B2B9CC9F21689D80BD79B594A613D0A68EB807DFDC1CF8",
+                "05A2ACEC64114845711A54199EA339ABD125BA38253B70A92C876DF10598BD1986B739CAD67961EB94F7076511B3B39A",
+                "02F99798E8A5ACDEED60D7E18E9120521BA1F47EC090984662846BC825DE191B5B7641148C0DBC237726A334473EEE94",
+                "145A81E418D4010CC027A68F14391B30074E89E60EE7A22F87217B2F6EB0C4B94C9115B436E6FA4607E95A98DE30A435",
+            ],
+            [
+                "15F7C0AA8F6B296AB5FF9C2C7581ADE64F4EE6F1BF18F55179FF44A2CF355FA53DD2A2158C5ECB17D7C52F63E7195771",
+                "01C8067BF4C0BA709AA8B9ABC3D1CEF589A4758E09EF53732D670FD8739A7274E111BA2FCAA71B3D33DF2A3A0C8529DD",
+                "187111D5E088B6B9ACFDFAD078C4DACF72DCD17CA17C82BE35E79F8C372A693F60A033B461D81B025864A0AD051A06E4",
+                "08B852331C96ED983E497EBC6DEE9B75E373D923B729194AF8E72A051EA586F3538A6EBB1E80881A082FA2B24DF9F566",
+            ],
+            [
+                "0313D9325081B415BFD4E5364EFAEF392ECF69B087496973B229303E1816D2080971470F7DA112C4EB43053130B785E1",
+                "062F84CB21ED89406890C051A0E8B9CF6C575CF6E8E18ECF63BA86826B0AE02548D83B483B79E48512B82A6C0686DF8F",
+                "1739123845406BAA7BE5C5DC74492051B6D42504DE008C635F3535BB831D478A341420E67DCC7B46B2E8CBA5379CCA97",
+                "01897665D9CB5DB16A27657760BBEA7951F67AD68F8D55F7113F24BA6DDD82CAEF240A9BFA627972279974894701D975",
+            ],
+            [
+                "025820CEFC7D06FD38DE7D8E370E0DA8A52498BE9B53CBA9927B2EF5C6DE1E12E12F188BBC7BC923864883C57E49E253",
+                "034147B77CE337A52E5948F66DB0BAB47A8D038E712123BB381899B6AB5AD20F02805601E6104C29DF18C254B8618C7B",
+                "0930315CAE1F9A6017C3F0C8F2314BAA130E1CF13F6532BFF0A8A1790CD70AF918088C3DB94BDA214E896E1543629795",
+                "10C4DF2CACF67EA3CB3108B00D4CBD0B3968031EBC8EAC4B1EBCEFE84D6B715FDE66BEF0219951ECE29D1FACC8A520EF",
+            ],
+            [
+                "190B513DA3E66FC9A3587B78C76D1D132B1152174D0B83E3C1114066392579A45824C5FA17649AB89299DDD4BDA54935",
+                "12AB625B0FE0EBD1367FE9FAC57BB1168891846039B4216B9D94007B674DE2D79126870E88AEEF54B2EC717A887DCF39",
+                "0E6A42010CF435FB5BACC156A585E1EA3294CC81D0CEB81924D95040298380B164F702275892CEDD81B62DE3ABA3F6B5",
+                "117D9A0DEFC57A33ED208428CB84E54C85A6840E7648480AE428838989D25D97A0AF8E3255BE62B25C2A85630D2DDDD8",
+            ],
+        ];
+
+        let fq2 = |c0, c1| Fq2::new(Fq::new(from_str_hex(c0)), Fq::new(from_str_hex(c1)));
+        let field_hasher = <DefaultFieldHasher<ExpanderXmd<Sha256>> as HashToField<Fq2>>::new(
+            b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_",
+        );
+        for (msg, [u00, u01, u10, u11]) in test_messages().iter().zip(u) {
+            let [v0, v1]: [Fq2; 2] = field_hasher.hash_to_field(msg);
+            assert_eq!(v0, fq2(u00, u01));
+            assert_eq!(v1, fq2(u10, u11));
+        }
+    }
+
+    #[test]
+    fn g2_generator() {
+        let g = G2Affine::generator();
+        assert!(g.is_on_curve());
+        assert!(g.is_in_correct_subgroup_assuming_on_curve());
+        assert!(sw_double_and_add_affine(&g, Fr::MODULUS).is_zero());
+    }
+
+    #[test]
+    fn pairing_generators() {
+        let expected = Fq12::new(
+            Fq6::new(
+                Fq2::new(
+                    fp_from_num!(
+                        "2819105605953691245277803056322684086884703000473961065716485506033588504203831029066448642358042597501014294104502"
+                    ),
+                    fp_from_num!(
+                        "1323968232986996742571315206151405965104242542339680722164220900812303524334628370163366153839984196298685227734799"
+                    ),
+                ),
+                Fq2::new(
+                    fp_from_num!(
+                        "2987335049721312504428602988447616328830341722376962214011674875969052835043875658579425548512925634040144704192135"
+                    ),
+                    fp_from_num!(
+                        "3879723582452552452538684314479081967502111497413076598816163759028842927668327542875108457755966417881797966271311"
+                    ),
+                ),
+                Fq2::new(
+                    fp_from_num!(
+                        "261508182517997003171385743374653339186059518494239543139839025878870012614975302676296704930880982238308326681253"
+                    ),
+                    fp_from_num!(
+                        "231488992246460459663813598342448669854473942105054381511346786719005883340876032043606739070883099647773793170614"
+                    ),
+                ),
+            ),
+            Fq6::new(
+                Fq2::new(
+                    fp_from_num!(
+                        "3993582095516422658773669068931361134188738159766715576187490305611759126554796569868053818105850661142222948198557"
+                    ),
+                    fp_from_num!(
+                        "1074773511698422344502264006159859710502164045911412750831641680783012525555872467108249271286757399121183508900634"
+                    ),
+                ),
+                Fq2::new(
+                    fp_from_num!(
+                        "2727588299083545686739024317998512740561167011046940249988557419323068809019137624943703910267790601287073339193943"
+                    ),
+                    fp_from_num!(
+                        "493643299814437640914745677854369670041080344349607504656543355799077485536288866009245028091988146107059514546594"
+                    ),
+                ),
+                Fq2::new(
+                    fp_from_num!(
+                        "734401332196641441839439105942623141234148957972407782257355060229193854324927417865401895596108124443575283868655"
+                    ),
+                    fp_from_num!(
+                        "2348330098288556420918672502923664952620152483128593484301759394583320358354186482723629999370241674973832318248497"
+                    ),
+                ),
+            ),
+        );
+        assert_eq!(Bls12_381::pairing(G1Affine::generator(), G2Affine::generator()).0, expected);
+    }
+}
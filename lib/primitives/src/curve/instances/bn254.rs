@@ -0,0 +1,598 @@
+//! The [BN254] pairing-friendly curve, also known as alt_bn128.
+//!
+//! The curve equation is `y² = x³ + 3` over a 254-bit prime field, with
+//! cofactor `1`. G2 lives on its sextic twist over [`Fq2`], and the
+//! [`Bn254`] pairing maps into [`Fq12`].
+//!
+//! [BN254]: https://eips.ethereum.org/EIPS/eip-196
+pub mod precompiles;
+
+use crate::{
+    arithmetic::U256,
+    bits::BitIteratorBE,
+    curve::{
+        hashing::svdw::SvdWConfig,
+        pairing::{
+            TwistType,
+            bn::{Bn, BnConfig},
+        },
+        scalar_mul::{gls::GLSConfig, glv::GLVConfig},
+        sw::{
+            SWCurveConfig, affine::Affine, complete::CompleteFormulas, projective::Projective,
+            sw_double_and_add_affine,
+        },
+        traits::CurveConfig,
+    },
+    field::{
+        Fp256, FpBN256, FpParams, LIMBS_256,
+        fp2::{Fp2, Fp2Config},
+        fp6::{Fp6, Fp6Config},
+        fp12::{Fp12, Fp12Config},
+        traits::AdditiveGroup,
+    },
+    fp_from_hex, fp_from_num, from_num,
+};
+
+/// Parameter `x` of the curve.
+pub const X: u64 = 4965661367192848881;
+
+/// Base field of the BN254 curve.
+pub type Fq = Fp256<FqParam>;
+/// Parameters of the BN254 base field.
+pub struct FqParam;
+
+impl FpParams<LIMBS_256> for FqParam {
+    const GENERATOR: Fq = fp_from_num!("3");
+    const MODULUS: U256 =
+        from_num!("21888242871839275222246405745257275088696311157297823662689037894645226208583");
+    const ONE: Fq = fp_from_num!("1");
+    const TWO_ADICITY: u32 = 1;
+    const TWO_ADIC_ROOT_OF_UNITY: Fq =
+        fp_from_hex!("30644E72E131A029B85045B68181585D97816A916871CA8D3C208C16D87CFD46");
+    const ZERO: Fq = fp_from_num!("0");
+}
+
+/// Scalar field of the BN254 curve.
+pub type Fr = FpBN256;
+
+/// Affine point of the BN254 G1 group.
+pub type G1Affine = Affine<G1Config>;
+/// Jacobian projective point of the BN254 G1 group.
+pub type G1Projective = Projective<G1Config>;
+
+/// BN254 G1 curve configuration.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct G1Config;
+
+impl CurveConfig for G1Config {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = &[1];
+    const COFACTOR_INV: Fr = fp_from_num!("1");
+}
+
+impl SWCurveConfig for G1Config {
+    const COEFF_A: Fq = fp_from_num!("0");
+    const COEFF_B: Fq = fp_from_num!("3");
+    const COMPLETE_FORMULAS: CompleteFormulas = CompleteFormulas::AIsZero;
+    const GENERATOR: G1Affine = Affine::new_unchecked(GENERATOR_X, GENERATOR_Y);
+
+    fn mul_projective(base: &G1Projective, scalar: impl BitIteratorBE) -> G1Projective {
+        Self::glv_mul_bits(base, scalar)
+    }
+
+    fn mul_affine(base: &G1Affine, scalar: impl BitIteratorBE) -> G1Projective {
+        Self::glv_mul_bits(&(*base).into(), scalar)
+    }
+
+    fn msm_unchecked(bases: &[G1Affine], scalars: &[Fr]) -> G1Projective {
+        Self::glv_msm(bases, scalars)
+    }
+}
+
+impl GLVConfig for G1Config {
+    const ENDO_COEFF: Fq =
+        fp_from_num!("2203960485148121921418603742825762020974279258880205651966");
+    const LAMBDA: Fr = fp_from_num!("4407920970296243842393367215006156084916469457145843978461");
+    const SCALAR_DECOMP_COEFFS: [(bool, U256); 4] = [
+        (true, from_num!("9931322734385697763")),
+        (false, from_num!("147946756881789319000765030803803410728")),
+        (true, from_num!("147946756881789319010696353538189108491")),
+        (true, from_num!("9931322734385697763")),
+    ];
+}
+
+impl SvdWConfig for G1Config {
+    const ZETA: Fq = fp_from_num!("1");
+    const C1: Fq = fp_from_num!("4");
+    const C2: Fq = fp_from_num!(
+        "10944121435919637611123202872628637544348155578648911831344518947322613104291"
+    );
+    const C3: Fq = fp_from_num!("8815841940592487685674414971303048083897117035520822607866");
+    const C4: Fq = fp_from_num!(
+        "7296080957279758407415468581752425029565437052432607887563012631548408736189"
+    );
+}
+
+/// `x` coordinate of the generator of the BN254 G1 group.
+pub const GENERATOR_X: Fq = fp_from_num!("1");
+
+/// `y` coordinate of the generator of the BN254 G1 group.
+pub const GENERATOR_Y: Fq = fp_from_num!("2");
+
+/// Quadratic extension `Fq[u] / (u² + 1)` of the BN254 base field.
+pub type Fq2 = Fp2<Fq2Config>;
+/// Parameters of the BN254 quadratic extension.
+pub struct Fq2Config;
+
+impl Fp2Config for Fq2Config {
+    type Fp = Fq;
+
+    const FROBENIUS_COEFF_FP2_C1: &'static [Fq] = &[
+        fp_from_num!("1"),
+        fp_from_num!(
+            "21888242871839275222246405745257275088696311157297823662689037894645226208582"
+        ),
+    ];
+    const NONRESIDUE: Fq = fp_from_num!(
+        "21888242871839275222246405745257275088696311157297823662689037894645226208582"
+    );
+
+    #[inline(always)]
+    fn mul_fp_by_nonresidue_in_place(fe: &mut Fq) -> &mut Fq {
+        fe.neg_in_place()
+    }
+}
+
+/// Cubic extension `Fq2[v] / (v³ - (9 + u))` of the BN254 base field.
+pub type Fq6 = Fp6<Fq6Config>;
+/// Parameters of the BN254 sextic extension.
+pub struct Fq6Config;
+
+impl Fp6Config for Fq6Config {
+    type Fp2Config = Fq2Config;
+
+    const FROBENIUS_COEFF_FP6_C1: &'static [Fq2] = &[
+        Fq2::new(fp_from_num!("1"), fp_from_num!("0")),
+        Fq2::new(
+            fp_from_num!(
+                "21575463638280843010398324269430826099269044274347216827212613867836435027261"
+            ),
+            fp_from_num!(
+                "10307601595873709700152284273816112264069230130616436755625194854815875713954"
+            ),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "21888242871839275220042445260109153167277707414472061641714758635765020556616"
+            ),
+            fp_from_num!("0"),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "3772000881919853776433695186713858239009073593817195771773381919316419345261"
+            ),
+            fp_from_num!(
+                "2236595495967245188281701248203181795121068902605861227855261137820944008926"
+            ),
+        ),
+        Fq2::new(
+            fp_from_num!("2203960485148121921418603742825762020974279258880205651966"),
+            fp_from_num!("0"),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "18429021223477853657660792034369865839114504446431234726392080002137598044644"
+            ),
+            fp_from_num!(
+                "9344045779998320333812420223237981029506012124075525679208581902008406485703"
+            ),
+        ),
+    ];
+    const FROBENIUS_COEFF_FP6_C2: &'static [Fq2] = &[
+        Fq2::new(fp_from_num!("1"), fp_from_num!("0")),
+        Fq2::new(
+            fp_from_num!(
+                "2581911344467009335267311115468803099551665605076196740867805258568234346338"
+            ),
+            fp_from_num!(
+                "19937756971775647987995932169929341994314640652964949448313374472400716661030"
+            ),
+        ),
+        Fq2::new(
+            fp_from_num!("2203960485148121921418603742825762020974279258880205651966"),
+            fp_from_num!("0"),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "5324479202449903542726783395506214481928257762400643279780343368557297135718"
+            ),
+            fp_from_num!(
+                "16208900380737693084919495127334387981393726419856888799917914180988844123039"
+            ),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "21888242871839275220042445260109153167277707414472061641714758635765020556616"
+            ),
+            fp_from_num!("0"),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "13981852324922362344252311234282257507216387789820983642040889267519694726527"
+            ),
+            fp_from_num!(
+                "7629828391165209371577384193250820201684255241773809077146787135900891633097"
+            ),
+        ),
+    ];
+    const NONRESIDUE: Fq2 = Fq2::new(fp_from_num!("9"), fp_from_num!("1"));
+
+    /// Multiplies `fe` by `9 + u`, with additions only.
+    #[inline(always)]
+    fn mul_fp2_by_nonresidue_in_place(fe: &mut Fq2) -> &mut Fq2 {
+        let mut c0 = fe.c0;
+        c0.double_in_place().double_in_place().double_in_place();
+        c0 += &fe.c0;
+        c0 -= &fe.c1;
+        let mut c1 = fe.c1;
+        c1.double_in_place().double_in_place().double_in_place();
+        c1 += &fe.c1;
+        c1 += &fe.c0;
+        *fe = Fq2::new(c0, c1);
+        fe
+    }
+}
+
+/// Quadratic extension `Fq6[w] / (w² - v)` of the BN254 base field, the
+/// target field of the pairing.
+pub type Fq12 = Fp12<Fq12Config>;
+/// Parameters of the BN254 dodecic extension.
+pub struct Fq12Config;
+
+impl Fp12Config for Fq12Config {
+    type Fp6Config = Fq6Config;
+
+    const FROBENIUS_COEFF_FP12_C1: &'static [Fq2] = &[
+        Fq2::new(fp_from_num!("1"), fp_from_num!("0")),
+        Fq2::new(
+            fp_from_num!(
+                "8376118865763821496583973867626364092589906065868298776909617916018768340080"
+            ),
+            fp_from_num!(
+                "16469823323077808223889137241176536799009286646108169935659301613961712198316"
+            ),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "21888242871839275220042445260109153167277707414472061641714758635765020556617"
+            ),
+            fp_from_num!("0"),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "11697423496358154304825782922584725312912383441159505038794027105778954184319"
+            ),
+            fp_from_num!(
+                "303847389135065887422783454877609941456349188919719272345083954437860409601"
+            ),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "21888242871839275220042445260109153167277707414472061641714758635765020556616"
+            ),
+            fp_from_num!("0"),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "3321304630594332808241809054958361220322477375291206261884409189760185844239"
+            ),
+            fp_from_num!(
+                "5722266937896532885780051958958348231143373700109372999374820235121374419868"
+            ),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "21888242871839275222246405745257275088696311157297823662689037894645226208582"
+            ),
+            fp_from_num!("0"),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "13512124006075453725662431877630910996106405091429524885779419978626457868503"
+            ),
+            fp_from_num!(
+                "5418419548761466998357268504080738289687024511189653727029736280683514010267"
+            ),
+        ),
+        Fq2::new(
+            fp_from_num!("2203960485148121921418603742825762020974279258880205651966"),
+            fp_from_num!("0"),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "10190819375481120917420622822672549775783927716138318623895010788866272024264"
+            ),
+            fp_from_num!(
+                "21584395482704209334823622290379665147239961968378104390343953940207365798982"
+            ),
+        ),
+        Fq2::new(
+            fp_from_num!("2203960485148121921418603742825762020974279258880205651967"),
+            fp_from_num!("0"),
+        ),
+        Fq2::new(
+            fp_from_num!(
+                "18566938241244942414004596690298913868373833782006617400804628704885040364344"
+            ),
+            fp_from_num!(
+                "16165975933942742336466353786298926857552937457188450663314217659523851788715"
+            ),
+        ),
+    ];
+}
+
+/// Affine point of the BN254 G2 group.
+pub type G2Affine = Affine<G2Config>;
+/// Jacobian projective point of the BN254 G2 group.
+pub type G2Projective = Projective<G2Config>;
+
+/// BN254 G2 curve configuration, on the sextic twist
+/// `y² = x³ + 3 / (9 + u)` over [`Fq2`].
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct G2Config;
+
+impl CurveConfig for G2Config {
+    type BaseField = Fq2;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = &[
+        0x345f_2299_c0f9_fa8d,
+        0x06ce_ecda_572a_2489,
+        0xb850_45b6_8181_585e,
+        0x3064_4e72_e131_a029,
+    ];
+    const COFACTOR_INV: Fr = fp_from_num!(
+        "10944121435919637613327163357776759465618812564592884533313067514031822496649"
+    );
+}
+
+impl SWCurveConfig for G2Config {
+    const COEFF_A: Fq2 = Fq2::ZERO;
+    const COEFF_B: Fq2 = Fq2::new(
+        fp_from_num!(
+            "19485874751759354771024239261021720505790618469301721065564631296452457478373"
+        ),
+        fp_from_num!("266929791119991161246907387137283842545076965332900288569378510910307636690"),
+    );
+    const COMPLETE_FORMULAS: CompleteFormulas = CompleteFormulas::AIsZero;
+    const GENERATOR: G2Affine = Affine::new_unchecked(G2_GENERATOR_X, G2_GENERATOR_Y);
+
+    /// Checks that `ψ(P) = 6x² * P`, with the equivalent relation
+    /// `(x + 1) * P + ψ(x * P) + ψ²(x * P) = ψ³(2x * P)` of [El Housni,
+    /// Guillevic and Piellard].
+    ///
+    /// [El Housni, Guillevic and Piellard]: https://eprint.iacr.org/2022/352.pdf
+    fn is_in_correct_subgroup_assuming_on_curve(item: &G2Affine) -> bool {
+        let x_p = sw_double_and_add_affine(item, X);
+        let psi_x_p = Self::psi(&x_p);
+        x_p + item + psi_x_p + Self::psi(&psi_x_p) == Self::psi(&Self::psi(&psi_x_p.double()))
+    }
+
+    fn mul_projective(base: &G2Projective, scalar: impl BitIteratorBE) -> G2Projective {
+        Self::gls_mul_bits(base, scalar)
+    }
+
+    fn mul_affine(base: &G2Affine, scalar: impl BitIteratorBE) -> G2Projective {
+        Self::gls_mul_bits(&(*base).into(), scalar)
+    }
+}
+
+impl GLSConfig for G2Config {
+    const PSI_COEFF_X: Fq2 = <Config as BnConfig>::TWIST_MUL_BY_Q_X;
+    const PSI_COEFF_Y: Fq2 = <Config as BnConfig>::TWIST_MUL_BY_Q_Y;
+    const PSI_EIGENVALUE: u128 = 6 * (X as u128) * (X as u128);
+    const PSI_EIGENVALUE_IS_NEGATIVE: bool = false;
+}
+
+/// `x` coordinate of the generator of the BN254 G2 group.
+pub const G2_GENERATOR_X: Fq2 = Fq2::new(
+    fp_from_num!("10857046999023057135944570762232829481370756359578518086990519993285655852781"),
+    fp_from_num!("11559732032986387107991004021392285783925812861821192530917403151452391805634"),
+);
+
+/// `y` coordinate of the generator of the BN254 G2 group.
+pub const G2_GENERATOR_Y: Fq2 = Fq2::new(
+    fp_from_num!("8495653923123431417604973247489272438418190587263600148770280649306958101930"),
+    fp_from_num!("4082367875863433681332203403145435568316851327593401208105741076214120093531"),
+);
+
+/// Optimal Ate pairing over BN254.
+pub type Bn254 = Bn<Config>;
+
+/// BN254 pairing configuration.
+pub struct Config;
+
+impl BnConfig for Config {
+    type Fp = Fq;
+    type Fp12Config = Fq12Config;
+    type Fp2Config = Fq2Config;
+    type Fp6Config = Fq6Config;
+    type G1Config = G1Config;
+    type G2Config = G2Config;
+
+    const ATE_LOOP_COUNT: &'static [i8] = &[
+        0, 0, 0, 1, 0, 1, 0, -1, 0, 0, -1, 0, 0, 0, 1, 0, 0, -1, 0, -1, 0, 0, 0, 1, 0, -1, 0, 0, 0,
+        0, -1, 0, 0, 1, 0, -1, 0, 0, 1, 0, 0, 0, 0, 0, -1, 0, 0, -1, 0, 1, 0, -1, 0, 0, 0, -1, 0,
+        -1, 0, 0, 0, 1, 0, -1, 0, 1,
+    ];
+    const TWIST_MUL_BY_Q_X: Fq2 = Fq2::new(
+        fp_from_num!(
+            "21575463638280843010398324269430826099269044274347216827212613867836435027261"
+        ),
+        fp_from_num!(
+            "10307601595873709700152284273816112264069230130616436755625194854815875713954"
+        ),
+    );
+    const TWIST_MUL_BY_Q_Y: Fq2 = Fq2::new(
+        fp_from_num!(
+            "2821565182194536844548159561693502659359617185244120367078079554186484126554"
+        ),
+        fp_from_num!(
+            "3505843767911556378687030309984248845540243509899259641013678093033130930403"
+        ),
+    );
+    const TWIST_TYPE: TwistType = TwistType::D;
+    const X: &'static [u64] = &[X];
+    const X_IS_NEGATIVE: bool = false;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        curve::{
+            hashing::{HashToCurve, MapToCurve, MapToCurveBasedHasher, sgn0, svdw::SvdWMap},
+            sw::validation::validate_curve_config,
+            traits::{AffineRepr, CurveGroup, Pairing},
+        },
+        field::{
+            hashers::{DefaultFieldHasher, expander::ExpanderXmd, test_messages},
+            traits::{Field, PrimeField},
+        },
+    };
+    use num_traits::Zero;
+    use proptest::prelude::*;
+    use sha2::Sha256;
+
+    #[test]
+    fn generator() {
+        let g = G1Affine::generator();
+        assert!(g.is_on_curve());
+        assert!(g.is_in_correct_subgroup_assuming_on_curve());
+        assert!(sw_double_and_add_affine(&g, Fr::MODULUS).is_zero());
+    }
+
+    #[test]
+    fn config() {
+        assert_eq!(validate_curve_config::<G1Config>(), Ok(()));
+        assert_eq!(validate_curve_config::<G2Config>(), Ok(()));
+    }
+
+    #[test]
+    fn double_generator() {
+        let expected = G1Affine::new(
+            fp_from_num!(
+                "1368015179489954701390400359078579693043519447331113978918064868415326638035"
+            ),
+            fp_from_num!(
+                "9918110051302171585080402603319702774565515993150576347155970296011118125764"
+            ),
+        );
+        assert_eq!(G1Affine::generator().mul_bigint(2u32).into_affine(), expected);
+    }
+
+    #[test]
+    fn svdw_map() {
+        assert!(SvdWMap::<G1Config>::check_parameters().is_ok());
+
+        // `0` and the roots of `1 - u⁴ * g(Z)²` are exceptional cases of the map.
+        let exceptional = Fq::from(4u8).inverse().unwrap().sqrt().unwrap();
+        for u in [Fq::zero(), Fq::ONE, exceptional, -exceptional] {
+            assert!(SvdWMap::<G1Config>::map_to_curve(u).unwrap().is_on_curve());
+        }
+
+        proptest!(|(u: u128)| {
+            let u = Fq::from(u);
+            let p = SvdWMap::<G1Config>::map_to_curve(u).unwrap();
+            prop_assert!(p.is_on_curve());
+            prop_assert_eq!(sgn0(&p.y), sgn0(&u));
+        });
+    }
+
+    #[test]
+    fn hash_to_curve() {
+        let hasher = MapToCurveBasedHasher::<
+            G1Projective,
+            DefaultFieldHasher<ExpanderXmd<Sha256>>,
+            SvdWMap<G1Config>,
+        >::new(b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_RO_")
+        .unwrap();
+
+        for msg in test_messages() {
+            let p = hasher.hash(&msg).unwrap();
+            assert!(p.is_on_curve());
+            assert!(!p.is_zero());
+            assert_eq!(p, hasher.hash(&msg).unwrap());
+            assert_ne!(p, hasher.encode(&msg).unwrap());
+        }
+    }
+
+    #[test]
+    fn g2_generator() {
+        let g = G2Affine::generator();
+        assert!(g.is_on_curve());
+        assert!(g.is_in_correct_subgroup_assuming_on_curve());
+        assert!(sw_double_and_add_affine(&g, Fr::MODULUS).is_zero());
+    }
+
+    #[test]
+    fn pairing_generators() {
+        let expected = Fq12::new(
+            Fq6::new(
+                Fq2::new(
+                    fp_from_num!(
+                        "17264119758069723980713015158403419364912226240334615592005620718956030922389"
+                    ),
+                    fp_from_num!(
+                        "1300711225518851207585954685848229181392358478699795190245709208408267917898"
+                    ),
+                ),
+                Fq2::new(
+                    fp_from_num!(
+                        "8894217292938489450175280157304813535227569267786222825147475294561798790624"
+                    ),
+                    fp_from_num!(
+                        "1829859855596098509359522796979920150769875799037311140071969971193843357227"
+                    ),
+                ),
+                Fq2::new(
+                    fp_from_num!(
+                        "4968700049505451466697923764727215585075098085662966862137174841375779106779"
+                    ),
+                    fp_from_num!(
+                        "12814315002058128940449527172080950701976819591738376253772993495204862218736"
+                    ),
+                ),
+            ),
+            Fq6::new(
+                Fq2::new(
+                    fp_from_num!(
+                        "4233474252585134102088637248223601499779641130562251948384759786370563844606"
+                    ),
+                    fp_from_num!(
+                        "9420544134055737381096389798327244442442230840902787283326002357297404128074"
+                    ),
+                ),
+                Fq2::new(
+                    fp_from_num!(
+                        "13457906610892676317612909831857663099224588803620954529514857102808143524905"
+                    ),
+                    fp_from_num!(
+                        "5122435115068592725432309312491733755581898052459744089947319066829791570839"
+                    ),
+                ),
+                Fq2::new(
+                    fp_from_num!(
+                        "8891987925005301465158626530377582234132838601606565363865129986128301774627"
+                    ),
+                    fp_from_num!(
+                        "440796048150724096437130979851431985500142692666486515369083499585648077975"
+                    ),
+                ),
+            ),
+        );
+        assert_eq!(Bn254::pairing(G1Affine::generator(), G2Affine::generator()).0, expected);
+    }
+}
@@ -0,0 +1,182 @@
+//! The BN254 precompiles of the EVM: `ECADD` (`0x06`), `ECMUL` (`0x07`) and
+//! `ECPAIRING` (`0x08`), from [EIP-196] and [EIP-197].
+//!
+//! Each function takes the raw input of the precompile and returns its raw
+//! output, or an error where the precompile fails. Like the EVM, `ECADD` and
+//! `ECMUL` pad short inputs with zeros and ignore trailing bytes, while
+//! `ECPAIRING` rejects inputs whose length is not a multiple of `192`.
+//!
+//! Points use the big-endian encoding of [`Affine::from_ethereum_bytes`], with
+//! `(0, 0)` as the point at infinity. Coordinates are rejected when they are
+//! not reduced, or when the point is not on the curve or, for G2, not in the
+//! prime-order subgroup.
+//!
+//! [EIP-196]: https://eips.ethereum.org/EIPS/eip-196
+//! [EIP-197]: https://eips.ethereum.org/EIPS/eip-197
+//! [`Affine::from_ethereum_bytes`]: crate::curve::sw::affine::Affine::from_ethereum_bytes
+use super::{Bn254, Fr, G1Affine, G1Projective, G2Affine};
+use crate::{
+    curve::{
+        sw::encoding::EncodingError,
+        traits::{CurveGroup, Pairing},
+    },
+    field::traits::PrimeField,
+};
+use alloc::vec::Vec;
+use num_traits::Zero;
+
+/// Length of an encoded G1 point.
+pub const G1_LEN: usize = 64;
+/// Length of an encoded G2 point.
+pub const G2_LEN: usize = 128;
+/// Length of an encoded scalar.
+pub const SCALAR_LEN: usize = 32;
+/// Length of an input pair of `ECPAIRING`.
+pub const PAIR_LEN: usize = G1_LEN + G2_LEN;
+
+/// Returns the first `N` bytes of `input`, padded with zeros.
+fn padded<const N: usize>(input: &[u8]) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    let len = input.len().min(N);
+    bytes[..len].copy_from_slice(&input[..len]);
+    bytes
+}
+
+/// Encodes `point` as the output of `ECADD` and `ECMUL`.
+fn encode(point: G1Projective) -> [u8; G1_LEN] {
+    let mut bytes = [0u8; G1_LEN];
+    bytes.copy_from_slice(&point.into_affine().to_ethereum_bytes());
+    bytes
+}
+
+/// `ECADD` precompile, at address `0x06`.
+///
+/// Adds the two G1 points of `input`.
+///
+/// # Errors
+///
+/// * If a point of `input` is invalid.
+pub fn ecadd(input: &[u8]) -> Result<[u8; G1_LEN], EncodingError> {
+    let input = padded::<{ 2 * G1_LEN }>(input);
+    let p = G1Affine::from_ethereum_bytes(&input[..G1_LEN])?;
+    let q = G1Affine::from_ethereum_bytes(&input[G1_LEN..])?;
+    Ok(encode(p + q))
+}
+
+/// `ECMUL` precompile, at address `0x07`.
+///
+/// Multiplies the G1 point of `input` by the big-endian 256-bit scalar that
+/// follows it. The scalar need not be reduced.
+///
+/// # Errors
+///
+/// * If the point of `input` is invalid.
+pub fn ecmul(input: &[u8]) -> Result<[u8; G1_LEN], EncodingError> {
+    let input = padded::<{ G1_LEN + SCALAR_LEN }>(input);
+    let p = G1Affine::from_ethereum_bytes(&input[..G1_LEN])?;
+    let scalar = Fr::from_be_bytes_mod_order(&input[G1_LEN..]);
+    Ok(encode(p * scalar))
+}
+
+/// `ECPAIRING` precompile, at address `0x08`.
+///
+/// Checks whether `e(p_1, q_1) * ... * e(p_k, q_k) = 1` for the pairs of G1
+/// and G2 points of `input`, and returns `1` as a big-endian 256-bit word if
+/// so, `0` otherwise. An empty input yields `1`.
+///
+/// # Errors
+///
+/// * If the length of `input` is not a multiple of [`PAIR_LEN`].
+/// * If a point of `input` is invalid.
+pub fn ecpairing(input: &[u8]) -> Result<[u8; 32], EncodingError> {
+    if input.len() % PAIR_LEN != 0 {
+        return Err(EncodingError::InvalidLength);
+    }
+
+    let (g1, g2): (Vec<_>, Vec<_>) = input
+        .chunks(PAIR_LEN)
+        .map(|pair| {
+            let p = G1Affine::from_ethereum_bytes(&pair[..G1_LEN])?;
+            let q = G2Affine::from_ethereum_bytes(&pair[G1_LEN..])?;
+            Ok((p, q))
+        })
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .unzip();
+
+    let mut output = [0u8; 32];
+    output[31] = u8::from(Bn254::multi_pairing(g1, g2).is_zero());
+    Ok(output)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_utils::from_hex;
+    use serde_json::Value;
+
+    type Precompile<const N: usize> = fn(&[u8]) -> Result<[u8; N], EncodingError>;
+
+    /// Checks the outputs of `precompile` on the successful calls of
+    /// `fixtures`, in the format of the go-ethereum precompile test data.
+    fn check_success<const N: usize>(precompile: Precompile<N>, fixtures: &str) {
+        let fixtures: Vec<Value> = serde_json::from_str(fixtures).unwrap();
+        for case in fixtures {
+            let name = case["Name"].as_str().unwrap();
+            let input = from_hex(case["Input"].as_str().unwrap());
+            let expected = from_hex(case["Expected"].as_str().unwrap());
+            assert_eq!(precompile(&input).map(Vec::from), Ok(expected), "{name}");
+        }
+    }
+
+    /// Checks that `precompile` fails on the inputs of `fixtures`.
+    ///
+    /// A failing precompile call only reverts in the EVM, so the kind of the
+    /// error is not checked.
+    fn check_failure<const N: usize>(precompile: Precompile<N>, fixtures: &str) {
+        let fixtures: Vec<Value> = serde_json::from_str(fixtures).unwrap();
+        for case in fixtures {
+            let name = case["Name"].as_str().unwrap();
+            let input = from_hex(case["Input"].as_str().unwrap());
+            assert!(precompile(&input).is_err(), "{name}");
+        }
+    }
+
+    #[test]
+    fn ecadd_fixtures() {
+        check_success(ecadd, include_str!("testdata/bn256Add.json"));
+        check_success(ecadd, include_str!("testdata/edge-cases/bn256Add.json"));
+        check_failure(ecadd, include_str!("testdata/edge-cases/fail-bn256Add.json"));
+    }
+
+    #[test]
+    fn ecmul_fixtures() {
+        check_success(ecmul, include_str!("testdata/bn256ScalarMul.json"));
+        check_success(ecmul, include_str!("testdata/edge-cases/bn256ScalarMul.json"));
+        check_failure(ecmul, include_str!("testdata/edge-cases/fail-bn256ScalarMul.json"));
+    }
+
+    #[test]
+    fn ecpairing_fixtures() {
+        check_success(ecpairing, include_str!("testdata/bn256Pairing.json"));
+        check_success(ecpairing, include_str!("testdata/edge-cases/bn256Pairing.json"));
+        check_failure(ecpairing, include_str!("testdata/edge-cases/fail-bn256Pairing.json"));
+    }
+
+    #[test]
+    fn errors() {
+        let mut point = [0u8; G1_LEN];
+        point[31] = 1;
+        point[63] = 3;
+        assert_eq!(ecadd(&point), Err(EncodingError::NotOnCurve));
+        assert_eq!(ecmul(&point), Err(EncodingError::NotOnCurve));
+
+        // `x = p`.
+        let modulus = from_hex("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47");
+        point[..32].copy_from_slice(&modulus);
+        assert_eq!(ecadd(&point), Err(EncodingError::NonCanonicalCoordinate));
+
+        assert_eq!(ecpairing(&[0u8; PAIR_LEN - 1]), Err(EncodingError::InvalidLength));
+        assert_eq!(ecpairing(&[0u8; 2 * PAIR_LEN]).unwrap()[31], 1);
+    }
+}
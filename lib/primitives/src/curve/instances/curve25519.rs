@@ -0,0 +1,308 @@
+//! [Curve25519] Montgomery curve, birationally equivalent to Ed25519.
+//!
+//! The curve equation is `y² = x³ + 486662 * x² + x` over the prime field
+//! `p = 2^255 - 19`, with cofactor `8`.
+//!
+//! [Curve25519]: https://datatracker.ietf.org/doc/html/rfc7748#section-4.1
+use super::ed25519::{Ed25519Config, Fq, Fr};
+use crate::{
+    arithmetic::{U256, bigint::BigInteger},
+    curve::{
+        hashing::elligator2::Elligator2Config,
+        montgomery::{MontCurveConfig, affine::Affine},
+        traits::CurveConfig,
+    },
+    field::traits::PrimeField,
+    fp_from_num,
+};
+
+/// Affine point of the Curve25519 curve.
+pub type MontgomeryAffine = Affine<Curve25519Config>;
+
+/// Curve25519 curve configuration.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Curve25519Config;
+
+impl CurveConfig for Curve25519Config {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = &[8];
+    const COFACTOR_INV: Fr = fp_from_num!(
+        "2713877091499598330239944961141122840321418634767465352250731601857045344121"
+    );
+}
+
+impl MontCurveConfig for Curve25519Config {
+    type TECurveConfig = Ed25519Config;
+
+    const COEFF_A: Fq = fp_from_num!("486662");
+    const COEFF_B: Fq = fp_from_num!("1");
+    const GENERATOR: MontgomeryAffine = Affine::new_unchecked(GENERATOR_X, GENERATOR_Y);
+    /// `sqrt(-486664)`, mapping the generator to the Ed25519 base point.
+    const TE_X_SCALE: Fq = fp_from_num!(
+        "51042569399160536130206135233146329284152202253034631822681833788666877215207"
+    );
+
+    #[inline(always)]
+    fn a24() -> Fq {
+        fp_from_num!("121665")
+    }
+}
+
+impl Elligator2Config for Curve25519Config {
+    const ZETA: Fq = fp_from_num!("2");
+    /// `sqrt(-486664)` with `sgn0 = 0`, as required by the
+    /// `edwards25519_XMD:SHA-512_ELL2_*` suites of
+    /// [RFC 9380, section 6.8.2](https://datatracker.ietf.org/doc/html/rfc9380#section-6.8.2).
+    const RATIONAL_MAP_SCALE: Fq = fp_from_num!(
+        "6853475219497561581579357271197624642482790079785650197046958215289687604742"
+    );
+}
+
+/// `x` coordinate of the Curve25519 base point, i.e. `9`.
+pub const GENERATOR_X: Fq = fp_from_num!("9");
+
+/// `y` coordinate of the Curve25519 base point.
+pub const GENERATOR_Y: Fq =
+    fp_from_num!("14781619447589544791020593568409986887264606134616475288964881837755586237401");
+
+/// Size in bytes of X25519 scalars and `x` coordinates.
+pub const X25519_BYTES: usize = 32;
+
+/// The [X25519] function of RFC 7748.
+///
+/// Clamps `scalar`, decodes the little-endian `x` coordinate `x` (ignoring
+/// its most significant bit and accepting non-canonical values) and returns
+/// the little-endian encoding of the `x` coordinate of `[scalar] x`.
+///
+/// [X25519]: https://datatracker.ietf.org/doc/html/rfc7748#section-5
+#[must_use]
+pub fn x25519(scalar: [u8; X25519_BYTES], x: [u8; X25519_BYTES]) -> [u8; X25519_BYTES] {
+    let mut scalar = scalar;
+    scalar[0] &= 248;
+    scalar[31] &= 127;
+    scalar[31] |= 64;
+
+    let mut x = x;
+    x[31] &= 127;
+
+    let scalar = U256::from_bytes_le(&scalar);
+    let x = Fq::new(U256::from_bytes_le(&x));
+    let result = Curve25519Config::mul_x(x, scalar).into_bigint().into_bytes_le();
+
+    let mut out = [0u8; X25519_BYTES];
+    out.copy_from_slice(&result);
+    out
+}
+
+/// Computes the X25519 public key of `scalar`, i.e. `x25519(scalar, 9)`.
+#[must_use]
+pub fn x25519_base(scalar: [u8; X25519_BYTES]) -> [u8; X25519_BYTES] {
+    let mut base = [0u8; X25519_BYTES];
+    base[0] = 9;
+    x25519(scalar, base)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        arithmetic::uint::from_str_hex,
+        curve::{
+            hashing::{HashToCurve, MapToCurve, MapToCurveBasedHasher, elligator2::Elligator2Map},
+            instances::ed25519::{EdwardsAffine, EdwardsProjective},
+            montgomery::montgomery_ladder,
+            traits::{AffineRepr, CurveGroup},
+        },
+        field::hashers::{DefaultFieldHasher, expander::ExpanderXmd, test_messages},
+        test_utils::from_hex,
+    };
+    use sha2::Sha512;
+
+    type Hasher = MapToCurveBasedHasher<
+        EdwardsProjective,
+        DefaultFieldHasher<ExpanderXmd<Sha512>>,
+        Elligator2Map<Curve25519Config>,
+    >;
+
+    fn edwards_point(x: &str, y: &str) -> EdwardsAffine {
+        EdwardsAffine::new(Fq::new(from_str_hex(x)), Fq::new(from_str_hex(y)))
+    }
+
+    fn bytes(s: &str) -> [u8; X25519_BYTES] {
+        from_hex(s).try_into().unwrap()
+    }
+
+    #[test]
+    fn generator() {
+        let g = MontgomeryAffine::generator();
+        assert!(g.is_on_curve());
+        assert_eq!(g.to_twisted_edwards(), Some(EdwardsAffine::generator()));
+        assert_eq!(MontgomeryAffine::from_twisted_edwards(&EdwardsAffine::generator()), g);
+    }
+
+    #[test]
+    fn ladder_matches_edwards_scalar_mul() {
+        let g = MontgomeryAffine::generator();
+        for k in [0u32, 1, 2, 3, 8, 255, 1 << 20] {
+            let expected = MontgomeryAffine::from_twisted_edwards(
+                &EdwardsAffine::generator().mul_bigint(k).into_affine(),
+            );
+            let x = if expected.is_zero() { Fq::from(0u8) } else { expected.x };
+            assert_eq!(g.mul_x(k), x);
+            assert_eq!(montgomery_ladder::<Curve25519Config>(g.x, U256::from(k)), x);
+        }
+    }
+
+    #[test]
+    fn twisted_edwards_round_trip() {
+        let mut p = EdwardsAffine::generator().into_group();
+        for _ in 0..8 {
+            let mont = MontgomeryAffine::from_twisted_edwards(&p.into_affine());
+            assert!(mont.is_on_curve());
+            assert_eq!(mont.to_twisted_edwards(), Some(p.into_affine()));
+            p += EdwardsAffine::generator();
+        }
+    }
+
+    /// Test vectors of RFC 7748, section 5.2.
+    #[test]
+    fn rfc7748_x25519() {
+        let cases = [
+            (
+                "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+                "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c",
+                "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552",
+            ),
+            (
+                "4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d",
+                "e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493",
+                "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957",
+            ),
+        ];
+        for (scalar, x, expected) in cases {
+            assert_eq!(x25519(bytes(scalar), bytes(x)), bytes(expected));
+        }
+    }
+
+    /// Iterated test vectors of RFC 7748, section 5.2.
+    #[test]
+    fn rfc7748_x25519_iterated() {
+        let mut k = [0u8; X25519_BYTES];
+        k[0] = 9;
+        let mut x = k;
+
+        for i in 1..=1000 {
+            (k, x) = (x25519(k, x), k);
+            if i == 1 {
+                assert_eq!(
+                    k,
+                    bytes("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079")
+                );
+            }
+        }
+        assert_eq!(k, bytes("684cf59ba83309552800ef566f2f4d3c1c3887c49360e3875f2eb94d99532c51"));
+    }
+
+    /// Diffie-Hellman test vectors of RFC 7748, section 6.1.
+    #[test]
+    fn rfc7748_diffie_hellman() {
+        let alice = bytes("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        let bob = bytes("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+
+        let alice_public = x25519_base(alice);
+        let bob_public = x25519_base(bob);
+        assert_eq!(
+            alice_public,
+            bytes("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+        );
+        assert_eq!(
+            bob_public,
+            bytes("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f")
+        );
+
+        let shared = bytes("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+        assert_eq!(x25519(alice, bob_public), shared);
+        assert_eq!(x25519(bob, alice_public), shared);
+    }
+
+    #[test]
+    fn elligator2() {
+        assert!(Elligator2Map::<Curve25519Config>::check_parameters().is_ok());
+        for u in [0u64, 1, 2, 3, 1 << 40] {
+            let u = Fq::from(u);
+            let point = Elligator2Map::<Curve25519Config>::map_to_montgomery(u).unwrap();
+            assert!(point.is_on_curve());
+            assert!(Elligator2Map::<Curve25519Config>::map_to_curve(u).unwrap().is_on_curve());
+        }
+    }
+
+    /// `edwards25519_XMD:SHA-512_ELL2_RO_` test vectors of
+    /// [RFC 9380, appendix J.5.1](https://datatracker.ietf.org/doc/html/rfc9380#appendix-J.5.1).
+    #[test]
+    fn hash_to_curve() {
+        let expected = [
+            (
+                "3C3DA6925A3C3C268448DCABB47CCDE5439559D9599646A8260E47B1E4822FC6",
+                "9A6C8561A0B22BEF63124C588CE4C62EA83A3C899763AF26D795302E115DC21",
+            ),
+            (
+                "608040B42285CC0D72CBB3985C6B04C935370C7361F4B7FBDB1AE7F8C1A8ECAD",
+                "1A8395B88338F22E435BBD301183E7F20A5F9DE643F11882FB237F88268A5531",
+            ),
+            (
+                "6D7FABF47A2DC03FE7D47F7DDDD21082C5FB8F86743CD020F3FB147D57161472",
+                "53060A3D140E7FBCDA641ED3CF42C88A75411E648A1ADD71217F70EA8EC561A6",
+            ),
+            (
+                "5FB0B92ACEDD16F3BCB0EF83F5C7B7A9466B5F1E0D8D217421878EA3686F8524",
+                "2ECA15E355FCFA39D2982F67DDB0EEA138E2994F5956ED37B7F72EEA5E89D2F7",
+            ),
+            (
+                "EFCFDE5898A839B00997FBE40D2EBE950BC81181AFBD5CD6B9618AA336C1E8C",
+                "6DC2FC04F266C5C27F236A80B14F92CCD051EF1FF027F26A07F8C0F327D8F995",
+            ),
+        ];
+
+        let hasher = Hasher::new(b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_RO_").unwrap();
+        for (msg, (x, y)) in test_messages().iter().zip(expected) {
+            let p = hasher.hash(msg).unwrap();
+            assert!(p.is_in_correct_subgroup_assuming_on_curve());
+            assert_eq!(p, edwards_point(x, y));
+        }
+    }
+
+    /// `edwards25519_XMD:SHA-512_ELL2_NU_` test vectors of
+    /// [RFC 9380, appendix J.5.2](https://datatracker.ietf.org/doc/html/rfc9380#appendix-J.5.2).
+    #[test]
+    fn encode_to_curve() {
+        let expected = [
+            (
+                "1FF2B70ECF862799E11B7AE744E3489AA058CE805DD323A936375A84695E76DA",
+                "222E314D04A4D5725E9F2AFF9FB2A6B69EF375A1214EB19021CEAB2D687F0F9B",
+            ),
+            (
+                "5F13CC69C891D86927EB37BD4AFC6672360007C63F68A33AB423A3AA040FD2A8",
+                "67732D50F9A26F73111DD1ED5DBA225614E538599DB58BA30AAEA1F5C827FA42",
+            ),
+            (
+                "1DD2FEFCE934ECFD7AAE6EC998DE088D7DD03316AA1847198AECF699BA6613F1",
+                "2F8A6C24DD1ADDE73909CADA6A4A137577B0F179D336685C4A955A0A8E1A86FB",
+            ),
+            (
+                "35FBDC5143E8A97AFD3096F2B843E07DF72E15BFCA2EAF6879BF97C5D3362F73",
+                "2AF6FF6EF5EBBA128B0774F4296CB4C2279A074658B083B8DCCA91F57A603450",
+            ),
+            (
+                "6E5E1F37E99345887FC12111575FC1C3E36DF4B289B8759D23AF14D774B66BFF",
+                "2C90C3D39EB18FF291D33441B35F3262CDD307162CC97C31BFCC7A4245891A37",
+            ),
+        ];
+
+        let hasher = Hasher::new(b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_NU_").unwrap();
+        for (msg, (x, y)) in test_messages().iter().zip(expected) {
+            assert_eq!(hasher.encode(msg).unwrap(), edwards_point(x, y));
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! [Ed25519] twisted Edwards curve, birationally equivalent to Curve25519.
+//!
+//! The curve equation is `-x² + y² = 1 + d * x² * y²` over the prime field
+//! `p = 2^255 - 19`, with cofactor `8`.
+//!
+//! [Ed25519]: https://datatracker.ietf.org/doc/html/rfc8032#section-5.1
+use crate::{
+    arithmetic::U256,
+    curve::{
+        te::{TECurveConfig, affine::Affine, projective::Projective},
+        traits::CurveConfig,
+    },
+    field::{Fp256, FpParams, LIMBS_256},
+    fp_from_hex, fp_from_num, from_num,
+};
+
+/// Base field of the Ed25519 curve.
+pub type Fq = Fp256<FqParam>;
+/// Parameters of the Ed25519 base field.
+pub struct FqParam;
+
+impl FpParams<LIMBS_256> for FqParam {
+    const GENERATOR: Fq = fp_from_num!("2");
+    const MODULUS: U256 =
+        from_num!("57896044618658097711785492504343953926634992332820282019728792003956564819949");
+    const ONE: Fq = fp_from_num!("1");
+    const TWO_ADICITY: u32 = 2;
+    const TWO_ADIC_ROOT_OF_UNITY: Fq =
+        fp_from_hex!("2B8324804FC1DF0B2B4D00993DFBD7A72F431806AD2FE478C4EE1B274A0EA0B0");
+    const ZERO: Fq = fp_from_num!("0");
+}
+
+/// Scalar field of the Ed25519 prime-order subgroup.
+pub type Fr = Fp256<FrParam>;
+/// Parameters of the Ed25519 scalar field.
+pub struct FrParam;
+
+impl FpParams<LIMBS_256> for FrParam {
+    const GENERATOR: Fr = fp_from_num!("2");
+    const MODULUS: U256 =
+        from_num!("7237005577332262213973186563042994240857116359379907606001950938285454250989");
+    const ONE: Fr = fp_from_num!("1");
+    const TWO_ADICITY: u32 = 2;
+    const TWO_ADIC_ROOT_OF_UNITY: Fr =
+        fp_from_hex!("94A7310E07981E77D3D6D60ABC1C27A0EF0565342CE83FEBE8775DFEBBE07D4");
+    const ZERO: Fr = fp_from_num!("0");
+}
+
+/// Affine point of the Ed25519 curve.
+pub type EdwardsAffine = Affine<Ed25519Config>;
+/// Extended projective point of the Ed25519 curve.
+pub type EdwardsProjective = Projective<Ed25519Config>;
+
+/// Ed25519 curve configuration.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Ed25519Config;
+
+impl CurveConfig for Ed25519Config {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = &[8];
+    const COFACTOR_INV: Fr = fp_from_num!(
+        "2713877091499598330239944961141122840321418634767465352250731601857045344121"
+    );
+}
+
+impl TECurveConfig for Ed25519Config {
+    /// `a = -1`
+    const COEFF_A: Fq = fp_from_num!(
+        "57896044618658097711785492504343953926634992332820282019728792003956564819948"
+    );
+    const COEFF_D: Fq = fp_from_num!(
+        "37095705934669439343138083508754565189542113879843219016388785533085940283555"
+    );
+    const GENERATOR: EdwardsAffine = Affine::new_unchecked(GENERATOR_X, GENERATOR_Y);
+
+    #[inline(always)]
+    fn mul_by_a(elem: Fq) -> Fq {
+        -elem
+    }
+}
+
+/// `x` coordinate of the Ed25519 base point `B`.
+pub const GENERATOR_X: Fq =
+    fp_from_num!("15112221349535400772501151409588531511454012693041857206046113283949847762202");
+
+/// `y` coordinate of the Ed25519 base point `B`, i.e. `4/5`.
+pub const GENERATOR_Y: Fq =
+    fp_from_num!("46316835694926478169428394003475163141307993866256225615783033603165251855960");
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        curve::traits::{AffineRepr, CurveGroup},
+        field::traits::PrimeField,
+        from_hex,
+    };
+    use num_traits::Zero;
+
+    #[test]
+    fn generator() {
+        let g = EdwardsAffine::generator();
+        assert!(g.is_on_curve());
+        assert!(g.is_in_correct_subgroup_assuming_on_curve());
+        assert!(g.mul_bigint(Fr::MODULUS).is_zero());
+    }
+
+    #[test]
+    fn double_generator() {
+        let expected = EdwardsAffine::new(
+            fp_from_num!(
+                "24727413235106541002554574571675588834622768167397638456726423682521233608206"
+            ),
+            fp_from_num!(
+                "15549675580280190176352668710449542251549572066445060580507079593062643049417"
+            ),
+        );
+        assert_eq!(EdwardsAffine::generator().mul_bigint(2u32).into_affine(), expected);
+    }
+
+    /// Public key derivation of the first test vector of RFC 8032, section 7.1.
+    #[test]
+    fn rfc8032_public_key() {
+        // Clamped lower half of `SHA-512(9d61b19d...7f60)`.
+        let secret: U256 =
+            from_hex!("4FE94D9006F020A5A3C080D96827FFFD3C010AC0F12E7A42CB33284F86837C30");
+        let public = EdwardsAffine::generator().mul_bigint(secret).into_affine();
+
+        let expected = EdwardsAffine::new(
+            fp_from_num!(
+                "38815646466658113194383306759739515082307681141926459231621296960732224964046"
+            ),
+            fp_from_num!(
+                "11903303657706407974989296177215005343713679411332034699907763981919547054807"
+            ),
+        );
+        assert_eq!(public, expected);
+    }
+}
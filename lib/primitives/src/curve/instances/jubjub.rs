@@ -0,0 +1,107 @@
+//! [Jubjub] twisted Edwards curve, defined over the scalar field of BLS12-381.
+//!
+//! The curve equation is `-x² + y² = 1 + d * x² * y²`, with `d = -(10240 /
+//! 10241)` and cofactor `8`.
+//!
+//! [Jubjub]: https://zips.z.cash/protocol/protocol.pdf#jubjub
+use crate::{
+    arithmetic::U256,
+    curve::{
+        te::{TECurveConfig, affine::Affine, projective::Projective},
+        traits::CurveConfig,
+    },
+    field::{Fp256, FpBLS12, FpParams, LIMBS_256},
+    fp_from_hex, fp_from_num, from_num,
+};
+
+/// Base field of the Jubjub curve, i.e. the scalar field of BLS12-381.
+pub type Fq = FpBLS12;
+
+/// Scalar field of the Jubjub prime-order subgroup.
+pub type Fr = Fp256<FrParam>;
+/// Parameters of the Jubjub scalar field.
+pub struct FrParam;
+
+impl FpParams<LIMBS_256> for FrParam {
+    const GENERATOR: Fr = fp_from_num!("3");
+    const MODULUS: U256 =
+        from_num!("6554484396890773809930967563523245729705921265872317281365359162392183254199");
+    const ONE: Fr = fp_from_num!("1");
+    const TWO_ADICITY: u32 = 1;
+    const TWO_ADIC_ROOT_OF_UNITY: Fr =
+        fp_from_hex!("E7DB4EA6533AFA906673B0101343B00A6682093CCC81082D0970E5ED6F72CB6");
+    const ZERO: Fr = fp_from_num!("0");
+}
+
+/// Affine point of the Jubjub curve.
+pub type EdwardsAffine = Affine<JubjubConfig>;
+/// Extended projective point of the Jubjub curve.
+pub type EdwardsProjective = Projective<JubjubConfig>;
+
+/// Jubjub curve configuration.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct JubjubConfig;
+
+impl CurveConfig for JubjubConfig {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = &[8];
+    const COFACTOR_INV: Fr =
+        fp_from_num!("819310549611346726241370945440405716213240158234039660170669895299022906775");
+}
+
+impl TECurveConfig for JubjubConfig {
+    /// `a = -1`
+    const COEFF_A: Fq = fp_from_num!(
+        "52435875175126190479447740508185965837690552500527637822603658699938581184512"
+    );
+    const COEFF_D: Fq = fp_from_num!(
+        "19257038036680949359750312669786877991949435402254120286184196891950884077233"
+    );
+    const GENERATOR: EdwardsAffine = Affine::new_unchecked(GENERATOR_X, GENERATOR_Y);
+
+    #[inline(always)]
+    fn mul_by_a(elem: Fq) -> Fq {
+        -elem
+    }
+}
+
+/// `x` coordinate of the generator of the Jubjub prime-order subgroup.
+pub const GENERATOR_X: Fq =
+    fp_from_num!("8076246640662884909881801758704306714034609987455869804520522091855516602923");
+
+/// `y` coordinate of the generator of the Jubjub prime-order subgroup.
+pub const GENERATOR_Y: Fq =
+    fp_from_num!("13262374693698910701929044844600465831413122818447359594527400194675274060458");
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        curve::traits::{AffineRepr, CurveGroup},
+        field::traits::PrimeField,
+    };
+    use num_traits::Zero;
+
+    #[test]
+    fn generator() {
+        let g = EdwardsAffine::generator();
+        assert!(g.is_on_curve());
+        assert!(g.is_in_correct_subgroup_assuming_on_curve());
+        assert!(g.mul_bigint(Fr::MODULUS).is_zero());
+    }
+
+    #[test]
+    fn double_generator() {
+        let expected = EdwardsAffine::new(
+            fp_from_num!(
+                "29927994414980659866747158113976867771786823169860303107907009997724489194957"
+            ),
+            fp_from_num!(
+                "462950763047385854792912911337076492277172577361226262929952084963852328241"
+            ),
+        );
+        assert_eq!(EdwardsAffine::generator().mul_bigint(2u32).into_affine(), expected);
+    }
+}
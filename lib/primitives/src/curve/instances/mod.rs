@@ -0,0 +1,11 @@
+//! This module contains configurations of commonly used elliptic curves.
+pub mod bandersnatch;
+pub mod bls12_381;
+pub mod bn254;
+pub mod curve25519;
+pub mod ed25519;
+pub mod jubjub;
+pub mod pallas;
+pub mod ristretto255;
+pub mod secp256k1;
+pub mod vesta;
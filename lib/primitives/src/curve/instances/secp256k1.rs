@@ -0,0 +1,375 @@
+//! [secp256k1] short Weierstrass curve, used by Bitcoin and Ethereum.
+//!
+//! The curve equation is `y² = x³ + 7` over the prime field
+//! `p = 2^256 - 2^32 - 977`, with cofactor `1`.
+//!
+//! [secp256k1]: https://www.secg.org/sec2-v2.pdf
+use crate::{
+    arithmetic::U256,
+    bits::BitIteratorBE,
+    curve::{
+        hashing::{
+            swu::SWUConfig,
+            wb::{IsogenyMap, WBConfig},
+        },
+        scalar_mul::glv::GLVConfig,
+        sw::{SWCurveConfig, affine, complete::CompleteFormulas, projective},
+        traits::CurveConfig,
+    },
+    field::{Fp256, FpParams, LIMBS_256},
+    fp_from_hex, fp_from_num, from_num,
+    polynomial::domain::EcfftConfig,
+};
+
+/// Base field of the secp256k1 curve.
+pub type Fq = Fp256<FqParam>;
+/// Parameters of the secp256k1 base field.
+pub struct FqParam;
+
+impl FpParams<LIMBS_256> for FqParam {
+    const GENERATOR: Fq = fp_from_num!("3");
+    const MODULUS: U256 =
+        from_num!("115792089237316195423570985008687907853269984665640564039457584007908834671663");
+    const ONE: Fq = fp_from_num!("1");
+    const TWO_ADICITY: u32 = 1;
+    const TWO_ADIC_ROOT_OF_UNITY: Fq =
+        fp_from_hex!("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2E");
+    const ZERO: Fq = fp_from_num!("0");
+}
+
+/// Scalar field of the secp256k1 curve.
+pub type Fr = Fp256<FrParam>;
+/// Parameters of the secp256k1 scalar field.
+pub struct FrParam;
+
+impl FpParams<LIMBS_256> for FrParam {
+    const GENERATOR: Fr = fp_from_num!("7");
+    const MODULUS: U256 =
+        from_num!("115792089237316195423570985008687907852837564279074904382605163141518161494337");
+    const ONE: Fr = fp_from_num!("1");
+    const TWO_ADICITY: u32 = 6;
+    const TWO_ADIC_ROOT_OF_UNITY: Fr =
+        fp_from_hex!("C1DC060E7A91986DF9879A3FBC483A898BDEAB680756045992F4B5402B052F2");
+    const ZERO: Fr = fp_from_num!("0");
+}
+
+/// Affine point of the secp256k1 curve.
+pub type Affine = affine::Affine<Secp256k1Config>;
+/// Jacobian projective point of the secp256k1 curve.
+pub type Projective = projective::Projective<Secp256k1Config>;
+
+/// secp256k1 curve configuration.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Secp256k1Config;
+
+impl CurveConfig for Secp256k1Config {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = &[1];
+    const COFACTOR_INV: Fr = fp_from_num!("1");
+}
+
+impl SWCurveConfig for Secp256k1Config {
+    const COEFF_A: Fq = fp_from_num!("0");
+    const COEFF_B: Fq = fp_from_num!("7");
+    const COMPLETE_FORMULAS: CompleteFormulas = CompleteFormulas::AIsZero;
+    const GENERATOR: Affine = Affine::new_unchecked(GENERATOR_X, GENERATOR_Y);
+
+    fn mul_projective(base: &Projective, scalar: impl BitIteratorBE) -> Projective {
+        Self::glv_mul_bits(base, scalar)
+    }
+
+    fn mul_affine(base: &Affine, scalar: impl BitIteratorBE) -> Projective {
+        Self::glv_mul_bits(&(*base).into(), scalar)
+    }
+
+    fn msm_unchecked(bases: &[Affine], scalars: &[Fr]) -> Projective {
+        Self::glv_msm(bases, scalars)
+    }
+}
+
+impl GLVConfig for Secp256k1Config {
+    const ENDO_COEFF: Fq = fp_from_num!(
+        "55594575648329892869085402983802832744385952214688224221778511981742606582254"
+    );
+    const LAMBDA: Fr = fp_from_num!(
+        "37718080363155996902926221483475020450927657555482586988616620542887997980018"
+    );
+    const SCALAR_DECOMP_COEFFS: [(bool, U256); 4] = [
+        (true, from_num!("64502973549206556628585045361533709077")),
+        (false, from_num!("303414439467246543595250775667605759171")),
+        (true, from_num!("367917413016453100223835821029139468248")),
+        (true, from_num!("64502973549206556628585045361533709077")),
+    ];
+}
+
+impl WBConfig for Secp256k1Config {
+    type IsogenousCurve = Secp256k1IsoConfig;
+
+    const ISOGENY_MAP: IsogenyMap<'static, Secp256k1IsoConfig, Self> = IsogenyMap::new(
+        &ISOGENY_X_NUMERATOR,
+        &ISOGENY_X_DENOMINATOR,
+        &ISOGENY_Y_NUMERATOR,
+        &ISOGENY_Y_DENOMINATOR,
+    );
+}
+
+/// Curve `y² = x³ + a' * x + 1771`, 3-isogenous to secp256k1, on which the
+/// Simplified SWU map of the `secp256k1_XMD:SHA-256_SSWU_*` suites of
+/// [RFC 9380, section 8.7](https://datatracker.ietf.org/doc/html/rfc9380#section-8.7)
+/// is computed.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Secp256k1IsoConfig;
+
+impl CurveConfig for Secp256k1IsoConfig {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = &[1];
+    const COFACTOR_INV: Fr = fp_from_num!("1");
+}
+
+impl SWCurveConfig for Secp256k1IsoConfig {
+    const COEFF_A: Fq =
+        fp_from_hex!("3F8731ABDD661ADCA08A5558F0F5D272E953D363CB6F0E5D405447C01A444533");
+    const COEFF_B: Fq = fp_from_num!("1771");
+    /// Point of smallest `x` coordinate, with even `y` coordinate.
+    const GENERATOR: affine::Affine<Self> = affine::Affine::new_unchecked(
+        fp_from_num!("1"),
+        fp_from_hex!("E7D52E5C8A61D29E54402EB5CE6637A9AB92C3E4E76F1A7F3915F02CF476DA6E"),
+    );
+}
+
+impl SWUConfig for Secp256k1IsoConfig {
+    /// `-11`.
+    const ZETA: Fq =
+        fp_from_hex!("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC24");
+}
+
+/// Coefficients of `x_num` of the isogeny map.
+const ISOGENY_X_NUMERATOR: [Fq; 4] = [
+    fp_from_hex!("8E38E38E38E38E38E38E38E38E38E38E38E38E38E38E38E38E38E38DAAAAA8C7"),
+    fp_from_hex!("7D3D4C80BC321D5B9F315CEA7FD44C5D595D2FC0BF63B92DFFF1044F17C6581"),
+    fp_from_hex!("534C328D23F234E6E2A413DECA25CAECE4506144037C40314ECBD0B53D9DD262"),
+    fp_from_hex!("8E38E38E38E38E38E38E38E38E38E38E38E38E38E38E38E38E38E38DAAAAA88C"),
+];
+
+/// Coefficients of `x_den` of the isogeny map.
+const ISOGENY_X_DENOMINATOR: [Fq; 3] = [
+    fp_from_hex!("D35771193D94918A9CA34CCBB7B640DD86CD409542F8487D9FE6B745781EB49B"),
+    fp_from_hex!("EDADC6F64383DC1DF7C4B2D51B54225406D36B641F5E41BBC52A56612A8C6D14"),
+    fp_from_hex!("1"),
+];
+
+/// Coefficients of `y_num` of the isogeny map.
+const ISOGENY_Y_NUMERATOR: [Fq; 4] = [
+    fp_from_hex!("4BDA12F684BDA12F684BDA12F684BDA12F684BDA12F684BDA12F684B8E38E23C"),
+    fp_from_hex!("C75E0C32D5CB7C0FA9D0A54B12A0A6D5647AB046D686DA6FDFFC90FC201D71A3"),
+    fp_from_hex!("29A6194691F91A73715209EF6512E576722830A201BE2018A765E85A9ECEE931"),
+    fp_from_hex!("2F684BDA12F684BDA12F684BDA12F684BDA12F684BDA12F684BDA12F38E38D84"),
+];
+
+/// Coefficients of `y_den` of the isogeny map.
+const ISOGENY_Y_DENOMINATOR: [Fq; 4] = [
+    fp_from_hex!("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFF93B"),
+    fp_from_hex!("7A06534BB8BDB49FD5E9E6632722C2989467C1BFC8E8D978DFB425D2685C2573"),
+    fp_from_hex!("6484AA716545CA2CF3A70C3FA8FE337E0A3D21162F0D6299A7BF8192BFD2A76F"),
+    fp_from_hex!("1"),
+];
+
+/// Curve `y² = x³ + a * x + b` over the secp256k1 base field, whose
+/// `2`-Sylow subgroup is cyclic of order `2^15`, for ECFFTs over the base
+/// field, which has no multiplicative subgroup of order `4`.
+///
+/// Domains are thus limited to `2^14` elements, which only fits small
+/// polynomial IOPs. Larger domains require a curve over the same field with
+/// a larger `2`-Sylow subgroup, found by a longer search.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct FqEcfftConfig;
+
+impl EcfftConfig for FqEcfftConfig {
+    type Field = Fq;
+
+    const COEFF_A: Fq =
+        fp_from_hex!("7B281C5C9ADAB5ECA826F3761D602A0A6F6FE1388C35065E39AE18DAA9B82A46");
+    const COEFF_B: Fq =
+        fp_from_hex!("8153FEC229B87E7514E304978D556033E93989CA33ACBE72413573CCD0E36DE7");
+    const GENERATOR: (Fq, Fq) = (
+        fp_from_hex!("5ECA4488DE70780F9DEC8F940652D511D23A8022B490137E5857DB74A15E48F8"),
+        fp_from_hex!("E449AFEAEC2569CE7BEF41A4F6B2FED51893F4075D427123F88C44B7212F2CD2"),
+    );
+    const LOG_ORDER: u32 = 15;
+    /// Point of smallest `x` coordinate.
+    const OFFSET: (Fq, Fq) = (
+        fp_from_num!("2"),
+        fp_from_hex!("8C3749EB451AC581EFA1FD93D6867EC5F186F0005C948341E911E1CF947EB57E"),
+    );
+}
+
+/// `x` coordinate of the generator of the secp256k1 curve.
+pub const GENERATOR_X: Fq =
+    fp_from_hex!("79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798");
+
+/// `y` coordinate of the generator of the secp256k1 curve.
+pub const GENERATOR_Y: Fq =
+    fp_from_hex!("483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8");
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        arithmetic::uint::from_str_hex,
+        curve::{
+            hashing::{HashToCurve, MapToCurve, MapToCurveBasedHasher, wb::WBMap},
+            sw::{sw_double_and_add_affine, validation::validate_curve_config},
+            traits::{AffineRepr, CurveGroup},
+        },
+        field::{
+            hashers::{DefaultFieldHasher, HashToField, expander::ExpanderXmd, test_messages},
+            traits::PrimeField,
+        },
+    };
+    use num_traits::Zero;
+    use sha2::Sha256;
+
+    type Hasher = MapToCurveBasedHasher<
+        Projective,
+        DefaultFieldHasher<ExpanderXmd<Sha256>>,
+        WBMap<Secp256k1Config>,
+    >;
+
+    fn point(x: &str, y: &str) -> Affine {
+        Affine::new(Fq::new(from_str_hex(x)), Fq::new(from_str_hex(y)))
+    }
+
+    #[test]
+    fn generator() {
+        let g = Affine::generator();
+        assert!(g.is_on_curve());
+        assert!(g.is_in_correct_subgroup_assuming_on_curve());
+        assert!(sw_double_and_add_affine(&g, Fr::MODULUS).is_zero());
+    }
+
+    #[test]
+    fn config() {
+        assert_eq!(validate_curve_config::<Secp256k1Config>(), Ok(()));
+        assert_eq!(validate_curve_config::<Secp256k1IsoConfig>(), Ok(()));
+    }
+
+    #[test]
+    fn double_generator() {
+        let expected = Affine::new(
+            fp_from_hex!("C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5"),
+            fp_from_hex!("1AE168FEA63DC339A3C58419466CEAEEF7F632653266D0E1236431A950CFE52A"),
+        );
+        assert_eq!(Affine::generator().mul_bigint(2u32).into_affine(), expected);
+    }
+
+    #[test]
+    fn isogenous_curve() {
+        let g = affine::Affine::<Secp256k1IsoConfig>::generator();
+        assert!(g.is_on_curve());
+        assert!(sw_double_and_add_affine(&g, Fr::MODULUS).is_zero());
+        assert!(WBMap::<Secp256k1Config>::check_parameters().is_ok());
+
+        // The isogeny is a group homomorphism.
+        let image = Secp256k1Config::ISOGENY_MAP.apply(g);
+        let double = Secp256k1Config::ISOGENY_MAP.apply(g.mul_bigint(2u32).into_affine());
+        assert!(image.is_on_curve());
+        assert_eq!(double, image.mul_bigint(2u32).into_affine());
+    }
+
+    /// `secp256k1_XMD:SHA-256_SSWU_RO_` test vectors of
+    /// [RFC 9380, appendix J.8.1](https://datatracker.ietf.org/doc/html/rfc9380#appendix-J.8.1).
+    #[test]
+    fn hash_to_curve() {
+        let u = [
+            (
+                "6B0F9910DD2BA71C78F2EE9F04D73B5F4C5F7FC773A701ABEA1E573CAB002FB3",
+                "1AE6C212E08FE1A5937F6202F929A2CC8EF4EE5B9782DB68B0D5799FD8F09E16",
+            ),
+            (
+                "128AAB5D3679A1F7601E3BDF94CED1F43E491F544767E18A4873F397B08A2B61",
+                "5897B65DA3B595A813D0FDCC75C895DC531BE76A03518B044DAAA0F2E4689E00",
+            ),
+            (
+                "EA67A7C02F2CD5D8B87715C169D055A22520F74DAEB080E6180958380E2F98B9",
+                "7434D0D1A500D38380D1F9615C021857AC8D546925F5F2355319D823A478DA18",
+            ),
+            (
+                "EDA89A5024FAC0A8207A87E8CC4E85AA3BCE10745D501A30DEB87341B05BCDF5",
+                "DFE78CD116818FC2C16F3837FEDBE2639FAB012C407EAC9DFE9245BF650AC51D",
+            ),
+            (
+                "8D862E7E7E23D7843FE16D811D46D7E6480127A6B78838C277BCA17DF6900E9F",
+                "68071D2530F040F081BA818D3C7188A94C900586761E9115EFA47AE9BD847938",
+            ),
+        ];
+        let expected = [
+            (
+                "C1CAE290E291AEE617EBAEF1BE6D73861479C48B841EABA9B7B5852DDFEB1346",
+                "64FA678E07AE116126F08B022A94AF6DE15985C996C3A91B64C406A960E51067",
+            ),
+            (
+                "3377E01EAB42DB296B512293120C6CEE72B6ECF9F9205760BD9FF11FB3CB2C4B",
+                "7F95890F33EFEBD1044D382A01B1BEE0900FB6116F94688D487C6C7B9C8371F6",
+            ),
+            (
+                "BAC54083F293F1FE08E4A70137260AA90783A5CB84D3F35848B324D0674B0E3A",
+                "4436476085D4C3C4508B60FCF4389C40176ADCE756B398BDEE27BCA19758D828",
+            ),
+            (
+                "E2167BC785333A37AA562F021F1E881DEFB853839BABF52A7F72B102E41890E9",
+                "F2401DD95CC35867FFED4F367CD564763719FBC6A53E969FB8496A1E6685D873",
+            ),
+            (
+                "E3C8D35AAAF0B9B647E88A0A0A7EE5D5BED5AD38238152E4E6FD8C1F8CB7C998",
+                "8446EEB6181BF12F56A9D24E262221CC2F0C4725C7E3803024B5888EE5823AA6",
+            ),
+        ];
+
+        const DST: &[u8] = b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_";
+        let field_hasher = <DefaultFieldHasher<ExpanderXmd<Sha256>> as HashToField<Fq>>::new(DST);
+        let hasher = Hasher::new(DST).unwrap();
+        for ((msg, (u0, u1)), (x, y)) in test_messages().iter().zip(u).zip(expected) {
+            let [v0, v1]: [Fq; 2] = field_hasher.hash_to_field(msg);
+            assert_eq!(v0, Fq::new(from_str_hex(u0)));
+            assert_eq!(v1, Fq::new(from_str_hex(u1)));
+            assert_eq!(hasher.hash(msg).unwrap(), point(x, y));
+        }
+    }
+
+    /// `secp256k1_XMD:SHA-256_SSWU_NU_` test vectors of
+    /// [RFC 9380, appendix J.8.2](https://datatracker.ietf.org/doc/html/rfc9380#appendix-J.8.2).
+    #[test]
+    fn encode_to_curve() {
+        let expected = [
+            (
+                "A4792346075FEAE77AC3B30026F99C1441B4ECF666DED19B7522CF65C4C55C5B",
+                "62C59E2A6AEED1B23BE5883E833912B08BA06BE7F57C0E9CDC663F31639FF3A7",
+            ),
+            (
+                "3F3B5842033FFF837D504BB4CE2A372BFEADBDBD84A1D2B678B6E1D7EE426B9D",
+                "902910D1FEF15D8AE2006FC84F2A5A7BDA0E0407DC913062C3A493C4F5D876A5",
+            ),
+            (
+                "7644FA6281C694709F53BDD21BED94DAB995671E4A8CD1904EC4AA50C59BFDF",
+                "C79F8D1DAD79B6540426922F7FBC9579C3018DAFEFFCD4552B1626B506C21E7B",
+            ),
+            (
+                "B734F05E9B9709AB631D960FA26D669C4AEAEA64AE62004B9D34F483AA9ACC33",
+                "3FC8A4A5A78632E2EB4D8460D69FF33C1D72574B79A35E402E801F2D0B1D6EE",
+            ),
+            (
+                "17D22B867658977B5002DBE8D0EE70A8CFDDEC3EEC50FB93F36136070FD9FA6C",
+                "E9178FF02F4DAB73480F8DD590328AEA99856A7B6CC8E5A6CDF289ECC2A51718",
+            ),
+        ];
+
+        let hasher = Hasher::new(b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_NU_").unwrap();
+        for (msg, (x, y)) in test_messages().iter().zip(expected) {
+            assert_eq!(hasher.encode(msg).unwrap(), point(x, y));
+        }
+    }
+}
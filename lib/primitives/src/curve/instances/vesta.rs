@@ -0,0 +1,119 @@
+//! [Vesta] short Weierstrass curve, whose scalar field is the base field of
+//! Pallas.
+//!
+//! The curve equation is `y² = x³ + 5`, with cofactor `1`.
+//!
+//! [Vesta]: https://electriccoin.co/blog/the-pasta-curves-for-halo-2-and-beyond/
+use crate::{
+    arithmetic::U256,
+    bits::BitIteratorBE,
+    curve::{
+        scalar_mul::glv::GLVConfig,
+        sw::{SWCurveConfig, affine, complete::CompleteFormulas, projective},
+        traits::CurveConfig,
+    },
+    field::{FpPallas, FpVesta},
+    fp_from_num, from_num,
+};
+
+/// Base field of the Vesta curve.
+pub type Fq = FpVesta;
+/// Scalar field of the Vesta curve, i.e. the base field of Pallas.
+pub type Fr = FpPallas;
+
+/// Affine point of the Vesta curve.
+pub type Affine = affine::Affine<VestaConfig>;
+/// Jacobian projective point of the Vesta curve.
+pub type Projective = projective::Projective<VestaConfig>;
+
+/// Vesta curve configuration.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct VestaConfig;
+
+impl CurveConfig for VestaConfig {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+
+    const COFACTOR: &'static [u64] = &[1];
+    const COFACTOR_INV: Fr = fp_from_num!("1");
+}
+
+impl SWCurveConfig for VestaConfig {
+    const COEFF_A: Fq = fp_from_num!("0");
+    const COEFF_B: Fq = fp_from_num!("5");
+    const COMPLETE_FORMULAS: CompleteFormulas = CompleteFormulas::AIsZero;
+    const GENERATOR: Affine = Affine::new_unchecked(GENERATOR_X, GENERATOR_Y);
+
+    fn mul_projective(base: &Projective, scalar: impl BitIteratorBE) -> Projective {
+        Self::glv_mul_bits(base, scalar)
+    }
+
+    fn mul_affine(base: &Affine, scalar: impl BitIteratorBE) -> Projective {
+        Self::glv_mul_bits(&(*base).into(), scalar)
+    }
+
+    fn msm_unchecked(bases: &[Affine], scalars: &[Fr]) -> Projective {
+        Self::glv_msm(bases, scalars)
+    }
+}
+
+impl GLVConfig for VestaConfig {
+    const ENDO_COEFF: Fq = fp_from_num!(
+        "2942865608506852014473558576493638302197734138389222805617480874486368177743"
+    );
+    const LAMBDA: Fr = fp_from_num!(
+        "8503465768106391777493614032514048814691664078728891710322960303815233784505"
+    );
+    const SCALAR_DECOMP_COEFFS: [(bool, U256); 4] = [
+        (true, from_num!("98231058071186745657228807397848383488")),
+        (false, from_num!("98231058071100081932162823354453065729")),
+        (true, from_num!("98231058071100081932162823354453065729")),
+        (true, from_num!("196462116142286827589391630752301449217")),
+    ];
+}
+
+/// `x` coordinate of the generator of the Vesta curve, i.e. `-1`.
+pub const GENERATOR_X: Fq =
+    fp_from_num!("28948022309329048855892746252171976963363056481941647379679742748393362948096");
+
+/// `y` coordinate of the generator of the Vesta curve.
+pub const GENERATOR_Y: Fq = fp_from_num!("2");
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        curve::{
+            sw::{sw_double_and_add_affine, validation::validate_curve_config},
+            traits::{AffineRepr, CurveGroup},
+        },
+        field::traits::PrimeField,
+    };
+    use num_traits::Zero;
+
+    #[test]
+    fn generator() {
+        let g = Affine::generator();
+        assert!(g.is_on_curve());
+        assert!(g.is_in_correct_subgroup_assuming_on_curve());
+        assert!(sw_double_and_add_affine(&g, Fr::MODULUS).is_zero());
+    }
+
+    #[test]
+    fn config() {
+        assert_eq!(validate_curve_config::<VestaConfig>(), Ok(()));
+    }
+
+    #[test]
+    fn double_generator() {
+        let expected = Affine::new(
+            fp_from_num!(
+                "12664759760331458874453076485325239921471337210849470728609887452422096289795"
+            ),
+            fp_from_num!(
+                "19449452489080454700052938888178047022259553573804544333222327159076790730748"
+            ),
+        );
+        assert_eq!(Affine::generator().mul_bigint(2u32).into_affine(), expected);
+    }
+}
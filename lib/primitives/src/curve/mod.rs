@@ -4,8 +4,14 @@
 //! library].
 //!
 //! [ark-ec library]: https://github.com/arkworks-rs/algebra/tree/master/ec
+pub mod hashing;
+pub mod instances;
 pub mod macros;
+pub mod montgomery;
+pub mod pairing;
+pub mod scalar_mul;
 pub mod sw;
+pub mod te;
 pub mod traits;
 
 use crate::field::traits::Field;
@@ -0,0 +1,172 @@
+//! Affine coordinates for a point on a Montgomery curve
+//! ([Affine Space]).
+//!
+//! [Affine Space]: https://en.wikipedia.org/wiki/Affine_space
+use super::MontCurveConfig;
+use crate::{
+    bits::BitIteratorBE,
+    curve::{te, traits::AffineRepr},
+    field::traits::{AdditiveGroup, Field},
+};
+use core::{
+    fmt::{Debug, Display, Formatter},
+    ops::Neg,
+};
+use educe::Educe;
+use num_traits::{One, Zero};
+use zeroize::Zeroize;
+
+/// Affine coordinates for a point on an elliptic curve in Montgomery
+/// form, over the base field `P::BaseField`.
+#[derive(Educe)]
+#[educe(Copy, Clone, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct Affine<P: MontCurveConfig> {
+    #[doc(hidden)]
+    pub x: P::BaseField,
+    #[doc(hidden)]
+    pub y: P::BaseField,
+    #[doc(hidden)]
+    pub infinity: bool,
+}
+
+impl<P: MontCurveConfig> Display for Affine<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.infinity { write!(f, "infinity") } else { write!(f, "({}, {})", self.x, self.y) }
+    }
+}
+
+impl<P: MontCurveConfig> Debug for Affine<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.infinity { write!(f, "infinity") } else { write!(f, "({}, {})", self.x, self.y) }
+    }
+}
+
+impl<P: MontCurveConfig> Affine<P> {
+    /// Constructs a group element from `x` and `y` coordinates.
+    /// Performs checks to ensure that the point is on the curve.
+    ///
+    /// # Panics
+    ///
+    /// * If point is not on curve.
+    pub fn new(x: P::BaseField, y: P::BaseField) -> Self {
+        let point = Self { x, y, infinity: false };
+        assert!(point.is_on_curve());
+        point
+    }
+
+    /// Constructs a group element from `x` and `y` coordinates.
+    ///
+    /// # Warning
+    ///
+    /// Does *not* perform any checks to ensure the point is in the curve.
+    pub const fn new_unchecked(x: P::BaseField, y: P::BaseField) -> Self {
+        Self { x, y, infinity: false }
+    }
+
+    /// Additive identity element of the curve group.
+    pub const fn identity() -> Self {
+        Self { x: P::BaseField::ZERO, y: P::BaseField::ZERO, infinity: true }
+    }
+
+    /// Returns the generator of the prime-order subgroup.
+    pub const fn generator() -> Self {
+        P::GENERATOR
+    }
+
+    /// Checks if `self` is the point at infinity.
+    pub fn is_zero(&self) -> bool {
+        self.infinity
+    }
+
+    /// Checks if `self` is a valid point on the curve.
+    pub fn is_on_curve(&self) -> bool {
+        if self.infinity {
+            return true;
+        }
+
+        let x2 = self.x.square();
+        let lhs = P::COEFF_B * self.y.square();
+        let rhs = x2 * self.x + P::COEFF_A * x2 + self.x;
+        lhs == rhs
+    }
+
+    /// Computes the `x` coordinate of `[by] self` with the x-only Montgomery
+    /// ladder. The point at infinity has `x = 0`.
+    pub fn mul_x(&self, by: impl BitIteratorBE) -> P::BaseField {
+        P::mul_x(self.x, by)
+    }
+
+    /// Maps `self` to the birationally equivalent twisted Edwards curve
+    /// [`MontCurveConfig::TECurveConfig`].
+    ///
+    /// Uses `x' = c * x / y` and `y' = (x - 1) / (x + 1)`, where `c` is
+    /// [`MontCurveConfig::TE_X_SCALE`].
+    /// Returns `None` for the exceptional points with `y = 0, x != 0` or
+    /// `x = -1`, which have no affine twisted Edwards image.
+    #[must_use]
+    pub fn to_twisted_edwards(&self) -> Option<te::affine::Affine<P::TECurveConfig>> {
+        if self.infinity {
+            return Some(te::affine::Affine::identity());
+        }
+
+        // The point of order two `(0, 0)` maps to `(0, -1)`.
+        if self.x.is_zero() && self.y.is_zero() {
+            return Some(te::affine::Affine::new_unchecked(
+                P::BaseField::ZERO,
+                -P::BaseField::one(),
+            ));
+        }
+
+        let x = P::TE_X_SCALE * self.x * self.y.inverse()?;
+        let y = (self.x - P::BaseField::one()) * (self.x + P::BaseField::one()).inverse()?;
+        Some(te::affine::Affine::new_unchecked(x, y))
+    }
+
+    /// Maps a point of the birationally equivalent twisted Edwards curve
+    /// [`MontCurveConfig::TECurveConfig`] to this curve.
+    ///
+    /// Uses `x = (1 + y') / (1 - y')` and `y = c * x / x'`, where `c` is
+    /// [`MontCurveConfig::TE_X_SCALE`].
+    pub fn from_twisted_edwards(point: &te::affine::Affine<P::TECurveConfig>) -> Self {
+        if point.is_zero() {
+            return Self::identity();
+        }
+
+        // The point of order two `(0, -1)` maps to `(0, 0)`.
+        if point.x.is_zero() {
+            return Self::new_unchecked(P::BaseField::ZERO, P::BaseField::ZERO);
+        }
+
+        let x = (P::BaseField::one() + point.y) / (P::BaseField::one() - point.y);
+        let y = P::TE_X_SCALE * x / point.x;
+        Self::new_unchecked(x, y)
+    }
+}
+
+impl<P: MontCurveConfig> Zeroize for Affine<P> {
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+        self.infinity.zeroize();
+    }
+}
+
+impl<P: MontCurveConfig> Neg for Affine<P> {
+    type Output = Self;
+
+    /// If `self.is_zero()`, returns `self` (`== Self::zero()`).
+    /// Else, returns `(x, -y)`, where `self = (x, y)`.
+    #[inline]
+    fn neg(mut self) -> Self {
+        self.y.neg_in_place();
+        self
+    }
+}
+
+impl<P: MontCurveConfig> Default for Affine<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
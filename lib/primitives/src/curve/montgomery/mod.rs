@@ -0,0 +1,111 @@
+//! This module contains definitions for the [Montgomery model] of the curve.
+//!
+//! [Montgomery model]: https://www.hyperelliptic.org/EFD/g1p/auto-montgom.html
+pub mod affine;
+use super::{te::TECurveConfig, traits::CurveConfig};
+use crate::{
+    bits::BitIteratorBE,
+    field::traits::{AdditiveGroup, Field},
+};
+use affine::Affine;
+
+/// Constants and convenience functions that collectively define the
+/// [Montgomery model] of the curve.
+///
+/// In this model, the curve equation is `b * y² = x³ + a * x² + x`, for
+/// constants `a` and `b`.
+///
+/// [Montgomery model]: https://www.hyperelliptic.org/EFD/g1p/auto-montgom.html
+pub trait MontCurveConfig: CurveConfig {
+    /// Coefficient `a` of the curve equation.
+    const COEFF_A: Self::BaseField;
+    /// Coefficient `b` of the curve equation.
+    const COEFF_B: Self::BaseField;
+    /// Generator of the prime-order subgroup.
+    const GENERATOR: Affine<Self>;
+
+    /// Factor `c` scaling the `x` coordinate of the birational map to
+    /// [`Self::TECurveConfig`], i.e. `c² = (a + 2) / (b * a')` where `a'` is
+    /// the twisted Edwards coefficient `a`.
+    ///
+    /// Defaults to one, for which the twisted Edwards curve is
+    /// `a' = (a + 2) / b` and `d' = (a - 2) / b`.
+    const TE_X_SCALE: Self::BaseField = Self::BaseField::ONE;
+
+    /// Model parameters for the twisted Edwards curve that is birationally
+    /// equivalent to this curve.
+    type TECurveConfig: TECurveConfig<BaseField = Self::BaseField, ScalarField = Self::ScalarField>;
+
+    /// Returns the constant `(a - 2) / 4` used by the doubling step of the
+    /// Montgomery ladder.
+    ///
+    /// The default implementation should be overridden when the constant is
+    /// known in advance.
+    fn a24() -> Self::BaseField {
+        let four = Self::BaseField::from(4u8);
+        (Self::COEFF_A - Self::BaseField::from(2u8)) / four
+    }
+
+    /// Default implementation of the `x`-only multiplication of a point by a
+    /// scalar.
+    fn mul_x(x: Self::BaseField, scalar: impl BitIteratorBE) -> Self::BaseField {
+        montgomery_ladder::<Self>(x, scalar)
+    }
+}
+
+/// Computes the `x` coordinate of `[scalar] P` given the `x` coordinate of
+/// `P`, using the x-only [Montgomery ladder].
+///
+/// Every bit of `scalar` is processed, including leading zeros, with the same
+/// sequence of field operations and a branch-free conditional swap, so that
+/// the running time does not depend on the value of `scalar`.
+///
+/// Following [RFC 7748], the point at infinity is encoded as `x = 0`.
+///
+/// [Montgomery ladder]: https://eprint.iacr.org/2017/212
+/// [RFC 7748]: https://datatracker.ietf.org/doc/html/rfc7748#section-5
+pub fn montgomery_ladder<P: MontCurveConfig>(
+    x: P::BaseField,
+    scalar: impl BitIteratorBE,
+) -> P::BaseField {
+    let a24 = P::a24();
+
+    let x1 = x;
+    let mut x2 = P::BaseField::ONE;
+    let mut z2 = P::BaseField::ZERO;
+    let mut x3 = x;
+    let mut z3 = P::BaseField::ONE;
+    let mut swap = false;
+
+    for bit in scalar.bit_be_iter() {
+        swap ^= bit;
+        conditional_swap(&mut x2, &mut x3, swap);
+        conditional_swap(&mut z2, &mut z3, swap);
+        swap = bit;
+
+        let a = x2 + z2;
+        let aa = a.square();
+        let b = x2 - z2;
+        let bb = b.square();
+        let e = aa - bb;
+        let c = x3 + z3;
+        let d = x3 - z3;
+        let da = d * a;
+        let cb = c * b;
+
+        x3 = (da + cb).square();
+        z3 = x1 * (da - cb).square();
+        x2 = aa * bb;
+        z2 = e * (aa + a24 * e);
+    }
+    conditional_swap(&mut x2, &mut x3, swap);
+    conditional_swap(&mut z2, &mut z3, swap);
+
+    z2.inverse().map_or(P::BaseField::ZERO, |z2_inv| x2 * z2_inv)
+}
+
+/// Swaps `a` and `b` if `choice` is set, without branching on `choice`.
+#[inline(always)]
+fn conditional_swap<F: Field>(a: &mut F, b: &mut F, choice: bool) {
+    (*a, *b) = (a.ct_select(b, choice), b.ct_select(a, choice));
+}
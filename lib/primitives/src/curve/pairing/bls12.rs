@@ -0,0 +1,208 @@
+//! Optimal Ate pairing for the [Barreto-Lynn-Scott] family of curves of
+//! embedding degree 12.
+//!
+//! [Barreto-Lynn-Scott]: https://eprint.iacr.org/2002/088.pdf
+use super::{EllCoeff, G2HomProjective, TwistType, ell};
+use crate::{
+    bits::BitIteratorBE,
+    curve::{
+        sw::{SWCurveConfig, affine::Affine, projective::Projective},
+        traits::{AffineRepr, CurveConfig, MillerLoopOutput, Pairing, PairingOutput},
+    },
+    field::{
+        fp2::{Fp2, Fp2Config},
+        fp6::Fp6Config,
+        fp12::{Fp12, Fp12Config},
+        traits::{AdditiveGroup, Field, PrimeField},
+    },
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use educe::Educe;
+
+/// Parameters of a BLS12 curve `E: y² = x³ + b`.
+pub trait Bls12Config: 'static + Sized {
+    /// Absolute value of the parameter `x` of the curve, as little-endian
+    /// limbs.
+    const X: &'static [u64];
+    /// Whether the parameter `x` is negative.
+    const X_IS_NEGATIVE: bool;
+    /// Type of the twist hosting G2.
+    const TWIST_TYPE: TwistType;
+
+    /// Base field of the curve.
+    type Fp: PrimeField;
+    /// Parameters of the quadratic extension hosting G2.
+    type Fp2Config: Fp2Config<Fp = Self::Fp>;
+    /// Parameters of the sextic extension of the tower.
+    type Fp6Config: Fp6Config<Fp2Config = Self::Fp2Config>;
+    /// Parameters of the target field of the pairing.
+    type Fp12Config: Fp12Config<Fp6Config = Self::Fp6Config>;
+    /// Configuration of G1.
+    type G1Config: SWCurveConfig<BaseField = Self::Fp>;
+    /// Configuration of G2, on the twist.
+    type G2Config: SWCurveConfig<
+            BaseField = Fp2<Self::Fp2Config>,
+            ScalarField = <Self::G1Config as CurveConfig>::ScalarField,
+        >;
+}
+
+/// Optimal Ate pairing engine of the BLS12 curve described by `P`.
+#[derive(Educe)]
+#[educe(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Bls12<P: Bls12Config>(PhantomData<fn() -> P>);
+
+/// A G1 point prepared for the Miller loop, i.e. in affine form.
+#[derive(Educe)]
+#[educe(Default, Clone, Debug, PartialEq, Eq)]
+pub struct G1Prepared<P: Bls12Config>(pub Affine<P::G1Config>);
+
+impl<P: Bls12Config> From<Affine<P::G1Config>> for G1Prepared<P> {
+    fn from(point: Affine<P::G1Config>) -> Self {
+        Self(point)
+    }
+}
+
+impl<P: Bls12Config> From<Projective<P::G1Config>> for G1Prepared<P> {
+    fn from(point: Projective<P::G1Config>) -> Self {
+        Self(point.into())
+    }
+}
+
+/// A G2 point prepared for the Miller loop, i.e. the coefficients of all of
+/// its lines.
+#[derive(Educe)]
+#[educe(Default, Clone, Debug, PartialEq, Eq)]
+pub struct G2Prepared<P: Bls12Config> {
+    /// Coefficients of the lines of the Miller loop, in order.
+    pub ell_coeffs: Vec<EllCoeff<P::Fp2Config>>,
+    /// Whether the point is at infinity.
+    pub infinity: bool,
+}
+
+impl<P: Bls12Config> From<Affine<P::G2Config>> for G2Prepared<P> {
+    fn from(q: Affine<P::G2Config>) -> Self {
+        if q.is_zero() {
+            return Self { ell_coeffs: Vec::new(), infinity: true };
+        }
+
+        let two_inv = P::Fp::ONE.double().inverse().expect("characteristic should be odd");
+        let coeff_b = P::G2Config::COEFF_B;
+        let mut ell_coeffs = Vec::new();
+        let mut r = G2HomProjective::from_affine(q.x, q.y);
+
+        for bit in P::X.bit_be_trimmed_iter().skip(1) {
+            ell_coeffs.push(r.double_in_place(&two_inv, &coeff_b, P::TWIST_TYPE));
+            if bit {
+                ell_coeffs.push(r.add_in_place(&q.x, &q.y, P::TWIST_TYPE));
+            }
+        }
+
+        Self { ell_coeffs, infinity: false }
+    }
+}
+
+impl<P: Bls12Config> From<Projective<P::G2Config>> for G2Prepared<P> {
+    fn from(q: Projective<P::G2Config>) -> Self {
+        Affine::from(q).into()
+    }
+}
+
+impl<P: Bls12Config> Bls12<P> {
+    /// Returns `f^x`, for `f` in the cyclotomic subgroup.
+    fn exp_by_x(f: Fp12<P::Fp12Config>) -> Fp12<P::Fp12Config> {
+        let mut f = f.cyclotomic_exp(P::X);
+        if P::X_IS_NEGATIVE {
+            f.cyclotomic_inverse_in_place();
+        }
+        f
+    }
+}
+
+impl<P: Bls12Config> Pairing for Bls12<P> {
+    type BaseField = P::Fp;
+    type G1 = Projective<P::G1Config>;
+    type G1Affine = Affine<P::G1Config>;
+    type G1Prepared = G1Prepared<P>;
+    type G2 = Projective<P::G2Config>;
+    type G2Affine = Affine<P::G2Config>;
+    type G2Prepared = G2Prepared<P>;
+    type ScalarField = <P::G1Config as CurveConfig>::ScalarField;
+    type TargetField = Fp12<P::Fp12Config>;
+
+    fn multi_miller_loop(
+        a: impl IntoIterator<Item = impl Into<Self::G1Prepared>>,
+        b: impl IntoIterator<Item = impl Into<Self::G2Prepared>>,
+    ) -> MillerLoopOutput<Self> {
+        let mut pairs: Vec<_> = a
+            .into_iter()
+            .zip(b)
+            .map(|(p, q)| (p.into(), q.into()))
+            .filter(|(p, q): &(G1Prepared<P>, G2Prepared<P>)| !p.0.is_zero() && !q.infinity)
+            .map(|(p, q)| (p.0, q.ell_coeffs.into_iter()))
+            .collect();
+        let mut multiply_lines = |f: &mut Fp12<P::Fp12Config>| {
+            for (p, coeffs) in &mut pairs {
+                let coeffs = coeffs.next().expect("prepared G2 point should have enough lines");
+                ell(f, &coeffs, &p.x, &p.y, P::TWIST_TYPE);
+            }
+        };
+
+        let mut f = Fp12::ONE;
+        for bit in P::X.bit_be_trimmed_iter().skip(1) {
+            f.square_in_place();
+            multiply_lines(&mut f);
+            if bit {
+                multiply_lines(&mut f);
+            }
+        }
+        if P::X_IS_NEGATIVE {
+            f.cyclotomic_inverse_in_place();
+        }
+
+        MillerLoopOutput(f)
+    }
+
+    /// Raises the output of the Miller loop to `3 * (p^12 - 1) / r`,
+    /// following [Hayashida, Hayasaka and Teruya] for the hard part.
+    ///
+    /// [Hayashida, Hayasaka and Teruya]: https://eprint.iacr.org/2020/875.pdf
+    fn final_exponentiation(mlo: MillerLoopOutput<Self>) -> Option<PairingOutput<Self>> {
+        // Easy part: `f^((p^6 - 1)(p^2 + 1))`, which maps `f` into the
+        // cyclotomic subgroup.
+        let mut f1 = mlo.0;
+        f1.cyclotomic_inverse_in_place();
+        let mut r = f1 * mlo.0.inverse()?;
+        let f2 = r;
+        r.frobenius_map_in_place(2);
+        r *= &f2;
+
+        // Hard part: `r^(3 * (p^4 - p^2 + 1) / r)`.
+        let mut y0 = r.cyclotomic_square();
+        y0.cyclotomic_inverse_in_place();
+        let mut y5 = Self::exp_by_x(r);
+        let mut y1 = y5.cyclotomic_square();
+        let mut y3 = y0 * y5;
+        y0 = Self::exp_by_x(y3);
+        let y2 = Self::exp_by_x(y0);
+        let mut y4 = Self::exp_by_x(y2);
+        y4 *= &y1;
+        y1 = Self::exp_by_x(y4);
+        y3.cyclotomic_inverse_in_place();
+        y1 *= &y3;
+        y1 *= &r;
+        y3 = r;
+        y3.cyclotomic_inverse_in_place();
+        y0 *= &r;
+        y0.frobenius_map_in_place(3);
+        y4 *= &y3;
+        y4.frobenius_map_in_place(1);
+        y5 *= &y2;
+        y5.frobenius_map_in_place(2);
+        y5 *= &y0;
+        y5 *= &y4;
+        y5 *= &y1;
+
+        Some(PairingOutput(y5))
+    }
+}
@@ -0,0 +1,242 @@
+//! Optimal Ate pairing for the [Barreto-Naehrig] family of curves.
+//!
+//! [Barreto-Naehrig]: https://eprint.iacr.org/2005/133.pdf
+use super::{EllCoeff, G2HomProjective, TwistType, ell};
+use crate::{
+    curve::{
+        sw::{SWCurveConfig, affine::Affine, projective::Projective},
+        traits::{AffineRepr, CurveConfig, MillerLoopOutput, Pairing, PairingOutput},
+    },
+    field::{
+        fp2::{Fp2, Fp2Config},
+        fp6::Fp6Config,
+        fp12::{Fp12, Fp12Config},
+        traits::{AdditiveGroup, Field, PrimeField},
+    },
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use educe::Educe;
+
+/// Parameters of a BN curve `E: y² = x³ + b`, of embedding degree 12.
+pub trait BnConfig: 'static + Sized {
+    /// Absolute value of the parameter `x` of the curve, as little-endian
+    /// limbs.
+    const X: &'static [u64];
+    /// Whether the parameter `x` is negative.
+    const X_IS_NEGATIVE: bool;
+    /// Signed binary digits of `6x + 2`, least significant digit first.
+    const ATE_LOOP_COUNT: &'static [i8];
+    /// Type of the twist hosting G2.
+    const TWIST_TYPE: TwistType;
+    /// Coefficient mapping the Frobenius of the `x` coordinate of the twist
+    /// back to the twist, `ξ^((p - 1) / 3)`.
+    const TWIST_MUL_BY_Q_X: Fp2<Self::Fp2Config>;
+    /// Coefficient mapping the Frobenius of the `y` coordinate of the twist
+    /// back to the twist, `ξ^((p - 1) / 2)`.
+    const TWIST_MUL_BY_Q_Y: Fp2<Self::Fp2Config>;
+
+    /// Base field of the curve.
+    type Fp: PrimeField;
+    /// Parameters of the quadratic extension hosting G2.
+    type Fp2Config: Fp2Config<Fp = Self::Fp>;
+    /// Parameters of the sextic extension of the tower.
+    type Fp6Config: Fp6Config<Fp2Config = Self::Fp2Config>;
+    /// Parameters of the target field of the pairing.
+    type Fp12Config: Fp12Config<Fp6Config = Self::Fp6Config>;
+    /// Configuration of G1.
+    type G1Config: SWCurveConfig<BaseField = Self::Fp>;
+    /// Configuration of G2, on the twist.
+    type G2Config: SWCurveConfig<
+            BaseField = Fp2<Self::Fp2Config>,
+            ScalarField = <Self::G1Config as CurveConfig>::ScalarField,
+        >;
+}
+
+/// Optimal Ate pairing engine of the BN curve described by `P`.
+#[derive(Educe)]
+#[educe(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Bn<P: BnConfig>(PhantomData<fn() -> P>);
+
+/// A G1 point prepared for the Miller loop, i.e. in affine form.
+#[derive(Educe)]
+#[educe(Default, Clone, Debug, PartialEq, Eq)]
+pub struct G1Prepared<P: BnConfig>(pub Affine<P::G1Config>);
+
+impl<P: BnConfig> From<Affine<P::G1Config>> for G1Prepared<P> {
+    fn from(point: Affine<P::G1Config>) -> Self {
+        Self(point)
+    }
+}
+
+impl<P: BnConfig> From<Projective<P::G1Config>> for G1Prepared<P> {
+    fn from(point: Projective<P::G1Config>) -> Self {
+        Self(point.into())
+    }
+}
+
+/// A G2 point prepared for the Miller loop, i.e. the coefficients of all of
+/// its lines.
+#[derive(Educe)]
+#[educe(Default, Clone, Debug, PartialEq, Eq)]
+pub struct G2Prepared<P: BnConfig> {
+    /// Coefficients of the lines of the Miller loop, in order.
+    pub ell_coeffs: Vec<EllCoeff<P::Fp2Config>>,
+    /// Whether the point is at infinity.
+    pub infinity: bool,
+}
+
+impl<P: BnConfig> From<Affine<P::G2Config>> for G2Prepared<P> {
+    fn from(q: Affine<P::G2Config>) -> Self {
+        if q.is_zero() {
+            return Self { ell_coeffs: Vec::new(), infinity: true };
+        }
+
+        let two_inv = P::Fp::ONE.double().inverse().expect("characteristic should be odd");
+        let coeff_b = P::G2Config::COEFF_B;
+        let mut ell_coeffs = Vec::new();
+        let mut r = G2HomProjective::from_affine(q.x, q.y);
+        let neg_q = -q;
+
+        for digit in P::ATE_LOOP_COUNT.iter().rev().skip(1) {
+            ell_coeffs.push(r.double_in_place(&two_inv, &coeff_b, P::TWIST_TYPE));
+            match digit {
+                1 => ell_coeffs.push(r.add_in_place(&q.x, &q.y, P::TWIST_TYPE)),
+                -1 => ell_coeffs.push(r.add_in_place(&neg_q.x, &neg_q.y, P::TWIST_TYPE)),
+                _ => {},
+            }
+        }
+
+        // Lines through `π(Q)` and `-π²(Q)`, where `π` is the Frobenius
+        // endomorphism.
+        let q1 = mul_by_char::<P>(q);
+        let mut q2 = mul_by_char::<P>(q1);
+        if P::X_IS_NEGATIVE {
+            r.neg_in_place();
+        }
+        q2.y.neg_in_place();
+        ell_coeffs.push(r.add_in_place(&q1.x, &q1.y, P::TWIST_TYPE));
+        ell_coeffs.push(r.add_in_place(&q2.x, &q2.y, P::TWIST_TYPE));
+
+        Self { ell_coeffs, infinity: false }
+    }
+}
+
+impl<P: BnConfig> From<Projective<P::G2Config>> for G2Prepared<P> {
+    fn from(q: Projective<P::G2Config>) -> Self {
+        Affine::from(q).into()
+    }
+}
+
+/// Applies the Frobenius endomorphism `π` to a point of the twist.
+fn mul_by_char<P: BnConfig>(q: Affine<P::G2Config>) -> Affine<P::G2Config> {
+    let mut x = q.x.frobenius_map(1);
+    x *= &P::TWIST_MUL_BY_Q_X;
+    let mut y = q.y.frobenius_map(1);
+    y *= &P::TWIST_MUL_BY_Q_Y;
+    Affine::new_unchecked(x, y)
+}
+
+impl<P: BnConfig> Bn<P> {
+    /// Returns `f^(-x)`, for `f` in the cyclotomic subgroup.
+    fn exp_by_neg_x(f: Fp12<P::Fp12Config>) -> Fp12<P::Fp12Config> {
+        let mut f = f.cyclotomic_exp(P::X);
+        if !P::X_IS_NEGATIVE {
+            f.cyclotomic_inverse_in_place();
+        }
+        f
+    }
+}
+
+impl<P: BnConfig> Pairing for Bn<P> {
+    type BaseField = P::Fp;
+    type G1 = Projective<P::G1Config>;
+    type G1Affine = Affine<P::G1Config>;
+    type G1Prepared = G1Prepared<P>;
+    type G2 = Projective<P::G2Config>;
+    type G2Affine = Affine<P::G2Config>;
+    type G2Prepared = G2Prepared<P>;
+    type ScalarField = <P::G1Config as CurveConfig>::ScalarField;
+    type TargetField = Fp12<P::Fp12Config>;
+
+    fn multi_miller_loop(
+        a: impl IntoIterator<Item = impl Into<Self::G1Prepared>>,
+        b: impl IntoIterator<Item = impl Into<Self::G2Prepared>>,
+    ) -> MillerLoopOutput<Self> {
+        let mut pairs: Vec<_> = a
+            .into_iter()
+            .zip(b)
+            .map(|(p, q)| (p.into(), q.into()))
+            .filter(|(p, q): &(G1Prepared<P>, G2Prepared<P>)| !p.0.is_zero() && !q.infinity)
+            .map(|(p, q)| (p.0, q.ell_coeffs.into_iter()))
+            .collect();
+        let mut multiply_lines = |f: &mut Fp12<P::Fp12Config>| {
+            for (p, coeffs) in &mut pairs {
+                let coeffs = coeffs.next().expect("prepared G2 point should have enough lines");
+                ell(f, &coeffs, &p.x, &p.y, P::TWIST_TYPE);
+            }
+        };
+
+        let mut f = Fp12::ONE;
+        let len = P::ATE_LOOP_COUNT.len();
+        for i in (1..len).rev() {
+            if i != len - 1 {
+                f.square_in_place();
+            }
+            multiply_lines(&mut f);
+            if P::ATE_LOOP_COUNT[i - 1] != 0 {
+                multiply_lines(&mut f);
+            }
+        }
+        if P::X_IS_NEGATIVE {
+            f.cyclotomic_inverse_in_place();
+        }
+        multiply_lines(&mut f);
+        multiply_lines(&mut f);
+
+        MillerLoopOutput(f)
+    }
+
+    /// Raises the output of the Miller loop to `(p^12 - 1) / r`, up to a
+    /// factor coprime to `r`, following [Fuentes-Castañeda, Knapp and
+    /// Rodríguez-Henríquez] for the hard part.
+    ///
+    /// [Fuentes-Castañeda, Knapp and Rodríguez-Henríquez]: https://link.springer.com/chapter/10.1007/978-3-642-28496-0_25
+    fn final_exponentiation(mlo: MillerLoopOutput<Self>) -> Option<PairingOutput<Self>> {
+        // Easy part: `f^((p^6 - 1)(p^2 + 1))`, which maps `f` into the
+        // cyclotomic subgroup.
+        let mut f1 = mlo.0;
+        f1.cyclotomic_inverse_in_place();
+        let mut r = f1 * mlo.0.inverse()?;
+        let f2 = r;
+        r.frobenius_map_in_place(2);
+        r *= &f2;
+
+        // Hard part: `r^(m * (p^4 - p^2 + 1) / r)`, for some `m` coprime to
+        // `r`.
+        let y0 = Self::exp_by_neg_x(r);
+        let y1 = y0.cyclotomic_square();
+        let y2 = y1.cyclotomic_square();
+        let mut y3 = y2 * y1;
+        let y4 = Self::exp_by_neg_x(y3);
+        let y5 = y4.cyclotomic_square();
+        let mut y6 = Self::exp_by_neg_x(y5);
+        y3.cyclotomic_inverse_in_place();
+        y6.cyclotomic_inverse_in_place();
+        let y7 = y6 * y4;
+        let mut y8 = y7 * y3;
+        let y9 = y8 * y1;
+        let y10 = y8 * y4;
+        let y11 = y10 * r;
+        let y12 = y9.frobenius_map(1);
+        let y13 = y12 * y11;
+        y8.frobenius_map_in_place(2);
+        let y14 = y8 * y13;
+        r.cyclotomic_inverse_in_place();
+        let mut y15 = r * y9;
+        y15.frobenius_map_in_place(3);
+        let y16 = y15 * y14;
+
+        Some(PairingOutput(y16))
+    }
+}
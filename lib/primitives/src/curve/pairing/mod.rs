@@ -0,0 +1,214 @@
+//! This module contains optimal Ate [pairings] for the BN and BLS12 families
+//! of pairing-friendly curves.
+//!
+//! Both families share the same tower `Fp12 = Fp6[w] / (w² - v)`,
+//! `Fp6 = Fp2[v] / (v³ - ξ)` for the target field, and a sextic twist of the
+//! curve over `Fp2` for G2. The Miller loop runs over G2 in homogeneous
+//! projective coordinates, following [Costello, Lange and Naehrig], so that
+//! the coefficients of its lines only depend on the G2 input and can be
+//! [prepared] ahead of time.
+//!
+//! [pairings]: https://en.wikipedia.org/wiki/Pairing-based_cryptography
+//! [Costello, Lange and Naehrig]: https://eprint.iacr.org/2009/615.pdf
+//! [prepared]: crate::curve::traits::Pairing::G2Prepared
+pub mod bls12;
+pub mod bn;
+
+use crate::field::{
+    fp2::{Fp2, Fp2Config},
+    fp6::Fp6Config,
+    fp12::{Fp12, Fp12Config},
+    traits::{AdditiveGroup, Field},
+};
+
+/// Type of the sextic twist `E'` of the curve `E: y² = x³ + b`, which hosts
+/// G2.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TwistType {
+    /// Multiplicative twist `E': y² = x³ + b * ξ`.
+    M,
+    /// Divisive twist `E': y² = x³ + b / ξ`.
+    D,
+}
+
+/// Coefficients of a line of the Miller loop, evaluated at a G1 point by
+/// [`ell`].
+pub type EllCoeff<P> = (Fp2<P>, Fp2<P>, Fp2<P>);
+
+/// Point of the twist in homogeneous projective coordinates, used to compute
+/// the lines of the Miller loop.
+pub(crate) struct G2HomProjective<P: Fp2Config> {
+    x: Fp2<P>,
+    y: Fp2<P>,
+    z: Fp2<P>,
+}
+
+impl<P: Fp2Config> G2HomProjective<P> {
+    /// Creates the point `(x : y : 1)`.
+    pub(crate) fn from_affine(x: Fp2<P>, y: Fp2<P>) -> Self {
+        Self { x, y, z: Fp2::ONE }
+    }
+
+    /// Negates the `y` coordinate of `self`.
+    pub(crate) fn neg_in_place(&mut self) {
+        self.y.neg_in_place();
+    }
+
+    /// Doubles `self`, and returns the coefficients of the tangent line at
+    /// `self`.
+    ///
+    /// `coeff_b` is the coefficient `b` of the twist.
+    pub(crate) fn double_in_place(
+        &mut self,
+        two_inv: &P::Fp,
+        coeff_b: &Fp2<P>,
+        twist: TwistType,
+    ) -> EllCoeff<P> {
+        let mut a = self.x * self.y;
+        a.mul_assign_by_fp(two_inv);
+        let b = self.y.square();
+        let c = self.z.square();
+        let e = *coeff_b * (c.double() + c);
+        let f = e.double() + e;
+        let mut g = b + f;
+        g.mul_assign_by_fp(two_inv);
+        let h = (self.y + self.z).square() - (b + c);
+        let i = e - b;
+        let j = self.x.square();
+        let e_square = e.square();
+
+        self.x = a * (b - f);
+        self.y = g.square() - (e_square.double() + e_square);
+        self.z = b * h;
+        match twist {
+            TwistType::M => (i, j.double() + j, -h),
+            TwistType::D => (-h, j.double() + j, i),
+        }
+    }
+
+    /// Adds the affine point `(x, y)` to `self`, and returns the coefficients
+    /// of the line through both points.
+    pub(crate) fn add_in_place(&mut self, x: &Fp2<P>, y: &Fp2<P>, twist: TwistType) -> EllCoeff<P> {
+        let theta = self.y - (*y * self.z);
+        let lambda = self.x - (*x * self.z);
+        let c = theta.square();
+        let d = lambda.square();
+        let e = lambda * d;
+        let f = self.z * c;
+        let g = self.x * d;
+        let h = e + f - g.double();
+
+        self.x = lambda * h;
+        self.y = theta * (g - h) - (e * self.y);
+        self.z *= &e;
+        let j = theta * x - (lambda * y);
+        match twist {
+            TwistType::M => (j, -theta, lambda),
+            TwistType::D => (lambda, -theta, j),
+        }
+    }
+}
+
+/// Multiplies `f` by the line with coefficients `coeffs`, evaluated at the
+/// G1 point `(x, y)`.
+pub(crate) fn ell<P: Fp12Config>(
+    f: &mut Fp12<P>,
+    coeffs: &EllCoeff<<P::Fp6Config as Fp6Config>::Fp2Config>,
+    x: &<<P::Fp6Config as Fp6Config>::Fp2Config as Fp2Config>::Fp,
+    y: &<<P::Fp6Config as Fp6Config>::Fp2Config as Fp2Config>::Fp,
+    twist: TwistType,
+) {
+    let (mut c0, mut c1, mut c2) = *coeffs;
+    c1.mul_assign_by_fp(x);
+    match twist {
+        TwistType::M => {
+            c2.mul_assign_by_fp(y);
+            f.mul_by_014(&c0, &c1, &c2);
+        },
+        TwistType::D => {
+            c0.mul_assign_by_fp(y);
+            f.mul_by_034(&c0, &c1, &c2);
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        UniformRand,
+        curve::{
+            instances::{bls12_381::Bls12_381, bn254::Bn254},
+            traits::{
+                AffineRepr, CurveGroup, MillerLoopOutput, Pairing, PairingOutput, PrimeGroup,
+            },
+        },
+        field::traits::AdditiveGroup,
+        test_rng,
+    };
+    use num_traits::Zero;
+
+    fn check_bilinearity<P: Pairing>() {
+        let rng = &mut test_rng();
+        let g1 = P::G1::generator();
+        let g2 = P::G2::generator();
+        let base = P::pairing(g1, g2);
+        assert!(!base.is_zero());
+        assert_eq!(PairingOutput::<P>::generator(), base);
+
+        for _ in 0..2 {
+            let a = P::ScalarField::rand(rng);
+            let b = P::ScalarField::rand(rng);
+            let expected = base * (a * b);
+            assert_eq!(P::pairing(g1 * a, g2 * b), expected);
+            assert_eq!(P::pairing(g1 * (a * b), g2), expected);
+            assert_eq!(P::pairing(g1, g2 * (a * b)), expected);
+        }
+    }
+
+    fn check_multi_pairing<P: Pairing>() {
+        let rng = &mut test_rng();
+        let [a, b, c] = [(); 3].map(|()| P::ScalarField::rand(rng));
+        let p = [P::G1::generator() * a, P::G1::generator() * b, P::G1::generator() * c];
+        let q = [P::G2::generator() * b, P::G2::generator() * c, P::G2::generator() * a];
+
+        let expected: PairingOutput<P> = p.iter().zip(&q).map(|(p, q)| P::pairing(*p, *q)).sum();
+        assert_eq!(P::multi_pairing(p, q), expected);
+        assert_eq!(
+            P::multi_pairing(P::G1::normalize_batch(&p), P::G2::normalize_batch(&q)),
+            expected
+        );
+
+        // `e(a * G1, G2) - e(G1, a * G2)` vanishes.
+        let g1 = P::G1::generator();
+        let g2 = P::G2::generator();
+        assert!(P::multi_pairing([g1 * a, -g1], [g2, g2 * a]).is_zero());
+    }
+
+    fn check_infinity<P: Pairing>() {
+        let g1 = P::G1Affine::generator();
+        let g2 = P::G2Affine::generator();
+        assert!(P::pairing(P::G1Affine::zero(), g2).is_zero());
+        assert!(P::pairing(g1, P::G2Affine::zero()).is_zero());
+        assert_eq!(P::multi_pairing([g1, P::G1Affine::zero()], [g2, g2]), P::pairing(g1, g2));
+        assert!(P::multi_pairing(Vec::<P::G1Affine>::new(), Vec::<P::G2Affine>::new()).is_zero());
+        assert_eq!(P::final_exponentiation(MillerLoopOutput(P::TargetField::ZERO)), None);
+    }
+
+    #[test]
+    fn bilinearity() {
+        check_bilinearity::<Bn254>();
+        check_bilinearity::<Bls12_381>();
+    }
+
+    #[test]
+    fn multi_pairing() {
+        check_multi_pairing::<Bn254>();
+        check_multi_pairing::<Bls12_381>();
+    }
+
+    #[test]
+    fn infinity() {
+        check_infinity::<Bn254>();
+        check_infinity::<Bls12_381>();
+    }
+}
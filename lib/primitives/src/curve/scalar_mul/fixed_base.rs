@@ -0,0 +1,409 @@
+//! Fixed-base scalar multiplication, i.e. computing many multiples `s * G` of
+//! the same point `G` from tables precomputed once.
+//!
+//! Two precomputations are provided:
+//!
+//! * [`FixedBase`] stores every multiple of `G` by a window of the scalar, so
+//!   that a multiplication only costs one addition per window.
+//! * [`Comb`] implements the [Lim-Lee comb method], whose much smaller table
+//!   is traded against one doubling per column of the comb.
+//!
+//! Table lookups scan the whole table with [`AffineRepr::ct_select`], and the
+//! scalars are recoded so that the same sequence of group operations is
+//! performed for every scalar. On curve models with incomplete
+//! addition formulae, the exceptional cases are only reached with negligible
+//! probability.
+//!
+//! [Lim-Lee comb method]: https://link.springer.com/chapter/10.1007/3-540-48658-5_11
+use super::ln_without_floats;
+use crate::{
+    arithmetic::bigint::BigInteger,
+    curve::traits::{AffineRepr, CurveGroup},
+    field::traits::{Field, PrimeField},
+};
+use alloc::vec::Vec;
+use fina_common::{
+    cfg_iter,
+    io::{Read, Write},
+};
+use fina_serialize::{ArkDeserialize, ArkSerialize, Compress, SerializationError, Valid, Validate};
+use num_traits::Zero;
+
+/// Largest supported window size of [`FixedBase`] and number of teeth of
+/// [`Comb`].
+pub const MAX_WINDOW_SIZE: usize = 16;
+
+/// Largest window size picked by [`FixedBase::window_size`].
+///
+/// Since every lookup scans its whole table, a multiplication costs about
+/// `2^w * bits / w` conditional selections besides its `bits / w` additions,
+/// so that the cost per scalar grows again past small windows, however many
+/// scalars amortize the precomputation. On BN254 G1, the `fixed_base` bench
+/// measures the fastest multiplications for `w` between `5` and `7`, and
+/// about four times slower ones for `w = 12`.
+const SCAN_WINDOW_SIZE: usize = 6;
+
+/// Precomputed window tables of a fixed base point.
+///
+/// The `i`-th table contains the multiples `(j + 1) * 2^(i * w) * G` for
+/// `0 <= j < 2^w`, where `w` is the window size. A scalar is split into
+/// windows of `w` bits, each selecting one entry of its table, and the
+/// resulting sum is corrected by the offset `Σ 2^(i * w) * G`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedBase<G: CurveGroup> {
+    window: usize,
+    tables: Vec<Vec<G::Affine>>,
+    offset: G::Affine,
+}
+
+impl<G: CurveGroup> FixedBase<G> {
+    /// Returns a window size suited to computing `num_scalars` multiples of
+    /// the same base.
+    ///
+    /// Smaller windows keep the precomputation cheap for few scalars, and
+    /// the window size is capped by the cost of the table scans.
+    #[must_use]
+    pub fn window_size(num_scalars: usize) -> usize {
+        if num_scalars < 32 { 3 } else { ln_without_floats(num_scalars).min(SCAN_WINDOW_SIZE) }
+    }
+
+    /// Precomputes the window tables of `base` for windows of `window` bits.
+    ///
+    /// # Panics
+    ///
+    /// * If `window` is zero or larger than [`MAX_WINDOW_SIZE`].
+    pub fn new(base: &G, window: usize) -> Self {
+        assert!((1..=MAX_WINDOW_SIZE).contains(&window), "window size should be in 1..=16");
+
+        let num_windows = num_windows::<G>(window);
+        let mut tables = Vec::with_capacity(num_windows);
+        let mut offset = G::zero();
+        let mut window_base = *base;
+        for _ in 0..num_windows {
+            let mut row = Vec::with_capacity(1 << window);
+            let mut multiple = window_base;
+            for _ in 0..1 << window {
+                row.push(multiple);
+                multiple += &window_base;
+            }
+            offset += &window_base;
+            tables.push(G::normalize_batch(&row));
+
+            // The last entry of the table is `2^w * window_base`.
+            window_base = *row.last().expect("table should not be empty");
+        }
+
+        Self { window, tables, offset: offset.into_affine() }
+    }
+
+    /// Returns the window size of the tables.
+    #[must_use]
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Computes `scalar * G` in constant time.
+    #[must_use]
+    pub fn mul(&self, scalar: &G::ScalarField) -> G {
+        let bigint = scalar.into_bigint();
+        let mut result = G::zero();
+        for (i, table) in self.tables.iter().enumerate() {
+            let digit = (0..self.window)
+                .map(|bit| usize::from(bigint.get_bit(i * self.window + bit)) << bit)
+                .sum();
+            result += ct_lookup(table, digit);
+        }
+        result - self.offset
+    }
+
+    /// Computes `[s * G for s in scalars]`, in affine coordinates.
+    #[must_use]
+    pub fn batch_mul(&self, scalars: &[G::ScalarField]) -> Vec<G::Affine> {
+        let results: Vec<G> = cfg_iter!(scalars).map(|s| self.mul(s)).collect();
+        G::normalize_batch(&results)
+    }
+}
+
+/// Precomputed table of the [Lim-Lee comb method] for a fixed base point.
+///
+/// A scalar of `n` bits is laid out in `t` rows ("teeth") of `d = ⌈n / t⌉`
+/// bits, so that each of the `d` columns selects one of the `2^(t - 1)`
+/// precomputed entries. The scalar is recoded into signed bits `±1`, so
+/// that every column is non-zero and only half of the combinations need to
+/// be stored.
+///
+/// [Lim-Lee comb method]: https://link.springer.com/chapter/10.1007/3-540-48658-5_11
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Comb<G: CurveGroup> {
+    teeth: usize,
+    table: Vec<G::Affine>,
+}
+
+impl<G: CurveGroup> Comb<G> {
+    /// Precomputes the comb table of `base` with `teeth` teeth.
+    ///
+    /// # Panics
+    ///
+    /// * If `teeth` is zero or larger than [`MAX_WINDOW_SIZE`].
+    pub fn new(base: &G, teeth: usize) -> Self {
+        assert!((1..=MAX_WINDOW_SIZE).contains(&teeth), "number of teeth should be in 1..=16");
+
+        let spacing = comb_spacing::<G>(teeth);
+        // `2^(d * m) * G` for every tooth `m`.
+        let mut tooth_bases = Vec::with_capacity(teeth);
+        let mut tooth_base = *base;
+        for _ in 0..teeth {
+            tooth_bases.push(tooth_base);
+            for _ in 0..spacing {
+                tooth_base.double_in_place();
+            }
+        }
+
+        // Entry `u` is `T_(t-1) + Σ_(m < t-1) (2 * u_m - 1) * T_m`, where `u_m`
+        // is the `m`-th bit of `u`.
+        let (top, lower) = tooth_bases.split_last().expect("comb should have a tooth");
+        let mut table = Vec::with_capacity(1 << (teeth - 1));
+        table.push(lower.iter().fold(*top, |acc, t| acc - t));
+        for u in 1usize..1 << (teeth - 1) {
+            let low_bit = u.trailing_zeros() as usize;
+            let entry = table[u & (u - 1)] + lower[low_bit].double();
+            table.push(entry);
+        }
+
+        Self { teeth, table: G::normalize_batch(&table) }
+    }
+
+    /// Returns the number of teeth of the comb.
+    #[must_use]
+    pub fn teeth(&self) -> usize {
+        self.teeth
+    }
+
+    /// Computes `scalar * G` in constant time.
+    #[must_use]
+    pub fn mul(&self, scalar: &G::ScalarField) -> G {
+        // The recoding needs an odd scalar: an even `k` is replaced by the odd
+        // `r - k`, and the result is negated.
+        let is_even = scalar.into_bigint().is_even();
+        let odd = scalar.ct_select(&-*scalar, is_even).into_bigint();
+
+        // With `N = t * d`, the odd scalar is `Σ s_i * 2^i`, where
+        // `s_i = 2 * b_(i + 1) - 1` for `i < N - 1` and `s_(N - 1) = 1`,
+        // `b_i` being the bits of the odd scalar.
+        let spacing = comb_spacing::<G>(self.teeth);
+        let top_index = spacing * (self.teeth - 1);
+        let signed_bit = |i: usize| i == spacing * self.teeth - 1 || odd.get_bit(i + 1);
+
+        let mut result = G::zero();
+        for column in (0..spacing).rev() {
+            result.double_in_place();
+
+            let top = signed_bit(column + top_index);
+            let index = (0..self.teeth - 1)
+                .map(|m| usize::from(signed_bit(column + spacing * m) == top) << m)
+                .sum();
+            let entry = ct_lookup(&self.table, index);
+            result += entry.ct_select(&-entry, top == is_even);
+        }
+        // The zero scalar is recoded like `1` and negated, which yields `-G`.
+        result.ct_select(&G::zero(), scalar.is_zero())
+    }
+
+    /// Computes `[s * G for s in scalars]`, in affine coordinates.
+    #[must_use]
+    pub fn batch_mul(&self, scalars: &[G::ScalarField]) -> Vec<G::Affine> {
+        let results: Vec<G> = cfg_iter!(scalars).map(|s| self.mul(s)).collect();
+        G::normalize_batch(&results)
+    }
+}
+
+/// Returns the number of windows of `window` bits covering a scalar.
+fn num_windows<G: CurveGroup>(window: usize) -> usize {
+    G::ScalarField::MODULUS.num_bits().div_ceil(window)
+}
+
+/// Returns the spacing `d` between the teeth of a comb.
+fn comb_spacing<G: CurveGroup>(teeth: usize) -> usize {
+    G::ScalarField::MODULUS.num_bits().div_ceil(teeth)
+}
+
+/// Returns `table[index]`, reading every entry of `table`.
+#[inline]
+fn ct_lookup<A: AffineRepr>(table: &[A], index: usize) -> A {
+    table.iter().enumerate().fold(A::zero(), |acc, (j, entry)| acc.ct_select(entry, j == index))
+}
+
+impl<G: CurveGroup> ArkSerialize for FixedBase<G> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.window.serialize_with_mode(&mut writer, compress)?;
+        self.tables.serialize_with_mode(&mut writer, compress)?;
+        self.offset.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.window.serialized_size(compress)
+            + self.tables.serialized_size(compress)
+            + self.offset.serialized_size(compress)
+    }
+}
+
+impl<G: CurveGroup> Valid for FixedBase<G> {
+    fn check(&self) -> Result<(), SerializationError> {
+        let well_formed = (1..=MAX_WINDOW_SIZE).contains(&self.window)
+            && self.tables.len() == num_windows::<G>(self.window)
+            && self.tables.iter().all(|table| table.len() == 1 << self.window);
+        if !well_formed {
+            return Err(SerializationError::InvalidData);
+        }
+        self.tables.check()?;
+        self.offset.check()
+    }
+}
+
+impl<G: CurveGroup> ArkDeserialize for FixedBase<G> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let window = usize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let tables = Vec::deserialize_with_mode(&mut reader, compress, Validate::No)?;
+        let offset = G::Affine::deserialize_with_mode(&mut reader, compress, Validate::No)?;
+        let fixed_base = Self { window, tables, offset };
+        if validate == Validate::Yes {
+            fixed_base.check()?;
+        }
+        Ok(fixed_base)
+    }
+}
+
+impl<G: CurveGroup> ArkSerialize for Comb<G> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.teeth.serialize_with_mode(&mut writer, compress)?;
+        self.table.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.teeth.serialized_size(compress) + self.table.serialized_size(compress)
+    }
+}
+
+impl<G: CurveGroup> Valid for Comb<G> {
+    fn check(&self) -> Result<(), SerializationError> {
+        let well_formed = (1..=MAX_WINDOW_SIZE).contains(&self.teeth)
+            && self.table.len() == 1 << (self.teeth - 1);
+        if !well_formed {
+            return Err(SerializationError::InvalidData);
+        }
+        self.table.check()
+    }
+}
+
+impl<G: CurveGroup> ArkDeserialize for Comb<G> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let teeth = usize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let table = Vec::deserialize_with_mode(&mut reader, compress, Validate::No)?;
+        let comb = Self { teeth, table };
+        if validate == Validate::Yes {
+            comb.check()?;
+        }
+        Ok(comb)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        curve::{
+            instances::{bandersnatch, ed25519},
+            traits::PrimeGroup,
+        },
+        test_rng,
+    };
+
+    fn scalars<F: PrimeField>() -> Vec<F> {
+        let rng = &mut test_rng();
+        let mut scalars = vec![F::zero(), F::one(), F::from(2u8), -F::one(), -F::from(2u8)];
+        scalars.extend((0..20).map(|_| F::rand(rng)));
+        scalars
+    }
+
+    #[test]
+    fn fixed_base_mul() {
+        let g = ed25519::EdwardsProjective::generator();
+        for window in [1, 3, 4, 8] {
+            let fixed_base = FixedBase::new(&g, window);
+            for s in scalars::<ed25519::Fr>() {
+                assert_eq!(fixed_base.mul(&s), g * s, "window {window}, scalar {s}");
+            }
+        }
+    }
+
+    #[test]
+    fn comb_mul() {
+        let g = bandersnatch::EdwardsProjective::generator();
+        for teeth in [1, 2, 5, 8] {
+            let comb = Comb::new(&g, teeth);
+            for s in scalars::<bandersnatch::Fr>() {
+                assert_eq!(comb.mul(&s), g * s, "teeth {teeth}, scalar {s}");
+            }
+        }
+    }
+
+    #[test]
+    fn batch_mul() {
+        let g = ed25519::EdwardsProjective::generator();
+        let scalars = scalars::<ed25519::Fr>();
+        let expected: Vec<_> = scalars.iter().map(|s| (g * s).into_affine()).collect();
+
+        let window = FixedBase::<ed25519::EdwardsProjective>::window_size(scalars.len());
+        assert_eq!(FixedBase::new(&g, window).batch_mul(&scalars), expected);
+        assert_eq!(Comb::new(&g, 6).batch_mul(&scalars), expected);
+    }
+
+    #[test]
+    fn window_size() {
+        let window_size = FixedBase::<ed25519::EdwardsProjective>::window_size;
+        assert_eq!(window_size(1), 3);
+        assert_eq!(window_size(64), 4);
+        assert_eq!(window_size(1 << 20), SCAN_WINDOW_SIZE);
+    }
+
+    #[test]
+    fn serialization_round_trip() {
+        let g = ed25519::EdwardsProjective::generator();
+
+        let fixed_base = FixedBase::new(&g, 2);
+        let mut bytes = Vec::new();
+        fixed_base.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), fixed_base.compressed_size());
+        assert_eq!(FixedBase::deserialize_compressed(bytes.as_slice()).unwrap(), fixed_base);
+
+        let comb = Comb::new(&g, 4);
+        let mut bytes = Vec::new();
+        comb.serialize_uncompressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), comb.uncompressed_size());
+        assert_eq!(Comb::deserialize_uncompressed(bytes.as_slice()).unwrap(), comb);
+
+        // Truncated tables are rejected.
+        let truncated = Comb::<ed25519::EdwardsProjective> { teeth: 5, table: comb.table };
+        let mut bytes = Vec::new();
+        truncated.serialize_uncompressed(&mut bytes).unwrap();
+        assert!(
+            Comb::<ed25519::EdwardsProjective>::deserialize_uncompressed(bytes.as_slice()).is_err()
+        );
+    }
+}
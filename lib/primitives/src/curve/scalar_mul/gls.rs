@@ -0,0 +1,239 @@
+//! Scalar multiplication accelerated by the untwist-Frobenius-twist
+//! endomorphism, following [Galbraith, Lin and Scott].
+//!
+//! On the sextic twist over `Fq2` carrying the G2 group of a BN or BLS12
+//! curve, `ψ(x, y) = (c_x * x^q, c_y * y^q)` is an endomorphism acting on G2
+//! as the multiplication by `λ = q mod r`, which is the curve parameter `x`
+//! for BLS12 curves and `6x²` for BN curves. A scalar `k < r` is written in
+//! base `|λ|` as `Σ kᵢ * |λ|^i`, and `k * P` is computed as
+//! `Σ kᵢ * (±ψ)^i(P)` with a joint double-and-add over digits of
+//! `log2(|λ|)` bits.
+//!
+//! [Galbraith, Lin and Scott]: https://eprint.iacr.org/2008/194.pdf
+use super::glv::{div_rem, in_prime_order_subgroup, to_limbs, to_scalar};
+use crate::{
+    bits::BitIteratorBE,
+    curve::{
+        sw::{SWCurveConfig, affine::Affine, projective::Projective, sw_double_and_add_projective},
+        traits::CurveGroup,
+    },
+    field::traits::{AdditiveGroup, Field, PrimeField},
+};
+use alloc::{vec, vec::Vec};
+use num_traits::Zero;
+
+/// Endomorphism parameters of a short Weierstrass curve over an extension
+/// field admitting the [GLS method](self).
+///
+/// The endomorphism only acts as the multiplication by its eigenvalue on the
+/// prime-order subgroup, so [`Self::gls_mul_projective`] assumes that its
+/// point lies in that subgroup.
+pub trait GLSConfig: SWCurveConfig {
+    /// Coefficient `c_x` of the endomorphism `ψ(x, y) = (c_x * x^q, c_y * y^q)`.
+    const PSI_COEFF_X: Self::BaseField;
+
+    /// Coefficient `c_y` of the endomorphism `ψ(x, y) = (c_x * x^q, c_y * y^q)`.
+    const PSI_COEFF_Y: Self::BaseField;
+
+    /// Absolute value of the eigenvalue `λ` of the endomorphism, i.e.
+    /// `ψ(P) = λ * P` for every point `P` of the prime-order subgroup.
+    const PSI_EIGENVALUE: u128;
+
+    /// Whether the eigenvalue `λ` is negative.
+    const PSI_EIGENVALUE_IS_NEGATIVE: bool;
+
+    /// Applies the endomorphism `ψ` to a point in Jacobian coordinates.
+    #[inline]
+    fn psi(p: &Projective<Self>) -> Projective<Self> {
+        Projective::new_unchecked(
+            p.x.frobenius_map(1) * Self::PSI_COEFF_X,
+            p.y.frobenius_map(1) * Self::PSI_COEFF_Y,
+            p.z.frobenius_map(1),
+        )
+    }
+
+    /// Applies the endomorphism `ψ` to a point in affine coordinates.
+    #[inline]
+    fn psi_affine(p: &Affine<Self>) -> Affine<Self> {
+        let mut res = *p;
+        res.x = p.x.frobenius_map(1) * Self::PSI_COEFF_X;
+        res.y = p.y.frobenius_map(1) * Self::PSI_COEFF_Y;
+        res
+    }
+
+    /// Decomposes `k` into its digits `kᵢ` in base `|λ|`, least significant
+    /// first, such that `k = Σ kᵢ * |λ|^i`.
+    fn scalar_decomposition(k: Self::ScalarField) -> Vec<u128> {
+        let radix = [Self::PSI_EIGENVALUE as u64, (Self::PSI_EIGENVALUE >> 64) as u64];
+        let mut digits = Vec::new();
+        let mut rest = k.into_bigint().as_ref().to_vec();
+        while rest.iter().any(|&limb| limb != 0) {
+            let (quotient, remainder) = div_rem(&rest, &radix);
+            digits.push(u128::from(remainder[0]) | (u128::from(remainder[1]) << 64));
+            rest = quotient;
+        }
+        digits
+    }
+
+    /// Computes `k * p` as `Σ kᵢ * (±ψ)^i(p)`, with a joint double-and-add
+    /// over the digits of the [decomposition](Self::scalar_decomposition) of
+    /// `k`.
+    fn gls_mul_projective(p: &Projective<Self>, k: Self::ScalarField) -> Projective<Self> {
+        let digits = Self::scalar_decomposition(k);
+
+        // `|λ|^i * p` for every digit, and the sums of all of their subsets.
+        let mut table = vec![Projective::zero()];
+        let mut base = *p;
+        for _ in &digits {
+            let sums: Vec<_> = table.iter().map(|t| *t + base).collect();
+            table.extend(sums);
+            base = Self::psi(&base);
+            if Self::PSI_EIGENVALUE_IS_NEGATIVE {
+                base = -base;
+            }
+        }
+        let table = Projective::normalize_batch(&table);
+
+        let num_bits = digits.iter().map(|d| u128::BITS - d.leading_zeros()).max().unwrap_or(0);
+        let mut res = Projective::zero();
+        for i in (0..num_bits).rev() {
+            res.double_in_place();
+            let index: usize =
+                digits.iter().enumerate().map(|(j, d)| usize::from((d >> i) & 1 == 1) << j).sum();
+            if index != 0 {
+                res += &table[index];
+            }
+        }
+        res
+    }
+
+    /// Computes `scalar * p`, where `scalar` is a *big-endian* bit
+    /// representation of some integer.
+    ///
+    /// The endomorphism only acts as `λ` on the prime-order subgroup, so this
+    /// falls back to double-and-add when `scalar` is not less than `r`, or
+    /// when `p` fails
+    /// [`SWCurveConfig::is_in_correct_subgroup_assuming_on_curve`], which
+    /// should then be implemented efficiently.
+    ///
+    /// Meant to be used as [`SWCurveConfig::mul_projective`] by curves
+    /// implementing this trait.
+    fn gls_mul_bits(p: &Projective<Self>, scalar: impl BitIteratorBE) -> Projective<Self> {
+        let limbs = to_limbs(scalar);
+        match to_scalar(&limbs) {
+            Some(k) if in_prime_order_subgroup(p) => Self::gls_mul_projective(p, k),
+            _ => sw_double_and_add_projective(p, limbs.as_slice()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        UniformRand,
+        curve::{
+            instances::{bls12_381, bn254},
+            scalar_mul::glv::test::plus_modulus,
+            sw::sw_double_and_add_affine,
+            traits::{AffineRepr, PrimeGroup},
+        },
+        test_rng,
+    };
+
+    fn check_endomorphism<P: GLSConfig>() {
+        let g = P::GENERATOR;
+        let mut expected = sw_double_and_add_affine(&g, P::PSI_EIGENVALUE);
+        if P::PSI_EIGENVALUE_IS_NEGATIVE {
+            expected = -expected;
+        }
+        assert_eq!(P::psi_affine(&g), expected);
+        assert_eq!(P::psi(&g.into_group()), expected);
+        assert!(P::psi_affine(&g).is_on_curve());
+    }
+
+    fn check_decomposition<P: GLSConfig>(k: P::ScalarField) {
+        let radix = P::ScalarField::from(P::PSI_EIGENVALUE);
+        let digits = P::scalar_decomposition(k);
+        assert!(digits.iter().all(|&d| d < P::PSI_EIGENVALUE));
+        assert_eq!(
+            digits
+                .iter()
+                .rev()
+                .fold(P::ScalarField::zero(), |acc, &d| acc * radix + P::ScalarField::from(d)),
+            k
+        );
+    }
+
+    fn check_mul<P: GLSConfig>(k: P::ScalarField) {
+        let g = P::GENERATOR;
+        let expected = sw_double_and_add_affine(&g, k.into_bigint());
+        assert_eq!(P::gls_mul_projective(&g.into_group(), k), expected);
+        assert_eq!(g * k, expected);
+        assert_eq!(g.into_group() * k, expected);
+    }
+
+    /// Returns a random point of the twist, which is almost surely not in
+    /// the prime-order subgroup.
+    fn random_point<P: GLSConfig>() -> Affine<P> {
+        let rng = &mut test_rng();
+        loop {
+            let x = P::BaseField::rand(rng);
+            if let Some(y) = (x.square() * x + P::mul_by_a(x) + P::COEFF_B).sqrt() {
+                return Affine::new_unchecked(x, y);
+            }
+        }
+    }
+
+    fn check_outside_of_the_subgroup<P: GLSConfig>() {
+        let p = random_point::<P>();
+        let r = P::ScalarField::MODULUS;
+        assert!(!sw_double_and_add_affine(&p, r).is_zero());
+        assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+        assert!(P::GENERATOR.is_in_correct_subgroup_assuming_on_curve());
+
+        let k = P::ScalarField::rand(&mut test_rng());
+        let p = p.into_group();
+        let expected = sw_double_and_add_projective(&p, k.into_bigint());
+        assert_eq!(P::gls_mul_bits(&p, k.into_bigint()), expected);
+        assert_eq!(p * k, expected);
+        assert_eq!(p.mul_bigint(r), sw_double_and_add_projective(&p, r));
+    }
+
+    fn check_unreduced_scalars<P: GLSConfig>() {
+        let g = P::GENERATOR.into_group();
+        let k = P::ScalarField::rand(&mut test_rng());
+        let k_plus_r = plus_modulus(k);
+        assert_eq!(P::gls_mul_bits(&g, k_plus_r.as_slice()), g * k);
+        let wide = [u64::MAX; 9];
+        assert_eq!(
+            g.mul_bigint(wide.as_slice()),
+            sw_double_and_add_projective(&g, wide.as_slice())
+        );
+    }
+
+    macro_rules! gls_tests {
+        ($name:ident, $config:ty, $fr:ty) => {
+            #[test]
+            fn $name() {
+                check_endomorphism::<$config>();
+                let lambda = <$fr>::from(<$config as GLSConfig>::PSI_EIGENVALUE);
+                for k in [<$fr>::ZERO, <$fr>::ONE, -<$fr>::ONE, lambda, -lambda] {
+                    check_decomposition::<$config>(k);
+                    check_mul::<$config>(k);
+                }
+                let rng = &mut test_rng();
+                for _ in 0..10 {
+                    let k = <$fr>::rand(rng);
+                    check_decomposition::<$config>(k);
+                    check_mul::<$config>(k);
+                }
+                check_outside_of_the_subgroup::<$config>();
+                check_unreduced_scalars::<$config>();
+            }
+        };
+    }
+
+    gls_tests!(bn254_gls, bn254::G2Config, bn254::Fr);
+    gls_tests!(bls12_381_gls, bls12_381::G2Config, bls12_381::Fr);
+}
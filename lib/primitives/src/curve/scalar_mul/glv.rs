@@ -0,0 +1,446 @@
+//! Scalar multiplication accelerated by an efficiently computable
+//! endomorphism, following [Gallant, Lambert and Vanstone].
+//!
+//! On curves `y² = x³ + b` over a field `Fq` with `q ≡ 1 (mod 3)`, the map
+//! `φ(x, y) = (β * x, y)`, where `β` is a non-trivial cube root of unity,
+//! acts on the prime-order subgroup as the multiplication by a scalar `λ`.
+//! A scalar `k` is then decomposed into two scalars `k1, k2` of half its
+//! size, such that `k = k1 + k2 * λ (mod r)`, and `k * P` is computed as
+//! `k1 * P + k2 * φ(P)` with half the number of doublings.
+//!
+//! [Gallant, Lambert and Vanstone]: https://www.iacr.org/archive/crypto2001/21390189.pdf
+use crate::{
+    arithmetic::{
+        bigint::BigInteger,
+        limb::{Limb, adc, carrying_mac, sbb},
+    },
+    bits::BitIteratorBE,
+    curve::{
+        scalar_mul::variable_base::VariableBaseMSM,
+        sw::{SWCurveConfig, affine::Affine, projective::Projective, sw_double_and_add_projective},
+        traits::CurveGroup,
+    },
+    field::traits::{AdditiveGroup, PrimeField},
+};
+use alloc::{vec, vec::Vec};
+use num_traits::Zero;
+
+/// Endomorphism and lattice parameters of a short Weierstrass curve admitting
+/// the [GLV method](self).
+///
+/// The endomorphism only acts as the multiplication by [`Self::LAMBDA`] on the
+/// prime-order subgroup, so every method of this trait assumes that its
+/// points lie in that subgroup.
+pub trait GLVConfig: SWCurveConfig {
+    /// Coefficient `β` of the endomorphism `φ(x, y) = (β * x, y)`.
+    const ENDO_COEFF: Self::BaseField;
+
+    /// Eigenvalue `λ` of the endomorphism, i.e. `φ(P) = λ * P` for every
+    /// point `P` of the prime-order subgroup.
+    const LAMBDA: Self::ScalarField;
+
+    /// Short basis `(a1, b1), (a2, b2)` of the lattice of the pairs `(x, y)`
+    /// such that `x + y * λ = 0 (mod r)`, with `a1 * b2 - a2 * b1 = r`.
+    ///
+    /// The coefficients are given in the order `a1, b1, a2, b2`, each as a
+    /// pair of a sign (`true` if non-negative) and a magnitude.
+    const SCALAR_DECOMP_COEFFS: [(bool, <Self::ScalarField as PrimeField>::BigInt); 4];
+
+    /// Decomposes `k` into `(k1, k2)`, such that `k = k1 + k2 * λ (mod r)`,
+    /// with `|k1|, |k2| = O(√r)`.
+    ///
+    /// Both scalars are returned as a pair of a sign (`true` if non-negative)
+    /// and a magnitude.
+    fn scalar_decomposition(
+        k: Self::ScalarField,
+    ) -> ((bool, Self::ScalarField), (bool, Self::ScalarField)) {
+        let [_, (b1_sign, b1), _, (b2_sign, b2)] = Self::SCALAR_DECOMP_COEFFS;
+        let modulus = Self::ScalarField::MODULUS;
+        let k_bigint = k.into_bigint();
+
+        // `(c1, c2)` is the closest lattice vector to `(k, 0)`, expressed in
+        // the basis: `c1 = round(k * b2 / r)` and `c2 = round(-k * b1 / r)`.
+        let c1 = signed::<Self::ScalarField>(b2_sign, mul_div_round(&k_bigint, &b2, &modulus));
+        let c2 = signed::<Self::ScalarField>(!b1_sign, mul_div_round(&k_bigint, &b1, &modulus));
+
+        let [a1, b1, a2, b2] =
+            Self::SCALAR_DECOMP_COEFFS.map(|(sign, c)| signed::<Self::ScalarField>(sign, c));
+        let k1 = k - c1 * a1 - c2 * a2;
+        let k2 = -(c1 * b1) - c2 * b2;
+        (sign_and_magnitude(k1), sign_and_magnitude(k2))
+    }
+
+    /// Applies the endomorphism `φ` to a point in Jacobian coordinates.
+    #[inline]
+    fn endomorphism(p: &Projective<Self>) -> Projective<Self> {
+        let mut res = *p;
+        res.x *= Self::ENDO_COEFF;
+        res
+    }
+
+    /// Applies the endomorphism `φ` to a point in affine coordinates.
+    #[inline]
+    fn endomorphism_affine(p: &Affine<Self>) -> Affine<Self> {
+        let mut res = *p;
+        res.x *= Self::ENDO_COEFF;
+        res
+    }
+
+    /// Computes `k * p` as `k1 * p + k2 * φ(p)`, with a joint double-and-add
+    /// over the bits of the [decomposition](Self::scalar_decomposition) of
+    /// `k`.
+    fn glv_mul_projective(p: &Projective<Self>, k: Self::ScalarField) -> Projective<Self> {
+        let ((k1_sign, k1), (k2_sign, k2)) = Self::scalar_decomposition(k);
+        let b1 = if k1_sign { *p } else { -*p };
+        let b2 = Self::endomorphism(&b1);
+        let b2 = if k1_sign == k2_sign { b2 } else { -b2 };
+        let table = Projective::normalize_batch(&[b1, b2, b1 + b2]);
+
+        let (k1, k2) = (k1.into_bigint(), k2.into_bigint());
+        let mut res = Projective::zero();
+        for i in (0..k1.num_bits().max(k2.num_bits())).rev() {
+            res.double_in_place();
+            match (k1.get_bit(i), k2.get_bit(i)) {
+                (true, false) => res += &table[0],
+                (false, true) => res += &table[1],
+                (true, true) => res += &table[2],
+                (false, false) => {},
+            }
+        }
+        res
+    }
+
+    /// Computes `k * p` for a point in affine coordinates, see
+    /// [`Self::glv_mul_projective`].
+    fn glv_mul_affine(p: &Affine<Self>, k: Self::ScalarField) -> Projective<Self> {
+        Self::glv_mul_projective(&(*p).into(), k)
+    }
+
+    /// Computes `scalar * p`, where `scalar` is a *big-endian* bit
+    /// representation of some integer.
+    ///
+    /// The decomposition only holds modulo `r`, and the endomorphism only acts
+    /// as `λ` on the prime-order subgroup, so this falls back to
+    /// double-and-add when `scalar` is not less than `r`, or when the cofactor
+    /// is not one and `p` fails
+    /// [`SWCurveConfig::is_in_correct_subgroup_assuming_on_curve`].
+    ///
+    /// Meant to be used as [`SWCurveConfig::mul_projective`] by curves
+    /// implementing this trait.
+    fn glv_mul_bits(p: &Projective<Self>, scalar: impl BitIteratorBE) -> Projective<Self> {
+        let limbs = to_limbs(scalar);
+        match to_scalar(&limbs) {
+            Some(k) if in_prime_order_subgroup(p) => Self::glv_mul_projective(p, k),
+            _ => sw_double_and_add_projective(p, limbs.as_slice()),
+        }
+    }
+
+    /// Computes `Σ sᵢ * Gᵢ` as a multi-scalar multiplication of twice as many
+    /// points, `±Gᵢ` and `±φ(Gᵢ)`, with scalars of half the size.
+    ///
+    /// The endomorphism only acts as `λ` on the prime-order subgroup, so this
+    /// falls back to the default multi-scalar multiplication when the cofactor
+    /// is not one and some base fails
+    /// [`SWCurveConfig::is_in_correct_subgroup_assuming_on_curve`].
+    ///
+    /// Meant to be used as [`SWCurveConfig::msm_unchecked`] by curves
+    /// implementing this trait.
+    fn glv_msm(bases: &[Affine<Self>], scalars: &[Self::ScalarField]) -> Projective<Self> {
+        let size = bases.len().min(scalars.len());
+        if !Self::cofactor_is_one()
+            && !bases[..size].iter().all(Self::is_in_correct_subgroup_assuming_on_curve)
+        {
+            let bigints: Vec<_> = scalars.iter().map(|s| s.into_bigint()).collect();
+            return Projective::msm_bigint(bases, &bigints);
+        }
+
+        let mut glv_bases = Vec::with_capacity(2 * size);
+        let mut glv_bigints = Vec::with_capacity(2 * size);
+        for (base, scalar) in bases.iter().zip(scalars) {
+            let ((k1_sign, k1), (k2_sign, k2)) = Self::scalar_decomposition(*scalar);
+            let endo = Self::endomorphism_affine(base);
+            glv_bases.push(if k1_sign { *base } else { -*base });
+            glv_bases.push(if k2_sign { endo } else { -endo });
+            glv_bigints.push(k1.into_bigint());
+            glv_bigints.push(k2.into_bigint());
+        }
+        Projective::msm_bigint(&glv_bases, &glv_bigints)
+    }
+}
+
+/// Returns the field element `±magnitude`.
+#[inline]
+fn signed<F: PrimeField>(sign: bool, magnitude: F::BigInt) -> F {
+    let value = F::from_bigint(magnitude);
+    if sign { value } else { -value }
+}
+
+/// Returns `(true, k)` if `k` is the smaller of `k` and `-k` as integers, and
+/// `(false, -k)` otherwise.
+#[inline]
+fn sign_and_magnitude<F: PrimeField>(k: F) -> (bool, F) {
+    let neg = -k;
+    if neg.into_bigint() < k.into_bigint() { (false, neg) } else { (true, k) }
+}
+
+/// Computes `round(a * b / d)`, assuming that the result fits in `B`.
+fn mul_div_round<B: BigInteger>(a: &B, b: &B, d: &B) -> B {
+    let (a, b, d) = (a.as_ref(), b.as_ref(), d.as_ref());
+
+    // Schoolbook multiplication, with one extra limb for the rounding.
+    let mut num = vec![0; a.len() + b.len() + 1];
+    for (i, &a_i) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &b_j) in b.iter().enumerate() {
+            (num[i + j], carry) = carrying_mac(num[i + j], a_i, b_j, carry);
+        }
+        num[i + b.len()] = carry;
+    }
+
+    // `round(n / d) = floor((n + floor(d / 2)) / d)`.
+    let mut carry = false;
+    for (i, limb) in num.iter_mut().enumerate() {
+        let half_d = d.get(i).map_or(0, |&d_i| (d_i >> 1) | (d.get(i + 1).map_or(0, |&d| d << 63)));
+        (*limb, carry) = adc(*limb, half_d, carry);
+    }
+
+    let (quotient, _) = div_rem(&num, d);
+    let bytes: Vec<u8> = quotient
+        .iter()
+        .take(B::NUM_LIMBS)
+        .chain(core::iter::repeat(&0))
+        .take(B::NUM_LIMBS)
+        .flat_map(|limb| limb.to_le_bytes())
+        .collect();
+    B::from_bytes_le(&bytes)
+}
+
+/// Returns whether `p` is in the prime-order subgroup, assuming that it is on
+/// the curve: either the cofactor is one, or `p` passes
+/// [`SWCurveConfig::is_in_correct_subgroup_assuming_on_curve`].
+pub(super) fn in_prime_order_subgroup<P: SWCurveConfig>(p: &Projective<P>) -> bool {
+    P::cofactor_is_one() || P::is_in_correct_subgroup_assuming_on_curve(&(*p).into())
+}
+
+/// Collects the bits of `scalar` into little-endian limbs.
+pub(super) fn to_limbs(scalar: impl BitIteratorBE) -> Vec<Limb> {
+    let bits: Vec<bool> = scalar.bit_be_trimmed_iter().collect();
+    let mut limbs = vec![0; bits.len().div_ceil(64)];
+    for (i, &bit) in bits.iter().rev().enumerate() {
+        limbs[i / 64] |= Limb::from(bit) << (i % 64);
+    }
+    limbs
+}
+
+/// Returns the integer with little-endian `limbs` as an element of `F`, if
+/// it is less than the modulus.
+pub(super) fn to_scalar<F: PrimeField>(limbs: &[Limb]) -> Option<F> {
+    if !less_than(limbs, F::MODULUS.as_ref()) {
+        return None;
+    }
+    let bytes: Vec<u8> = limbs
+        .iter()
+        .chain(core::iter::repeat(&0))
+        .take(F::BigInt::NUM_LIMBS)
+        .flat_map(|limb| limb.to_le_bytes())
+        .collect();
+    Some(F::from_bigint(F::BigInt::from_bytes_le(&bytes)))
+}
+
+/// Computes the quotient and the remainder of the division of `num` by `d`,
+/// with a binary long division.
+pub(super) fn div_rem(num: &[Limb], d: &[Limb]) -> (Vec<Limb>, Vec<Limb>) {
+    let mut quotient: Vec<Limb> = vec![0; num.len()];
+    let mut rem = vec![0; d.len() + 1];
+    for i in (0..num.len() * Limb::BITS as usize).rev() {
+        let mut bit = (num[i / 64] >> (i % 64)) & 1;
+        for limb in &mut rem {
+            (*limb, bit) = ((*limb << 1) | bit, *limb >> 63);
+        }
+
+        if !less_than(&rem, d) {
+            let mut borrow = false;
+            for (j, limb) in rem.iter_mut().enumerate() {
+                (*limb, borrow) = sbb(*limb, d.get(j).copied().unwrap_or(0), borrow);
+            }
+            quotient[i / 64] |= 1 << (i % 64);
+        }
+    }
+    (quotient, rem)
+}
+
+/// Compares two little-endian limb slices, padded with zeros.
+fn less_than(a: &[Limb], b: &[Limb]) -> bool {
+    let len = a.len().max(b.len());
+    for i in (0..len).rev() {
+        let (a_i, b_i) = (a.get(i).copied().unwrap_or(0), b.get(i).copied().unwrap_or(0));
+        if a_i != b_i {
+            return a_i < b_i;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+pub(super) mod test {
+    use super::*;
+    use crate::{
+        UniformRand,
+        arithmetic::U256,
+        curve::{
+            instances::{bls12_381, bn254, pallas, secp256k1, vesta},
+            sw::sw_double_and_add_affine,
+            traits::{AffineRepr, PrimeGroup},
+        },
+        fp_from_hex, fp_from_num, test_rng,
+    };
+
+    #[test]
+    fn mul_div_round_rounds_to_nearest() {
+        let d = U256::from(10u64);
+        for (a, b, expected) in [(7u64, 2u64, 1u64), (3, 5, 2), (1, 4, 0), (9, 9, 8), (0, 5, 0)] {
+            let q = mul_div_round(&U256::from(a), &U256::from(b), &d);
+            assert_eq!(q, U256::from(expected), "round({a} * {b} / 10)");
+        }
+
+        let a = U256::new([u64::MAX, u64::MAX, 0, 0]);
+        let d = U256::new([0, 1, 0, 0]);
+        // round((2^128 - 1)² / 2^64) = 2^192 - 2^65 + 0 (remainder 1 / 2^64).
+        assert_eq!(mul_div_round(&a, &a, &d), U256::new([0, u64::MAX - 1, u64::MAX, 0]));
+    }
+
+    fn check_decomposition<P: GLVConfig>(k: P::ScalarField) {
+        let ((k1_sign, k1), (k2_sign, k2)) = P::scalar_decomposition(k);
+        let half_bits = P::ScalarField::MODULUS.num_bits().div_ceil(2) + 1;
+        assert!(k1.into_bigint().num_bits() <= half_bits);
+        assert!(k2.into_bigint().num_bits() <= half_bits);
+
+        let k1 = if k1_sign { k1 } else { -k1 };
+        let k2 = if k2_sign { k2 } else { -k2 };
+        assert_eq!(k1 + k2 * P::LAMBDA, k);
+    }
+
+    fn check_endomorphism<P: GLVConfig>() {
+        let g = P::GENERATOR;
+        let expected = sw_double_and_add_affine(&g, P::LAMBDA.into_bigint());
+        assert_eq!(P::endomorphism_affine(&g), expected);
+        assert_eq!(P::endomorphism(&g.into_group()), expected);
+        assert!(P::endomorphism_affine(&g).is_on_curve());
+    }
+
+    fn check_mul<P: GLVConfig>(k: P::ScalarField) {
+        let g = P::GENERATOR;
+        let expected = sw_double_and_add_affine(&g, k.into_bigint());
+        assert_eq!(P::glv_mul_affine(&g, k), expected);
+        assert_eq!(g * k, expected);
+        assert_eq!(g.into_group() * k, expected);
+    }
+
+    macro_rules! glv_tests {
+        ($name:ident, $config:ty, $fr:ty) => {
+            #[test]
+            fn $name() {
+                check_endomorphism::<$config>();
+                for k in [<$fr>::ZERO, <$fr>::ONE, -<$fr>::ONE, <$config as GLVConfig>::LAMBDA] {
+                    check_decomposition::<$config>(k);
+                    check_mul::<$config>(k);
+                }
+                let rng = &mut test_rng();
+                for _ in 0..20 {
+                    let k = <$fr>::rand(rng);
+                    check_decomposition::<$config>(k);
+                    check_mul::<$config>(k);
+                }
+            }
+        };
+    }
+
+    /// Returns the limbs of `k + r`.
+    pub(in crate::curve::scalar_mul) fn plus_modulus<F: PrimeField>(k: F) -> Vec<Limb> {
+        let (k, r) = (k.into_bigint(), F::MODULUS);
+        let mut carry = false;
+        let mut limbs: Vec<_> = k
+            .as_ref()
+            .iter()
+            .zip(r.as_ref())
+            .map(|(&k, &r)| {
+                let sum;
+                (sum, carry) = adc(k, r, carry);
+                sum
+            })
+            .collect();
+        limbs.push(Limb::from(carry));
+        limbs
+    }
+
+    #[test]
+    fn unreduced_scalars() {
+        let rng = &mut test_rng();
+        let g = bn254::G1Affine::generator();
+        let k = bn254::Fr::rand(rng);
+        assert_eq!(g.mul_bigint(plus_modulus(k).as_slice()), g * k);
+        let wide = [u64::MAX; 5];
+        assert_eq!(g.mul_bigint(wide.as_slice()), sw_double_and_add_affine(&g, wide.as_slice()));
+    }
+
+    #[test]
+    fn points_outside_of_the_subgroup() {
+        // `(4, y)` is on the curve, but not in the prime-order subgroup.
+        let p = bls12_381::G1Affine::new_unchecked(
+            fp_from_num!("4"),
+            fp_from_hex!(
+                "0A989BADD40D6212B33CFFC3F3763E9BC760F988C9926B26DA9DD85E928483446346B8ED00E1DE5D5EA93E354ABE706C"
+            ),
+        );
+        assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+
+        let rng = &mut test_rng();
+        let k = bls12_381::Fr::rand(rng);
+        let p = p.into_group();
+        for scalar in [
+            k.into_bigint().as_ref().to_vec(),
+            plus_modulus(k),
+            bls12_381::Fr::MODULUS.as_ref().to_vec(),
+        ] {
+            let expected = sw_double_and_add_projective(&p, scalar.as_slice());
+            assert_eq!(bls12_381::G1Config::glv_mul_bits(&p, scalar.as_slice()), expected);
+            assert_eq!(p.mul_bigint(scalar.as_slice()), expected);
+        }
+        assert!(!p.mul_bigint(bls12_381::Fr::MODULUS).is_zero());
+
+        let g = bls12_381::G1Affine::generator();
+        let bases = [g, p.into_affine()];
+        let scalars = [bls12_381::Fr::rand(rng), k];
+        let expected = g * scalars[0] + sw_double_and_add_projective(&p, k.into_bigint());
+        assert_eq!(bls12_381::G1Config::glv_msm(&bases, &scalars), expected);
+        assert_eq!(bls12_381::G1Projective::msm(&bases, &scalars).unwrap(), expected);
+    }
+
+    glv_tests!(bn254_glv, bn254::G1Config, bn254::Fr);
+    glv_tests!(bls12_381_glv, bls12_381::G1Config, bls12_381::Fr);
+    glv_tests!(secp256k1_glv, secp256k1::Secp256k1Config, secp256k1::Fr);
+    glv_tests!(pallas_glv, pallas::PallasConfig, pallas::Fr);
+    glv_tests!(vesta_glv, vesta::VestaConfig, vesta::Fr);
+
+    fn check_msm<P: GLVConfig>() {
+        let rng = &mut test_rng();
+        let g = P::GENERATOR;
+        let bases: Vec<_> =
+            (0..70).map(|_| (g * P::ScalarField::rand(rng)).into_affine()).collect();
+        let scalars: Vec<_> = (0..70).map(|_| P::ScalarField::rand(rng)).collect();
+
+        let expected: Projective<P> = bases
+            .iter()
+            .zip(&scalars)
+            .map(|(base, scalar)| sw_double_and_add_affine(base, scalar.into_bigint()))
+            .sum();
+        assert_eq!(Projective::<P>::msm(&bases, &scalars).unwrap(), expected);
+        assert_eq!(P::glv_msm(&bases, &scalars), expected);
+    }
+
+    #[test]
+    fn msm_uses_glv() {
+        check_msm::<bn254::G1Config>();
+        check_msm::<bls12_381::G1Config>();
+    }
+}
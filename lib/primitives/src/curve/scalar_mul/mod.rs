@@ -0,0 +1,12 @@
+//! This module provides algorithms for multiplying curve points by scalars.
+pub mod fixed_base;
+pub mod gls;
+pub mod glv;
+pub mod variable_base;
+
+/// Returns `ln(a)` approximated as `log2(a) * 69 / 100`, where `log2` is the
+/// ceiling of the base-2 logarithm.
+#[inline]
+pub(crate) fn ln_without_floats(a: usize) -> usize {
+    (crate::log2(a) * 69 / 100) as usize
+}
@@ -0,0 +1,363 @@
+//! Variable-base multi-scalar multiplication, i.e. computing `Σ sᵢ * Gᵢ` for
+//! arbitrary points `Gᵢ`, using [Pippenger's algorithm].
+//!
+//! [Pippenger's algorithm]: https://eprint.iacr.org/2022/1400.pdf
+use super::ln_without_floats;
+use crate::{
+    arithmetic::bigint::BigInteger,
+    curve::traits::{AffineRepr, CurveGroup},
+    field::traits::PrimeField,
+};
+use alloc::{vec, vec::Vec};
+use core::borrow::Borrow;
+use fina_common::{cfg_into_iter, iterable::Iterable};
+
+/// Minimal number of points in a window for the bucket accumulation to use
+/// batched affine additions instead of mixed projective additions.
+const BATCH_AFFINE_THRESHOLD: usize = 1 << 6;
+
+/// Variable-base multi-scalar multiplication for a curve group.
+pub trait VariableBaseMSM: CurveGroup {
+    /// Computes `[lhs_i + rhs_i]` in affine coordinates, for every pair
+    /// `(lhs_i, rhs_i)` of `pairs`.
+    ///
+    /// The default implementation adds in projective coordinates and
+    /// normalizes the results with a single inversion.
+    /// Curve models having cheap affine addition formulae should override it
+    /// to share one inversion across every addition of the batch.
+    fn batch_add_affine_pairs(pairs: &[(Self::Affine, Self::Affine)]) -> Vec<Self::Affine> {
+        let sums: Vec<Self> = pairs.iter().map(|(lhs, rhs)| *lhs + rhs).collect();
+        Self::normalize_batch(&sums)
+    }
+
+    /// Computes an inner product between the [`PrimeField`] elements in
+    /// `scalars` and the corresponding group elements in `bases`.
+    ///
+    /// If the elements have different length, it will chop the slices to the
+    /// shortest length between `scalars.len()` and `bases.len()`.
+    fn msm_unchecked(bases: &[Self::Affine], scalars: &[Self::ScalarField]) -> Self {
+        let bigints: Vec<_> = cfg_into_iter!(scalars).map(|s| s.into_bigint()).collect();
+        Self::msm_bigint(bases, &bigints)
+    }
+
+    /// Performs multi-scalar multiplication.
+    ///
+    /// # Errors
+    ///
+    /// If `bases` and `scalars` have different lengths, returns the smallest
+    /// of the two lengths.
+    fn msm(bases: &[Self::Affine], scalars: &[Self::ScalarField]) -> Result<Self, usize> {
+        (bases.len() == scalars.len())
+            .then(|| Self::msm_unchecked(bases, scalars))
+            .ok_or_else(|| bases.len().min(scalars.len()))
+    }
+
+    /// Optimized implementation of multi-scalar multiplication, taking the
+    /// scalars in their integer representation.
+    fn msm_bigint(
+        bases: &[Self::Affine],
+        bigints: &[<Self::ScalarField as PrimeField>::BigInt],
+    ) -> Self {
+        msm_signed_digits(bases, bigints)
+    }
+
+    /// Streaming multi-scalar multiplication algorithm, for inputs that do
+    /// not fit in memory.
+    ///
+    /// Consumes `bases_stream` and `scalars_stream` in chunks of `chunk_size`
+    /// elements, so that at most `chunk_size` points and scalars are held in
+    /// memory at once. Stops at the end of the shortest stream.
+    ///
+    /// # Panics
+    ///
+    /// * If `chunk_size` is zero.
+    fn msm_chunks<I, J>(bases_stream: &J, scalars_stream: &I, chunk_size: usize) -> Self
+    where
+        I: Iterable + ?Sized,
+        I::Item: Borrow<Self::ScalarField>,
+        J: Iterable,
+        J::Item: Borrow<Self::Affine>,
+    {
+        assert!(chunk_size > 0, "chunk size should be positive");
+
+        let mut bases_iter = bases_stream.iter();
+        let mut scalars_iter = scalars_stream.iter();
+        let mut result = Self::zero();
+
+        loop {
+            let bases: Vec<Self::Affine> =
+                bases_iter.by_ref().take(chunk_size).map(|b| *b.borrow()).collect();
+            let bigints: Vec<_> =
+                scalars_iter.by_ref().take(bases.len()).map(|s| s.borrow().into_bigint()).collect();
+            if bigints.is_empty() {
+                break;
+            }
+
+            result += Self::msm_bigint(&bases[..bigints.len()], &bigints);
+            if bigints.len() < chunk_size {
+                break;
+            }
+        }
+
+        result
+    }
+}
+
+/// Pippenger's algorithm over signed digits of radix `2^c`.
+///
+/// Each window of `c` bits of a scalar is recoded into a digit in
+/// `[-2^(c - 1), 2^(c - 1)]`, so that only `2^(c - 1)` buckets are needed per
+/// window, since a point is subtracted from the bucket of `-d` for a negative
+/// digit `d`.
+fn msm_signed_digits<G: VariableBaseMSM>(
+    bases: &[G::Affine],
+    bigints: &[<G::ScalarField as PrimeField>::BigInt],
+) -> G {
+    let size = bases.len().min(bigints.len());
+    if size == 0 {
+        return G::zero();
+    }
+    let (bases, bigints) = (&bases[..size], &bigints[..size]);
+
+    let c = if size < 32 { 3 } else { ln_without_floats(size) + 2 };
+    // Scalars shorter than the modulus, eg: after an endomorphism-based
+    // decomposition, need fewer windows.
+    let num_bits = bigints.iter().map(BigInteger::num_bits).max().unwrap_or(0);
+    let digits_count = num_bits.div_ceil(c);
+
+    let digits: Vec<Vec<i64>> =
+        cfg_into_iter!(bigints).map(|b| make_digits(b, c, num_bits).collect()).collect();
+
+    let window_sums: Vec<G> = cfg_into_iter!(0..digits_count)
+        .map(|i| {
+            // The carry of the top window is not propagated, so its digits
+            // can reach `2^c`.
+            let num_buckets = if i == digits_count - 1 { 1 << c } else { 1 << (c - 1) };
+            let entries = digits.iter().zip(bases).filter_map(|(digits, base)| {
+                let digit = digits[i];
+                match digit.signum() {
+                    1 => Some(((digit - 1) as usize, *base)),
+                    -1 => Some(((-digit - 1) as usize, -*base)),
+                    _ => None,
+                }
+            });
+
+            let buckets = accumulate_buckets::<G>(entries, num_buckets);
+
+            // Σ (j + 1) * bucket_j, computed with a running sum.
+            let mut running_sum = G::zero();
+            let mut res = G::zero();
+            for bucket in buckets.into_iter().rev() {
+                running_sum += bucket;
+                res += &running_sum;
+            }
+            res
+        })
+        .collect();
+
+    // Combine the windows from the most significant one.
+    window_sums.into_iter().rev().fold(G::zero(), |mut total, sum_i| {
+        for _ in 0..c {
+            total.double_in_place();
+        }
+        total + sum_i
+    })
+}
+
+/// Sums the points falling in each of the `num_buckets` buckets.
+///
+/// Small inputs are accumulated with mixed projective additions. Larger
+/// inputs are sorted by bucket and reduced pairwise, level by level, with
+/// [`VariableBaseMSM::batch_add_affine_pairs`], which shares one inversion
+/// across every addition of a level.
+fn accumulate_buckets<G: VariableBaseMSM>(
+    entries: impl Iterator<Item = (usize, G::Affine)>,
+    num_buckets: usize,
+) -> Vec<G::Affine> {
+    let mut entries: Vec<_> = entries.filter(|(_, p)| !p.is_zero()).collect();
+
+    if entries.len() < BATCH_AFFINE_THRESHOLD {
+        let mut buckets = vec![G::zero(); num_buckets];
+        for (index, point) in entries {
+            buckets[index] += point;
+        }
+        return G::normalize_batch(&buckets);
+    }
+
+    entries.sort_unstable_by_key(|(index, _)| *index);
+
+    loop {
+        let mut pairs = Vec::new();
+        let mut next = Vec::with_capacity(entries.len());
+        let mut start = 0;
+        while start < entries.len() {
+            let index = entries[start].0;
+            let mut end = start;
+            while end < entries.len() && entries[end].0 == index {
+                end += 1;
+            }
+
+            for chunk in entries[start..end].chunks(2) {
+                match chunk {
+                    [(_, lhs), (_, rhs)] => {
+                        pairs.push((*lhs, *rhs));
+                        // Placeholder for the sum, filled in below.
+                        next.push((index, None));
+                    },
+                    [(_, point)] => next.push((index, Some(*point))),
+                    _ => unreachable!(),
+                }
+            }
+            start = end;
+        }
+
+        if pairs.is_empty() {
+            break;
+        }
+
+        let mut sums = G::batch_add_affine_pairs(&pairs).into_iter();
+        entries = next
+            .into_iter()
+            .map(|(index, point)| (index, point.unwrap_or_else(|| sums.next().unwrap())))
+            .collect();
+    }
+
+    let mut buckets = vec![G::Affine::zero(); num_buckets];
+    for (index, point) in entries {
+        buckets[index] = point;
+    }
+    buckets
+}
+
+/// Recodes `a` into `⌈num_bits / w⌉` signed digits of radix `2^w`, from the
+/// least significant one.
+///
+/// Every digit but the last lies in `[-2^(w - 1), 2^(w - 1)]`; the last one
+/// absorbs the final carry.
+fn make_digits(a: &impl BigInteger, w: usize, num_bits: usize) -> impl Iterator<Item = i64> + '_ {
+    let scalar = a.as_ref();
+    let radix: u64 = 1 << w;
+    let window_mask: u64 = radix - 1;
+
+    let mut carry = 0u64;
+    let digits_count = num_bits.div_ceil(w);
+    (0..digits_count).map(move |i| {
+        // Construct a buffer of bits of the scalar, starting at `bit_offset`.
+        let bit_offset = i * w;
+        let u64_idx = bit_offset / 64;
+        let bit_idx = bit_offset % 64;
+        // Read the bits from the scalar
+        let bit_buf = if bit_idx < 64 - w || u64_idx == scalar.len() - 1 {
+            // This window's bits are contained in a single u64,
+            // or it's the last u64 anyway.
+            scalar[u64_idx] >> bit_idx
+        } else {
+            // Combine the current u64's bits with the bits from the next u64
+            (scalar[u64_idx] >> bit_idx) | (scalar[1 + u64_idx] << (64 - bit_idx))
+        };
+
+        // Read the actual coefficient value from the window
+        let coef = carry + (bit_buf & window_mask); // coef = [0, 2^r)
+
+        // Recenter coefficients from [0,2^w) to [-2^w/2, 2^w/2)
+        carry = (coef + radix / 2) >> w;
+        let mut digit = (coef as i64) - (carry << w) as i64;
+
+        if i == digits_count - 1 {
+            digit += (carry << w) as i64;
+        }
+        digit
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use num_traits::Zero;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::{
+        UniformRand,
+        curve::instances::{bandersnatch, ed25519},
+        test_rng,
+    };
+
+    fn naive_msm<G: CurveGroup>(bases: &[G::Affine], scalars: &[G::ScalarField]) -> G {
+        bases.iter().zip(scalars).map(|(b, s)| *b * s).sum()
+    }
+
+    fn random_instance<G: CurveGroup>(size: usize) -> (Vec<G::Affine>, Vec<G::ScalarField>) {
+        let rng = &mut test_rng();
+        let g = G::generator();
+        let scalars: Vec<G::ScalarField> = (0..size).map(|_| G::ScalarField::rand(rng)).collect();
+        let bases: Vec<G> = (0..size).map(|_| g * G::ScalarField::rand(rng)).collect();
+        (G::normalize_batch(&bases), scalars)
+    }
+
+    #[test]
+    fn make_digits_recompose() {
+        proptest!(|(lo: u64, hi: u32, w in 2usize..12)| {
+            let value = crate::arithmetic::U256::new([lo, u64::from(hi), 0, 0]);
+            let digits: Vec<i64> = make_digits(&value, w, 256).collect();
+            for (i, digit) in digits.iter().enumerate().take(digits.len() - 1) {
+                prop_assert!(digit.unsigned_abs() <= 1 << (w - 1), "digit {i} out of range");
+            }
+
+            // Horner evaluation of the digits from the most significant one.
+            let recomposed = digits.iter().rev().fold(0i128, |acc, d| (acc << w) + i128::from(*d));
+            prop_assert_eq!(recomposed, i128::from(lo) + (i128::from(hi) << 64));
+        });
+    }
+
+    #[test]
+    fn msm_matches_naive() {
+        for size in [0, 1, 2, 31, 32, 100, 300] {
+            let (bases, scalars) = random_instance::<ed25519::EdwardsProjective>(size);
+            let expected = naive_msm::<ed25519::EdwardsProjective>(&bases, &scalars);
+            assert_eq!(ed25519::EdwardsProjective::msm(&bases, &scalars).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn msm_repeated_and_zero_points() {
+        let g = bandersnatch::EdwardsAffine::generator();
+        let bases = vec![g; 200]
+            .into_iter()
+            .chain(core::iter::repeat_n(bandersnatch::EdwardsAffine::zero(), 10))
+            .chain(core::iter::repeat_n(-g, 50))
+            .collect::<Vec<_>>();
+        let rng = &mut test_rng();
+        let scalars: Vec<_> = (0..bases.len()).map(|_| bandersnatch::Fr::rand(rng)).collect();
+
+        let expected = naive_msm::<bandersnatch::EdwardsProjective>(&bases, &scalars);
+        assert_eq!(bandersnatch::EdwardsProjective::msm(&bases, &scalars).unwrap(), expected);
+    }
+
+    #[test]
+    fn msm_length_mismatch() {
+        let (bases, scalars) = random_instance::<ed25519::EdwardsProjective>(4);
+        assert_eq!(ed25519::EdwardsProjective::msm(&bases, &scalars[..3]), Err(3));
+        assert_eq!(
+            ed25519::EdwardsProjective::msm_unchecked(&bases, &scalars[..3]),
+            naive_msm::<ed25519::EdwardsProjective>(&bases[..3], &scalars[..3])
+        );
+    }
+
+    #[test]
+    fn msm_chunks_matches_msm() {
+        let (bases, scalars) = random_instance::<ed25519::EdwardsProjective>(100);
+        let expected = ed25519::EdwardsProjective::msm(&bases, &scalars).unwrap();
+
+        for chunk_size in [1, 7, 50, 100, 1000] {
+            let result = ed25519::EdwardsProjective::msm_chunks(
+                &bases.as_slice(),
+                &scalars.as_slice(),
+                chunk_size,
+            );
+            assert_eq!(result, expected);
+        }
+        assert!(
+            ed25519::EdwardsProjective::msm_chunks(&bases.as_slice(), &&scalars[..0], 8).is_zero()
+        );
+    }
+}
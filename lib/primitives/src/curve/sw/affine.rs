@@ -2,14 +2,20 @@
 //! ([Affine Space]).
 //!
 //! [Affine Space]: https://en.wikipedia.org/wiki/Affine_space
-use super::{AdditiveGroup, AffineRepr, Field, PrimeField, Projective, SWCurveConfig};
+use super::{
+    AdditiveGroup, AffineRepr, Field, PrimeField, Projective, SWCurveConfig, batch_inversion,
+    sw_double_and_add_affine,
+};
 use crate::bits::BitIteratorBE;
+use alloc::vec::Vec;
 use core::{
     borrow::Borrow,
     fmt::{Debug, Display, Formatter},
     ops::{Add, Mul, Neg, Sub},
 };
 use educe::Educe;
+use fina_common::io::{Read, Write};
+use fina_serialize::{ArkDeserialize, ArkSerialize, Compress, SerializationError, Valid, Validate};
 use num_traits::{One, Zero};
 use zeroize::Zeroize;
 
@@ -100,6 +106,81 @@ impl<P: SWCurveConfig> Affine<P> {
     }
 }
 
+impl<P: SWCurveConfig> Affine<P> {
+    /// Computes `lhs[i] += rhs[i]` for every index, in affine coordinates.
+    ///
+    /// Uses Montgomery's trick to share a single field inversion across
+    /// every addition of the batch, which makes it cheaper than projective
+    /// additions followed by normalization once the batch is large enough.
+    ///
+    /// # Panics
+    ///
+    /// * If `lhs` and `rhs` have different lengths.
+    pub fn batch_add_affine(lhs: &mut [Self], rhs: &[Self]) {
+        assert_eq!(lhs.len(), rhs.len(), "batches should have the same length");
+
+        let mut denominators: Vec<P::BaseField> =
+            lhs.iter().zip(rhs).map(|(lhs, rhs)| lhs.slope_denominator(rhs)).collect();
+        batch_inversion(&mut denominators);
+
+        for ((lhs, rhs), inv) in lhs.iter_mut().zip(rhs).zip(denominators) {
+            *lhs = lhs.add_with_denominator_inverse(rhs, inv);
+        }
+    }
+
+    /// Doubles every point of `points` in affine coordinates, sharing a
+    /// single field inversion across the batch.
+    pub fn batch_double_affine(points: &mut [Self]) {
+        let mut denominators: Vec<P::BaseField> =
+            points.iter().map(|point| point.slope_denominator(point)).collect();
+        batch_inversion(&mut denominators);
+
+        for (point, inv) in points.iter_mut().zip(denominators) {
+            *point = point.add_with_denominator_inverse(point, inv);
+        }
+    }
+
+    /// Returns the denominator of the slope of the line through `self` and
+    /// `other`, or zero if their sum is computed without one.
+    fn slope_denominator(&self, other: &Self) -> P::BaseField {
+        if self.infinity || other.infinity {
+            P::BaseField::ZERO
+        } else if self.x != other.x {
+            other.x - self.x
+        } else if self.y == other.y {
+            // Doubling, with `y = 0` being handled by `batch_inversion`.
+            self.y.double()
+        } else {
+            P::BaseField::ZERO
+        }
+    }
+
+    /// Computes `self + other`, given the inverse `inv` of
+    /// [`Self::slope_denominator`] (zero if the denominator is zero).
+    fn add_with_denominator_inverse(&self, other: &Self, inv: P::BaseField) -> Self {
+        if self.infinity {
+            *other
+        } else if other.infinity {
+            *self
+        } else if inv.is_zero() {
+            // `self = -other`, or `self = other` has order two.
+            Self::identity()
+        } else {
+            let lambda = if self.x == other.x {
+                // λ = (3 * x² + a) / (2 * y)
+                let x_squared = self.x.square();
+                (x_squared.double() + x_squared + P::COEFF_A) * inv
+            } else {
+                // λ = (y2 - y1) / (x2 - x1)
+                (other.y - self.y) * inv
+            };
+            let x = lambda.square() - self.x - other.x;
+            let y = lambda * (self.x - x) - self.y;
+            Self::new_unchecked(x, y)
+        }
+    }
+}
+
 impl<P: SWCurveConfig> Zeroize for Affine<P> {
     // The phantom data does not contain element-specific data
     // and thus does not need to be zeroized.
@@ -129,6 +210,14 @@ impl<P: SWCurveConfig> AffineRepr for Affine<P> {
         Self { x: P::BaseField::ZERO, y: P::BaseField::ZERO, infinity: true }
     }
 
+    fn ct_select(&self, other: &Self, choice: bool) -> Self {
+        Self {
+            x: self.x.ct_select(&other.x, choice),
+            y: self.y.ct_select(&other.y, choice),
+            infinity: (self.infinity & !choice) | (other.infinity & choice),
+        }
+    }
+
     fn mul_bigint(&self, by: impl BitIteratorBE) -> Self::Group {
         P::mul_affine(self, by)
     }
@@ -136,7 +225,9 @@ impl<P: SWCurveConfig> AffineRepr for Affine<P> {
     /// Multiplies this element by the cofactor and output the
     /// resulting projective element.
     fn mul_by_cofactor_to_group(&self) -> Self::Group {
-        P::mul_affine(self, Self::Config::COFACTOR)
+        // Not `P::mul_affine`, which may only be valid in the prime-order
+        // subgroup.
+        sw_double_and_add_affine(self, Self::Config::COFACTOR)
     }
 
     /// Performs cofactor clearing.
@@ -252,3 +343,53 @@ impl<P: SWCurveConfig> From<Projective<P>> for Affine<P> {
         }
     }
 }
+
+impl<P: SWCurveConfig> ArkSerialize for Affine<P> {
+    /// Writes the coordinates followed by the infinity flag.
+    ///
+    /// Points are written uncompressed in both modes.
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.x.serialize_with_mode(&mut writer, compress)?;
+        self.y.serialize_with_mode(&mut writer, compress)?;
+        self.infinity.serialize_with_mode(&mut writer, compress)
+    }
+
+    #[inline]
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.x.serialized_size(compress)
+            + self.y.serialized_size(compress)
+            + self.infinity.serialized_size(compress)
+    }
+}
+
+impl<P: SWCurveConfig> Valid for Affine<P> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if self.is_on_curve() && self.is_in_correct_subgroup_assuming_on_curve() {
+            Ok(())
+        } else {
+            Err(SerializationError::InvalidData)
+        }
+    }
+}
+
+impl<P: SWCurveConfig> ArkDeserialize for Affine<P> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let x = P::BaseField::deserialize_with_mode(&mut reader, compress, validate)?;
+        let y = P::BaseField::deserialize_with_mode(&mut reader, compress, validate)?;
+        let infinity = bool::deserialize_with_mode(&mut reader, compress, validate)?;
+        let point = if infinity { Self::identity() } else { Self::new_unchecked(x, y) };
+        if validate == Validate::Yes {
+            point.check()?;
+        }
+        Ok(point)
+    }
+}
@@ -0,0 +1,593 @@
+//! Homogeneous projective coordinates for a point on a Short Weierstrass
+//! curve, with the complete addition formulae of [Renes, Costello and Batina].
+//!
+//! Unlike the Jacobian formulae of [`Projective`], these formulae have no
+//! exceptional cases: the same sequence of field operations computes
+//! `P + Q` for any inputs, including `P = Q`, `P = -Q` and the identity, as
+//! long as the curve has odd order. This makes them suitable for
+//! constant-time code and for arithmetic circuits, at the cost of a few more
+//! field multiplications.
+//!
+//! [Renes, Costello and Batina]: https://eprint.iacr.org/2015/1060.pdf
+use super::{
+    AdditiveGroup, Affine, AffineRepr, Field, PrimeField, PrimeGroup, Projective, SWCurveConfig,
+    batch_inversion, impl_additive_ops_from_ref,
+};
+use crate::bits::BitIteratorBE;
+use alloc::vec::Vec;
+use core::{
+    borrow::Borrow,
+    fmt::{Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+use educe::Educe;
+use num_traits::Zero;
+use zeroize::Zeroize;
+
+/// Specialization of the complete formulae of [`CompleteProjective`] for the
+/// coefficient `a` of a curve.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CompleteFormulas {
+    /// Formulae for any coefficient `a` (algorithms 1 and 3).
+    Generic,
+    /// Formulae for `a = -3` (algorithms 4 and 6).
+    AIsMinusThree,
+    /// Formulae for `a = 0` (algorithms 7 and 9).
+    AIsZero,
+}
+
+/// Homogeneous projective coordinates `(X : Y : Z)` for a point on an elliptic
+/// curve in short Weierstrass form, representing the affine point
+/// `(X / Z, Y / Z)`.
+///
+/// Arithmetic uses the complete formulae selected by
+/// [`SWCurveConfig::COMPLETE_FORMULAS`].
+#[derive(Educe)]
+#[educe(Copy, Clone)]
+#[must_use]
+pub struct CompleteProjective<P: SWCurveConfig> {
+    /// `X / Z` projection of the affine `X`
+    pub x: P::BaseField,
+    /// `Y / Z` projection of the affine `Y`
+    pub y: P::BaseField,
+    /// Projective multiplicative inverse. Will be `0` only at infinity.
+    pub z: P::BaseField,
+}
+
+impl<P: SWCurveConfig> Display for CompleteProjective<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.into_affine())
+    }
+}
+
+impl<P: SWCurveConfig> Debug for CompleteProjective<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.is_zero() {
+            write!(f, "infinity")
+        } else {
+            write!(f, "({}, {}, {})", self.x, self.y, self.z)
+        }
+    }
+}
+
+impl<P: SWCurveConfig> Eq for CompleteProjective<P> {}
+impl<P: SWCurveConfig> PartialEq for CompleteProjective<P> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_zero() {
+            return other.is_zero();
+        }
+
+        if other.is_zero() {
+            return false;
+        }
+
+        // The points (X, Y, Z) and (X', Y', Z') are equal when
+        // X * Z' = X' * Z and Y * Z' = Y' * Z.
+        self.x * other.z == other.x * self.z && self.y * other.z == other.y * self.z
+    }
+}
+
+impl<P: SWCurveConfig> PartialEq<Affine<P>> for CompleteProjective<P> {
+    fn eq(&self, other: &Affine<P>) -> bool {
+        *self == Self::from(*other)
+    }
+}
+
+impl<P: SWCurveConfig> Hash for CompleteProjective<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.into_affine().hash(state);
+    }
+}
+
+impl<P: SWCurveConfig> Default for CompleteProjective<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<P: SWCurveConfig> CompleteProjective<P> {
+    /// Constructs a new group element without checking whether the coordinates
+    /// specify a point in the subgroup.
+    pub const fn new_unchecked(x: P::BaseField, y: P::BaseField, z: P::BaseField) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Converts `self` into affine coordinates.
+    pub fn into_affine(self) -> Affine<P> {
+        match self.z.inverse() {
+            Some(z_inv) => Affine::new_unchecked(self.x * z_inv, self.y * z_inv),
+            None => Affine::identity(),
+        }
+    }
+
+    /// Normalizes a slice of points into affine coordinates, with a single
+    /// field inversion.
+    pub fn normalize_batch(v: &[Self]) -> Vec<Affine<P>> {
+        let mut z_s: Vec<_> = v.iter().map(|g| g.z).collect();
+        batch_inversion(&mut z_s);
+
+        v.iter()
+            .zip(z_s)
+            .map(|(g, z)| {
+                if g.is_zero() {
+                    Affine::identity()
+                } else {
+                    Affine::new_unchecked(g.x * z, g.y * z)
+                }
+            })
+            .collect()
+    }
+
+    /// Returns `self + other`, with the complete addition formulae
+    /// specialized by `formulas`.
+    fn add_with(&self, other: &Self, formulas: CompleteFormulas) -> Self {
+        let (x1, y1, z1) = (self.x, self.y, self.z);
+        let (x2, y2, z2) = (other.x, other.y, other.z);
+        let b3 = P::COEFF_B.double() + P::COEFF_B;
+
+        let t0 = x1 * x2;
+        let t1 = y1 * y2;
+        let t2 = z1 * z2;
+        // t3 = X1 * Y2 + X2 * Y1
+        let t3 = (x1 + y1) * (x2 + y2) - (t0 + t1);
+        // t4 = Y1 * Z2 + Y2 * Z1
+        let t4 = (y1 + z1) * (y2 + z2) - (t1 + t2);
+        // t5 = X1 * Z2 + X2 * Z1
+        let t5 = (x1 + z1) * (x2 + z2) - (t0 + t2);
+
+        match formulas {
+            CompleteFormulas::Generic => {
+                let z3 = P::mul_by_a(t5) + b3 * t2;
+                let x3 = t1 - z3;
+                let z3 = t1 + z3;
+                let y3 = x3 * z3;
+                let a_t2 = P::mul_by_a(t2);
+                let t1 = t0.double() + t0 + a_t2;
+                let t5 = b3 * t5 + P::mul_by_a(t0 - a_t2);
+                Self { x: t3 * x3 - t4 * t5, y: y3 + t1 * t5, z: t4 * z3 + t3 * t1 }
+            },
+            CompleteFormulas::AIsMinusThree => {
+                let x3 = t5 - P::COEFF_B * t2;
+                let x3 = x3.double() + x3;
+                let z3 = t1 - x3;
+                let x3 = t1 + x3;
+                let t2 = t2.double() + t2;
+                let y3 = P::COEFF_B * t5 - t2 - t0;
+                let y3 = y3.double() + y3;
+                let t0 = t0.double() + t0 - t2;
+                Self { x: t3 * x3 - t4 * y3, y: x3 * z3 + t0 * y3, z: t4 * z3 + t3 * t0 }
+            },
+            CompleteFormulas::AIsZero => {
+                let t0 = t0.double() + t0;
+                let t2 = b3 * t2;
+                let z3 = t1 + t2;
+                let t1 = t1 - t2;
+                let y3 = b3 * t5;
+                Self { x: t3 * t1 - t4 * y3, y: t1 * z3 + y3 * t0, z: z3 * t4 + t0 * t3 }
+            },
+        }
+    }
+
+    /// Returns `2 * self`, with the complete doubling formulae specialized by
+    /// `formulas`.
+    fn double_with(&self, formulas: CompleteFormulas) -> Self {
+        let (x, y, z) = (self.x, self.y, self.z);
+        let b3 = P::COEFF_B.double() + P::COEFF_B;
+
+        match formulas {
+            CompleteFormulas::Generic => {
+                let t0 = x.square();
+                let t1 = y.square();
+                let t2 = z.square();
+                let t3 = (x * y).double();
+                let z3 = (x * z).double();
+                let y3 = P::mul_by_a(z3) + b3 * t2;
+                let x3 = t1 - y3;
+                let y3 = x3 * (t1 + y3);
+                let x3 = t3 * x3;
+                let t2 = P::mul_by_a(t2);
+                let t3 = P::mul_by_a(t0 - t2) + b3 * z3;
+                let t0 = t0.double() + t0 + t2;
+                let t2 = (y * z).double();
+                Self { x: x3 - t2 * t3, y: y3 + t0 * t3, z: (t2 * t1).double().double() }
+            },
+            CompleteFormulas::AIsMinusThree => {
+                let t0 = x.square();
+                let t1 = y.square();
+                let t2 = z.square();
+                let t3 = (x * y).double();
+                let z3 = (x * z).double();
+                let y3 = P::COEFF_B * t2 - z3;
+                let y3 = y3.double() + y3;
+                let x3 = t1 - y3;
+                let y3 = x3 * (t1 + y3);
+                let x3 = x3 * t3;
+                let t2 = t2.double() + t2;
+                let z3 = P::COEFF_B * z3 - t2 - t0;
+                let z3 = z3.double() + z3;
+                let t0 = t0.double() + t0 - t2;
+                let y3 = y3 + t0 * z3;
+                let t0 = (y * z).double();
+                Self { x: x3 - t0 * z3, y: y3, z: (t0 * t1).double().double() }
+            },
+            CompleteFormulas::AIsZero => {
+                let t0 = y.square();
+                let z3 = t0.double().double().double();
+                let t1 = y * z;
+                let t2 = b3 * z.square();
+                let x3 = t2 * z3;
+                let y3 = t0 + t2;
+                let z3 = t1 * z3;
+                let t0 = t0 - (t2.double() + t2);
+                Self { x: (t0 * (x * y)).double(), y: x3 + t0 * y3, z: z3 }
+            },
+        }
+    }
+}
+
+impl<P: SWCurveConfig> Zeroize for CompleteProjective<P> {
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+        self.z.zeroize();
+    }
+}
+
+impl<P: SWCurveConfig> Zero for CompleteProjective<P> {
+    /// Returns the point at infinity `(0 : 1 : 0)`.
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    /// Checks whether `self.z.is_zero()`.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.z == P::BaseField::ZERO
+    }
+}
+
+impl<P: SWCurveConfig> AdditiveGroup for CompleteProjective<P> {
+    type Scalar = P::ScalarField;
+
+    const ZERO: Self =
+        Self::new_unchecked(P::BaseField::ZERO, P::BaseField::ONE, P::BaseField::ZERO);
+
+    fn double_in_place(&mut self) -> &mut Self {
+        *self = self.double_with(P::COMPLETE_FORMULAS);
+        self
+    }
+}
+
+impl<P: SWCurveConfig> PrimeGroup for CompleteProjective<P> {
+    type ScalarField = P::ScalarField;
+
+    #[inline]
+    fn generator() -> Self {
+        Affine::generator().into()
+    }
+
+    fn mul_bigint(&self, other: impl BitIteratorBE) -> Self {
+        let mut res = Self::zero();
+        for bit in other.bit_be_trimmed_iter() {
+            res.double_in_place();
+            if bit {
+                res += self;
+            }
+        }
+        res
+    }
+
+    #[inline]
+    fn ct_select(&self, other: &Self, choice: bool) -> Self {
+        Self {
+            x: self.x.ct_select(&other.x, choice),
+            y: self.y.ct_select(&other.y, choice),
+            z: self.z.ct_select(&other.z, choice),
+        }
+    }
+}
+
+impl<P: SWCurveConfig> Neg for CompleteProjective<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self {
+        self.y = -self.y;
+        self
+    }
+}
+
+impl<'a, P: SWCurveConfig> AddAssign<&'a Self> for CompleteProjective<P> {
+    fn add_assign(&mut self, other: &'a Self) {
+        *self = self.add_with(other, P::COMPLETE_FORMULAS);
+    }
+}
+
+impl<'a, P: SWCurveConfig> Add<&'a Self> for CompleteProjective<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: &'a Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<'a, P: SWCurveConfig> SubAssign<&'a Self> for CompleteProjective<P> {
+    fn sub_assign(&mut self, other: &'a Self) {
+        *self += &(-*other);
+    }
+}
+
+impl<'a, P: SWCurveConfig> Sub<&'a Self> for CompleteProjective<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, other: &'a Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl_additive_ops_from_ref!(CompleteProjective, SWCurveConfig);
+
+/// Adds an affine point, through its homogeneous representation `(x : y : 1)`
+/// or `(0 : 1 : 0)`, so that the addition stays complete.
+impl<P: SWCurveConfig> AddAssign<Affine<P>> for CompleteProjective<P> {
+    fn add_assign(&mut self, other: Affine<P>) {
+        *self += &Self::from(other);
+    }
+}
+
+impl<P: SWCurveConfig> Add<Affine<P>> for CompleteProjective<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: Affine<P>) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<P: SWCurveConfig, T: Borrow<P::ScalarField>> MulAssign<T> for CompleteProjective<P> {
+    fn mul_assign(&mut self, other: T) {
+        *self = self.mul_bigint(other.borrow().into_bigint());
+    }
+}
+
+impl<P: SWCurveConfig, T: Borrow<P::ScalarField>> Mul<T> for CompleteProjective<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, other: T) -> Self {
+        self *= other;
+        self
+    }
+}
+
+impl<P: SWCurveConfig> From<Affine<P>> for CompleteProjective<P> {
+    #[inline]
+    fn from(p: Affine<P>) -> Self {
+        p.xy().map_or(Self::zero(), |(x, y)| Self::new_unchecked(x, y, P::BaseField::ONE))
+    }
+}
+
+impl<P: SWCurveConfig> From<CompleteProjective<P>> for Affine<P> {
+    #[inline]
+    fn from(p: CompleteProjective<P>) -> Self {
+        p.into_affine()
+    }
+}
+
+/// Maps the Jacobian point `(X : Y : Z)` to the homogeneous point
+/// `(X * Z : Y : Z³)`.
+impl<P: SWCurveConfig> From<Projective<P>> for CompleteProjective<P> {
+    fn from(p: Projective<P>) -> Self {
+        if p.is_zero() {
+            return Self::zero();
+        }
+        Self::new_unchecked(p.x * p.z, p.y, p.z.square() * p.z)
+    }
+}
+
+/// Maps the homogeneous point `(X : Y : Z)` to the Jacobian point
+/// `(X * Z : Y * Z² : Z)`.
+impl<P: SWCurveConfig> From<CompleteProjective<P>> for Projective<P> {
+    fn from(p: CompleteProjective<P>) -> Self {
+        if p.is_zero() {
+            return Self::zero();
+        }
+        let z_square = p.z.square();
+        Self::new_unchecked(p.x * p.z, p.y * z_square, p.z)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        UniformRand,
+        curve::{
+            hashing::{MapToCurve, swu::SWUMap},
+            instances::{bls12_381, bn254, secp256k1},
+            traits::CurveGroup,
+        },
+        test_rng,
+    };
+
+    /// Checks every formula of `formulas` against the Jacobian arithmetic of
+    /// [`Projective`], on random points and on the exceptional cases of the
+    /// Jacobian formulae.
+    fn check_formulas<P: SWCurveConfig>(points: &[Affine<P>], formulas: &[CompleteFormulas]) {
+        let mut inputs: Vec<Affine<P>> = points.to_vec();
+        inputs.push(Affine::identity());
+        inputs.extend(points.iter().map(|p| -*p));
+
+        for formulas in formulas {
+            for lhs in &inputs {
+                let lhs_complete = CompleteProjective::from(*lhs);
+                let expected = lhs.into_group().double();
+                assert_eq!(lhs_complete.double_with(*formulas).into_affine(), expected);
+
+                for rhs in &inputs {
+                    let rhs_complete = CompleteProjective::from(*rhs);
+                    let expected = lhs.into_group() + rhs;
+                    let sum = lhs_complete.add_with(&rhs_complete, *formulas);
+                    assert_eq!(sum.into_affine(), expected, "{formulas:?}: {lhs} + {rhs}");
+                }
+            }
+        }
+    }
+
+    fn random_points<G: CurveGroup>(count: usize) -> Vec<G::Affine> {
+        let rng = &mut test_rng();
+        let points: Vec<G> =
+            (0..count).map(|_| G::generator() * G::ScalarField::rand(rng)).collect();
+        G::normalize_batch(&points)
+    }
+
+    #[test]
+    fn a_is_zero() {
+        assert!(secp256k1::Secp256k1Config::COEFF_A.is_zero());
+        assert!(bn254::G1Config::COEFF_A.is_zero());
+        assert!(bls12_381::G2Config::COEFF_A.is_zero());
+        let formulas = [CompleteFormulas::Generic, CompleteFormulas::AIsZero];
+        check_formulas(&random_points::<secp256k1::Projective>(4), &formulas);
+        check_formulas(&random_points::<bn254::G1Projective>(4), &formulas);
+        check_formulas(&random_points::<bls12_381::G2Projective>(2), &formulas);
+    }
+
+    #[test]
+    fn a_is_minus_three() {
+        assert_eq!(p256::Config::COEFF_A, -p256::Fq::from(3u8));
+        let formulas = [CompleteFormulas::Generic, CompleteFormulas::AIsMinusThree];
+        check_formulas(&random_points::<p256::Projective>(4), &formulas);
+    }
+
+    #[test]
+    fn generic_a() {
+        // Points of the isogenous curve of secp256k1, which has `a ≠ 0, -3`.
+        let rng = &mut test_rng();
+        let points: Vec<_> = (0..4)
+            .map(|_| {
+                SWUMap::<secp256k1::Secp256k1IsoConfig>::map_to_curve(secp256k1::Fq::rand(rng))
+            })
+            .collect::<Result<_, _>>()
+            .unwrap();
+        check_formulas(&points, &[CompleteFormulas::Generic]);
+    }
+
+    #[test]
+    fn group_operations() {
+        let rng = &mut test_rng();
+        let g = CompleteProjective::<p256::Config>::generator();
+        let [a, b] = [(); 2].map(|()| p256::Fr::rand(rng));
+
+        assert_eq!(
+            (g * a + g * b).into_affine(),
+            (p256::Projective::generator() * (a + b)).into_affine()
+        );
+        assert_eq!(g * a - g * a, CompleteProjective::zero());
+        assert_eq!(g.mul_ladder(&a), g * a);
+        assert_eq!(Projective::from(g * a), p256::Projective::generator() * a);
+        assert_eq!(CompleteProjective::from(p256::Projective::generator() * a), g * a);
+        assert_eq!(
+            CompleteProjective::normalize_batch(&[g * a, CompleteProjective::zero()]),
+            [(g * a).into_affine(), Affine::identity()]
+        );
+    }
+
+    /// The NIST P-256 curve, which has `a = -3`.
+    mod p256 {
+        use crate::{
+            arithmetic::U256,
+            curve::{
+                sw::{SWCurveConfig, affine::Affine, complete::CompleteFormulas},
+                traits::CurveConfig,
+            },
+            field::{Fp256, FpParams, LIMBS_256},
+            fp_from_hex, fp_from_num, from_num,
+        };
+
+        pub(super) type Projective = super::Projective<Config>;
+
+        pub(super) type Fq = Fp256<FqParam>;
+        pub(super) struct FqParam;
+
+        impl FpParams<LIMBS_256> for FqParam {
+            const GENERATOR: Fq = fp_from_num!("6");
+            const MODULUS: U256 = from_num!(
+                "115792089210356248762697446949407573530086143415290314195533631308867097853951"
+            );
+            const ONE: Fq = fp_from_num!("1");
+            const TWO_ADICITY: u32 = 1;
+            const TWO_ADIC_ROOT_OF_UNITY: Fq = fp_from_num!(
+                "115792089210356248762697446949407573530086143415290314195533631308867097853950"
+            );
+            const ZERO: Fq = fp_from_num!("0");
+        }
+
+        pub(super) type Fr = Fp256<FrParam>;
+        pub(super) struct FrParam;
+
+        impl FpParams<LIMBS_256> for FrParam {
+            const GENERATOR: Fr = fp_from_num!("7");
+            const MODULUS: U256 = from_num!(
+                "115792089210356248762697446949407573529996955224135760342422259061068512044369"
+            );
+            const ONE: Fr = fp_from_num!("1");
+            const TWO_ADICITY: u32 = 4;
+            const TWO_ADIC_ROOT_OF_UNITY: Fr = fp_from_num!(
+                "115695789336771192084080718687965001507772259361175921799893286721837170845186"
+            );
+            const ZERO: Fr = fp_from_num!("0");
+        }
+
+        #[derive(Clone, Default, PartialEq, Eq)]
+        pub(super) struct Config;
+
+        impl CurveConfig for Config {
+            type BaseField = Fq;
+            type ScalarField = Fr;
+
+            const COFACTOR: &'static [u64] = &[1];
+            const COFACTOR_INV: Fr = fp_from_num!("1");
+        }
+
+        impl SWCurveConfig for Config {
+            const COEFF_A: Fq = fp_from_num!(
+                "115792089210356248762697446949407573530086143415290314195533631308867097853948"
+            );
+            const COEFF_B: Fq =
+                fp_from_hex!("5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B");
+            const COMPLETE_FORMULAS: CompleteFormulas = CompleteFormulas::AIsMinusThree;
+            const GENERATOR: Affine<Self> = Affine::new_unchecked(
+                fp_from_hex!("6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296"),
+                fp_from_hex!("4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5"),
+            );
+        }
+    }
+}
@@ -0,0 +1,558 @@
+//! Interoperable byte encodings of points of short Weierstrass curves.
+//!
+//! Unlike the [`ArkSerialize`](fina_serialize::ArkSerialize) format, these
+//! encodings are byte-exact with other implementations:
+//!
+//! * [SEC1], with the `0x02`/`0x03` compressed and `0x04` uncompressed tags,
+//! * the [Zcash] flag-bit format of BLS12-381 points,
+//! * the big-endian uncompressed encoding of the Ethereum precompiles, from
+//!   [EIP-196] and [EIP-197].
+//!
+//! Coordinates are written in big-endian, and elements of extension fields
+//! are written from their highest coefficient down, as in the Zcash and
+//! Ethereum formats. With `serde`, points are written as the `0x`-prefixed hex
+//! of their compressed SEC1 encoding in human-readable formats, and as raw
+//! bytes otherwise.
+//!
+//! [SEC1]: https://www.secg.org/sec1-v2.pdf
+//! [Zcash]: https://github.com/zkcrypto/pairing/tree/master/src/bls12_381#serialization
+//! [EIP-196]: https://eips.ethereum.org/EIPS/eip-196
+//! [EIP-197]: https://eips.ethereum.org/EIPS/eip-197
+use super::{SWCurveConfig, affine::Affine};
+use crate::{
+    arithmetic::bigint::BigInteger,
+    curve::hashing::sgn0,
+    field::traits::{Field, PrimeField},
+};
+use alloc::{vec, vec::Vec};
+use core::fmt;
+use fina_serialize::Compress;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_encoded_bytes::{Hex, SliceLike};
+
+/// SEC1 tag of the point at infinity.
+const SEC1_INFINITY: u8 = 0x00;
+/// SEC1 tag of a compressed point with an even `y`.
+const SEC1_EVEN: u8 = 0x02;
+/// SEC1 tag of a compressed point with an odd `y`.
+const SEC1_ODD: u8 = 0x03;
+/// SEC1 tag of an uncompressed point.
+const SEC1_UNCOMPRESSED: u8 = 0x04;
+
+/// Zcash flag of a compressed point.
+const ZCASH_COMPRESSION: u8 = 0x80;
+/// Zcash flag of the point at infinity.
+const ZCASH_INFINITY: u8 = 0x40;
+/// Zcash flag of a compressed point whose `y` is lexicographically largest.
+const ZCASH_SORT: u8 = 0x20;
+
+/// Errors of decoding a point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodingError {
+    /// The input does not have the length of an encoding.
+    InvalidLength,
+    /// The tag or flags are invalid, or the point at infinity is not padded
+    /// with zeros.
+    InvalidFlags,
+    /// A coordinate is not the canonical encoding of a field element.
+    NonCanonicalCoordinate,
+    /// The point is not on the curve.
+    NotOnCurve,
+    /// The point is not in the prime-order subgroup.
+    NotInSubgroup,
+}
+
+impl core::error::Error for EncodingError {}
+
+impl fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            Self::InvalidLength => "invalid length",
+            Self::InvalidFlags => "invalid flags",
+            Self::NonCanonicalCoordinate => "non-canonical coordinate",
+            Self::NotOnCurve => "point not on the curve",
+            Self::NotInSubgroup => "point not in the prime-order subgroup",
+        };
+        write!(f, "invalid point encoding: {reason}")
+    }
+}
+
+/// Length in bytes of an element of the base prime field of `F`.
+fn prime_element_len<F: Field>() -> usize {
+    F::BasePrimeField::MODULUS.num_bits().div_ceil(8)
+}
+
+/// Panics unless the base prime field of `F` leaves the three most
+/// significant bits of its encoding unused for the Zcash flags.
+fn assert_zcash_spare_bits<F: Field>() {
+    let unused_bits = (8 - F::BasePrimeField::MODULUS.num_bits() % 8) % 8;
+    assert!(
+        unused_bits >= 3,
+        "the base prime field should leave three spare bits for the Zcash flags"
+    );
+}
+
+/// Length in bytes of an element of `F`.
+fn element_len<F: Field>() -> usize {
+    prime_element_len::<F>() * F::extension_degree()
+}
+
+/// Appends the big-endian encoding of `element`, highest coefficient first.
+fn write_element<F: Field>(element: &F, out: &mut Vec<u8>) {
+    let len = prime_element_len::<F>();
+    let coeffs: Vec<_> = element.to_base_prime_field_elements().collect();
+    for coeff in coeffs.into_iter().rev() {
+        let mut bytes = coeff.into_bigint().into_bytes_le();
+        bytes.truncate(len);
+        out.extend(bytes.into_iter().rev());
+    }
+}
+
+/// Reads an element from its big-endian encoding, highest coefficient first.
+fn read_element<F: Field>(bytes: &[u8]) -> Result<F, EncodingError> {
+    type BigInt<F> = <<F as Field>::BasePrimeField as PrimeField>::BigInt;
+
+    let mut coeffs = bytes
+        .chunks(prime_element_len::<F>())
+        .map(|chunk| {
+            let mut repr = vec![0u8; BigInt::<F>::BITS / 8];
+            for (byte, &value) in repr.iter_mut().zip(chunk.iter().rev()) {
+                *byte = value;
+            }
+            let repr = BigInt::<F>::from_bytes_le(&repr);
+            if repr < F::BasePrimeField::MODULUS {
+                Ok(F::BasePrimeField::from_bigint(repr))
+            } else {
+                Err(EncodingError::NonCanonicalCoordinate)
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    coeffs.reverse();
+    F::from_base_prime_field_elems(coeffs).ok_or(EncodingError::InvalidLength)
+}
+
+/// Returns whether `element > -element`, comparing coefficients from the
+/// highest one down.
+fn is_lexicographically_largest<F: Field>(element: &F) -> bool {
+    let coeffs: Vec<_> = element.to_base_prime_field_elements().collect();
+    let neg_coeffs: Vec<_> = (-*element).to_base_prime_field_elements().collect();
+    for (coeff, neg_coeff) in coeffs.into_iter().zip(neg_coeffs).rev() {
+        let (coeff, neg_coeff) = (coeff.into_bigint(), neg_coeff.into_bigint());
+        if coeff != neg_coeff {
+            return coeff > neg_coeff;
+        }
+    }
+    false
+}
+
+impl<P: SWCurveConfig> Affine<P> {
+    /// Returns the point of the curve with abscissa `x` whose ordinate `y`
+    /// satisfies `choose(y)`, if any.
+    ///
+    /// Fails with [`EncodingError::InvalidFlags`] if neither root satisfies
+    /// `choose`, e.g. when `y = 0` but the flags select the other root.
+    fn from_x(
+        x: P::BaseField,
+        choose: impl Fn(&P::BaseField) -> bool,
+    ) -> Result<Self, EncodingError> {
+        let y = P::add_b((x.square() + P::COEFF_A) * x).sqrt().ok_or(EncodingError::NotOnCurve)?;
+        let y = if choose(&y) { y } else { -y };
+        if !choose(&y) {
+            return Err(EncodingError::InvalidFlags);
+        }
+        Ok(Self::new_unchecked(x, y))
+    }
+
+    /// Checks that `self` is on the curve and in the prime-order subgroup.
+    fn validated(self) -> Result<Self, EncodingError> {
+        if !self.is_on_curve() {
+            return Err(EncodingError::NotOnCurve);
+        }
+        if !self.is_in_correct_subgroup_assuming_on_curve() {
+            return Err(EncodingError::NotInSubgroup);
+        }
+        Ok(self)
+    }
+
+    /// Encodes `self` with [SEC1], section 2.3.3.
+    ///
+    /// The point at infinity is encoded as a single zero byte. Compressed
+    /// points are tagged with the [`sgn0`] of `y`, i.e. its parity over prime
+    /// fields.
+    ///
+    /// [SEC1]: https://www.secg.org/sec1-v2.pdf
+    #[must_use]
+    pub fn to_sec1_bytes(&self, compress: Compress) -> Vec<u8> {
+        if self.infinity {
+            return vec![SEC1_INFINITY];
+        }
+
+        let mut bytes = Vec::with_capacity(1 + 2 * element_len::<P::BaseField>());
+        match compress {
+            Compress::Yes => {
+                bytes.push(if sgn0(&self.y) { SEC1_ODD } else { SEC1_EVEN });
+                write_element(&self.x, &mut bytes);
+            },
+            Compress::No => {
+                bytes.push(SEC1_UNCOMPRESSED);
+                write_element(&self.x, &mut bytes);
+                write_element(&self.y, &mut bytes);
+            },
+        }
+        bytes
+    }
+
+    /// Decodes a point from its compressed or uncompressed [SEC1] encoding,
+    /// section 2.3.4, checking that it is in the prime-order subgroup.
+    ///
+    /// # Errors
+    ///
+    /// * If `bytes` is not a valid encoding of a point of the subgroup.
+    ///
+    /// [SEC1]: https://www.secg.org/sec1-v2.pdf
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, EncodingError> {
+        let len = element_len::<P::BaseField>();
+        let (&tag, rest) = bytes.split_first().ok_or(EncodingError::InvalidLength)?;
+
+        let point = match tag {
+            SEC1_INFINITY if rest.is_empty() => Self::identity(),
+            SEC1_EVEN | SEC1_ODD if rest.len() == len => {
+                let x = read_element(rest)?;
+                Self::from_x(x, |y| sgn0(y) == (tag == SEC1_ODD))?
+            },
+            SEC1_UNCOMPRESSED if rest.len() == 2 * len => {
+                Self::new_unchecked(read_element(&rest[..len])?, read_element(&rest[len..])?)
+            },
+            SEC1_INFINITY | SEC1_EVEN | SEC1_ODD | SEC1_UNCOMPRESSED => {
+                return Err(EncodingError::InvalidLength);
+            },
+            _ => return Err(EncodingError::InvalidFlags),
+        };
+        point.validated()
+    }
+
+    /// Encodes `self` with the [Zcash] format of BLS12-381 points.
+    ///
+    /// The three most significant bits of the encoding are flags, which
+    /// requires the base prime field to leave at least three bits unused.
+    ///
+    /// # Panics
+    ///
+    /// * If the base prime field does not leave three bits unused.
+    ///
+    /// [Zcash]: https://github.com/zkcrypto/pairing/tree/master/src/bls12_381#serialization
+    #[must_use]
+    pub fn to_zcash_bytes(&self, compress: Compress) -> Vec<u8> {
+        assert_zcash_spare_bits::<P::BaseField>();
+        let len = element_len::<P::BaseField>();
+        let mut bytes = Vec::with_capacity(2 * len);
+        let mut flags = 0;
+        if compress == Compress::Yes {
+            flags |= ZCASH_COMPRESSION;
+        }
+
+        if self.infinity {
+            flags |= ZCASH_INFINITY;
+            bytes.resize(if compress == Compress::Yes { len } else { 2 * len }, 0);
+        } else {
+            write_element(&self.x, &mut bytes);
+            match compress {
+                Compress::Yes if is_lexicographically_largest(&self.y) => flags |= ZCASH_SORT,
+                Compress::Yes => {},
+                Compress::No => write_element(&self.y, &mut bytes),
+            }
+        }
+        bytes[0] |= flags;
+        bytes
+    }
+
+    /// Decodes a point from its compressed or uncompressed [Zcash] encoding,
+    /// checking that it is in the prime-order subgroup.
+    ///
+    /// # Errors
+    ///
+    /// * If `bytes` is not a valid encoding of a point of the subgroup.
+    ///
+    /// # Panics
+    ///
+    /// * If the base prime field does not leave three bits unused.
+    ///
+    /// [Zcash]: https://github.com/zkcrypto/pairing/tree/master/src/bls12_381#serialization
+    pub fn from_zcash_bytes(bytes: &[u8]) -> Result<Self, EncodingError> {
+        assert_zcash_spare_bits::<P::BaseField>();
+        let len = element_len::<P::BaseField>();
+        let flags = bytes.first().ok_or(EncodingError::InvalidLength)? & 0xe0;
+        let compressed = flags & ZCASH_COMPRESSION != 0;
+        let infinity = flags & ZCASH_INFINITY != 0;
+        let sort = flags & ZCASH_SORT != 0;
+
+        if bytes.len() != if compressed { len } else { 2 * len } {
+            return Err(EncodingError::InvalidLength);
+        }
+        let mut bytes = bytes.to_vec();
+        bytes[0] &= !0xe0;
+
+        if infinity {
+            if sort || bytes.iter().any(|&byte| byte != 0) {
+                return Err(EncodingError::InvalidFlags);
+            }
+            return Ok(Self::identity());
+        }
+
+        let x = read_element(&bytes[..len])?;
+        let point = if compressed {
+            Self::from_x(x, |y| is_lexicographically_largest(y) == sort)?
+        } else if sort {
+            return Err(EncodingError::InvalidFlags);
+        } else {
+            Self::new_unchecked(x, read_element(&bytes[len..])?)
+        };
+        point.validated()
+    }
+
+    /// Encodes `self` with the uncompressed big-endian format of the Ethereum
+    /// precompiles, from [EIP-196] and [EIP-197].
+    ///
+    /// The point at infinity is encoded as zeros.
+    ///
+    /// [EIP-196]: https://eips.ethereum.org/EIPS/eip-196
+    /// [EIP-197]: https://eips.ethereum.org/EIPS/eip-197
+    #[must_use]
+    pub fn to_ethereum_bytes(&self) -> Vec<u8> {
+        let len = element_len::<P::BaseField>();
+        let mut bytes = Vec::with_capacity(2 * len);
+        if self.infinity {
+            bytes.resize(2 * len, 0);
+        } else {
+            write_element(&self.x, &mut bytes);
+            write_element(&self.y, &mut bytes);
+        }
+        bytes
+    }
+
+    /// Decodes a point from the format of the Ethereum precompiles, from
+    /// [EIP-196] and [EIP-197], checking that it is in the prime-order
+    /// subgroup.
+    ///
+    /// # Errors
+    ///
+    /// * If `bytes` is not a valid encoding of a point of the subgroup.
+    ///
+    /// [EIP-196]: https://eips.ethereum.org/EIPS/eip-196
+    /// [EIP-197]: https://eips.ethereum.org/EIPS/eip-197
+    pub fn from_ethereum_bytes(bytes: &[u8]) -> Result<Self, EncodingError> {
+        let len = element_len::<P::BaseField>();
+        if bytes.len() != 2 * len {
+            return Err(EncodingError::InvalidLength);
+        }
+        if bytes.iter().all(|&byte| byte == 0) {
+            return Ok(Self::identity());
+        }
+        Self::new_unchecked(read_element(&bytes[..len])?, read_element(&bytes[len..])?).validated()
+    }
+}
+
+impl<P: SWCurveConfig> Serialize for Affine<P> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SliceLike::<Hex>::serialize(&self.to_sec1_bytes(Compress::Yes), serializer)
+    }
+}
+
+impl<'de, P: SWCurveConfig> Deserialize<'de> for Affine<P> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes: Vec<u8> = SliceLike::<Hex>::deserialize(deserializer)?;
+        Self::from_sec1_bytes(&bytes).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        curve::{
+            instances::{bls12_381, bn254, secp256k1},
+            traits::{AffineRepr, CurveGroup},
+        },
+        test_utils::{from_hex, to_hex},
+    };
+
+    #[test]
+    fn sec1_secp256k1() {
+        let g = secp256k1::Affine::generator();
+        let x = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+        let y = "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+        assert_eq!(to_hex(&g.to_sec1_bytes(Compress::Yes)), format!("02{x}"));
+        assert_eq!(to_hex(&g.to_sec1_bytes(Compress::No)), format!("04{x}{y}"));
+
+        let g2 = (g + g).into_affine();
+        assert_eq!(
+            to_hex(&g2.to_sec1_bytes(Compress::Yes)),
+            "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5"
+        );
+        assert_eq!(to_hex(&(-g).to_sec1_bytes(Compress::Yes)), format!("03{x}"));
+
+        for point in [g, -g, g2, secp256k1::Affine::zero()] {
+            for compress in [Compress::Yes, Compress::No] {
+                let bytes = point.to_sec1_bytes(compress);
+                assert_eq!(secp256k1::Affine::from_sec1_bytes(&bytes), Ok(point));
+            }
+        }
+    }
+
+    #[test]
+    fn sec1_invalid() {
+        let bytes = secp256k1::Affine::generator().to_sec1_bytes(Compress::No);
+        let decode = secp256k1::Affine::from_sec1_bytes;
+
+        assert_eq!(decode(&[]), Err(EncodingError::InvalidLength));
+        assert_eq!(decode(&bytes[..64]), Err(EncodingError::InvalidLength));
+        assert_eq!(decode(&[0x00, 0x00]), Err(EncodingError::InvalidLength));
+
+        let mut wrong_tag = bytes.clone();
+        wrong_tag[0] = 0x05;
+        assert_eq!(decode(&wrong_tag), Err(EncodingError::InvalidFlags));
+
+        let mut off_curve = bytes.clone();
+        off_curve[64] ^= 1;
+        assert_eq!(decode(&off_curve), Err(EncodingError::NotOnCurve));
+
+        // `x = p`.
+        let p = "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+        let non_canonical = from_hex(&format!("02{p}"));
+        assert_eq!(decode(&non_canonical), Err(EncodingError::NonCanonicalCoordinate));
+    }
+
+    #[test]
+    fn zcash_bls12_381() {
+        let g1 = bls12_381::G1Affine::generator();
+        let x = "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+        let y = "08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1";
+        assert_eq!(to_hex(&g1.to_zcash_bytes(Compress::No)), format!("{x}{y}"));
+        assert_eq!(to_hex(&g1.to_zcash_bytes(Compress::Yes)), format!("97{}", &x[2..]));
+        assert_eq!(to_hex(&(-g1).to_zcash_bytes(Compress::Yes)), format!("b7{}", &x[2..]));
+
+        let g2 = bls12_381::G2Affine::generator();
+        assert_eq!(
+            to_hex(&g2.to_zcash_bytes(Compress::Yes)),
+            "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e\
+             024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"
+        );
+
+        let mut infinity = vec![0u8; 48];
+        infinity[0] = 0xc0;
+        assert_eq!(bls12_381::G1Affine::zero().to_zcash_bytes(Compress::Yes), infinity);
+        infinity.resize(96, 0);
+        infinity[0] = 0x40;
+        assert_eq!(bls12_381::G1Affine::zero().to_zcash_bytes(Compress::No), infinity);
+
+        for point in [g1, -g1, g1.mul_bigint(5u8).into_affine(), bls12_381::G1Affine::zero()] {
+            for compress in [Compress::Yes, Compress::No] {
+                let bytes = point.to_zcash_bytes(compress);
+                assert_eq!(bls12_381::G1Affine::from_zcash_bytes(&bytes), Ok(point));
+            }
+        }
+        for point in [g2, -g2, g2.mul_bigint(5u8).into_affine(), bls12_381::G2Affine::zero()] {
+            for compress in [Compress::Yes, Compress::No] {
+                let bytes = point.to_zcash_bytes(compress);
+                assert_eq!(bls12_381::G2Affine::from_zcash_bytes(&bytes), Ok(point));
+            }
+        }
+    }
+
+    #[test]
+    fn zcash_invalid() {
+        let decode = bls12_381::G1Affine::from_zcash_bytes;
+        let compressed = bls12_381::G1Affine::generator().to_zcash_bytes(Compress::Yes);
+
+        let mut uncompressed_flag = compressed.clone();
+        uncompressed_flag[0] &= !ZCASH_COMPRESSION;
+        assert_eq!(decode(&uncompressed_flag), Err(EncodingError::InvalidLength));
+
+        let mut infinity_with_data = compressed.clone();
+        infinity_with_data[0] |= ZCASH_INFINITY;
+        assert_eq!(decode(&infinity_with_data), Err(EncodingError::InvalidFlags));
+
+        let mut sorted_uncompressed = bls12_381::G1Affine::generator().to_zcash_bytes(Compress::No);
+        sorted_uncompressed[0] |= ZCASH_SORT;
+        assert_eq!(decode(&sorted_uncompressed), Err(EncodingError::InvalidFlags));
+
+        // `x = 0` is on the curve, but not in the prime-order subgroup.
+        let mut torsion = vec![0u8; 48];
+        torsion[0] = ZCASH_COMPRESSION;
+        assert_eq!(decode(&torsion), Err(EncodingError::NotInSubgroup));
+    }
+
+    #[test]
+    fn roots_not_satisfying_flags() {
+        // Neither root satisfies the flags, as when `y = 0` and the sign or
+        // sort flag is set.
+        let x = secp256k1::Affine::generator().x;
+        assert_eq!(secp256k1::Affine::from_x(x, |_| false), Err(EncodingError::InvalidFlags));
+        assert!(secp256k1::Affine::from_x(x, |_| true).is_ok());
+    }
+
+    #[test]
+    #[should_panic = "should leave three spare bits"]
+    fn zcash_without_spare_bits() {
+        let _ = secp256k1::Affine::generator().to_zcash_bytes(Compress::Yes);
+    }
+
+    #[test]
+    #[should_panic = "should leave three spare bits"]
+    fn zcash_decoding_without_spare_bits() {
+        let _ = bn254::G1Affine::from_zcash_bytes(&[0u8; 32]);
+    }
+
+    #[test]
+    fn ethereum_bn254() {
+        let g1 = bn254::G1Affine::generator();
+        let expected = format!("{:064x}{:064x}", 1, 2);
+        assert_eq!(to_hex(&g1.to_ethereum_bytes()), expected);
+        assert_eq!(bn254::G1Affine::zero().to_ethereum_bytes(), vec![0u8; 64]);
+
+        // Generator of G2 from EIP-197, imaginary parts first.
+        let g2 = bn254::G2Affine::generator();
+        assert_eq!(
+            to_hex(&g2.to_ethereum_bytes()),
+            "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+             1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+             090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+             12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"
+        );
+
+        for point in [g1, -g1, g1.mul_bigint(7u8).into_affine(), bn254::G1Affine::zero()] {
+            assert_eq!(bn254::G1Affine::from_ethereum_bytes(&point.to_ethereum_bytes()), Ok(point));
+        }
+        for point in [g2, -g2, g2.mul_bigint(7u8).into_affine(), bn254::G2Affine::zero()] {
+            assert_eq!(bn254::G2Affine::from_ethereum_bytes(&point.to_ethereum_bytes()), Ok(point));
+        }
+
+        let mut off_curve = g1.to_ethereum_bytes();
+        off_curve[63] = 3;
+        assert_eq!(
+            bn254::G1Affine::from_ethereum_bytes(&off_curve),
+            Err(EncodingError::NotOnCurve)
+        );
+        assert_eq!(
+            bn254::G1Affine::from_ethereum_bytes(&[0u8; 63]),
+            Err(EncodingError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn serde_hex() {
+        let g = secp256k1::Affine::generator();
+        let json = serde_json::to_string(&g).unwrap();
+        assert_eq!(
+            json,
+            "\"0x0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798\""
+        );
+        assert_eq!(serde_json::from_str::<secp256k1::Affine>(&json).unwrap(), g);
+
+        let g2 = bls12_381::G2Affine::generator();
+        let json = serde_json::to_string(&g2).unwrap();
+        assert_eq!(serde_json::from_str::<bls12_381::G2Affine>(&json).unwrap(), g2);
+
+        assert!(serde_json::from_str::<secp256k1::Affine>("\"05\"").is_err());
+    }
+}
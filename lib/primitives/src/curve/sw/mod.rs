@@ -3,9 +3,13 @@
 //!
 //! [Short Weierstrass model]: https://www.hyperelliptic.org/EFD/g1p/auto-shortw.html
 pub mod affine;
+pub mod complete;
+pub mod encoding;
 pub mod projective;
+pub mod validation;
 use super::{
     batch_inversion,
+    scalar_mul::variable_base::VariableBaseMSM,
     traits::{AffineRepr, CurveConfig, CurveGroup, PrimeGroup},
 };
 use crate::{
@@ -14,6 +18,8 @@ use crate::{
     impl_additive_ops_from_ref,
 };
 use affine::Affine;
+use alloc::vec::Vec;
+use complete::CompleteFormulas;
 use num_traits::Zero;
 use projective::Projective;
 
@@ -31,6 +37,13 @@ pub trait SWCurveConfig: CurveConfig {
     const COEFF_B: Self::BaseField;
     /// Generator of the prime-order subgroup.
     const GENERATOR: Affine<Self>;
+    /// Specialization of the complete formulae used by
+    /// [`CompleteProjective`](complete::CompleteProjective).
+    ///
+    /// [`CompleteFormulas::Generic`] is correct for every curve, and curves
+    /// with `a = 0` or `a = -3` should select the cheaper specialized
+    /// formulae.
+    const COMPLETE_FORMULAS: CompleteFormulas = CompleteFormulas::Generic;
 
     /// Helper method for computing `elem * Self::COEFF_A`.
     ///
@@ -55,15 +68,17 @@ pub trait SWCurveConfig: CurveConfig {
     /// Check if the provided curve point is in the prime-order subgroup.
     ///
     /// The default implementation multiplies `item` by the order `r` of the
-    /// prime-order subgroup, and checks if the result is zero. If the
-    /// curve's cofactor is one, this check automatically returns true.
+    /// prime-order subgroup with [`sw_double_and_add_affine`], since
+    /// [`Self::mul_affine`] may rely on an endomorphism only valid in that
+    /// subgroup, and checks if the result is zero. If the curve's cofactor is
+    /// one, this check automatically returns true.
     /// Implementors can choose to override this default impl
     /// if the given curve has faster methods
     /// for performing this check (for example, via leveraging curve
     /// isomorphisms).
     fn is_in_correct_subgroup_assuming_on_curve(item: &Affine<Self>) -> bool {
         Self::cofactor_is_one()
-            || Self::mul_affine(item, Self::ScalarField::characteristic()).is_zero()
+            || sw_double_and_add_affine(item, Self::ScalarField::characteristic()).is_zero()
     }
 
     /// Performs cofactor clearing.
@@ -84,6 +99,13 @@ pub trait SWCurveConfig: CurveConfig {
     fn mul_affine(base: &Affine<Self>, scalar: impl BitIteratorBE) -> Projective<Self> {
         sw_double_and_add_affine(base, scalar)
     }
+
+    /// Default implementation of multi-scalar multiplication, used by
+    /// [`VariableBaseMSM::msm_unchecked`].
+    fn msm_unchecked(bases: &[Affine<Self>], scalars: &[Self::ScalarField]) -> Projective<Self> {
+        let bigints: Vec<_> = scalars.iter().map(|s| s.into_bigint()).collect();
+        Projective::msm_bigint(bases, &bigints)
+    }
 }
 
 /// Standard double-and-add method for multiplication by a scalar.
@@ -125,13 +147,21 @@ mod test {
     use num_traits::Zero;
 
     use crate::{
+        UniformRand,
         arithmetic::U256,
         curve::{
+            scalar_mul::{
+                fixed_base::{Comb, FixedBase},
+                variable_base::VariableBaseMSM,
+            },
             sw::SWCurveConfig,
-            traits::{AffineRepr, CurveConfig, CurveGroup},
+            traits::{AffineRepr, CurveConfig, CurveGroup, check_scalar_mul_variants},
+        },
+        field::{
+            Fp256, FpParams, LIMBS_256,
+            traits::{AdditiveGroup, PrimeField},
         },
-        field::{Fp256, FpParams, LIMBS_256, traits::AdditiveGroup},
-        fp_from_hex, fp_from_num, from_num,
+        fp_from_hex, fp_from_num, from_num, test_rng,
     };
 
     type Affine = super::Affine<Config>;
@@ -148,6 +178,11 @@ mod test {
         const MODULUS: U256 = from_num!(
             "115792089237316195423570985008687907853269984665640564039457584007908834671663"
         );
+        const ONE: Fp256<Self> = fp_from_num!("1");
+        const TWO_ADICITY: u32 = 1;
+        const TWO_ADIC_ROOT_OF_UNITY: Fp256<Self> =
+            fp_from_hex!("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2E");
+        const ZERO: Fp256<Self> = fp_from_num!("0");
     }
 
     type Fr = Fp256<FrParam>;
@@ -158,6 +193,11 @@ mod test {
         const MODULUS: U256 = from_num!(
             "115792089237316195423570985008687907852837564279074904382605163141518161494337"
         );
+        const ONE: Fp256<Self> = fp_from_num!("1");
+        const TWO_ADICITY: u32 = 6;
+        const TWO_ADIC_ROOT_OF_UNITY: Fp256<Self> =
+            fp_from_hex!("C1DC060E7A91986DF9879A3FBC483A898BDEAB680756045992F4B5402B052F2");
+        const ZERO: Fp256<Self> = fp_from_num!("0");
     }
 
     impl CurveConfig for Config {
@@ -351,4 +391,78 @@ mod test {
         assert!(Config::cofactor_is_one());
         assert!(!NotOneCofactorConfig::cofactor_is_one());
     }
+
+    #[test]
+    fn msm_batch_affine() {
+        let rng = &mut test_rng();
+        let g = Affine::generator();
+        let g2 = g.into_group().double().into_affine();
+
+        // Repeated and opposite points exercise the doubling and cancelling
+        // cases of the batched affine additions.
+        let mut bases: Vec<Affine> = (0..100).map(|_| (g * Fr::rand(rng)).into_affine()).collect();
+        bases.extend(core::iter::repeat_n(g, 100));
+        bases.extend(core::iter::repeat_n(-g2, 60));
+        bases.extend(core::iter::repeat_n(Affine::identity(), 5));
+        let scalars: Vec<Fr> = (0..bases.len()).map(|_| Fr::rand(rng)).collect();
+
+        let expected: Projective = bases.iter().zip(&scalars).map(|(b, s)| *b * s).sum();
+        assert_eq!(Projective::msm(&bases, &scalars).unwrap(), expected);
+
+        let pairs = [(g, g), (g, -g), (g, g2), (Affine::identity(), g2), (g2, Affine::identity())];
+        let sums = Projective::batch_add_affine_pairs(&pairs);
+        for ((lhs, rhs), sum) in pairs.iter().zip(sums) {
+            assert_eq!(*lhs + rhs, sum);
+        }
+    }
+
+    #[test]
+    fn batch_affine_arithmetic() {
+        let rng = &mut test_rng();
+        let g = Affine::generator();
+        let g2 = g.into_group().double().into_affine();
+
+        let mut lhs: Vec<Affine> = (0..50).map(|_| (g * Fr::rand(rng)).into_affine()).collect();
+        let mut rhs: Vec<Affine> = (0..50).map(|_| (g * Fr::rand(rng)).into_affine()).collect();
+        lhs.extend([g, g, g, Affine::identity(), g2, Affine::identity()]);
+        rhs.extend([g, -g, g2, g2, Affine::identity(), Affine::identity()]);
+
+        let expected: Vec<Projective> = lhs.iter().zip(&rhs).map(|(lhs, rhs)| *lhs + rhs).collect();
+        let mut sums = lhs.clone();
+        Affine::batch_add_affine(&mut sums, &rhs);
+        assert_eq!(sums, Projective::normalize_batch(&expected));
+
+        let expected: Vec<Projective> = lhs.iter().map(|p| p.into_group().double()).collect();
+        Affine::batch_double_affine(&mut lhs);
+        assert_eq!(lhs, Projective::normalize_batch(&expected));
+
+        Affine::batch_add_affine(&mut [], &[]);
+        Affine::batch_double_affine(&mut []);
+    }
+
+    #[test]
+    #[should_panic = "batches should have the same length"]
+    fn batch_add_affine_length_mismatch() {
+        Affine::batch_add_affine(&mut [Affine::generator()], &[]);
+    }
+
+    #[test]
+    fn fixed_base_tables() {
+        let rng = &mut test_rng();
+        let g = Affine::generator().into_group();
+        let fixed_base = FixedBase::new(&g, 4);
+        let comb = Comb::new(&g, 5);
+
+        let scalars: Vec<Fr> = (0..20).map(|_| Fr::rand(rng)).chain([Fr::ZERO, -Fr::ONE]).collect();
+        let expected: Vec<Affine> = scalars.iter().map(|s| (g * s).into_affine()).collect();
+        assert_eq!(fixed_base.batch_mul(&scalars), expected);
+        assert_eq!(comb.batch_mul(&scalars), expected);
+    }
+
+    #[test]
+    fn scalar_mul_variants() {
+        check_scalar_mul_variants(Affine::generator().into_group(), |g, s| {
+            super::sw_double_and_add_projective(g, s.into_bigint())
+        });
+    }
 }
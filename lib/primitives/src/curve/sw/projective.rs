@@ -6,7 +6,7 @@ use super::{
     AdditiveGroup, Affine, AffineRepr, CurveGroup, Field, PrimeField, PrimeGroup, SWCurveConfig,
     batch_inversion, impl_additive_ops_from_ref,
 };
-use crate::bits::BitIteratorBE;
+use crate::{bits::BitIteratorBE, curve::scalar_mul::variable_base::VariableBaseMSM};
 use alloc::vec::Vec;
 use core::{
     borrow::Borrow,
@@ -263,6 +263,15 @@ impl<P: SWCurveConfig> PrimeGroup for Projective<P> {
     fn mul_bigint(&self, other: impl BitIteratorBE) -> Self {
         P::mul_projective(self, other)
     }
+
+    #[inline]
+    fn ct_select(&self, other: &Self, choice: bool) -> Self {
+        Self {
+            x: self.x.ct_select(&other.x, choice),
+            y: self.y.ct_select(&other.y, choice),
+            z: self.z.ct_select(&other.z, choice),
+        }
+    }
 }
 
 impl<P: SWCurveConfig> CurveGroup for Projective<P> {
@@ -570,3 +579,17 @@ impl<P: SWCurveConfig, T: Borrow<Affine<P>>> core::iter::Sum<T> for Projective<P
         iter.fold(Projective::zero(), |sum, x| sum + x.borrow())
     }
 }
+
+impl<P: SWCurveConfig> VariableBaseMSM for Projective<P> {
+    fn msm_unchecked(bases: &[Affine<P>], scalars: &[P::ScalarField]) -> Self {
+        P::msm_unchecked(bases, scalars)
+    }
+
+    /// Adds each pair with [`Affine::batch_add_affine`], sharing a single
+    /// inversion across the batch.
+    fn batch_add_affine_pairs(pairs: &[(Affine<P>, Affine<P>)]) -> Vec<Affine<P>> {
+        let (mut lhs, rhs): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+        Affine::batch_add_affine(&mut lhs, &rhs);
+        lhs
+    }
+}
@@ -0,0 +1,377 @@
+//! Consistency checks of [`SWCurveConfig`]s, and derivation of their
+//! parameters from the curve equation.
+//!
+//! [`validate_curve_config`] audits the constants of a configuration against
+//! each other, and [`derive_curve_parameters`] computes the cofactor, its
+//! inverse and a nothing-up-my-sleeve generator of a new curve from its
+//! coefficients and its order.
+use super::{SWCurveConfig, affine::Affine, complete::CompleteFormulas, sw_double_and_add_affine};
+use crate::{
+    curve::{hashing::sgn0, traits::AffineRepr},
+    field::traits::{Field, PrimeField},
+};
+use alloc::{vec, vec::Vec};
+use core::fmt;
+use educe::Educe;
+use num_traits::Zero;
+
+/// Number of curve points sampled by [`validate_curve_config`] to check the
+/// order of the group and the subgroup-related methods.
+const SAMPLED_POINTS: usize = 2;
+
+/// Number of curve points tried by [`derive_curve_parameters`] before giving
+/// up on finding a generator.
+///
+/// With the right order, a point is only mapped to zero by the cofactor with
+/// probability `1 / r`, so the bound is only reached with a wrong order.
+const GENERATOR_CANDIDATES: usize = 64;
+
+/// Inconsistency of a [`SWCurveConfig`], found by [`validate_curve_config`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurveConfigError {
+    /// The discriminant `4 * a³ + 27 * b²` is zero, i.e. the curve is
+    /// singular.
+    SingularCurve,
+    /// `GENERATOR` is the point at infinity.
+    GeneratorIsZero,
+    /// `GENERATOR` does not satisfy the curve equation.
+    GeneratorNotOnCurve,
+    /// `GENERATOR` is not of order `r`, the modulus of the scalar field.
+    GeneratorOrderMismatch,
+    /// `COFACTOR_INV * COFACTOR` is not one modulo `r`.
+    CofactorInverseMismatch,
+    /// Some point of the curve is not killed by `COFACTOR * r`, i.e. the
+    /// cofactor does not match the order of the curve.
+    GroupOrderMismatch,
+    /// `is_in_correct_subgroup_assuming_on_curve` disagrees with
+    /// multiplication by `r`.
+    SubgroupCheckMismatch,
+    /// `clear_cofactor` maps some point outside of the prime-order subgroup.
+    ClearCofactorMismatch,
+    /// `mul_affine` or `mul_projective` disagrees with double-and-add in the
+    /// prime-order subgroup.
+    ScalarMulMismatch,
+    /// `COMPLETE_FORMULAS` is specialized for another value of `COEFF_A`.
+    CompleteFormulasMismatch,
+}
+
+impl core::error::Error for CurveConfigError {}
+
+impl fmt::Display for CurveConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            Self::SingularCurve => "the curve is singular",
+            Self::GeneratorIsZero => "the generator is the point at infinity",
+            Self::GeneratorNotOnCurve => "the generator is not on the curve",
+            Self::GeneratorOrderMismatch => "the generator is not of order r",
+            Self::CofactorInverseMismatch => "the cofactor inverse is incorrect",
+            Self::GroupOrderMismatch => "the cofactor does not match the curve order",
+            Self::SubgroupCheckMismatch => "the subgroup check disagrees with multiplication by r",
+            Self::ClearCofactorMismatch => "cofactor clearing leaves the prime-order subgroup",
+            Self::ScalarMulMismatch => "scalar multiplication disagrees with double-and-add",
+            Self::CompleteFormulasMismatch => "the complete formulae do not match COEFF_A",
+        };
+        write!(f, "invalid curve configuration: {reason}")
+    }
+}
+
+/// Checks the constants of the curve configuration `P` against each other,
+/// and returns every inconsistency found.
+///
+/// Besides the constants, this checks that the overridable methods of
+/// [`SWCurveConfig`] agree with their default implementations on the
+/// generator and on a few points sampled outside of the prime-order subgroup.
+/// The order of the curve is only checked on these points, so that a wrong
+/// cofactor may go unnoticed if it is a multiple of the actual one.
+///
+/// # Errors
+///
+/// * If any of the checks fails, with the list of failed checks.
+pub fn validate_curve_config<P: SWCurveConfig>() -> Result<(), Vec<CurveConfigError>> {
+    let mut errors = Vec::new();
+    let r = P::ScalarField::MODULUS;
+
+    let discriminant = P::COEFF_A.square() * P::COEFF_A * P::BaseField::from(4u8)
+        + P::COEFF_B.square() * P::BaseField::from(27u8);
+    if discriminant.is_zero() {
+        errors.push(CurveConfigError::SingularCurve);
+    }
+
+    let complete_formulas_match = match P::COMPLETE_FORMULAS {
+        CompleteFormulas::Generic => true,
+        CompleteFormulas::AIsMinusThree => P::COEFF_A == -P::BaseField::from(3u8),
+        CompleteFormulas::AIsZero => P::COEFF_A.is_zero(),
+    };
+    if !complete_formulas_match {
+        errors.push(CurveConfigError::CompleteFormulasMismatch);
+    }
+
+    if cofactor_inverse::<P>(P::COFACTOR) != Some(P::COFACTOR_INV) {
+        errors.push(CurveConfigError::CofactorInverseMismatch);
+    }
+
+    let generator = P::GENERATOR;
+    if generator.is_zero() {
+        errors.push(CurveConfigError::GeneratorIsZero);
+    } else if !generator.is_on_curve() {
+        errors.push(CurveConfigError::GeneratorNotOnCurve);
+    } else if !sw_double_and_add_affine(&generator, r).is_zero() {
+        errors.push(CurveConfigError::GeneratorOrderMismatch);
+    } else {
+        if !generator.is_in_correct_subgroup_assuming_on_curve() {
+            errors.push(CurveConfigError::SubgroupCheckMismatch);
+        }
+        // `(r - 1) * G = -G`, computed with the configured methods.
+        let minus_one = (-P::ScalarField::ONE).into_bigint();
+        if P::mul_affine(&generator, minus_one) != -generator
+            || P::mul_projective(&generator.into_group(), minus_one) != -generator
+        {
+            errors.push(CurveConfigError::ScalarMulMismatch);
+        }
+    }
+
+    for point in points_by_increasing_x::<P>().take(SAMPLED_POINTS) {
+        let in_subgroup = sw_double_and_add_affine(&point, r).is_zero();
+        if !sw_double_and_add_affine(&point.mul_by_cofactor(), r).is_zero() {
+            errors.push(CurveConfigError::GroupOrderMismatch);
+        }
+        if point.is_in_correct_subgroup_assuming_on_curve() != in_subgroup {
+            errors.push(CurveConfigError::SubgroupCheckMismatch);
+        }
+        if !sw_double_and_add_affine(&P::clear_cofactor(&point), r).is_zero() {
+            errors.push(CurveConfigError::ClearCofactorMismatch);
+        }
+    }
+
+    errors.dedup();
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+/// Parameters of a curve derived by [`derive_curve_parameters`].
+#[derive(Educe)]
+#[educe(Debug, Clone, PartialEq, Eq)]
+pub struct DerivedParameters<P: SWCurveConfig> {
+    /// Cofactor, as little-endian limbs without trailing zeros.
+    pub cofactor: Vec<u64>,
+    /// Inverse of the cofactor modulo `r`.
+    pub cofactor_inv: P::ScalarField,
+    /// Nothing-up-my-sleeve generator of the prime-order subgroup.
+    pub generator: Affine<P>,
+}
+
+/// Derives the cofactor, its inverse and a nothing-up-my-sleeve generator of
+/// the curve `P` of order `order`, given as little-endian limbs.
+///
+/// Only [`SWCurveConfig::COEFF_A`], [`SWCurveConfig::COEFF_B`] and the
+/// scalar field of `P` are used, so the other constants of `P` can be
+/// placeholders while the curve is being set up. The order itself is not
+/// computed, since point counting is out of scope.
+///
+/// The generator is `COFACTOR * (x, y)` for the smallest integer `x ≥ 0`
+/// such that `(x, y)` is on the curve and the product is not zero, where `y`
+/// is the square root with [`sgn0`] equal to zero.
+///
+/// Returns `None` if `r` does not divide `order`, if `r` divides the
+/// cofactor, or if the cofactor maps the first [`GENERATOR_CANDIDATES`]
+/// points to zero, which happens when `order` is a wrong multiple of the
+/// exponent of the group.
+#[must_use]
+pub fn derive_curve_parameters<P: SWCurveConfig>(order: &[u64]) -> Option<DerivedParameters<P>> {
+    let cofactor = cofactor_from_order::<P>(order)?;
+    let cofactor_inv = cofactor_inverse::<P>(&cofactor)?;
+    let generator = points_by_increasing_x::<P>()
+        .take(GENERATOR_CANDIDATES)
+        .map(|point| sw_double_and_add_affine(&point, cofactor.as_slice()).into())
+        .find(|point: &Affine<P>| !point.is_zero())?;
+    Some(DerivedParameters { cofactor, cofactor_inv, generator })
+}
+
+/// Returns the inverse of `cofactor`, given as little-endian limbs, modulo
+/// the order `r` of the scalar field of `P`.
+///
+/// Returns `None` if `r` divides `cofactor`.
+#[must_use]
+pub fn cofactor_inverse<P: SWCurveConfig>(cofactor: &[u64]) -> Option<P::ScalarField> {
+    let bytes: Vec<u8> = cofactor.iter().flat_map(|limb| limb.to_le_bytes()).collect();
+    P::ScalarField::from_le_bytes_mod_order(&bytes).inverse()
+}
+
+/// Returns `order / r`, where `r` is the order of the scalar field of `P`,
+/// as little-endian limbs without trailing zeros.
+///
+/// Returns `None` if `r` does not divide `order`.
+#[must_use]
+pub fn cofactor_from_order<P: SWCurveConfig>(order: &[u64]) -> Option<Vec<u64>> {
+    let modulus = P::ScalarField::MODULUS;
+    let modulus = modulus.as_ref();
+
+    // Schoolbook binary long division, with one limb of headroom in the
+    // remainder for the shift.
+    let mut quotient = vec![0u64; order.len()];
+    let mut remainder = vec![0u64; modulus.len() + 1];
+    for i in (0..64 * order.len()).rev() {
+        let mut carry = (order[i / 64] >> (i % 64)) & 1;
+        for limb in &mut remainder {
+            let next_carry = *limb >> 63;
+            *limb = (*limb << 1) | carry;
+            carry = next_carry;
+        }
+
+        if !is_less_than(&remainder, modulus) {
+            let mut borrow = false;
+            for (j, limb) in remainder.iter_mut().enumerate() {
+                let (diff, b1) = limb.overflowing_sub(modulus.get(j).copied().unwrap_or(0));
+                let (diff, b2) = diff.overflowing_sub(u64::from(borrow));
+                *limb = diff;
+                borrow = b1 || b2;
+            }
+            quotient[i / 64] |= 1 << (i % 64);
+        }
+    }
+
+    if remainder.iter().any(|&limb| limb != 0) {
+        return None;
+    }
+    while quotient.last() == Some(&0) {
+        quotient.pop();
+    }
+    Some(quotient)
+}
+
+/// Returns whether `lhs < rhs`, as little-endian limbs, with `rhs` possibly
+/// shorter than `lhs`.
+fn is_less_than(lhs: &[u64], rhs: &[u64]) -> bool {
+    for i in (0..lhs.len()).rev() {
+        let rhs = rhs.get(i).copied().unwrap_or(0);
+        if lhs[i] != rhs {
+            return lhs[i] < rhs;
+        }
+    }
+    false
+}
+
+/// Returns the points `(x, y)` of the curve for `x = 0, 1, 2, ...`, with `y`
+/// the square root with [`sgn0`] equal to zero.
+fn points_by_increasing_x<P: SWCurveConfig>() -> impl Iterator<Item = Affine<P>> {
+    (0u64..).filter_map(|x| {
+        let x = P::BaseField::from(x);
+        let y = P::add_b((x.square() + P::COEFF_A) * x).sqrt()?;
+        let y = if sgn0(&y) { -y } else { y };
+        Some(Affine::new_unchecked(x, y))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        curve::{
+            instances::{bls12_381, bn254},
+            traits::CurveConfig,
+        },
+        fp_from_num,
+    };
+
+    /// BN254 G1 with an inconsistent generator and cofactor inverse.
+    struct BrokenConfig;
+
+    impl CurveConfig for BrokenConfig {
+        type BaseField = bn254::Fq;
+        type ScalarField = bn254::Fr;
+
+        const COFACTOR: &'static [u64] = &[1];
+        const COFACTOR_INV: bn254::Fr = fp_from_num!("2");
+    }
+
+    impl SWCurveConfig for BrokenConfig {
+        const COEFF_A: bn254::Fq = fp_from_num!("0");
+        const COEFF_B: bn254::Fq = fp_from_num!("3");
+        const COMPLETE_FORMULAS: CompleteFormulas = CompleteFormulas::AIsMinusThree;
+        const GENERATOR: Affine<Self> = Affine::new_unchecked(fp_from_num!("1"), fp_from_num!("3"));
+    }
+
+    /// BN254 G1 paired with the scalar field of BLS12-381, whose order does
+    /// not divide the order of the curve.
+    struct MismatchedScalarFieldConfig;
+
+    impl CurveConfig for MismatchedScalarFieldConfig {
+        type BaseField = bn254::Fq;
+        type ScalarField = bls12_381::Fr;
+
+        const COFACTOR: &'static [u64] = &[1];
+        const COFACTOR_INV: bls12_381::Fr = fp_from_num!("1");
+    }
+
+    impl SWCurveConfig for MismatchedScalarFieldConfig {
+        const COEFF_A: bn254::Fq = fp_from_num!("0");
+        const COEFF_B: bn254::Fq = fp_from_num!("3");
+        const COMPLETE_FORMULAS: CompleteFormulas = CompleteFormulas::AIsZero;
+        const GENERATOR: Affine<Self> = Affine::new_unchecked(fp_from_num!("1"), fp_from_num!("2"));
+    }
+
+    #[test]
+    fn broken_config() {
+        assert_eq!(
+            validate_curve_config::<BrokenConfig>(),
+            Err(vec![
+                CurveConfigError::CompleteFormulasMismatch,
+                CurveConfigError::CofactorInverseMismatch,
+                CurveConfigError::GeneratorNotOnCurve,
+            ])
+        );
+    }
+
+    #[test]
+    fn bn254_parameters() {
+        let order = bn254::Fr::MODULUS;
+        let derived = derive_curve_parameters::<bn254::G1Config>(order.as_ref()).unwrap();
+        assert_eq!(derived.cofactor, [1]);
+        assert_eq!(derived.cofactor_inv, bn254::G1Config::COFACTOR_INV);
+        // The standard generator `(1, 2)` is the first point found.
+        assert_eq!(derived.generator, bn254::G1Config::GENERATOR);
+    }
+
+    #[test]
+    fn bls12_381_parameters() {
+        // `#E = h * r`, as little-endian limbs.
+        let order = [
+            0x8c00_0000_0000_aaab,
+            0x1eab_fffe_b154_0000,
+            0x6730_d2a0_f6b0_f624,
+            0x6477_4b84_f385_12bf,
+            0x4b1b_a7b6_434b_acd7,
+            0x1a01_11ea_397f_e69a,
+        ];
+        let derived = derive_curve_parameters::<bls12_381::G1Config>(&order).unwrap();
+        assert_eq!(derived.cofactor, bls12_381::G1Config::COFACTOR);
+        assert_eq!(derived.cofactor_inv, bls12_381::G1Config::COFACTOR_INV);
+        assert!(derived.generator.is_on_curve());
+        assert!(derived.generator.is_in_correct_subgroup_assuming_on_curve());
+
+        let mut not_a_multiple = order;
+        not_a_multiple[0] += 1;
+        assert_eq!(cofactor_from_order::<bls12_381::G1Config>(&not_a_multiple), None);
+    }
+
+    #[test]
+    fn wrong_order() {
+        // The product of the orders of the scalar fields of BN254 and
+        // BLS12-381, so that the cofactor is the order of BN254 G1 and maps
+        // every point to zero.
+        let order = [
+            0x53e1_f592_f000_0001,
+            0xd76f_7066_45d5_d6fc,
+            0xa661_456a_2e88_5aa5,
+            0x3081_5474_7dc5_239e,
+            0x1236_99ef_c35e_84eb,
+            0xef02_4109_0313_4770,
+            0x89b5_f78b_a07e_7e72,
+            0x15e9_fbbb_6313_fbcd,
+        ];
+        assert_eq!(
+            cofactor_from_order::<MismatchedScalarFieldConfig>(&order).unwrap(),
+            bn254::Fr::MODULUS.as_ref()
+        );
+        assert_eq!(derive_curve_parameters::<MismatchedScalarFieldConfig>(&order), None);
+    }
+}
@@ -0,0 +1,406 @@
+//! Affine coordinates for a point on a Twisted Edwards curve
+//! ([Affine Space]).
+//!
+//! [Affine Space]: https://en.wikipedia.org/wiki/Affine_space
+use super::{AdditiveGroup, AffineRepr, Field, PrimeField, Projective, TECurveConfig};
+use crate::{
+    bits::BitIteratorBE,
+    curve::sw::{self, SWCurveConfig},
+};
+use core::{
+    borrow::Borrow,
+    fmt::{Debug, Display, Formatter},
+    ops::{Add, Mul, Neg, Sub},
+};
+use educe::Educe;
+use fina_common::io::{Read, Write};
+use fina_serialize::{ArkDeserialize, ArkSerialize, Compress, SerializationError, Valid, Validate};
+use num_traits::{One, Zero};
+use zeroize::Zeroize;
+
+/// Affine coordinates for a point on an elliptic curve in twisted Edwards
+/// form, over the base field `P::BaseField`.
+#[derive(Educe)]
+#[educe(Copy, Clone, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct Affine<P: TECurveConfig> {
+    #[doc(hidden)]
+    pub x: P::BaseField,
+    #[doc(hidden)]
+    pub y: P::BaseField,
+}
+
+impl<P: TECurveConfig> PartialEq<Projective<P>> for Affine<P> {
+    fn eq(&self, other: &Projective<P>) -> bool {
+        self.into_group() == *other
+    }
+}
+
+impl<P: TECurveConfig> Display for Affine<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.is_zero() { write!(f, "infinity") } else { write!(f, "({}, {})", self.x, self.y) }
+    }
+}
+
+impl<P: TECurveConfig> Debug for Affine<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.is_zero() { write!(f, "infinity") } else { write!(f, "({}, {})", self.x, self.y) }
+    }
+}
+
+impl<P: TECurveConfig> Affine<P> {
+    /// Constructs a group element from `x` and `y` coordinates.
+    /// Performs checks to ensure that the point is on the curve and is in the
+    /// right subgroup.
+    ///
+    /// # Panics
+    ///
+    /// * If point is not on curve.
+    /// * If point is not in the prime-order subgroup.
+    pub fn new(x: P::BaseField, y: P::BaseField) -> Self {
+        let point = Self { x, y };
+        assert!(point.is_on_curve());
+        assert!(point.is_in_correct_subgroup_assuming_on_curve());
+        point
+    }
+
+    /// Constructs a group element from `x` and `y` coordinates.
+    ///
+    /// # Warning
+    ///
+    /// Does *not* perform any checks to ensure the point is in the curve or
+    /// is in the right subgroup.
+    pub const fn new_unchecked(x: P::BaseField, y: P::BaseField) -> Self {
+        Self { x, y }
+    }
+
+    /// Additive identity element of the curve group, i.e. the point `(0, 1)`.
+    pub const fn identity() -> Self {
+        Self { x: P::BaseField::ZERO, y: P::BaseField::ONE }
+    }
+
+    /// Checks if `self` is a valid point on the curve.
+    pub fn is_on_curve(&self) -> bool {
+        let x2 = self.x.square();
+        let y2 = self.y.square();
+
+        let lhs = y2 + P::mul_by_a(x2);
+        let rhs = P::BaseField::one() + (P::COEFF_D * (x2 * y2));
+
+        lhs == rhs
+    }
+
+    /// Maps `self` to the coordinates `(u, v)` of the birationally equivalent
+    /// Montgomery curve with coefficients
+    /// [`TECurveConfig::montgomery_coefficients`].
+    ///
+    /// Uses `u = (1 + y) / (1 - y)` and `v = u / x`.
+    /// Returns `None` for the identity, which maps to the point at infinity.
+    #[must_use]
+    pub fn to_montgomery(&self) -> Option<(P::BaseField, P::BaseField)> {
+        if self.is_zero() {
+            return None;
+        }
+
+        // The point of order two `(0, -1)` maps to `(0, 0)`.
+        if self.x.is_zero() {
+            return Some((P::BaseField::ZERO, P::BaseField::ZERO));
+        }
+
+        let u = (P::BaseField::one() + self.y) / (P::BaseField::one() - self.y);
+        let v = u / self.x;
+        Some((u, v))
+    }
+
+    /// Maps the coordinates `(u, v)` of a point on the birationally equivalent
+    /// Montgomery curve back to the twisted Edwards curve.
+    ///
+    /// Uses `x = u / v` and `y = (u - 1) / (u + 1)`.
+    /// Returns `None` for the exceptional points with `v = 0, u != 0` or
+    /// `u = -1`, which have no affine twisted Edwards image.
+    #[must_use]
+    pub fn from_montgomery(u: P::BaseField, v: P::BaseField) -> Option<Self> {
+        if u.is_zero() && v.is_zero() {
+            return Some(Self::new_unchecked(P::BaseField::ZERO, -P::BaseField::one()));
+        }
+
+        let x = u * v.inverse()?;
+        let y = (u - P::BaseField::one()) * (u + P::BaseField::one()).inverse()?;
+        Some(Self::new_unchecked(x, y))
+    }
+
+    /// Maps `self` to the coordinates `(x, y)` of the isomorphic short
+    /// Weierstrass curve with coefficients
+    /// [`TECurveConfig::weierstrass_coefficients`].
+    ///
+    /// Returns `None` for the identity, which maps to the point at infinity.
+    #[must_use]
+    pub fn to_weierstrass(&self) -> Option<(P::BaseField, P::BaseField)> {
+        let (u, v) = self.to_montgomery()?;
+        let (mont_a, mont_b) = P::montgomery_coefficients();
+        let mont_b_inv = mont_b.inverse().expect("`B` should not be zero");
+
+        // x = u / B + A / (3 * B), y = v / B
+        let x = (u + mont_a / P::BaseField::from(3u8)) * mont_b_inv;
+        let y = v * mont_b_inv;
+        Some((x, y))
+    }
+
+    /// Maps the coordinates `(x, y)` of a point on the isomorphic short
+    /// Weierstrass curve back to the twisted Edwards curve.
+    #[must_use]
+    pub fn from_weierstrass(x: P::BaseField, y: P::BaseField) -> Option<Self> {
+        let (mont_a, mont_b) = P::montgomery_coefficients();
+
+        // u = B * x - A / 3, v = B * y
+        let u = mont_b * x - mont_a / P::BaseField::from(3u8);
+        let v = mont_b * y;
+        Self::from_montgomery(u, v)
+    }
+
+    /// Converts `self` into a point of the short Weierstrass curve defined by
+    /// `Q`.
+    ///
+    /// # Panics
+    ///
+    /// * If coefficients of `Q` are not [`TECurveConfig::weierstrass_coefficients`].
+    pub fn to_sw<Q>(&self) -> sw::affine::Affine<Q>
+    where
+        Q: SWCurveConfig<BaseField = P::BaseField, ScalarField = P::ScalarField>,
+    {
+        assert_eq!(
+            (Q::COEFF_A, Q::COEFF_B),
+            P::weierstrass_coefficients(),
+            "short Weierstrass curve should be isomorphic"
+        );
+        self.to_weierstrass().map_or(sw::affine::Affine::identity(), |(x, y)| {
+            sw::affine::Affine::new_unchecked(x, y)
+        })
+    }
+
+    /// Converts a point of the short Weierstrass curve defined by `Q` into a
+    /// point of this curve.
+    ///
+    /// # Panics
+    ///
+    /// * If coefficients of `Q` are not [`TECurveConfig::weierstrass_coefficients`].
+    /// * If the point has no affine twisted Edwards image.
+    pub fn from_sw<Q>(point: &sw::affine::Affine<Q>) -> Self
+    where
+        Q: SWCurveConfig<BaseField = P::BaseField, ScalarField = P::ScalarField>,
+    {
+        assert_eq!(
+            (Q::COEFF_A, Q::COEFF_B),
+            P::weierstrass_coefficients(),
+            "short Weierstrass curve should be isomorphic"
+        );
+        point.xy().map_or(Self::identity(), |(x, y)| {
+            Self::from_weierstrass(x, y).expect("point should have a twisted Edwards image")
+        })
+    }
+}
+
+impl<P: TECurveConfig> Affine<P> {
+    /// Checks if `self` is in the subgroup having order that equaling that of
+    /// `P::ScalarField`.
+    pub fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
+        P::is_in_correct_subgroup_assuming_on_curve(self)
+    }
+}
+
+impl<P: TECurveConfig> Zeroize for Affine<P> {
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+    }
+}
+
+impl<P: TECurveConfig> AffineRepr for Affine<P> {
+    type BaseField = P::BaseField;
+    type Config = P;
+    type Group = Projective<P>;
+    type ScalarField = P::ScalarField;
+
+    fn xy(&self) -> Option<(Self::BaseField, Self::BaseField)> {
+        (!self.is_zero()).then_some((self.x, self.y))
+    }
+
+    #[inline]
+    fn generator() -> Self {
+        P::GENERATOR
+    }
+
+    fn zero() -> Self {
+        Self::identity()
+    }
+
+    fn ct_select(&self, other: &Self, choice: bool) -> Self {
+        Self::new_unchecked(self.x.ct_select(&other.x, choice), self.y.ct_select(&other.y, choice))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.x.is_zero() && self.y.is_one()
+    }
+
+    fn mul_bigint(&self, by: impl BitIteratorBE) -> Self::Group {
+        P::mul_affine(self, by)
+    }
+
+    /// Multiplies this element by the cofactor and output the
+    /// resulting projective element.
+    fn mul_by_cofactor_to_group(&self) -> Self::Group {
+        P::mul_affine(self, Self::Config::COFACTOR)
+    }
+
+    /// Performs cofactor clearing.
+    /// The default method is simply to multiply by the cofactor.
+    /// Some curves can implement a more efficient algorithm.
+    fn clear_cofactor(&self) -> Self {
+        P::clear_cofactor(self)
+    }
+}
+
+impl<P: TECurveConfig> Neg for Affine<P> {
+    type Output = Self;
+
+    /// Returns `(-x, y)`, where `self = (x, y)`.
+    #[inline]
+    fn neg(mut self) -> Self {
+        self.x.neg_in_place();
+        self
+    }
+}
+
+impl<P: TECurveConfig, T: Borrow<Self>> Add<T> for Affine<P> {
+    type Output = Projective<P>;
+
+    fn add(self, other: T) -> Projective<P> {
+        let mut copy = self.into_group();
+        copy += other.borrow();
+        copy
+    }
+}
+
+impl<P: TECurveConfig> Add<Projective<P>> for Affine<P> {
+    type Output = Projective<P>;
+
+    fn add(self, other: Projective<P>) -> Projective<P> {
+        other + self
+    }
+}
+
+impl<'a, P: TECurveConfig> Add<&'a Projective<P>> for Affine<P> {
+    type Output = Projective<P>;
+
+    fn add(self, other: &'a Projective<P>) -> Projective<P> {
+        *other + self
+    }
+}
+
+impl<P: TECurveConfig, T: Borrow<Self>> Sub<T> for Affine<P> {
+    type Output = Projective<P>;
+
+    fn sub(self, other: T) -> Projective<P> {
+        let mut copy = self.into_group();
+        copy -= other.borrow();
+        copy
+    }
+}
+
+impl<P: TECurveConfig> Sub<Projective<P>> for Affine<P> {
+    type Output = Projective<P>;
+
+    fn sub(self, other: Projective<P>) -> Projective<P> {
+        self + (-other)
+    }
+}
+
+impl<'a, P: TECurveConfig> Sub<&'a Projective<P>> for Affine<P> {
+    type Output = Projective<P>;
+
+    fn sub(self, other: &'a Projective<P>) -> Projective<P> {
+        self + (-*other)
+    }
+}
+
+impl<P: TECurveConfig> Default for Affine<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<P: TECurveConfig, T: Borrow<P::ScalarField>> Mul<T> for Affine<P> {
+    type Output = Projective<P>;
+
+    #[inline]
+    fn mul(self, other: T) -> Self::Output {
+        self.mul_bigint(other.borrow().into_bigint())
+    }
+}
+
+// The extended point X, Y, T, Z is represented in the affine
+// coordinates as X/Z, Y/Z.
+impl<P: TECurveConfig> From<Projective<P>> for Affine<P> {
+    #[inline]
+    fn from(p: Projective<P>) -> Affine<P> {
+        if p.is_zero() {
+            Affine::identity()
+        } else if p.z.is_one() {
+            // If Z is one, the point is already normalized.
+            Affine::new_unchecked(p.x, p.y)
+        } else {
+            // Z is nonzero, so it must have an inverse in a field.
+            let z_inv = p.z.inverse().unwrap();
+            let x = p.x * z_inv;
+            let y = p.y * z_inv;
+            Affine::new_unchecked(x, y)
+        }
+    }
+}
+
+impl<P: TECurveConfig> ArkSerialize for Affine<P> {
+    /// Writes the coordinates of the point.
+    ///
+    /// Points are written uncompressed in both modes.
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.x.serialize_with_mode(&mut writer, compress)?;
+        self.y.serialize_with_mode(&mut writer, compress)
+    }
+
+    #[inline]
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.x.serialized_size(compress) + self.y.serialized_size(compress)
+    }
+}
+
+impl<P: TECurveConfig> Valid for Affine<P> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if self.is_on_curve() && self.is_in_correct_subgroup_assuming_on_curve() {
+            Ok(())
+        } else {
+            Err(SerializationError::InvalidData)
+        }
+    }
+}
+
+impl<P: TECurveConfig> ArkDeserialize for Affine<P> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let x = P::BaseField::deserialize_with_mode(&mut reader, compress, validate)?;
+        let y = P::BaseField::deserialize_with_mode(&mut reader, compress, validate)?;
+        let point = Self::new_unchecked(x, y);
+        if validate == Validate::Yes {
+            point.check()?;
+        }
+        Ok(point)
+    }
+}
@@ -0,0 +1,391 @@
+//! Prime-order groups built on top of a Twisted Edwards curve with cofactor
+//! `4`, following [Decaf].
+//!
+//! The `2`-isogeny `φ(s, t) = (2 * s / (1 + a * s²), (1 - a * s²) / t)` maps
+//! the Jacobi quartic `t² = a² * s⁴ + 2 * (a - 2 * d) * s² + 1` onto a
+//! subgroup of index `2` of the curve, which contains the `2`-torsion point
+//! `(0, -1)` and no point at infinity. An element of [`Decaf`] is a coset of
+//! `{(0, 1), (0, -1)}` in that image, represented by either of its points,
+//! and is encoded as the `s` coordinate of one of its preimages by `φ`.
+//!
+//! Unlike [`super::ristretto`], the formulae hold for any `a` and `d`, but
+//! square roots and inversions are computed in variable time.
+//!
+//! [Decaf]: https://eprint.iacr.org/2015/673.pdf
+use super::{
+    Projective, TECurveConfig,
+    affine::Affine,
+    ristretto::{abs, decode_canonical, encode_canonical, is_negative},
+};
+use crate::{
+    arithmetic::bigint::BigInteger,
+    bits::BitIteratorBE,
+    curve::traits::{AffineRepr, PrimeGroup},
+    field::traits::{AdditiveGroup, Field, PrimeField},
+    impl_additive_ops_from_ref,
+};
+use alloc::vec::Vec;
+use core::{
+    borrow::Borrow,
+    fmt::{Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    ops::{AddAssign, Mul, MulAssign, Neg, SubAssign},
+};
+use educe::Educe;
+use num_traits::Zero;
+use zeroize::Zeroize;
+
+/// Constants of the [Decaf] construction over a Twisted Edwards curve.
+///
+/// The curve should have a cofactor of `4`: implementing this trait for any
+/// other curve yields a group which is not prime-order.
+///
+/// [Decaf]: https://eprint.iacr.org/2015/673.pdf
+pub trait DecafConfig: TECurveConfig<BaseField: PrimeField> {
+    /// Non-square `Z` of the Elligator 2 map of
+    /// [`Decaf::from_uniform_bytes`].
+    const ZETA: Self::BaseField;
+}
+
+/// Element of the prime-order group of the curve described by `P`.
+#[derive(Educe)]
+#[educe(Copy, Clone)]
+#[must_use]
+pub struct Decaf<P: DecafConfig>(Projective<P>);
+
+impl<P: DecafConfig> Decaf<P> {
+    /// Length in bytes of an encoded element.
+    pub const ENCODED_LEN: usize = P::BaseField::MODULUS_BIT_SIZE.div_ceil(8);
+
+    /// Constructs an element from one of its representatives, without checking
+    /// that `point` is the sum of a point of the prime-order subgroup and a
+    /// `2`-torsion point of the image of `φ`.
+    pub const fn new_unchecked(point: Projective<P>) -> Self {
+        Self(point)
+    }
+
+    /// Returns the `s` coordinate of the preimage `(s, t)` of a representative
+    /// of `self` by `φ` such that `s` and `t / s` are non-negative.
+    ///
+    /// With `P = (x, y)`, the preimages of `P` and `P + (0, -1) = (-x, -y)`
+    /// have `s` coordinates `±s` and `±1 / (a * s)`, where `s` is a root of
+    /// `a * x * s² - 2 * s + x`. Switching from `s` to `1 / (a * s)` negates
+    /// `t / s`, and switching from `s` to `-s` preserves it.
+    fn s(&self) -> P::BaseField {
+        let one = P::BaseField::ONE;
+        let z_inv = self.0.z.inverse().unwrap_or_default();
+        let (x, y) = (self.0.x * z_inv, self.0.y * z_inv);
+        let Some(ax_inv) = P::mul_by_a(x).inverse() else {
+            return P::BaseField::ZERO;
+        };
+
+        let root = (one - P::mul_by_a(x.square())).sqrt().unwrap_or_default();
+        let s = (one + root) * ax_inv;
+        let t = (one - P::mul_by_a(s.square())) * y.inverse().unwrap_or_default();
+        let s_inv = s.inverse().unwrap_or_default();
+        let other_s = P::mul_by_a(s).inverse().unwrap_or_default();
+        abs(s.ct_select(&other_s, is_negative(&(t * s_inv))))
+    }
+
+    /// Encodes `self` as the little-endian bytes of its canonical field
+    /// element.
+    #[must_use]
+    pub fn encode(&self) -> Vec<u8> {
+        encode_canonical(self.s(), Self::ENCODED_LEN)
+    }
+
+    /// Decodes an element from its canonical encoding.
+    ///
+    /// Returns `None` if `bytes` is not the output of [`Self::encode`] for
+    /// any element.
+    #[must_use]
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        let s: P::BaseField = decode_canonical(bytes, Self::ENCODED_LEN)?;
+        if is_negative(&s) {
+            return None;
+        }
+
+        let one = P::BaseField::ONE;
+        let ss = s.square();
+        let a_ss = P::mul_by_a(ss);
+        let t = (a_ss.square() + (P::COEFF_A - P::COEFF_D.double()).double() * ss + one).sqrt()?;
+        let t = t.ct_select(&-t, is_negative(&(t * s.inverse().unwrap_or_default())));
+
+        // `φ(s, t)`, with `Z = (1 + a * s²) * t`.
+        let den = one + a_ss;
+        let num = one - a_ss;
+        let z = den * t;
+        if z.is_zero() {
+            return None;
+        }
+        Some(Self(Projective::new_unchecked(s.double() * t, num * den, s.double() * num, z)))
+    }
+
+    /// Maps a field element to the image of `φ`, with the Elligator 2 map of
+    /// [RFC 9380, section 6.7.1] to the Montgomery form of the curve, whose
+    /// output is doubled.
+    ///
+    /// The root `v` is picked non-negative, and the exceptional points of the
+    /// birational map to the curve are sent to the identity.
+    ///
+    /// [RFC 9380, section 6.7.1]: https://datatracker.ietf.org/doc/html/rfc9380#section-6.7.1
+    fn map(u: P::BaseField) -> Projective<P> {
+        let one = P::BaseField::ONE;
+        let (mont_a, mont_b) = P::montgomery_coefficients();
+        let mont_b_inv = mont_b.inverse().expect("`B` should be nonzero");
+
+        // Right-hand side of `v² = (w³ + A * w² + w) / B`.
+        let g = |w: P::BaseField| ((w + mont_a) * w + one) * w * mont_b_inv;
+
+        let w1 = (one + P::ZETA * u.square()).inverse().map_or(-mont_a, |den| -mont_a * den);
+        let w = if g(w1).is_square() { w1 } else { -w1 - mont_a };
+        let v = abs(g(w).sqrt().expect("`ZETA` should be a non-square"));
+
+        let (Some(v_inv), Some(w_plus_one_inv)) = (v.inverse(), (w + one).inverse()) else {
+            return Projective::zero();
+        };
+        Affine::new_unchecked(w * v_inv, (w - one) * w_plus_one_inv).into_group().double()
+    }
+
+    /// Derives an element from `2 * ENCODED_LEN` uniformly random bytes, eg:
+    /// the output of a hash function.
+    ///
+    /// Each half of `bytes` is reduced to the size of the modulus and mapped
+    /// to the group, and the two images are added, so that the result is
+    /// indistinguishable from a uniformly random element.
+    ///
+    /// # Panics
+    ///
+    /// * If `bytes` is not `2 * ENCODED_LEN` bytes long.
+    pub fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), 2 * Self::ENCODED_LEN, "bytes should be twice the encoded length");
+
+        let excess_bits = 8 * Self::ENCODED_LEN - P::BaseField::MODULUS.num_bits();
+        let [r0, r1] = [&bytes[..Self::ENCODED_LEN], &bytes[Self::ENCODED_LEN..]].map(|half| {
+            let mut half = half.to_vec();
+            half[Self::ENCODED_LEN - 1] &= u8::MAX >> excess_bits;
+            P::BaseField::from_le_bytes_mod_order(&half)
+        });
+        Self(Self::map(r0) + Self::map(r1))
+    }
+}
+
+impl<P: DecafConfig> Display for Decaf<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.encode().iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl<P: DecafConfig> Debug for Decaf<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Decaf({self})")
+    }
+}
+
+impl<P: DecafConfig> Eq for Decaf<P> {}
+impl<P: DecafConfig> PartialEq for Decaf<P> {
+    /// Two representatives are equal when `X1 * Y2 = Y1 * X2`, i.e. when
+    /// they differ by a `2`-torsion point.
+    fn eq(&self, other: &Self) -> bool {
+        let (p, q) = (&self.0, &other.0);
+        p.x * q.y == p.y * q.x
+    }
+}
+
+impl<P: DecafConfig> Hash for Decaf<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.encode().hash(state);
+    }
+}
+
+impl<P: DecafConfig> Default for Decaf<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<P: DecafConfig> Zeroize for Decaf<P> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<P: DecafConfig> Zero for Decaf<P> {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    /// Checks whether `self` is a `2`-torsion point, i.e. `X = 0`.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.x.is_zero()
+    }
+}
+
+impl<P: DecafConfig> AdditiveGroup for Decaf<P> {
+    type Scalar = P::ScalarField;
+
+    const ZERO: Self = Self(Projective::ZERO);
+
+    fn double_in_place(&mut self) -> &mut Self {
+        self.0.double_in_place();
+        self
+    }
+}
+
+impl<P: DecafConfig> PrimeGroup for Decaf<P> {
+    type ScalarField = P::ScalarField;
+
+    #[inline]
+    fn generator() -> Self {
+        Self(Projective::generator())
+    }
+
+    #[inline]
+    fn mul_bigint(&self, other: impl BitIteratorBE) -> Self {
+        Self(self.0.mul_bigint(other))
+    }
+
+    #[inline]
+    fn ct_select(&self, other: &Self, choice: bool) -> Self {
+        Self(self.0.ct_select(&other.0, choice))
+    }
+}
+
+impl<P: DecafConfig> Neg for Decaf<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl_additive_ops_from_ref!(Decaf, DecafConfig);
+
+impl<'a, P: DecafConfig> core::ops::Add<&'a Self> for Decaf<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: &'a Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<'a, P: DecafConfig> AddAssign<&'a Self> for Decaf<P> {
+    fn add_assign(&mut self, other: &'a Self) {
+        self.0 += &other.0;
+    }
+}
+
+impl<'a, P: DecafConfig> core::ops::Sub<&'a Self> for Decaf<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, other: &'a Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<'a, P: DecafConfig> SubAssign<&'a Self> for Decaf<P> {
+    fn sub_assign(&mut self, other: &'a Self) {
+        self.0 -= &other.0;
+    }
+}
+
+impl<P: DecafConfig, T: Borrow<P::ScalarField>> MulAssign<T> for Decaf<P> {
+    fn mul_assign(&mut self, other: T) {
+        *self = self.mul_bigint(other.borrow().into_bigint());
+    }
+}
+
+impl<P: DecafConfig, T: Borrow<P::ScalarField>> Mul<T> for Decaf<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, other: T) -> Self {
+        self *= other;
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        UniformRand,
+        curve::{
+            instances::bandersnatch::{
+                BandersnatchConfig, BandersnatchDecaf, EdwardsProjective, Fq, Fr,
+            },
+            traits::CurveConfig,
+        },
+        test_rng,
+    };
+    use proptest::prelude::*;
+
+    #[test]
+    fn bandersnatch_satisfies_assumptions() {
+        assert_eq!(BandersnatchConfig::COFACTOR, &[4]);
+        assert!(!BandersnatchConfig::ZETA.is_square());
+    }
+
+    #[test]
+    fn torsion_is_quotiented_out() {
+        let torsion = EdwardsProjective::new_unchecked(Fq::ZERO, -Fq::ONE, Fq::ZERO, Fq::ONE);
+        let p = EdwardsProjective::generator() * Fr::from(7u8);
+        let expected = BandersnatchDecaf::generator() * Fr::from(7u8);
+        let element = BandersnatchDecaf::new_unchecked(p + torsion);
+        assert_eq!(element, expected);
+        assert_eq!(element.encode(), expected.encode());
+        assert!(BandersnatchDecaf::new_unchecked(torsion).is_zero());
+        assert_ne!(expected, -expected);
+    }
+
+    #[test]
+    fn decodings_are_canonical() {
+        let rng = &mut test_rng();
+        let mut num_valid = 0;
+        for _ in 0..200 {
+            let bytes = Fq::rand(rng).into_bigint().into_bytes_le();
+            let Some(element) = BandersnatchDecaf::decode(&bytes) else {
+                continue;
+            };
+            num_valid += 1;
+            assert_eq!(element.encode(), bytes);
+            assert!(element.mul_bigint(Fr::MODULUS).is_zero());
+        }
+        // A quarter of the field elements are valid encodings.
+        assert!((25..75).contains(&num_valid), "{num_valid}");
+    }
+
+    #[test]
+    fn from_uniform_bytes_lies_in_the_group() {
+        let rng = &mut test_rng();
+        for _ in 0..20 {
+            let bytes: Vec<u8> =
+                (0..2 * BandersnatchDecaf::ENCODED_LEN).map(|_| u8::rand(rng)).collect();
+            let element = BandersnatchDecaf::from_uniform_bytes(&bytes);
+            assert_eq!(BandersnatchDecaf::decode(&element.encode()), Some(element));
+            assert!(element.mul_bigint(Fr::MODULUS).is_zero());
+        }
+    }
+
+    #[test]
+    fn round_trip_and_group_law() {
+        proptest!(|(a: u64, b: u64)| {
+            let g = BandersnatchDecaf::generator();
+            let (a, b) = (Fr::from(a), Fr::from(b));
+            let (p, q) = (g * a, g * b);
+            prop_assert_eq!(BandersnatchDecaf::decode(&p.encode()), Some(p));
+            prop_assert_eq!(p + q, g * (a + b));
+            prop_assert_eq!((p - q).encode(), (g * (a - b)).encode());
+            prop_assert_eq!(p.double(), p + p);
+            prop_assert_eq!(-p + p, BandersnatchDecaf::zero());
+        });
+    }
+}
@@ -0,0 +1,254 @@
+//! This module contains definitions for the [Twisted Edwards model] of the
+//! curve.
+//!
+//! [Twisted Edwards model]: https://www.hyperelliptic.org/EFD/g1p/auto-twisted.html
+pub mod affine;
+pub mod decaf;
+pub mod projective;
+pub mod ristretto;
+use super::{
+    batch_inversion,
+    traits::{AffineRepr, CurveConfig, CurveGroup, PrimeGroup},
+};
+use crate::{
+    bits::BitIteratorBE,
+    field::traits::{AdditiveGroup, Field, PrimeField},
+    impl_additive_ops_from_ref,
+};
+use affine::Affine;
+use num_traits::Zero;
+use projective::Projective;
+
+/// Constants and convenience functions that collectively define the
+/// [Twisted Edwards model] of the curve.
+///
+/// In this model, the curve equation is `a * x² + y² = 1 + d * x² * y²`, for
+/// constants `a` and `d`.
+///
+/// [Twisted Edwards model]: https://www.hyperelliptic.org/EFD/g1p/auto-twisted.html
+pub trait TECurveConfig: CurveConfig {
+    /// Coefficient `a` of the curve equation.
+    const COEFF_A: Self::BaseField;
+    /// Coefficient `d` of the curve equation.
+    const COEFF_D: Self::BaseField;
+    /// Generator of the prime-order subgroup.
+    const GENERATOR: Affine<Self>;
+
+    /// Helper method for computing `elem * Self::COEFF_A`.
+    ///
+    /// The default implementation should be overridden only if
+    /// the product can be computed faster than standard field multiplication
+    /// (eg: via negation if `COEFF_A == -1`).
+    #[inline(always)]
+    fn mul_by_a(elem: Self::BaseField) -> Self::BaseField {
+        elem * Self::COEFF_A
+    }
+
+    /// Coefficients `(A, B)` of the birationally equivalent Montgomery curve
+    /// `B * v² = u³ + A * u² + u`.
+    ///
+    /// Computed as `A = 2 * (a + d) / (a - d)` and `B = 4 / (a - d)`.
+    fn montgomery_coefficients() -> (Self::BaseField, Self::BaseField) {
+        let a_minus_d_inv =
+            (Self::COEFF_A - Self::COEFF_D).inverse().expect("`a` and `d` should be distinct");
+        let coeff_a = (Self::COEFF_A + Self::COEFF_D).double() * a_minus_d_inv;
+        let coeff_b = a_minus_d_inv.double().double();
+        (coeff_a, coeff_b)
+    }
+
+    /// Coefficients `(a, b)` of the short Weierstrass curve
+    /// `y² = x³ + a * x + b` isomorphic to the Montgomery form of this curve.
+    ///
+    /// Computed as `a = (3 - A²) / (3 * B²)` and `b = (2 * A³ - 9 * A) / (27 *
+    /// B³)`, where `(A, B)` are [`Self::montgomery_coefficients`].
+    fn weierstrass_coefficients() -> (Self::BaseField, Self::BaseField) {
+        let (mont_a, mont_b) = Self::montgomery_coefficients();
+        let three = Self::BaseField::from(3u8);
+        let b_squared = mont_b.square();
+        let a_squared = mont_a.square();
+
+        let coeff_a = (three - a_squared) / (three * b_squared);
+        let coeff_b = (a_squared * mont_a).double() - mont_a * Self::BaseField::from(9u8);
+        let coeff_b = coeff_b / (Self::BaseField::from(27u8) * b_squared * mont_b);
+        (coeff_a, coeff_b)
+    }
+
+    /// Check if the provided curve point is in the prime-order subgroup.
+    ///
+    /// The default implementation multiplies `item` by the order `r` of the
+    /// prime-order subgroup, and checks if the result is zero. If the
+    /// curve's cofactor is one, this check automatically returns true.
+    /// Implementors can choose to override this default impl
+    /// if the given curve has faster methods
+    /// for performing this check (for example, via leveraging curve
+    /// isomorphisms).
+    fn is_in_correct_subgroup_assuming_on_curve(item: &Affine<Self>) -> bool {
+        Self::cofactor_is_one()
+            || Self::mul_affine(item, Self::ScalarField::characteristic()).is_zero()
+    }
+
+    /// Performs cofactor clearing.
+    /// The default method is simply to multiply by the cofactor.
+    /// Some curves can implement a more efficient algorithm.
+    fn clear_cofactor(item: &Affine<Self>) -> Affine<Self> {
+        item.mul_by_cofactor()
+    }
+
+    /// Default implementation of group multiplication for projective
+    /// coordinates
+    fn mul_projective(base: &Projective<Self>, scalar: impl BitIteratorBE) -> Projective<Self> {
+        te_double_and_add_projective(base, scalar)
+    }
+
+    /// Default implementation of group multiplication for affine
+    /// coordinates.
+    fn mul_affine(base: &Affine<Self>, scalar: impl BitIteratorBE) -> Projective<Self> {
+        te_double_and_add_affine(base, scalar)
+    }
+}
+
+/// Standard double-and-add method for multiplication by a scalar.
+#[inline(always)]
+pub fn te_double_and_add_affine<P: TECurveConfig>(
+    base: &Affine<P>,
+    scalar: impl BitIteratorBE,
+) -> Projective<P> {
+    let mut res = Projective::zero();
+    for b in scalar.bit_be_trimmed_iter() {
+        res.double_in_place();
+        if b {
+            res += base;
+        }
+    }
+
+    res
+}
+
+/// Standard double-and-add method for multiplication by a scalar.
+#[inline(always)]
+pub fn te_double_and_add_projective<P: TECurveConfig>(
+    base: &Projective<P>,
+    scalar: impl BitIteratorBE,
+) -> Projective<P> {
+    let mut res = Projective::zero();
+    for b in scalar.bit_be_trimmed_iter() {
+        res.double_in_place();
+        if b {
+            res += base;
+        }
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod test {
+    use num_traits::Zero;
+    use proptest::prelude::*;
+
+    use crate::{
+        curve::{
+            instances::{bandersnatch, ed25519},
+            traits::{AffineRepr, CurveGroup, PrimeGroup, check_scalar_mul_variants},
+        },
+        field::traits::{AdditiveGroup, Field, PrimeField},
+    };
+
+    type Ed25519Projective = ed25519::EdwardsProjective;
+    type BandersnatchProjective = bandersnatch::EdwardsProjective;
+
+    #[test]
+    fn identity() {
+        let zero = Ed25519Projective::zero();
+        assert!(zero.is_zero());
+        assert!(zero.into_affine().is_zero());
+        assert_eq!(zero.double(), zero);
+
+        let g = Ed25519Projective::generator();
+        assert_eq!(g + zero, g);
+        assert_eq!(g - g, zero);
+        assert_eq!(g + (-g.into_affine()), zero);
+    }
+
+    #[test]
+    fn point_of_order_two() {
+        // `(0, -1)` is on every twisted Edwards curve and has order two.
+        let p = ed25519::EdwardsAffine::new_unchecked(ed25519::Fq::ZERO, -ed25519::Fq::ONE);
+        assert!(p.is_on_curve());
+        assert!(!p.is_zero());
+        assert!(p.into_group().double().is_zero());
+        assert!(!p.is_in_correct_subgroup_assuming_on_curve());
+    }
+
+    #[test]
+    fn add_and_double_agree() {
+        proptest!(|(a: u64, b: u64)| {
+            let g = Ed25519Projective::generator();
+            let (p, q) = (g * ed25519::Fr::from(a), g * ed25519::Fr::from(b));
+            prop_assert_eq!(p + p, p.double());
+            prop_assert_eq!(p + q, q + p);
+            prop_assert_eq!(p + q.into_affine(), p + q);
+            prop_assert_eq!((p + q) - q, p);
+        });
+    }
+
+    #[test]
+    fn scalar_mul_distributes() {
+        proptest!(|(a: u64, b: u64)| {
+            let g = BandersnatchProjective::generator();
+            let (a, b) = (bandersnatch::Fr::from(a), bandersnatch::Fr::from(b));
+            prop_assert_eq!(g * (a + b), g * a + g * b);
+            prop_assert_eq!(g * (a * b), (g * a) * b);
+            prop_assert_eq!((g * a).into_affine(), g.into_affine() * a);
+        });
+    }
+
+    #[test]
+    fn normalize_batch() {
+        let g = Ed25519Projective::generator();
+        let points = [Ed25519Projective::zero(), g, g.double(), g.double() + g];
+        let expected: Vec<_> = points.iter().map(|p| p.into_affine()).collect();
+        assert_eq!(Ed25519Projective::normalize_batch(&points), expected);
+    }
+
+    #[test]
+    fn montgomery_round_trip() {
+        let (mont_a, mont_b) =
+            <ed25519::Ed25519Config as super::TECurveConfig>::montgomery_coefficients();
+        // Ed25519 is birationally equivalent to `-486664 * v² = u³ + 486662 * u² + u`.
+        assert_eq!(mont_a, ed25519::Fq::from(486662u32));
+        assert_eq!(mont_b, -ed25519::Fq::from(486664u32));
+
+        let mut p = Ed25519Projective::generator();
+        for _ in 0..8 {
+            let affine = p.into_affine();
+            let (u, v) = affine.to_montgomery().unwrap();
+            assert_eq!(mont_b * v.square(), u.square() * u + mont_a * u.square() + u);
+            assert_eq!(ed25519::EdwardsAffine::from_montgomery(u, v), Some(affine));
+            p.double_in_place();
+        }
+        assert!(ed25519::EdwardsAffine::identity().to_montgomery().is_none());
+    }
+
+    #[test]
+    fn weierstrass_round_trip() {
+        let (a, b) =
+            <bandersnatch::BandersnatchConfig as super::TECurveConfig>::weierstrass_coefficients();
+
+        let mut p = BandersnatchProjective::generator();
+        for _ in 0..8 {
+            let affine = p.into_affine();
+            let (x, y) = affine.to_weierstrass().unwrap();
+            assert_eq!(y.square(), x.square() * x + a * x + b);
+            assert_eq!(bandersnatch::EdwardsAffine::from_weierstrass(x, y), Some(affine));
+            p.double_in_place();
+        }
+    }
+
+    #[test]
+    fn scalar_mul_variants() {
+        check_scalar_mul_variants(BandersnatchProjective::generator(), |g, s| {
+            super::te_double_and_add_projective(g, s.into_bigint())
+        });
+    }
+}
@@ -0,0 +1,407 @@
+//! Extended projective coordinates for a point on a Twisted Edwards curve
+//! ([Extended coordinates]).
+//!
+//! [Extended coordinates]: https://www.hyperelliptic.org/EFD/g1p/auto-twisted-extended.html
+use super::{
+    AdditiveGroup, Affine, AffineRepr, CurveGroup, Field, PrimeField, PrimeGroup, TECurveConfig,
+    batch_inversion, impl_additive_ops_from_ref,
+};
+use crate::{bits::BitIteratorBE, curve::scalar_mul::variable_base::VariableBaseMSM};
+use alloc::vec::Vec;
+use core::{
+    borrow::Borrow,
+    fmt::{Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+use educe::Educe;
+use num_traits::{One, Zero};
+use zeroize::Zeroize;
+
+/// Extended coordinates for a point on an elliptic curve in twisted Edwards
+/// form, over the base field `P::BaseField`. This struct implements arithmetic
+/// via the extended twisted Edwards formulae.
+#[derive(Educe)]
+#[educe(Copy, Clone)]
+#[must_use]
+pub struct Projective<P: TECurveConfig> {
+    /// `X / Z` projection of the affine `x`
+    pub x: P::BaseField,
+    /// `Y / Z` projection of the affine `y`
+    pub y: P::BaseField,
+    /// Auxiliary coordinate, such that `T / Z` equals the affine `x * y`
+    pub t: P::BaseField,
+    /// Projective multiplicative inverse. Never `0` for a valid point.
+    pub z: P::BaseField,
+}
+
+impl<P: TECurveConfig> Display for Projective<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", Affine::from(*self))
+    }
+}
+
+impl<P: TECurveConfig> Debug for Projective<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.is_zero() {
+            write!(f, "infinity")
+        } else {
+            write!(f, "({}, {}, {}, {})", self.x, self.y, self.t, self.z)
+        }
+    }
+}
+
+impl<P: TECurveConfig> Eq for Projective<P> {}
+impl<P: TECurveConfig> PartialEq for Projective<P> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_zero() {
+            return other.is_zero();
+        }
+
+        if other.is_zero() {
+            return false;
+        }
+
+        // The points (X, Y, Z) and (X', Y', Z')
+        // are equal when (X * Z') = (X' * Z)
+        // and (Y * Z') = (Y' * Z).
+        (self.x * other.z == other.x * self.z) && (self.y * other.z == other.y * self.z)
+    }
+}
+
+impl<P: TECurveConfig> PartialEq<Affine<P>> for Projective<P> {
+    fn eq(&self, other: &Affine<P>) -> bool {
+        self == &other.into_group()
+    }
+}
+
+impl<P: TECurveConfig> Hash for Projective<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.into_affine().hash(state);
+    }
+}
+
+impl<P: TECurveConfig> Default for Projective<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<P: TECurveConfig> Projective<P> {
+    /// Constructs a new group element without checking whether the coordinates
+    /// specify a point in the subgroup.
+    pub const fn new_unchecked(
+        x: P::BaseField,
+        y: P::BaseField,
+        t: P::BaseField,
+        z: P::BaseField,
+    ) -> Self {
+        Self { x, y, t, z }
+    }
+
+    /// Constructs a new group element in a way while enforcing that points are
+    /// in the prime-order subgroup.
+    ///
+    /// # Panics
+    ///
+    /// * If point is not on curve.
+    /// * If point is not in the prime-order subgroup.
+    pub fn new(x: P::BaseField, y: P::BaseField, t: P::BaseField, z: P::BaseField) -> Self {
+        let p = Self::new_unchecked(x, y, t, z).into_affine();
+        assert!(p.is_on_curve());
+        assert!(p.is_in_correct_subgroup_assuming_on_curve());
+        p.into()
+    }
+}
+
+impl<P: TECurveConfig> Zeroize for Projective<P> {
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+        self.t.zeroize();
+        self.z.zeroize();
+    }
+}
+
+impl<P: TECurveConfig> Zero for Projective<P> {
+    /// Returns the identity `(0, 1)`, which has `X = T = 0` and `Y = Z`.
+    #[inline]
+    fn zero() -> Self {
+        Self::new_unchecked(
+            P::BaseField::zero(),
+            P::BaseField::one(),
+            P::BaseField::zero(),
+            P::BaseField::one(),
+        )
+    }
+
+    /// Checks whether `self.x.is_zero()` and `self.y == self.z`.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.x.is_zero() && self.y == self.z && !self.y.is_zero() && self.t.is_zero()
+    }
+}
+
+impl<P: TECurveConfig> AdditiveGroup for Projective<P> {
+    type Scalar = P::ScalarField;
+
+    const ZERO: Self = Self::new_unchecked(
+        P::BaseField::ZERO,
+        P::BaseField::ONE,
+        P::BaseField::ZERO,
+        P::BaseField::ONE,
+    );
+
+    /// Sets `self = 2 * self`, using the dedicated doubling formulae, which do
+    /// not need the `T` coordinate:
+    ///
+    /// * [dbl-2008-hwcd](https://www.hyperelliptic.org/EFD/g1p/auto-twisted-extended.html#doubling-dbl-2008-hwcd)
+    fn double_in_place(&mut self) -> &mut Self {
+        // A = X1^2
+        let a = self.x.square();
+        // B = Y1^2
+        let b = self.y.square();
+        // C = 2 * Z1^2
+        let c = self.z.square().double();
+        // D = a * A
+        let d = P::mul_by_a(a);
+        // E = (X1 + Y1)^2 - A - B
+        let e = (self.x + self.y).square() - a - b;
+        // G = D + B
+        let g = d + b;
+        // F = G - C
+        let f = g - c;
+        // H = D - B
+        let h = d - b;
+        // X3 = E * F
+        self.x = e * f;
+        // Y3 = G * H
+        self.y = g * h;
+        // T3 = E * H
+        self.t = e * h;
+        // Z3 = F * G
+        self.z = f * g;
+
+        self
+    }
+}
+
+impl<P: TECurveConfig> PrimeGroup for Projective<P> {
+    type ScalarField = P::ScalarField;
+
+    #[inline]
+    fn generator() -> Self {
+        Affine::generator().into()
+    }
+
+    #[inline]
+    fn mul_bigint(&self, other: impl BitIteratorBE) -> Self {
+        P::mul_projective(self, other)
+    }
+
+    #[inline]
+    fn ct_select(&self, other: &Self, choice: bool) -> Self {
+        Self {
+            x: self.x.ct_select(&other.x, choice),
+            y: self.y.ct_select(&other.y, choice),
+            t: self.t.ct_select(&other.t, choice),
+            z: self.z.ct_select(&other.z, choice),
+        }
+    }
+}
+
+impl<P: TECurveConfig> CurveGroup for Projective<P> {
+    type Affine = Affine<P>;
+    type BaseField = P::BaseField;
+    type Config = P;
+    type FullGroup = Affine<P>;
+
+    /// Normalizes a slice of projective elements so that
+    /// conversion to affine is inexpensive.
+    ///
+    /// In more detail, this method converts a curve point in extended
+    /// coordinates (x, y, t, z) into an equivalent representation (x/z, y/z).
+    ///
+    /// For `N = v.len()`, this costs 1 inversion + 5N field multiplications.
+    #[inline]
+    fn normalize_batch(v: &[Self]) -> Vec<Self::Affine> {
+        let mut z_s = v.iter().map(|g| g.z).collect::<Vec<_>>();
+
+        batch_inversion(&mut z_s);
+
+        // Perform affine transformations
+        v.iter()
+            .zip(z_s)
+            .map(|(g, z)| {
+                if g.is_zero() {
+                    Affine::identity()
+                } else {
+                    Affine::new_unchecked(g.x * z, g.y * z)
+                }
+            })
+            .collect()
+    }
+}
+
+impl<P: TECurveConfig> Neg for Projective<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self {
+        self.x = -self.x;
+        self.t = -self.t;
+        self
+    }
+}
+
+impl<P: TECurveConfig, T: Borrow<Affine<P>>> AddAssign<T> for Projective<P> {
+    /// Using <https://www.hyperelliptic.org/EFD/g1p/auto-twisted-extended.html#addition-madd-2008-hwcd>
+    fn add_assign(&mut self, other: T) {
+        let other = other.borrow();
+
+        // A = X1*X2
+        let a = self.x * other.x;
+        // B = Y1*Y2
+        let b = self.y * other.y;
+        // C = T1*d*T2
+        let c = P::COEFF_D * self.t * other.x * other.y;
+        // D = Z1
+        let d = self.z;
+        // E = (X1+Y1)*(X2+Y2)-A-B
+        let e = (self.x + self.y) * (other.x + other.y) - a - b;
+        // F = D-C
+        let f = d - c;
+        // G = D+C
+        let g = d + c;
+        // H = B-a*A
+        let h = b - P::mul_by_a(a);
+        // X3 = E*F
+        self.x = e * f;
+        // Y3 = G*H
+        self.y = g * h;
+        // T3 = E*H
+        self.t = e * h;
+        // Z3 = F*G
+        self.z = f * g;
+    }
+}
+
+impl<P: TECurveConfig, T: Borrow<Affine<P>>> Add<T> for Projective<P> {
+    type Output = Self;
+
+    fn add(mut self, other: T) -> Self {
+        let other = other.borrow();
+        self += other;
+        self
+    }
+}
+
+impl<P: TECurveConfig, T: Borrow<Affine<P>>> SubAssign<T> for Projective<P> {
+    fn sub_assign(&mut self, other: T) {
+        *self += -(*other.borrow());
+    }
+}
+
+impl<P: TECurveConfig, T: Borrow<Affine<P>>> Sub<T> for Projective<P> {
+    type Output = Self;
+
+    fn sub(mut self, other: T) -> Self {
+        self -= other.borrow();
+        self
+    }
+}
+
+impl_additive_ops_from_ref!(Projective, TECurveConfig);
+
+impl<'a, P: TECurveConfig> Add<&'a Self> for Projective<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: &'a Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<'a, P: TECurveConfig> AddAssign<&'a Self> for Projective<P> {
+    /// Using <https://www.hyperelliptic.org/EFD/g1p/auto-twisted-extended.html#addition-add-2008-hwcd>
+    ///
+    /// These formulae are unified, and complete whenever `a` is a square and
+    /// `d` is a non-square in the base field.
+    fn add_assign(&mut self, other: &'a Self) {
+        // A = X1*X2
+        let a = self.x * other.x;
+        // B = Y1*Y2
+        let b = self.y * other.y;
+        // C = T1*d*T2
+        let c = P::COEFF_D * self.t * other.t;
+        // D = Z1*Z2
+        let d = self.z * other.z;
+        // E = (X1+Y1)*(X2+Y2)-A-B
+        let e = (self.x + self.y) * (other.x + other.y) - a - b;
+        // F = D-C
+        let f = d - c;
+        // G = D+C
+        let g = d + c;
+        // H = B-a*A
+        let h = b - P::mul_by_a(a);
+        // X3 = E*F
+        self.x = e * f;
+        // Y3 = G*H
+        self.y = g * h;
+        // T3 = E*H
+        self.t = e * h;
+        // Z3 = F*G
+        self.z = f * g;
+    }
+}
+
+impl<'a, P: TECurveConfig> Sub<&'a Self> for Projective<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, other: &'a Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<'a, P: TECurveConfig> SubAssign<&'a Self> for Projective<P> {
+    fn sub_assign(&mut self, other: &'a Self) {
+        *self += &(-(*other));
+    }
+}
+
+impl<P: TECurveConfig, T: Borrow<P::ScalarField>> MulAssign<T> for Projective<P> {
+    fn mul_assign(&mut self, other: T) {
+        *self = self.mul_bigint(other.borrow().into_bigint());
+    }
+}
+
+impl<P: TECurveConfig, T: Borrow<P::ScalarField>> Mul<T> for Projective<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, other: T) -> Self {
+        self *= other;
+        self
+    }
+}
+
+// The affine point x, y is represented in the extended
+// coordinates with Z = 1 and T = x * y.
+impl<P: TECurveConfig> From<Affine<P>> for Projective<P> {
+    #[inline]
+    fn from(p: Affine<P>) -> Projective<P> {
+        Self::new_unchecked(p.x, p.y, p.x * p.y, P::BaseField::one())
+    }
+}
+
+impl<P: TECurveConfig, T: Borrow<Affine<P>>> core::iter::Sum<T> for Projective<P> {
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Projective::zero(), |sum, x| sum + x.borrow())
+    }
+}
+
+impl<P: TECurveConfig> VariableBaseMSM for Projective<P> {}
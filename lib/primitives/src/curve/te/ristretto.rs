@@ -0,0 +1,429 @@
+//! Prime-order groups built on top of a Twisted Edwards curve with cofactor
+//! `8`, following [Ristretto], the cofactor-`8` flavour of [Decaf].
+//!
+//! An element of [`Ristretto`] is a coset of the `4`-torsion of the curve,
+//! represented by any of its points. Two representatives are equal when they
+//! differ by a `4`-torsion point, and each coset has a unique canonical
+//! encoding as a single field element.
+//!
+//! Only the curves of [RFC 9496] are supported, i.e. curves with `a = -1`,
+//! a cofactor of `8` and a base field with `p = 5 mod 8`. Curves with
+//! cofactor `4`, eg: Bandersnatch, are covered by [`super::decaf`].
+//!
+//! [Ristretto]: https://ristretto.group
+//! [RFC 9496]: https://datatracker.ietf.org/doc/html/rfc9496
+//! [Decaf]: https://eprint.iacr.org/2015/673.pdf
+use super::{Projective, TECurveConfig};
+use crate::{
+    arithmetic::bigint::BigInteger,
+    bits::BitIteratorBE,
+    curve::traits::PrimeGroup,
+    field::traits::{AdditiveGroup, Field, PrimeField},
+    impl_additive_ops_from_ref,
+};
+use alloc::{vec, vec::Vec};
+use core::{
+    borrow::Borrow,
+    fmt::{Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    ops::{AddAssign, Mul, MulAssign, Neg, SubAssign},
+};
+use educe::Educe;
+use num_traits::Zero;
+use zeroize::Zeroize;
+
+/// Constants of the [Ristretto construction] over a Twisted Edwards curve.
+///
+/// The formulae are only valid for `a = -1`, a cofactor of `8` and a prime
+/// base field with `p = 5 mod 8`, as for Ed25519: implementing this trait for
+/// any other curve yields a group which is neither prime-order nor
+/// canonically encoded.
+///
+/// [Ristretto construction]: https://datatracker.ietf.org/doc/html/rfc9496#section-4.1
+pub trait RistrettoConfig: TECurveConfig<BaseField: PrimeField> {
+    /// A square root of `-1`.
+    const SQRT_M1: Self::BaseField;
+    /// A square root of `a * d - 1`.
+    const SQRT_AD_MINUS_ONE: Self::BaseField;
+    /// `1 / sqrt(a - d)`.
+    const INVSQRT_A_MINUS_D: Self::BaseField;
+    /// `1 - d²`.
+    const ONE_MINUS_D_SQ: Self::BaseField;
+    /// `(d - 1)²`.
+    const D_MINUS_ONE_SQ: Self::BaseField;
+}
+
+/// Element of the prime-order group of the curve described by `P`.
+#[derive(Educe)]
+#[educe(Copy, Clone)]
+#[must_use]
+pub struct Ristretto<P: RistrettoConfig>(Projective<P>);
+
+/// Returns whether `element` is negative, i.e. whether its canonical
+/// representative is odd.
+pub(super) fn is_negative<F: PrimeField>(element: &F) -> bool {
+    element.into_bigint().is_odd()
+}
+
+/// Returns the non-negative one of `element` and `-element`.
+pub(super) fn abs<F: PrimeField>(element: F) -> F {
+    element.ct_select(&-element, is_negative(&element))
+}
+
+/// Returns the field element with little-endian encoding `bytes`, if it is
+/// `len` bytes long and less than the modulus.
+pub(super) fn decode_canonical<F: PrimeField>(bytes: &[u8], len: usize) -> Option<F> {
+    if bytes.len() != len {
+        return None;
+    }
+    let mut repr = vec![0u8; F::BigInt::BITS / 8];
+    repr[..bytes.len()].copy_from_slice(bytes);
+    let repr = F::BigInt::from_bytes_le(&repr);
+    if repr >= F::MODULUS {
+        return None;
+    }
+    Some(F::from_bigint(repr))
+}
+
+/// Returns the little-endian encoding of `element` on `len` bytes.
+pub(super) fn encode_canonical<F: PrimeField>(element: F, len: usize) -> Vec<u8> {
+    let mut bytes = element.into_bigint().into_bytes_le();
+    bytes.truncate(len);
+    bytes
+}
+
+impl<P: RistrettoConfig> Ristretto<P> {
+    /// Length in bytes of an encoded element.
+    pub const ENCODED_LEN: usize = P::BaseField::MODULUS_BIT_SIZE.div_ceil(8);
+
+    /// Constructs an element from one of its representatives, without checking
+    /// that `point` is the sum of a point of the prime-order subgroup and a
+    /// `4`-torsion point.
+    pub const fn new_unchecked(point: Projective<P>) -> Self {
+        Self(point)
+    }
+
+    /// Returns `(true, sqrt(u / v))` if `u / v` is a square, and
+    /// `(false, sqrt(SQRT_M1 * u / v))` otherwise, picking the non-negative
+    /// root.
+    ///
+    /// If `v` is zero, returns `(u == 0, 0)`.
+    ///
+    /// Computed in constant time with a single exponentiation, following
+    /// [RFC 9496, section 4.2].
+    ///
+    /// [RFC 9496, section 4.2]: https://datatracker.ietf.org/doc/html/rfc9496#section-4.2
+    fn sqrt_ratio_m1(u: P::BaseField, v: P::BaseField) -> (bool, P::BaseField) {
+        let v3 = v.square() * v;
+        let v7 = v3.square() * v;
+        // `(p - 5) / 8`, since `p = 5 mod 8`.
+        let exponent = P::BaseField::MODULUS >> 3;
+        let r = (u * v3) * (u * v7).pow(exponent);
+        let check = v * r.square();
+
+        let correct_sign_sqrt = check == u;
+        let flipped_sign_sqrt = check == -u;
+        let flipped_sign_sqrt_i = check == -u * P::SQRT_M1;
+
+        let r = r.ct_select(&(r * P::SQRT_M1), flipped_sign_sqrt | flipped_sign_sqrt_i);
+        (correct_sign_sqrt | flipped_sign_sqrt, abs(r))
+    }
+
+    /// Encodes `self` as the little-endian bytes of its canonical field
+    /// element.
+    #[must_use]
+    pub fn encode(&self) -> Vec<u8> {
+        let Projective { x: x0, y: y0, t: t0, z: z0 } = self.0;
+
+        let u1 = (z0 + y0) * (z0 - y0);
+        let u2 = x0 * y0;
+        let (_, invsqrt) = Self::sqrt_ratio_m1(P::BaseField::ONE, u1 * u2.square());
+        let den1 = invsqrt * u1;
+        let den2 = invsqrt * u2;
+        let z_inv = den1 * den2 * t0;
+
+        // Rotate by the `4`-torsion point if needed, so that `x * y` is
+        // non-negative.
+        let ix0 = x0 * P::SQRT_M1;
+        let iy0 = y0 * P::SQRT_M1;
+        let enchanted_denominator = den1 * P::INVSQRT_A_MINUS_D;
+        let rotate = is_negative(&(t0 * z_inv));
+        let x = x0.ct_select(&iy0, rotate);
+        let mut y = y0.ct_select(&ix0, rotate);
+        let den_inv = den2.ct_select(&enchanted_denominator, rotate);
+
+        // Then negate if needed, so that `x` is non-negative.
+        y = y.ct_select(&-y, is_negative(&(x * z_inv)));
+        let s = abs(den_inv * (z0 - y));
+        encode_canonical(s, Self::ENCODED_LEN)
+    }
+
+    /// Decodes an element from its canonical encoding.
+    ///
+    /// Returns `None` if `bytes` is not the output of [`Self::encode`] for
+    /// any element.
+    #[must_use]
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        let s: P::BaseField = decode_canonical(bytes, Self::ENCODED_LEN)?;
+        if is_negative(&s) {
+            return None;
+        }
+
+        let ss = s.square();
+        let u1 = P::BaseField::ONE - ss;
+        let u2 = P::BaseField::ONE + ss;
+        let u2_sqr = u2.square();
+        let v = -(P::COEFF_D * u1.square()) - u2_sqr;
+        let (was_square, invsqrt) = Self::sqrt_ratio_m1(P::BaseField::ONE, v * u2_sqr);
+
+        let den_x = invsqrt * u2;
+        let den_y = invsqrt * den_x * v;
+        let x = abs((s + s) * den_x);
+        let y = u1 * den_y;
+        let t = x * y;
+
+        if !was_square || is_negative(&t) || y.is_zero() {
+            return None;
+        }
+        Some(Self(Projective::new_unchecked(x, y, t, P::BaseField::ONE)))
+    }
+
+    /// Maps a field element to an element of the group, with the Elligator
+    /// map of [RFC 9496, section 4.3.4].
+    ///
+    /// [RFC 9496, section 4.3.4]: https://datatracker.ietf.org/doc/html/rfc9496#section-4.3.4
+    fn map(t: P::BaseField) -> Self {
+        let one = P::BaseField::ONE;
+        let d = P::COEFF_D;
+
+        let r = P::SQRT_M1 * t.square();
+        let u = (r + one) * P::ONE_MINUS_D_SQ;
+        let v = (-one - r * d) * (r + d);
+        let (was_square, s) = Self::sqrt_ratio_m1(u, v);
+        let s_prime = -abs(s * t);
+        let s = s_prime.ct_select(&s, was_square);
+        let c = r.ct_select(&-one, was_square);
+
+        let n = c * (r - one) * P::D_MINUS_ONE_SQ - v;
+        let w0 = (s + s) * v;
+        let w1 = n * P::SQRT_AD_MINUS_ONE;
+        let w2 = one - s.square();
+        let w3 = one + s.square();
+
+        Self(Projective::new_unchecked(w0 * w3, w2 * w1, w0 * w2, w1 * w3))
+    }
+
+    /// Derives an element from `2 * ENCODED_LEN` uniformly random bytes, eg:
+    /// the output of a hash function.
+    ///
+    /// Each half of `bytes` is reduced to the size of the modulus and mapped
+    /// to the group, and the two images are added, so that the result is
+    /// indistinguishable from a uniformly random element.
+    ///
+    /// # Panics
+    ///
+    /// * If `bytes` is not `2 * ENCODED_LEN` bytes long.
+    pub fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), 2 * Self::ENCODED_LEN, "bytes should be twice the encoded length");
+
+        let excess_bits = 8 * Self::ENCODED_LEN - P::BaseField::MODULUS.num_bits();
+        let [r0, r1] = [&bytes[..Self::ENCODED_LEN], &bytes[Self::ENCODED_LEN..]].map(|half| {
+            let mut half = half.to_vec();
+            half[Self::ENCODED_LEN - 1] &= u8::MAX >> excess_bits;
+            P::BaseField::from_le_bytes_mod_order(&half)
+        });
+        Self::map(r0) + Self::map(r1)
+    }
+}
+
+impl<P: RistrettoConfig> Display for Ristretto<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.encode().iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl<P: RistrettoConfig> Debug for Ristretto<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Ristretto({self})")
+    }
+}
+
+impl<P: RistrettoConfig> Eq for Ristretto<P> {}
+impl<P: RistrettoConfig> PartialEq for Ristretto<P> {
+    /// Two representatives are equal when `X1 * Y2 = Y1 * X2` or
+    /// `Y1 * Y2 = X1 * X2`, i.e. when they differ by a `4`-torsion point.
+    fn eq(&self, other: &Self) -> bool {
+        let (p, q) = (&self.0, &other.0);
+        p.x * q.y == p.y * q.x || p.y * q.y == p.x * q.x
+    }
+}
+
+impl<P: RistrettoConfig> Hash for Ristretto<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.encode().hash(state);
+    }
+}
+
+impl<P: RistrettoConfig> Default for Ristretto<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<P: RistrettoConfig> Zeroize for Ristretto<P> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<P: RistrettoConfig> Zero for Ristretto<P> {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    /// Checks whether `self` is a `4`-torsion point, i.e. `X = 0` or `Y = 0`.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.x.is_zero() || self.0.y.is_zero()
+    }
+}
+
+impl<P: RistrettoConfig> AdditiveGroup for Ristretto<P> {
+    type Scalar = P::ScalarField;
+
+    const ZERO: Self = Self(Projective::ZERO);
+
+    fn double_in_place(&mut self) -> &mut Self {
+        self.0.double_in_place();
+        self
+    }
+}
+
+impl<P: RistrettoConfig> PrimeGroup for Ristretto<P> {
+    type ScalarField = P::ScalarField;
+
+    #[inline]
+    fn generator() -> Self {
+        Self(Projective::generator())
+    }
+
+    #[inline]
+    fn mul_bigint(&self, other: impl BitIteratorBE) -> Self {
+        Self(self.0.mul_bigint(other))
+    }
+
+    #[inline]
+    fn ct_select(&self, other: &Self, choice: bool) -> Self {
+        Self(self.0.ct_select(&other.0, choice))
+    }
+}
+
+impl<P: RistrettoConfig> Neg for Ristretto<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl_additive_ops_from_ref!(Ristretto, RistrettoConfig);
+
+impl<'a, P: RistrettoConfig> core::ops::Add<&'a Self> for Ristretto<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: &'a Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<'a, P: RistrettoConfig> AddAssign<&'a Self> for Ristretto<P> {
+    fn add_assign(&mut self, other: &'a Self) {
+        self.0 += &other.0;
+    }
+}
+
+impl<'a, P: RistrettoConfig> core::ops::Sub<&'a Self> for Ristretto<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, other: &'a Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<'a, P: RistrettoConfig> SubAssign<&'a Self> for Ristretto<P> {
+    fn sub_assign(&mut self, other: &'a Self) {
+        self.0 -= &other.0;
+    }
+}
+
+impl<P: RistrettoConfig, T: Borrow<P::ScalarField>> MulAssign<T> for Ristretto<P> {
+    fn mul_assign(&mut self, other: T) {
+        *self = self.mul_bigint(other.borrow().into_bigint());
+    }
+}
+
+impl<P: RistrettoConfig, T: Borrow<P::ScalarField>> Mul<T> for Ristretto<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, other: T) -> Self {
+        self *= other;
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        UniformRand,
+        curve::{instances::ed25519::Ed25519Config, traits::CurveConfig},
+        test_rng,
+    };
+
+    type Fq = <Ed25519Config as CurveConfig>::BaseField;
+
+    /// Definition of [`Ristretto::sqrt_ratio_m1`] with field inversion and
+    /// square roots.
+    fn sqrt_ratio_m1(u: Fq, v: Fq) -> (bool, Fq) {
+        let Some(v_inv) = v.inverse() else {
+            return (u.is_zero(), Fq::ZERO);
+        };
+        let ratio = u * v_inv;
+        match ratio.sqrt() {
+            Some(root) => (true, abs(root)),
+            None => (false, abs((ratio * Ed25519Config::SQRT_M1).sqrt().unwrap())),
+        }
+    }
+
+    #[test]
+    fn sqrt_ratio_m1_matches_definition() {
+        let rng = &mut test_rng();
+        let mut inputs = vec![(Fq::ZERO, Fq::ZERO), (Fq::ONE, Fq::ZERO), (Fq::ZERO, Fq::ONE)];
+        inputs.extend((0..100).map(|_| (Fq::rand(rng), Fq::rand(rng))));
+        for (u, v) in inputs {
+            assert_eq!(Ristretto::<Ed25519Config>::sqrt_ratio_m1(u, v), sqrt_ratio_m1(u, v));
+        }
+    }
+
+    #[test]
+    fn ed25519_satisfies_assumptions() {
+        let (a, d) = (Ed25519Config::COEFF_A, Ed25519Config::COEFF_D);
+        assert_eq!(a, -Fq::ONE);
+        assert_eq!(Ed25519Config::COFACTOR, &[8]);
+        assert_eq!(Fq::MODULUS.as_ref()[0] % 8, 5);
+
+        assert_eq!(Ed25519Config::SQRT_M1.square(), -Fq::ONE);
+        assert_eq!(Ed25519Config::SQRT_AD_MINUS_ONE.square(), a * d - Fq::ONE);
+        assert_eq!(Ed25519Config::INVSQRT_A_MINUS_D.square() * (a - d), Fq::ONE);
+        assert_eq!(Ed25519Config::ONE_MINUS_D_SQ, Fq::ONE - d.square());
+        assert_eq!(Ed25519Config::D_MINUS_ONE_SQ, (d - Fq::ONE).square());
+    }
+}
@@ -1,6 +1,7 @@
 mod affine;
 mod curve;
 mod group;
+mod pairing;
 
 use crate::{
     bits::BitIteratorBE,
@@ -8,4 +9,7 @@ use crate::{
 };
 pub use affine::AffineRepr;
 pub use curve::CurveConfig;
+#[cfg(test)]
+pub(crate) use group::check_scalar_mul_variants;
 pub use group::{CurveGroup, PrimeGroup};
+pub use pairing::{MillerLoopOutput, Pairing, PairingOutput};
@@ -4,6 +4,7 @@ use core::{
     hash::Hash,
     ops::{Add, Mul, MulAssign, Neg, Sub},
 };
+use fina_serialize::{ArkDeserialize, ArkSerialize};
 use zeroize::Zeroize;
 /// The canonical representation of an elliptic curve group element.
 /// This should represent the affine coordinates of the point corresponding
@@ -23,7 +24,9 @@ pub trait AffineRepr:
     + Debug
     + Display
     + Zeroize
-    + Neg
+    + ArkSerialize
+    + ArkDeserialize
+    + Neg<Output = Self>
     + From<<Self as AffineRepr>::Group>
     + Into<<Self as AffineRepr>::Group>
     + Add<Self, Output = Self::Group>
@@ -73,6 +76,11 @@ pub trait AffineRepr:
     /// Returns the point at infinity.
     fn zero() -> Self;
 
+    /// Returns `other` if `choice` is set, and `self` otherwise, in constant
+    /// time.
+    #[must_use]
+    fn ct_select(&self, other: &Self, choice: bool) -> Self;
+
     /// Is `self` the point at infinity?
     fn is_zero(&self) -> bool {
         self.xy().is_none()
@@ -1,6 +1,17 @@
 use super::{AdditiveGroup, BitIteratorBE, Field, PrimeField};
+use crate::{
+    arithmetic::bigint::BigInteger,
+    bits::{to_signed_window, to_wnaf},
+};
+use alloc::vec::Vec;
 use core::ops::{Add, AddAssign, Sub, SubAssign};
 
+/// Window size of [`PrimeGroup::mul_wnaf`].
+const WNAF_WINDOW: usize = 5;
+
+/// Window size of [`PrimeGroup::mul_fixed_window`].
+const FIXED_WINDOW: usize = 4;
+
 /// Represents (elements of) a group of prime order `r`.
 pub trait PrimeGroup: AdditiveGroup<Scalar = Self::ScalarField> {
     /// The scalar field `F_r`, where `r` is the order of this group.
@@ -28,6 +39,99 @@ pub trait PrimeGroup: AdditiveGroup<Scalar = Self::ScalarField> {
         }
         res
     }
+
+    /// Returns `other` if `choice` is set, and `self` otherwise, in constant
+    /// time.
+    #[must_use]
+    fn ct_select(&self, other: &Self, choice: bool) -> Self;
+
+    /// Computes `scalar * self` from the [width-`w` NAF](to_wnaf) of `scalar`.
+    ///
+    /// This is faster than double-and-add, but runs in variable time, and
+    /// should only be used with public scalars.
+    #[must_use]
+    fn mul_wnaf(&self, scalar: &Self::ScalarField) -> Self {
+        // Odd multiples `P, 3P, ..., (2^(w - 1) - 1)P`.
+        let double = self.double();
+        let mut table = Vec::with_capacity(1 << (WNAF_WINDOW - 2));
+        table.push(*self);
+        for i in 1..1 << (WNAF_WINDOW - 2) {
+            table.push(table[i - 1] + double);
+        }
+
+        let mut res = Self::zero();
+        for digit in to_wnaf(scalar.into_bigint(), WNAF_WINDOW).into_iter().rev() {
+            res.double_in_place();
+            let index = (digit.unsigned_abs() / 2) as usize;
+            match digit.signum() {
+                1 => res += &table[index],
+                -1 => res -= &table[index],
+                _ => {},
+            }
+        }
+        res
+    }
+
+    /// Computes `scalar * self` in constant time, with a fixed window over
+    /// the [signed digits](to_signed_window) of `scalar`.
+    ///
+    /// Every scalar goes through the same sequence of table scans, doublings
+    /// and additions, up to the exceptional cases of incomplete addition
+    /// formulae, which are only reached with negligible probability.
+    #[must_use]
+    fn mul_fixed_window(&self, scalar: &Self::ScalarField) -> Self {
+        // Odd multiples `P, 3P, ..., (2^w - 1)P`.
+        let double = self.double();
+        let mut table = Vec::with_capacity(1 << (FIXED_WINDOW - 1));
+        table.push(*self);
+        for i in 1..1 << (FIXED_WINDOW - 1) {
+            table.push(table[i - 1] + double);
+        }
+        let lookup = |digit: i64| {
+            let index = (digit.unsigned_abs() / 2) as usize;
+            let entry = table
+                .iter()
+                .enumerate()
+                .fold(table[0], |acc, (j, entry)| acc.ct_select(entry, j == index));
+            entry.ct_select(&-entry, digit < 0)
+        };
+
+        // The digits represent `scalar | 1`, so even scalars are corrected by
+        // subtracting `self` at the end.
+        let bigint = scalar.into_bigint();
+        let num_bits = Self::ScalarField::MODULUS.num_bits();
+        let mut digits = to_signed_window(bigint, FIXED_WINDOW, num_bits).into_iter().rev();
+
+        let mut res = lookup(digits.next().expect("there should be at least one digit"));
+        for digit in digits {
+            for _ in 0..FIXED_WINDOW {
+                res.double_in_place();
+            }
+            res += lookup(digit);
+        }
+        res.ct_select(&(res - self), bigint.is_even())
+    }
+
+    /// Computes `scalar * self` in constant time, with a Montgomery ladder
+    /// over every bit of the modulus size.
+    ///
+    /// Every scalar goes through the same sequence of conditional swaps,
+    /// additions and doublings, up to the exceptional cases of incomplete
+    /// addition formulae, eg: while the accumulator is still the identity.
+    #[must_use]
+    fn mul_ladder(&self, scalar: &Self::ScalarField) -> Self {
+        let bigint = scalar.into_bigint();
+        let (mut r0, mut r1) = (Self::zero(), *self);
+        for i in (0..Self::ScalarField::MODULUS.num_bits()).rev() {
+            // Invariant: `r1 = r0 + self`.
+            let bit = bigint.get_bit(i);
+            (r0, r1) = (r0.ct_select(&r1, bit), r1.ct_select(&r0, bit));
+            r1 += r0;
+            r0.double_in_place();
+            (r0, r1) = (r0.ct_select(&r1, bit), r1.ct_select(&r0, bit));
+        }
+        r0
+    }
 }
 
 /// An opaque representation of an elliptic curve group element that is suitable
@@ -73,3 +177,31 @@ pub trait CurveGroup:
         self.into()
     }
 }
+
+/// Checks [`PrimeGroup::mul_wnaf`], [`PrimeGroup::mul_fixed_window`] and
+/// [`PrimeGroup::mul_ladder`] against the double-and-add `reference`, on
+/// edge cases and random scalars.
+#[cfg(test)]
+pub(crate) fn check_scalar_mul_variants<G: PrimeGroup>(
+    g: G,
+    reference: impl Fn(&G, &G::ScalarField) -> G,
+) {
+    use proptest::prelude::*;
+
+    let one = G::ScalarField::ONE;
+    let two = G::ScalarField::from(2u8);
+    for s in [G::ScalarField::ZERO, one, -one, two, -two] {
+        let expected = reference(&g, &s);
+        assert_eq!(g.mul_wnaf(&s), expected);
+        assert_eq!(g.mul_fixed_window(&s), expected);
+        assert_eq!(g.mul_ladder(&s), expected);
+    }
+    proptest!(|(a: u64, b: u64)| {
+        let b = G::ScalarField::from(b);
+        let s = G::ScalarField::from(a) * b - b;
+        let expected = reference(&g, &s);
+        prop_assert_eq!(g.mul_wnaf(&s), expected);
+        prop_assert_eq!(g.mul_fixed_window(&s), expected);
+        prop_assert_eq!(g.mul_ladder(&s), expected);
+    });
+}
@@ -0,0 +1,230 @@
+use super::{AdditiveGroup, AffineRepr, BitIteratorBE, CurveGroup, Field, PrimeField, PrimeGroup};
+use crate::impl_additive_ops_from_ref;
+use core::{
+    borrow::Borrow,
+    fmt::{Debug, Display, Formatter},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+use educe::Educe;
+use num_traits::{One, Zero};
+use zeroize::Zeroize;
+
+/// Collection of types (mainly fields and curves) that together describe
+/// how to compute a bilinear pairing `e: G1 × G2 → Gt` over a
+/// pairing-friendly curve.
+pub trait Pairing: Sized + 'static + Copy + Debug + Sync + Send + Eq {
+    /// The base field that hosts G1.
+    type BaseField: PrimeField;
+
+    /// The scalar field of the pairing groups.
+    type ScalarField: PrimeField;
+
+    /// An element in G1.
+    type G1: CurveGroup<ScalarField = Self::ScalarField, Affine = Self::G1Affine>;
+
+    /// The affine representation of an element in G1.
+    type G1Affine: AffineRepr<Group = Self::G1, ScalarField = Self::ScalarField>;
+
+    /// A G1 element that has been preprocessed for use in a pairing.
+    type G1Prepared: Default + Clone + Send + Sync + Debug + From<Self::G1> + From<Self::G1Affine>;
+
+    /// An element of G2.
+    type G2: CurveGroup<ScalarField = Self::ScalarField, Affine = Self::G2Affine>;
+
+    /// The affine representation of an element in G2.
+    type G2Affine: AffineRepr<Group = Self::G2, ScalarField = Self::ScalarField>;
+
+    /// A G2 element that has been preprocessed for use in a pairing, eg: the
+    /// coefficients of the lines of its Miller loop.
+    type G2Prepared: Default + Clone + Send + Sync + Debug + From<Self::G2> + From<Self::G2Affine>;
+
+    /// The extension field that hosts the target group of the pairing.
+    type TargetField: Field;
+
+    /// Computes the product of Miller loops for some number of `(G1, G2)`
+    /// pairs.
+    ///
+    /// Pairs with an element at infinity are skipped.
+    fn multi_miller_loop(
+        a: impl IntoIterator<Item = impl Into<Self::G1Prepared>>,
+        b: impl IntoIterator<Item = impl Into<Self::G2Prepared>>,
+    ) -> MillerLoopOutput<Self>;
+
+    /// Computes the Miller loop over `a` and `b`.
+    fn miller_loop(
+        a: impl Into<Self::G1Prepared>,
+        b: impl Into<Self::G2Prepared>,
+    ) -> MillerLoopOutput<Self> {
+        Self::multi_miller_loop([a], [b])
+    }
+
+    /// Performs final exponentiation of the result of a
+    /// [`Self::multi_miller_loop`].
+    ///
+    /// Returns `None` if the output of the Miller loop is zero.
+    fn final_exponentiation(mlo: MillerLoopOutput<Self>) -> Option<PairingOutput<Self>>;
+
+    /// Computes the "sum" `e(a_1, b_1) + ... + e(a_n, b_n)` of pairings, with
+    /// a single final exponentiation.
+    fn multi_pairing(
+        a: impl IntoIterator<Item = impl Into<Self::G1Prepared>>,
+        b: impl IntoIterator<Item = impl Into<Self::G2Prepared>>,
+    ) -> PairingOutput<Self> {
+        Self::final_exponentiation(Self::multi_miller_loop(a, b))
+            .expect("output of the Miller loop should be non-zero")
+    }
+
+    /// Computes the pairing `e(p, q)`.
+    fn pairing(
+        p: impl Into<Self::G1Prepared>,
+        q: impl Into<Self::G2Prepared>,
+    ) -> PairingOutput<Self> {
+        Self::multi_pairing([p], [q])
+    }
+}
+
+/// Represents the target group of a pairing. This struct is a wrapper around
+/// the field that the target group is embedded in.
+///
+/// Although the target group is a multiplicative subgroup of
+/// [`Pairing::TargetField`], it is written additively, like G1 and G2: the
+/// identity is `ONE`, "addition" is field multiplication and "scalar
+/// multiplication" is exponentiation.
+#[derive(Educe)]
+#[educe(Copy, Clone, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct PairingOutput<P: Pairing>(pub P::TargetField);
+
+/// Represents the output of the Miller loop of the pairing.
+#[derive(Educe)]
+#[educe(Debug, Copy, Clone, PartialEq, Eq)]
+#[must_use]
+pub struct MillerLoopOutput<P: Pairing>(pub P::TargetField);
+
+impl<P: Pairing> Default for PairingOutput<P> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<P: Pairing> Debug for PairingOutput<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<P: Pairing> Display for PairingOutput<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<P: Pairing> Zero for PairingOutput<P> {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_one()
+    }
+}
+
+impl<P: Pairing> Zeroize for PairingOutput<P> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<P: Pairing> Neg for PairingOutput<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(self.0.inverse().expect("elements of the target group should be non-zero"))
+    }
+}
+
+impl<P: Pairing> Add<&Self> for PairingOutput<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: &Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<P: Pairing> Sub<&Self> for PairingOutput<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, other: &Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<P: Pairing> AddAssign<&Self> for PairingOutput<P> {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        self.0 *= &other.0;
+    }
+}
+
+impl<P: Pairing> SubAssign<&Self> for PairingOutput<P> {
+    #[allow(clippy::suspicious_op_assign_impl)]
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        self.0 *= &(-*other).0;
+    }
+}
+
+impl_additive_ops_from_ref!(PairingOutput, Pairing);
+
+impl<P: Pairing, T: Borrow<P::ScalarField>> MulAssign<T> for PairingOutput<P> {
+    #[inline]
+    fn mul_assign(&mut self, other: T) {
+        *self = self.mul_bigint(other.borrow().into_bigint());
+    }
+}
+
+impl<P: Pairing, T: Borrow<P::ScalarField>> Mul<T> for PairingOutput<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, other: T) -> Self {
+        self *= other;
+        self
+    }
+}
+
+impl<P: Pairing> AdditiveGroup for PairingOutput<P> {
+    type Scalar = P::ScalarField;
+
+    const ZERO: Self = Self(P::TargetField::ONE);
+
+    #[inline]
+    fn double_in_place(&mut self) -> &mut Self {
+        self.0.square_in_place();
+        self
+    }
+}
+
+impl<P: Pairing> PrimeGroup for PairingOutput<P> {
+    type ScalarField = P::ScalarField;
+
+    fn generator() -> Self {
+        P::pairing(P::G1Affine::generator(), P::G2Affine::generator())
+    }
+
+    #[inline]
+    fn mul_bigint(&self, other: impl BitIteratorBE) -> Self {
+        Self(self.0.pow(other))
+    }
+
+    #[inline]
+    fn ct_select(&self, other: &Self, choice: bool) -> Self {
+        Self(self.0.ct_select(&other.0, choice))
+    }
+}
@@ -0,0 +1,567 @@
+//! This module contains the implementation of a cubic extension
+//! `F[v] / (v³ - β)` of a field `F`, with elements [`CubicExtField`].
+//!
+//! Here `β` is a cubic non-residue of `F`, given by
+//! [`CubicExtConfig::NONRESIDUE`].
+//!
+//! Note that implementation of `Ord` for [`CubicExtField`] compares the
+//! coefficient of `v²` first, then the coefficient of `v`, and then the
+//! constant coefficient.
+use super::traits::{AdditiveGroup, Field, PrimeField};
+use crate::{UniformRand, impl_additive_ops_from_ref, impl_multiplicative_ops_from_ref};
+use alloc::{vec, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+use educe::Educe;
+use fina_common::io::{Read, Write};
+use fina_serialize::{ArkDeserialize, ArkSerialize, Compress, SerializationError, Valid, Validate};
+use num_traits::{One, Zero};
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+
+/// Defines a cubic extension `F[v] / (v³ - β)` of the field `F`.
+pub trait CubicExtConfig: 'static + Send + Sync + Sized {
+    /// Base prime field underlying this extension.
+    type BasePrimeField: PrimeField;
+
+    /// Base field `F` of this extension.
+    type BaseField: Field<BasePrimeField = Self::BasePrimeField>;
+
+    /// Type of the coefficients of the Frobenius map.
+    ///
+    /// These are elements of the smallest subfield containing them, which may
+    /// be smaller than [`Self::BaseField`] for towers of extensions.
+    type FrobCoeff: Field;
+
+    /// Degree of this extension over [`Self::BasePrimeField`].
+    const DEGREE_OVER_BASE_PRIME_FIELD: usize;
+
+    /// Cubic non-residue `β` of [`Self::BaseField`] defining the extension.
+    const NONRESIDUE: Self::BaseField;
+
+    /// Coefficients of `v` in the Frobenius map, where
+    /// `FROBENIUS_COEFF_C1[i]` is `β^((q^i - 1) / 3)`, with `q` the
+    /// characteristic.
+    const FROBENIUS_COEFF_C1: &'static [Self::FrobCoeff];
+
+    /// Coefficients of `v²` in the Frobenius map, where
+    /// `FROBENIUS_COEFF_C2[i]` is `β^((2q^i - 2) / 3)`, with `q` the
+    /// characteristic.
+    const FROBENIUS_COEFF_C2: &'static [Self::FrobCoeff];
+
+    /// Helper method for computing `fe * Self::NONRESIDUE`.
+    ///
+    /// The default implementation should be overridden only if the product
+    /// can be computed faster than standard field multiplication.
+    #[inline(always)]
+    fn mul_base_field_by_nonresidue_in_place(fe: &mut Self::BaseField) -> &mut Self::BaseField {
+        *fe *= &Self::NONRESIDUE;
+        fe
+    }
+
+    /// Multiplies `c1` and `c2` by the Frobenius coefficients of the
+    /// `power`-th Frobenius map.
+    fn mul_base_field_by_frob_coeff(
+        c1: &mut Self::BaseField,
+        c2: &mut Self::BaseField,
+        power: usize,
+    );
+}
+
+/// An element `c0 + c1 * v + c2 * v²` of the cubic extension defined by `P`.
+#[derive(Educe)]
+#[educe(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CubicExtField<P: CubicExtConfig> {
+    /// Constant coefficient.
+    pub c0: P::BaseField,
+    /// Coefficient of `v`.
+    pub c1: P::BaseField,
+    /// Coefficient of `v²`.
+    pub c2: P::BaseField,
+}
+
+impl<P: CubicExtConfig> CubicExtField<P> {
+    /// Creates a new field element `c0 + c1 * v + c2 * v²`.
+    pub const fn new(c0: P::BaseField, c1: P::BaseField, c2: P::BaseField) -> Self {
+        Self { c0, c1, c2 }
+    }
+
+    /// Returns the norm of `self` over the base field, i.e. the product of
+    /// its conjugates.
+    #[must_use]
+    pub fn norm(&self) -> P::BaseField {
+        let (s0, s1, s2) = self.adjugate();
+        self.norm_with_adjugate(s0, s1, s2)
+    }
+
+    /// Multiplies `self` by an element of the base field.
+    pub fn mul_assign_by_base_field(&mut self, value: &P::BaseField) {
+        self.c0 *= value;
+        self.c1 *= value;
+        self.c2 *= value;
+    }
+
+    /// Returns the coefficients of `norm(self) / self`.
+    fn adjugate(&self) -> (P::BaseField, P::BaseField, P::BaseField) {
+        let mul_by_nonresidue = |mut fe: P::BaseField| {
+            P::mul_base_field_by_nonresidue_in_place(&mut fe);
+            fe
+        };
+        let s0 = self.c0.square() - mul_by_nonresidue(self.c1 * self.c2);
+        let s1 = mul_by_nonresidue(self.c2.square()) - self.c0 * self.c1;
+        let s2 = self.c1.square() - self.c0 * self.c2;
+        (s0, s1, s2)
+    }
+
+    /// Returns the norm of `self`, from the coefficients of its adjugate.
+    fn norm_with_adjugate(
+        &self,
+        s0: P::BaseField,
+        s1: P::BaseField,
+        s2: P::BaseField,
+    ) -> P::BaseField {
+        let mut t = self.c2 * s1 + self.c1 * s2;
+        P::mul_base_field_by_nonresidue_in_place(&mut t);
+        self.c0 * s0 + t
+    }
+}
+
+impl<P: CubicExtConfig> Zero for CubicExtField<P> {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero() && self.c2.is_zero()
+    }
+}
+
+impl<P: CubicExtConfig> One for CubicExtField<P> {
+    #[inline]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        self.c0.is_one() && self.c1.is_zero() && self.c2.is_zero()
+    }
+}
+
+impl<P: CubicExtConfig> AdditiveGroup for CubicExtField<P> {
+    type Scalar = Self;
+
+    const ZERO: Self = Self::new(P::BaseField::ZERO, P::BaseField::ZERO, P::BaseField::ZERO);
+
+    #[inline]
+    fn double_in_place(&mut self) -> &mut Self {
+        self.c0.double_in_place();
+        self.c1.double_in_place();
+        self.c2.double_in_place();
+        self
+    }
+
+    #[inline]
+    fn neg_in_place(&mut self) -> &mut Self {
+        self.c0.neg_in_place();
+        self.c1.neg_in_place();
+        self.c2.neg_in_place();
+        self
+    }
+}
+
+impl<P: CubicExtConfig> Field for CubicExtField<P> {
+    type BasePrimeField = P::BasePrimeField;
+
+    const ONE: Self = Self::new(P::BaseField::ONE, P::BaseField::ZERO, P::BaseField::ZERO);
+
+    fn extension_degree() -> usize {
+        P::DEGREE_OVER_BASE_PRIME_FIELD
+    }
+
+    #[inline]
+    fn square(&self) -> Self {
+        let mut temp = *self;
+        temp.square_in_place();
+        temp
+    }
+
+    /// Squaring with the `CH-SQR2` formulae of [Chung and Hasan].
+    ///
+    /// [Chung and Hasan]: https://link.springer.com/chapter/10.1007/978-3-540-74462-7_5
+    fn square_in_place(&mut self) -> &mut Self {
+        let (a, b, c) = (self.c0, self.c1, self.c2);
+        let s0 = a.square();
+        let s1 = (a * b).double();
+        let s2 = (a - b + c).square();
+        let s3 = (b * c).double();
+        let s4 = c.square();
+
+        self.c0 = s3;
+        P::mul_base_field_by_nonresidue_in_place(&mut self.c0);
+        self.c0 += &s0;
+        self.c1 = s4;
+        P::mul_base_field_by_nonresidue_in_place(&mut self.c1);
+        self.c1 += &s1;
+        self.c2 = s1 + s2 + s3 - s0 - s4;
+        self
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        let (s0, s1, s2) = self.adjugate();
+        let norm_inv = self.norm_with_adjugate(s0, s1, s2).inverse()?;
+        Some(Self::new(s0 * norm_inv, s1 * norm_inv, s2 * norm_inv))
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        *self = self.inverse()?;
+        Some(self)
+    }
+
+    #[inline]
+    fn ct_select(&self, other: &Self, choice: bool) -> Self {
+        Self::new(
+            self.c0.ct_select(&other.c0, choice),
+            self.c1.ct_select(&other.c1, choice),
+            self.c2.ct_select(&other.c2, choice),
+        )
+    }
+
+    /// Tonelli-Shanks square root over the whole multiplicative group, in
+    /// variable time.
+    fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(Self::zero());
+        }
+        if !self.is_square() {
+            return None;
+        }
+
+        // The multiplicative group has order `q - 1 = 2^s * t`, with `t` odd.
+        let (two_adicity, trace) = multiplicative_group_order::<Self>();
+
+        // Any non-square generates the 2-Sylow subgroup, raised to `t`.
+        let nonresidue = (1u64..)
+            .map(|k| {
+                let elems = core::iter::once(Self::BasePrimeField::from(k)).chain(
+                    core::iter::repeat_n(Self::BasePrimeField::ONE, Self::extension_degree() - 1),
+                );
+                Self::from_base_prime_field_elems(elems).expect("degree should match")
+            })
+            .find(|candidate| !candidate.is_square())
+            .expect("a non-square should exist");
+
+        // `w = self^((t - 1) / 2)`.
+        let w = self.pow(shr_one(&trace).as_slice());
+        let mut x = w * self;
+        let mut b = x * w;
+        let mut z = nonresidue.pow(trace.as_slice());
+        let mut v = two_adicity;
+
+        // Invariant: `x^2 = self * b`, and `b` has order dividing `2^(v - 1)`.
+        while !b.is_one() {
+            let mut k = 0;
+            let mut b2k = b;
+            while !b2k.is_one() {
+                b2k.square_in_place();
+                k += 1;
+            }
+
+            let mut w = z;
+            for _ in 1..v - k {
+                w.square_in_place();
+            }
+
+            z = w.square();
+            b *= z;
+            x *= w;
+            v = k;
+        }
+
+        Some(x)
+    }
+
+    /// An element is a square if and only if its norm is.
+    fn is_square(&self) -> bool {
+        self.norm().is_square()
+    }
+
+    fn from_base_prime_field_elems(
+        elems: impl IntoIterator<Item = Self::BasePrimeField>,
+    ) -> Option<Self> {
+        let degree = P::BaseField::extension_degree();
+        let mut elems = elems.into_iter();
+        let c0 = P::BaseField::from_base_prime_field_elems(elems.by_ref().take(degree))?;
+        let c1 = P::BaseField::from_base_prime_field_elems(elems.by_ref().take(degree))?;
+        let c2 = P::BaseField::from_base_prime_field_elems(elems.by_ref().take(degree))?;
+        elems.next().is_none().then_some(Self::new(c0, c1, c2))
+    }
+
+    fn to_base_prime_field_elements(&self) -> impl Iterator<Item = Self::BasePrimeField> {
+        self.c0
+            .to_base_prime_field_elements()
+            .chain(self.c1.to_base_prime_field_elements())
+            .chain(self.c2.to_base_prime_field_elements())
+    }
+
+    fn frobenius_map_in_place(&mut self, power: usize) {
+        self.c0.frobenius_map_in_place(power);
+        self.c1.frobenius_map_in_place(power);
+        self.c2.frobenius_map_in_place(power);
+        P::mul_base_field_by_frob_coeff(&mut self.c1, &mut self.c2, power);
+    }
+}
+
+/// Returns `(s, t)` such that the multiplicative group of `F` has order
+/// `2^s * t`, with `t` odd, as little-endian limbs.
+fn multiplicative_group_order<F: Field>() -> (u32, Vec<u64>) {
+    let modulus = F::BasePrimeField::MODULUS;
+    let modulus = modulus.as_ref();
+
+    // `q = p^degree`, by schoolbook multiplication.
+    let mut order = vec![1u64];
+    for _ in 0..F::extension_degree() {
+        let mut product = vec![0u64; order.len() + modulus.len()];
+        for (i, &a) in order.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, &b) in modulus.iter().enumerate() {
+                let t = u128::from(a) * u128::from(b) + u128::from(product[i + j]) + carry;
+                product[i + j] = t as u64;
+                carry = t >> 64;
+            }
+            product[i + modulus.len()] = carry as u64;
+        }
+        order = product;
+    }
+
+    // `q` is odd, so `q - 1` only clears the lowest bit.
+    order[0] -= 1;
+    let two_adicity = order.iter().position(|&limb| limb != 0).expect("order should be non-zero");
+    let mut trace = order.split_off(two_adicity);
+    let shift = trace[0].trailing_zeros();
+    for _ in 0..shift {
+        trace = shr_one(&trace);
+    }
+    let two_adicity = u32::try_from(two_adicity * 64).expect("two-adicity should fit") + shift;
+    (two_adicity, trace)
+}
+
+/// Shifts little-endian `limbs` one bit to the right.
+fn shr_one(limbs: &[u64]) -> Vec<u64> {
+    let mut result: Vec<u64> = limbs.iter().map(|limb| limb >> 1).collect();
+    for (i, limb) in limbs.iter().enumerate().skip(1) {
+        result[i - 1] |= limb << 63;
+    }
+    result
+}
+
+impl<P: CubicExtConfig> Ord for CubicExtField<P> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.c2
+            .cmp(&other.c2)
+            .then_with(|| self.c1.cmp(&other.c1))
+            .then_with(|| self.c0.cmp(&other.c0))
+    }
+}
+
+impl<P: CubicExtConfig> PartialOrd for CubicExtField<P> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: CubicExtConfig> Display for CubicExtField<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "CubicExtField({}, {}, {})", self.c0, self.c1, self.c2)
+    }
+}
+
+impl<P: CubicExtConfig> Neg for CubicExtField<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self {
+        self.neg_in_place();
+        self
+    }
+}
+
+impl<P: CubicExtConfig> Add<&Self> for CubicExtField<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: &Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<P: CubicExtConfig> Sub<&Self> for CubicExtField<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, other: &Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<P: CubicExtConfig> Mul<&Self> for CubicExtField<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, other: &Self) -> Self {
+        self *= other;
+        self
+    }
+}
+
+impl<P: CubicExtConfig> Div<&Self> for CubicExtField<P> {
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(mut self, other: &Self) -> Self {
+        self *= &other.inverse().expect("should not divide by zero");
+        self
+    }
+}
+
+impl<P: CubicExtConfig> AddAssign<&Self> for CubicExtField<P> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        self.c0 += &other.c0;
+        self.c1 += &other.c1;
+        self.c2 += &other.c2;
+    }
+}
+
+impl<P: CubicExtConfig> SubAssign<&Self> for CubicExtField<P> {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        self.c0 -= &other.c0;
+        self.c1 -= &other.c1;
+        self.c2 -= &other.c2;
+    }
+}
+
+impl<P: CubicExtConfig> MulAssign<&Self> for CubicExtField<P> {
+    /// Karatsuba multiplication, with six base field multiplications.
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        let (a, b, c) = (self.c0, self.c1, self.c2);
+        let (d, e, f) = (other.c0, other.c1, other.c2);
+
+        let ad = a * d;
+        let be = b * e;
+        let cf = c * f;
+
+        let mut x = (b + c) * (e + f) - be - cf;
+        let y = (a + b) * (d + e) - ad - be;
+        let z = (a + c) * (d + f) - ad + be - cf;
+
+        P::mul_base_field_by_nonresidue_in_place(&mut x);
+        self.c0 = ad + x;
+        self.c1 = cf;
+        P::mul_base_field_by_nonresidue_in_place(&mut self.c1);
+        self.c1 += &y;
+        self.c2 = z;
+    }
+}
+
+impl<P: CubicExtConfig> DivAssign<&Self> for CubicExtField<P> {
+    #[inline]
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn div_assign(&mut self, other: &Self) {
+        *self *= &other.inverse().expect("should not divide by zero");
+    }
+}
+
+impl_additive_ops_from_ref!(CubicExtField, CubicExtConfig);
+impl_multiplicative_ops_from_ref!(CubicExtField, CubicExtConfig);
+
+macro_rules! impl_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl<P: CubicExtConfig> From<$int> for CubicExtField<P> {
+                fn from(other: $int) -> Self {
+                    Self::new(P::BaseField::from(other), P::BaseField::ZERO, P::BaseField::ZERO)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u128, u64, u32, u16, u8, i128, i64, i32, i16, i8, bool);
+
+impl<P: CubicExtConfig> zeroize::Zeroize for CubicExtField<P> {
+    fn zeroize(&mut self) {
+        self.c0.zeroize();
+        self.c1.zeroize();
+        self.c2.zeroize();
+    }
+}
+
+impl<P: CubicExtConfig> Distribution<CubicExtField<P>> for StandardUniform {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> CubicExtField<P> {
+        CubicExtField::new(
+            P::BaseField::rand(rng),
+            P::BaseField::rand(rng),
+            P::BaseField::rand(rng),
+        )
+    }
+}
+
+impl<P: CubicExtConfig> ArkSerialize for CubicExtField<P> {
+    /// Writes `c0`, `c1` and `c2`, in this order.
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.c0.serialize_with_mode(&mut writer, compress)?;
+        self.c1.serialize_with_mode(&mut writer, compress)?;
+        self.c2.serialize_with_mode(&mut writer, compress)
+    }
+
+    #[inline]
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.c0.serialized_size(compress)
+            + self.c1.serialized_size(compress)
+            + self.c2.serialized_size(compress)
+    }
+}
+
+impl<P: CubicExtConfig> Valid for CubicExtField<P> {
+    #[inline]
+    fn check(&self) -> Result<(), SerializationError> {
+        self.c0.check()?;
+        self.c1.check()?;
+        self.c2.check()
+    }
+}
+
+impl<P: CubicExtConfig> ArkDeserialize for CubicExtField<P> {
+    #[inline]
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let c0 = P::BaseField::deserialize_with_mode(&mut reader, compress, validate)?;
+        let c1 = P::BaseField::deserialize_with_mode(&mut reader, compress, validate)?;
+        let c2 = P::BaseField::deserialize_with_mode(&mut reader, compress, validate)?;
+        Ok(Self::new(c0, c1, c2))
+    }
+}
@@ -31,7 +31,13 @@ use core::{
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 use educe::Educe;
+use fina_common::io::{Read, Write};
+use fina_serialize::{ArkDeserialize, ArkSerialize, Compress, SerializationError, Valid, Validate};
 use num_traits::{One, Zero};
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
 
 /// Represents an element of the prime field `F_p`, where `p == P::MODULUS`.
 ///
@@ -515,6 +521,76 @@ impl<P: FpParams<N>, const N: usize> Field for Fp<P, N> {
             None
         }
     }
+
+    #[inline(always)]
+    fn ct_select(&self, other: &Self, choice: bool) -> Self {
+        Fp::new_unchecked(self.montgomery_form.ct_select(&other.montgomery_form, choice))
+    }
+
+    /// Tonelli-Shanks square root, in variable time.
+    fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(Self::zero());
+        }
+
+        // Since the modulus is odd, `(p - 1) / 2^s = p >> s`, which is the
+        // odd part `t` of the multiplicative group order.
+        let trace = P::MODULUS >> P::TWO_ADICITY;
+
+        // `w = self^((t - 1) / 2)`.
+        let w = self.pow(trace >> 1);
+        let mut x = w * self;
+        let mut b = x * w;
+        let mut z = P::TWO_ADIC_ROOT_OF_UNITY;
+        let mut v = P::TWO_ADICITY;
+
+        // Invariant: `x^2 = self * b`, and `b` has order dividing `2^(v - 1)`
+        // if `self` is a square.
+        while !b.is_one() {
+            let mut k = 0;
+            let mut b2k = b;
+            while !b2k.is_one() {
+                b2k.square_in_place();
+                k += 1;
+                if k == v {
+                    return None;
+                }
+            }
+
+            let mut w = z;
+            for _ in 1..v - k {
+                w.square_in_place();
+            }
+
+            z = w.square();
+            b *= z;
+            x *= w;
+            v = k;
+        }
+
+        Some(x)
+    }
+
+    /// Euler's criterion.
+    fn is_square(&self) -> bool {
+        let legendre = self.pow(P::MODULUS >> 1);
+        legendre.is_zero() || legendre.is_one()
+    }
+
+    fn from_base_prime_field_elems(
+        elems: impl IntoIterator<Item = Self::BasePrimeField>,
+    ) -> Option<Self> {
+        let mut elems = elems.into_iter();
+        let elem = elems.next()?;
+        elems.next().is_none().then_some(elem)
+    }
+
+    fn to_base_prime_field_elements(&self) -> impl Iterator<Item = Self::BasePrimeField> {
+        core::iter::once(*self)
+    }
+
+    #[inline]
+    fn frobenius_map_in_place(&mut self, _power: usize) {}
 }
 
 impl<P: FpParams<N>, const N: usize> PrimeField for Fp<P, N> {
@@ -867,6 +943,73 @@ impl<P: FpParams<N>, const N: usize> zeroize::Zeroize for Fp<P, N> {
     }
 }
 
+impl<P: FpParams<N>, const N: usize> Distribution<Fp<P, N>> for StandardUniform {
+    /// Samples a uniformly random field element by rejection sampling random
+    /// integers, masked to the bit size of the modulus.
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Fp<P, N> {
+        let modulus_bits = P::MODULUS.num_bits();
+        let top_limb = (modulus_bits - 1) / 64;
+        let top_mask = u64::MAX >> (64 * (top_limb + 1) - modulus_bits);
+        loop {
+            let mut limbs = [0u64; N];
+            for limb in limbs.iter_mut().take(top_limb + 1) {
+                *limb = rng.next_u64();
+            }
+            limbs[top_limb] &= top_mask;
+            let candidate = Uint::new(limbs);
+            if candidate < P::MODULUS {
+                return Fp::from_bigint(candidate);
+            }
+        }
+    }
+}
+
+impl<P: FpParams<N>, const N: usize> ArkSerialize for Fp<P, N> {
+    /// Writes the canonical little-endian representation of `self`, in both
+    /// compressed and uncompressed modes.
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        _compress: Compress,
+    ) -> Result<(), SerializationError> {
+        writer.write_all(&self.into_bigint().into_bytes_le())?;
+        Ok(())
+    }
+
+    #[inline]
+    fn serialized_size(&self, _compress: Compress) -> usize {
+        <Uint<N> as BigInteger>::BITS / 8
+    }
+}
+
+impl<P: FpParams<N>, const N: usize> Valid for Fp<P, N> {
+    #[inline]
+    fn check(&self) -> Result<(), SerializationError> {
+        Ok(())
+    }
+}
+
+impl<P: FpParams<N>, const N: usize> ArkDeserialize for Fp<P, N> {
+    /// Reads the canonical little-endian representation of an element,
+    /// rejecting integers not smaller than the modulus.
+    #[inline]
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        _compress: Compress,
+        _validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let mut bytes = alloc::vec![0u8; <Uint<N> as BigInteger>::BITS / 8];
+        reader.read_exact(&mut bytes)?;
+        let repr = Uint::<N>::from_bytes_le(&bytes);
+        if repr.ct_ge(&P::MODULUS) {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(Self::from_bigint(repr))
+    }
+}
+
 impl<P: FpParams<N>, const N: usize> From<Fp<P, N>> for Uint<N> {
     #[inline]
     fn from(fp: Fp<P, N>) -> Self {
@@ -1035,4 +1178,51 @@ mod tests {
             prop_assert_eq!(res, a.rem_euclid(MODULUS));
         });
     }
+
+    #[test]
+    fn sqrt() {
+        proptest!(|(a: i64)| {
+            let a = Field64::from(a);
+            let square = a.square();
+            prop_assert!(square.is_square());
+            let root = square.sqrt().unwrap();
+            prop_assert_eq!(root.square(), square);
+
+            // `-1` is a non-residue, since the modulus is `3 mod 4`.
+            let non_square = -square;
+            prop_assert_eq!(non_square.is_square(), a.is_zero());
+            prop_assert_eq!(non_square.sqrt().is_some(), a.is_zero());
+        });
+    }
+
+    #[test]
+    fn from_bytes_mod_order() {
+        use crate::field::FpBLS12;
+
+        proptest!(|(a: u64, b: u128)| {
+            let be = [[0u8; 8], a.to_be_bytes()].concat();
+            let le = [a.to_le_bytes(), [0u8; 8]].concat();
+            prop_assert_eq!(Field64::from_be_bytes_mod_order(&be), Field64::from(a));
+            prop_assert_eq!(Field64::from_le_bytes_mod_order(&le), Field64::from(a));
+
+            let expected = FpBLS12::from(b & (u128::MAX >> 24));
+            prop_assert_eq!(FpBLS12::from_be_bytes_mod_order(&b.to_be_bytes()[3..]), expected);
+            prop_assert_eq!(FpBLS12::from_le_bytes_mod_order(&b.to_le_bytes()[..13]), expected);
+        });
+    }
+
+    #[test]
+    fn sqrt_high_two_adicity() {
+        use crate::field::FpBLS12;
+
+        proptest!(|(a: u128)| {
+            let a = FpBLS12::from(a);
+            let square = a.square();
+            prop_assert_eq!(square.sqrt().unwrap().square(), square);
+
+            let non_square = square * FpBLS12::GENERATOR;
+            prop_assert_eq!(non_square.is_square(), a.is_zero());
+            prop_assert_eq!(non_square.sqrt().is_some(), a.is_zero());
+        });
+    }
 }
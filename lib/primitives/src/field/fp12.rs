@@ -0,0 +1,258 @@
+//! This module contains the quadratic extension [`Fp12`] of a cubic extension
+//! [`Fp6`], the target field of the pairings of BN and BLS12 curves.
+use super::{
+    fp2::Fp2,
+    fp6::{Fp6, Fp6Config},
+    quadratic_extension::{QuadExtConfig, QuadExtField},
+    traits::{AdditiveGroup, Field},
+};
+use crate::bits::BitIteratorBE;
+use core::marker::PhantomData;
+
+/// Quadratic extension [`Fp2`] underlying the tower of `P`.
+type BaseFp2<P> = Fp2<<<P as Fp12Config>::Fp6Config as Fp6Config>::Fp2Config>;
+
+/// Parameters of a quadratic extension `Fp6[w] / (w² - v)` of [`Fp6`].
+pub trait Fp12Config: 'static + Send + Sync + Sized {
+    /// Parameters of the underlying cubic extension.
+    type Fp6Config: Fp6Config;
+
+    /// Quadratic non-residue `v` of [`Fp6`].
+    const NONRESIDUE: Fp6<Self::Fp6Config> =
+        Fp6::new(<BaseFp2<Self>>::ZERO, <BaseFp2<Self>>::ONE, <BaseFp2<Self>>::ZERO);
+
+    /// Coefficients of the Frobenius map, `ξ^((p^i - 1) / 6)` for
+    /// `i = 0, ..., 11`.
+    const FROBENIUS_COEFF_FP12_C1: &'static [BaseFp2<Self>];
+}
+
+/// Wrapper implementing [`QuadExtConfig`] for a [`Fp12Config`].
+pub struct Fp12ConfigWrapper<P: Fp12Config>(PhantomData<P>);
+
+impl<P: Fp12Config> QuadExtConfig for Fp12ConfigWrapper<P> {
+    type BaseField = Fp6<P::Fp6Config>;
+    type BasePrimeField = <Fp6<P::Fp6Config> as Field>::BasePrimeField;
+    type FrobCoeff = BaseFp2<P>;
+
+    const DEGREE_OVER_BASE_PRIME_FIELD: usize = 12;
+    const FROBENIUS_COEFF_C1: &'static [Self::FrobCoeff] = P::FROBENIUS_COEFF_FP12_C1;
+    const NONRESIDUE: Self::BaseField = P::NONRESIDUE;
+
+    /// Multiplication by `v` rotates the coefficients of `fe`.
+    #[inline(always)]
+    fn mul_base_field_by_nonresidue_in_place(fe: &mut Self::BaseField) -> &mut Self::BaseField {
+        let c1 = fe.c1;
+        fe.c1 = fe.c0;
+        fe.c0 = fe.c2;
+        P::Fp6Config::mul_fp2_by_nonresidue_in_place(&mut fe.c0);
+        fe.c2 = c1;
+        fe
+    }
+
+    fn mul_base_field_by_frob_coeff(fe: &mut Self::BaseField, power: usize) {
+        fe.mul_assign_by_fp2(&Self::FROBENIUS_COEFF_C1[power % Self::DEGREE_OVER_BASE_PRIME_FIELD]);
+    }
+}
+
+/// Quadratic extension of [`Fp6`].
+pub type Fp12<P> = QuadExtField<Fp12ConfigWrapper<P>>;
+
+impl<P: Fp12Config> Fp12<P> {
+    /// Multiplies `self` by the sparse element `c0 + c1 * v + c4 * v * w`.
+    pub fn mul_by_014(&mut self, c0: &BaseFp2<P>, c1: &BaseFp2<P>, c4: &BaseFp2<P>) {
+        let mut aa = self.c0;
+        aa.mul_by_01(c0, c1);
+        let mut bb = self.c1;
+        bb.mul_by_1(c4);
+        let o = *c1 + c4;
+
+        self.c1 += &self.c0;
+        self.c1.mul_by_01(c0, &o);
+        self.c1 -= &aa;
+        self.c1 -= &bb;
+        self.c0 = bb;
+        Fp12ConfigWrapper::<P>::mul_base_field_by_nonresidue_in_place(&mut self.c0);
+        self.c0 += &aa;
+    }
+
+    /// Multiplies `self` by the sparse element `c0 + c3 * w + c4 * v * w`.
+    pub fn mul_by_034(&mut self, c0: &BaseFp2<P>, c3: &BaseFp2<P>, c4: &BaseFp2<P>) {
+        let mut a = self.c0;
+        a.mul_assign_by_fp2(c0);
+        let mut b = self.c1;
+        b.mul_by_01(c3, c4);
+        let d0 = *c0 + c3;
+
+        let mut e = self.c0 + self.c1;
+        e.mul_by_01(&d0, c4);
+        self.c1 = e - (a + b);
+        self.c0 = b;
+        Fp12ConfigWrapper::<P>::mul_base_field_by_nonresidue_in_place(&mut self.c0);
+        self.c0 += &a;
+    }
+
+    /// Inverts an element of the cyclotomic subgroup, i.e. of norm one over
+    /// [`Fp6`], by conjugation.
+    pub fn cyclotomic_inverse_in_place(&mut self) -> &mut Self {
+        self.conjugate_in_place()
+    }
+
+    /// Squares an element of the cyclotomic subgroup, following
+    /// [Granger and Scott].
+    ///
+    /// The result is unspecified for elements outside of the subgroup.
+    ///
+    /// [Granger and Scott]: https://eprint.iacr.org/2009/565.pdf
+    pub fn cyclotomic_square_in_place(&mut self) -> &mut Self {
+        let mul_by_nonresidue = |mut fe: BaseFp2<P>| {
+            P::Fp6Config::mul_fp2_by_nonresidue_in_place(&mut fe);
+            fe
+        };
+        // Squares `a + b * s` in `Fp4 = Fp2[s] / (s² - ξ)`.
+        let fp4_square = |a: BaseFp2<P>, b: BaseFp2<P>| {
+            let ab = a * b;
+            let square = (a + b) * (mul_by_nonresidue(b) + a) - ab - mul_by_nonresidue(ab);
+            (square, ab.double())
+        };
+
+        let (t0, t1) = fp4_square(self.c0.c0, self.c1.c1);
+        let (t2, t3) = fp4_square(self.c1.c0, self.c0.c2);
+        let (t4, t5) = fp4_square(self.c0.c1, self.c1.c2);
+
+        // `z = 3 * t - 2 * z`, or `z = 3 * t + 2 * z`.
+        let minus = |z: &mut BaseFp2<P>, t: BaseFp2<P>| {
+            *z = t - *z;
+            z.double_in_place();
+            *z += &t;
+        };
+        let plus = |z: &mut BaseFp2<P>, t: BaseFp2<P>| {
+            *z += &t;
+            z.double_in_place();
+            *z += &t;
+        };
+
+        minus(&mut self.c0.c0, t0);
+        plus(&mut self.c1.c1, t1);
+        plus(&mut self.c1.c0, mul_by_nonresidue(t5));
+        minus(&mut self.c0.c2, t4);
+        minus(&mut self.c0.c1, t2);
+        plus(&mut self.c1.c2, t3);
+        self
+    }
+
+    /// Returns the square of an element of the cyclotomic subgroup.
+    #[must_use]
+    pub fn cyclotomic_square(&self) -> Self {
+        let mut this = *self;
+        this.cyclotomic_square_in_place();
+        this
+    }
+
+    /// Returns `self^exp` for an element of the cyclotomic subgroup.
+    #[must_use]
+    pub fn cyclotomic_exp(&self, exp: impl BitIteratorBE) -> Self {
+        let mut res = Self::ONE;
+        for bit in exp.bit_be_trimmed_iter() {
+            res.cyclotomic_square_in_place();
+            if bit {
+                res *= self;
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        UniformRand,
+        curve::instances::{bls12_381, bn254},
+        field::{fp2::Fp2Config, traits::PrimeField},
+        test_rng,
+    };
+
+    type BasePrimeField<P> =
+        <<<P as Fp12Config>::Fp6Config as Fp6Config>::Fp2Config as Fp2Config>::Fp;
+
+    /// Maps `f` into the cyclotomic subgroup, by raising it to
+    /// `(p^6 - 1)(p^2 + 1)`.
+    fn into_cyclotomic<P: Fp12Config>(f: Fp12<P>) -> Fp12<P> {
+        let mut conjugate = f;
+        conjugate.conjugate_in_place();
+        let mut f = conjugate * f.inverse().unwrap();
+        let f2 = f;
+        f.frobenius_map_in_place(2);
+        f * f2
+    }
+
+    fn check_tower<P: Fp12Config>() {
+        let rng = &mut test_rng();
+        let modulus = BasePrimeField::<P>::MODULUS;
+        for _ in 0..4 {
+            let a = BaseFp2::<P>::rand(rng);
+            assert_eq!(a * a.inverse().unwrap(), BaseFp2::<P>::ONE);
+            assert_eq!(a.frobenius_map(1), a.pow(modulus));
+            assert_eq!(a.square().sqrt().map(|r| r.square()), Some(a.square()));
+
+            let b = Fp6::<P::Fp6Config>::rand(rng);
+            assert_eq!(b * b.inverse().unwrap(), Fp6::ONE);
+            assert_eq!(b.frobenius_map(1), b.pow(modulus));
+            assert_eq!(b.frobenius_map(3), b.frobenius_map(1).frobenius_map(2));
+
+            let f = Fp12::<P>::rand(rng);
+            assert_eq!(f * f.inverse().unwrap(), Fp12::ONE);
+            assert_eq!(f.frobenius_map(1), f.pow(modulus));
+            assert_eq!(f.frobenius_map(12), f);
+        }
+    }
+
+    fn check_sparse_mul<P: Fp12Config>() {
+        let rng = &mut test_rng();
+        for _ in 0..8 {
+            let f = Fp12::<P>::rand(rng);
+            let [c0, c1, c3, c4] = [(); 4].map(|()| BaseFp2::<P>::rand(rng));
+            let zero = BaseFp2::<P>::ZERO;
+
+            let mut sparse = f;
+            sparse.mul_by_014(&c0, &c1, &c4);
+            assert_eq!(sparse, f * Fp12::new(Fp6::new(c0, c1, zero), Fp6::new(zero, c4, zero)));
+
+            let mut sparse = f;
+            sparse.mul_by_034(&c0, &c3, &c4);
+            assert_eq!(sparse, f * Fp12::new(Fp6::new(c0, zero, zero), Fp6::new(c3, c4, zero)));
+        }
+    }
+
+    fn check_cyclotomic<P: Fp12Config>() {
+        let rng = &mut test_rng();
+        for _ in 0..8 {
+            let f = into_cyclotomic(Fp12::<P>::rand(rng));
+            assert_eq!(f.cyclotomic_square(), f.square());
+            let mut inverse = f;
+            inverse.cyclotomic_inverse_in_place();
+            assert_eq!(inverse, f.inverse().unwrap());
+
+            let exp = u64::rand(rng);
+            assert_eq!(f.cyclotomic_exp(exp), f.pow(exp));
+        }
+    }
+
+    #[test]
+    fn tower_arithmetic() {
+        check_tower::<bn254::Fq12Config>();
+        check_tower::<bls12_381::Fq12Config>();
+    }
+
+    #[test]
+    fn sparse_mul() {
+        check_sparse_mul::<bn254::Fq12Config>();
+        check_sparse_mul::<bls12_381::Fq12Config>();
+    }
+
+    #[test]
+    fn cyclotomic_ops() {
+        check_cyclotomic::<bn254::Fq12Config>();
+        check_cyclotomic::<bls12_381::Fq12Config>();
+    }
+}
@@ -0,0 +1,62 @@
+//! This module contains the quadratic extension [`Fp2`] of a prime field,
+//! used as the base of the towers of pairing-friendly curves.
+use super::{
+    quadratic_extension::{QuadExtConfig, QuadExtField},
+    traits::PrimeField,
+};
+use core::marker::PhantomData;
+
+/// Parameters of a quadratic extension `Fp[u] / (u² - β)` of a prime field.
+pub trait Fp2Config: 'static + Send + Sync + Sized {
+    /// Prime field `Fp` of this extension.
+    type Fp: PrimeField;
+
+    /// Quadratic non-residue `β` of `Fp`.
+    const NONRESIDUE: Self::Fp;
+
+    /// Coefficients of the Frobenius map, `β^((p^i - 1) / 2)` for `i = 0, 1`.
+    const FROBENIUS_COEFF_FP2_C1: &'static [Self::Fp];
+
+    /// Helper method for computing `fe * Self::NONRESIDUE`.
+    ///
+    /// The default implementation should be overridden only if the product
+    /// can be computed faster than standard field multiplication (eg: via
+    /// negation if `NONRESIDUE == -1`).
+    #[inline(always)]
+    fn mul_fp_by_nonresidue_in_place(fe: &mut Self::Fp) -> &mut Self::Fp {
+        *fe *= &Self::NONRESIDUE;
+        fe
+    }
+}
+
+/// Wrapper implementing [`QuadExtConfig`] for a [`Fp2Config`].
+pub struct Fp2ConfigWrapper<P: Fp2Config>(PhantomData<P>);
+
+impl<P: Fp2Config> QuadExtConfig for Fp2ConfigWrapper<P> {
+    type BaseField = P::Fp;
+    type BasePrimeField = P::Fp;
+    type FrobCoeff = P::Fp;
+
+    const DEGREE_OVER_BASE_PRIME_FIELD: usize = 2;
+    const FROBENIUS_COEFF_C1: &'static [P::Fp] = P::FROBENIUS_COEFF_FP2_C1;
+    const NONRESIDUE: P::Fp = P::NONRESIDUE;
+
+    #[inline(always)]
+    fn mul_base_field_by_nonresidue_in_place(fe: &mut P::Fp) -> &mut P::Fp {
+        P::mul_fp_by_nonresidue_in_place(fe)
+    }
+
+    fn mul_base_field_by_frob_coeff(fe: &mut P::Fp, power: usize) {
+        *fe *= &Self::FROBENIUS_COEFF_C1[power % Self::DEGREE_OVER_BASE_PRIME_FIELD];
+    }
+}
+
+/// Quadratic extension of the prime field `P::Fp`.
+pub type Fp2<P> = QuadExtField<Fp2ConfigWrapper<P>>;
+
+impl<P: Fp2Config> Fp2<P> {
+    /// Multiplies `self` by an element of the prime field.
+    pub fn mul_assign_by_fp(&mut self, other: &P::Fp) {
+        self.mul_assign_by_base_field(other);
+    }
+}
@@ -0,0 +1,101 @@
+//! This module contains the cubic extension [`Fp6`] of a quadratic extension
+//! [`Fp2`], used in the towers of pairing-friendly curves.
+use super::{
+    cubic_extension::{CubicExtConfig, CubicExtField},
+    fp2::{Fp2, Fp2Config},
+};
+use core::marker::PhantomData;
+
+/// Parameters of a cubic extension `Fp2[v] / (v³ - ξ)` of [`Fp2`].
+pub trait Fp6Config: 'static + Send + Sync + Sized {
+    /// Parameters of the underlying quadratic extension.
+    type Fp2Config: Fp2Config;
+
+    /// Cubic non-residue `ξ` of [`Fp2`].
+    const NONRESIDUE: Fp2<Self::Fp2Config>;
+
+    /// Coefficients of `v` in the Frobenius map, `ξ^((p^i - 1) / 3)` for
+    /// `i = 0, ..., 5`.
+    const FROBENIUS_COEFF_FP6_C1: &'static [Fp2<Self::Fp2Config>];
+
+    /// Coefficients of `v²` in the Frobenius map, `ξ^((2p^i - 2) / 3)` for
+    /// `i = 0, ..., 5`.
+    const FROBENIUS_COEFF_FP6_C2: &'static [Fp2<Self::Fp2Config>];
+
+    /// Helper method for computing `fe * Self::NONRESIDUE`.
+    ///
+    /// The default implementation should be overridden only if the product
+    /// can be computed faster than standard field multiplication.
+    #[inline(always)]
+    fn mul_fp2_by_nonresidue_in_place(fe: &mut Fp2<Self::Fp2Config>) -> &mut Fp2<Self::Fp2Config> {
+        *fe *= &Self::NONRESIDUE;
+        fe
+    }
+}
+
+/// Wrapper implementing [`CubicExtConfig`] for a [`Fp6Config`].
+pub struct Fp6ConfigWrapper<P: Fp6Config>(PhantomData<P>);
+
+impl<P: Fp6Config> CubicExtConfig for Fp6ConfigWrapper<P> {
+    type BaseField = Fp2<P::Fp2Config>;
+    type BasePrimeField = <P::Fp2Config as Fp2Config>::Fp;
+    type FrobCoeff = Fp2<P::Fp2Config>;
+
+    const DEGREE_OVER_BASE_PRIME_FIELD: usize = 6;
+    const FROBENIUS_COEFF_C1: &'static [Self::FrobCoeff] = P::FROBENIUS_COEFF_FP6_C1;
+    const FROBENIUS_COEFF_C2: &'static [Self::FrobCoeff] = P::FROBENIUS_COEFF_FP6_C2;
+    const NONRESIDUE: Self::BaseField = P::NONRESIDUE;
+
+    #[inline(always)]
+    fn mul_base_field_by_nonresidue_in_place(fe: &mut Self::BaseField) -> &mut Self::BaseField {
+        P::mul_fp2_by_nonresidue_in_place(fe)
+    }
+
+    fn mul_base_field_by_frob_coeff(
+        c1: &mut Self::BaseField,
+        c2: &mut Self::BaseField,
+        power: usize,
+    ) {
+        *c1 *= &Self::FROBENIUS_COEFF_C1[power % Self::DEGREE_OVER_BASE_PRIME_FIELD];
+        *c2 *= &Self::FROBENIUS_COEFF_C2[power % Self::DEGREE_OVER_BASE_PRIME_FIELD];
+    }
+}
+
+/// Cubic extension of [`Fp2`].
+pub type Fp6<P> = CubicExtField<Fp6ConfigWrapper<P>>;
+
+impl<P: Fp6Config> Fp6<P> {
+    /// Multiplies `self` by an element of [`Fp2`].
+    pub fn mul_assign_by_fp2(&mut self, other: &Fp2<P::Fp2Config>) {
+        self.mul_assign_by_base_field(other);
+    }
+
+    /// Multiplies `self` by `c1 * v`.
+    pub fn mul_by_1(&mut self, c1: &Fp2<P::Fp2Config>) {
+        let b_b = self.c1 * c1;
+        let mut t1 = (self.c1 + self.c2) * c1 - b_b;
+        P::mul_fp2_by_nonresidue_in_place(&mut t1);
+        let t2 = (self.c0 + self.c1) * c1 - b_b;
+
+        self.c0 = t1;
+        self.c1 = t2;
+        self.c2 = b_b;
+    }
+
+    /// Multiplies `self` by `c0 + c1 * v`.
+    pub fn mul_by_01(&mut self, c0: &Fp2<P::Fp2Config>, c1: &Fp2<P::Fp2Config>) {
+        let a_a = self.c0 * c0;
+        let b_b = self.c1 * c1;
+
+        let mut t1 = (self.c1 + self.c2) * c1 - b_b;
+        P::mul_fp2_by_nonresidue_in_place(&mut t1);
+        t1 += &a_a;
+
+        let t3 = (self.c0 + self.c2) * c0 - a_a + b_b;
+        let t2 = (*c0 + c1) * (self.c0 + self.c1) - a_a - b_b;
+
+        self.c0 = t1;
+        self.c1 = t2;
+        self.c2 = t3;
+    }
+}
@@ -0,0 +1,271 @@
+//! Expansion of a message into a uniformly random byte string, following
+//! [RFC 9380, section 5.3](https://datatracker.ietf.org/doc/html/rfc9380#section-5.3).
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use digest::{Digest, ExtendableOutput, Update, XofReader, crypto_common::BlockSizeUser};
+
+/// Prefix of the hashed domain separation tag, used when the tag exceeds
+/// [`MAX_DST_LENGTH`] bytes.
+const LONG_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// Maximum length of a domain separation tag.
+pub const MAX_DST_LENGTH: usize = 255;
+
+/// Expands a message into a byte string of the requested length.
+pub trait Expander: Sized {
+    /// Creates an expander for the domain separation tag `dst`, targeting
+    /// `security_param` bits of security.
+    ///
+    /// Tags longer than [`MAX_DST_LENGTH`] bytes are hashed down, as
+    /// specified by [RFC 9380, section 5.3.3].
+    ///
+    /// [RFC 9380, section 5.3.3]: https://datatracker.ietf.org/doc/html/rfc9380#section-5.3.3
+    fn new(dst: &[u8], security_param: usize) -> Self;
+
+    /// Returns `len_in_bytes` pseudo-random bytes derived from `msg`.
+    fn expand(&self, msg: &[u8], len_in_bytes: usize) -> Vec<u8>;
+}
+
+/// `expand_message_xmd`, built on a fixed-output hash function `H`.
+pub struct ExpanderXmd<H> {
+    dst: Vec<u8>,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: Digest + BlockSizeUser> Expander for ExpanderXmd<H> {
+    fn new(dst: &[u8], _security_param: usize) -> Self {
+        let dst = if dst.len() > MAX_DST_LENGTH {
+            H::new().chain_update(LONG_DST_PREFIX).chain_update(dst).finalize().to_vec()
+        } else {
+            dst.to_vec()
+        };
+        Self { dst, _hasher: PhantomData }
+    }
+
+    /// # Panics
+    ///
+    /// * If `len_in_bytes` exceeds `65535`, or `255` hash outputs.
+    fn expand(&self, msg: &[u8], len_in_bytes: usize) -> Vec<u8> {
+        let b_len = <H as Digest>::output_size();
+        let ell = len_in_bytes.div_ceil(b_len);
+        assert!(ell <= 255, "expanded length should be at most 255 hash outputs");
+        let len_in_bytes_be = u16::try_from(len_in_bytes)
+            .expect("expanded length should fit in 2 bytes")
+            .to_be_bytes();
+        let dst_len = u8::try_from(self.dst.len()).expect("dst should be at most 255 bytes");
+
+        let z_pad = alloc::vec![0u8; H::block_size()];
+        let b0 = H::new()
+            .chain_update(z_pad)
+            .chain_update(msg)
+            .chain_update(len_in_bytes_be)
+            .chain_update([0u8])
+            .chain_update(&self.dst)
+            .chain_update([dst_len])
+            .finalize();
+
+        let mut uniform_bytes = Vec::with_capacity(ell * b_len);
+        let mut bi = H::new()
+            .chain_update(&b0)
+            .chain_update([1u8])
+            .chain_update(&self.dst)
+            .chain_update([dst_len])
+            .finalize();
+        uniform_bytes.extend_from_slice(&bi);
+
+        for i in 2..=ell {
+            // `b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)`.
+            for (b, b0) in bi.iter_mut().zip(&b0) {
+                *b ^= b0;
+            }
+            let i = u8::try_from(i).expect("ell should be at most 255");
+            bi = H::new()
+                .chain_update(&bi)
+                .chain_update([i])
+                .chain_update(&self.dst)
+                .chain_update([dst_len])
+                .finalize();
+            uniform_bytes.extend_from_slice(&bi);
+        }
+
+        uniform_bytes.truncate(len_in_bytes);
+        uniform_bytes
+    }
+}
+
+/// `expand_message_xof`, built on an extendable-output function `H`.
+pub struct ExpanderXof<H> {
+    dst: Vec<u8>,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ExtendableOutput + Update + Default> Expander for ExpanderXof<H> {
+    fn new(dst: &[u8], security_param: usize) -> Self {
+        let dst = if dst.len() > MAX_DST_LENGTH {
+            let mut hasher = H::default();
+            hasher.update(LONG_DST_PREFIX);
+            hasher.update(dst);
+            let mut dst = alloc::vec![0u8; (2 * security_param).div_ceil(8)];
+            hasher.finalize_xof().read(&mut dst);
+            dst
+        } else {
+            dst.to_vec()
+        };
+        Self { dst, _hasher: PhantomData }
+    }
+
+    /// # Panics
+    ///
+    /// * If `len_in_bytes` exceeds `65535`.
+    fn expand(&self, msg: &[u8], len_in_bytes: usize) -> Vec<u8> {
+        let len_in_bytes_be = u16::try_from(len_in_bytes)
+            .expect("expanded length should fit in 2 bytes")
+            .to_be_bytes();
+        let dst_len = u8::try_from(self.dst.len()).expect("dst should be at most 255 bytes");
+
+        let mut hasher = H::default();
+        hasher.update(msg);
+        hasher.update(&len_in_bytes_be);
+        hasher.update(&self.dst);
+        hasher.update(&[dst_len]);
+
+        let mut uniform_bytes = alloc::vec![0u8; len_in_bytes];
+        hasher.finalize_xof().read(&mut uniform_bytes);
+        uniform_bytes
+    }
+}
+
+/// Expands `msg` into `len_in_bytes` bytes with `expand_message_xmd`.
+///
+/// # Panics
+///
+/// * If `len_in_bytes` exceeds `65535`, or `255` hash outputs.
+#[must_use]
+pub fn expand_message_xmd<H: Digest + BlockSizeUser>(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Vec<u8> {
+    ExpanderXmd::<H>::new(dst, 0).expand(msg, len_in_bytes)
+}
+
+/// Expands `msg` into `len_in_bytes` bytes with `expand_message_xof`,
+/// targeting `security_param` bits of security.
+///
+/// # Panics
+///
+/// * If `len_in_bytes` exceeds `65535`.
+#[must_use]
+pub fn expand_message_xof<H: ExtendableOutput + Update + Default>(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+    security_param: usize,
+) -> Vec<u8> {
+    ExpanderXof::<H>::new(dst, security_param).expand(msg, len_in_bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{field::hashers::test_messages, test_utils::from_hex};
+    use alloc::string::String;
+    use sha2::Sha256;
+    use sha3::Shake128;
+
+    /// Test vectors of [RFC 9380, appendix K.1](https://datatracker.ietf.org/doc/html/rfc9380#appendix-K.1).
+    #[test]
+    fn expand_message_xmd_sha256() {
+        const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let short = [
+            "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+            "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+            "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1",
+            "b23a1d2b4d97b2ef7785562a7e8bac7eed54ed6e97e29aa51bfe3f12ddad1ff9",
+            "4623227bcc01293b8c130bf771da8c298dede7383243dc0993d2d94823958c4c",
+        ];
+        let long = [
+            "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+            "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40",
+            "ef904a29bffc4cf9ee82832451c946ac3c8f8058ae97d8d629831a74c6572bd9ebd0df635cd1f208e2038e760c4994984ce73f0d55ea9f22af83ba4734569d4bc95e18350f740c07eef653cbb9f87910d833751825f0ebefa1abe5420bb52be14cf489b37fe1a72f7de2d10be453b2c9d9eb20c7e3f6edc5a60629178d9478df",
+            "80be107d0884f0d881bb460322f0443d38bd222db8bd0b0a5312a6fedb49c1bbd88fd75d8b9a09486c60123dfa1d73c1cc3169761b17476d3c6b7cbbd727acd0e2c942f4dd96ae3da5de368d26b32286e32de7e5a8cb2949f866a0b80c58116b29fa7fabb3ea7d520ee603e0c25bcaf0b9a5e92ec6a1fe4e0391d1cdbce8c68a",
+            "546aff5444b5b79aa6148bd81728704c32decb73a3ba76e9e75885cad9def1d06d6792f8a7d12794e90efed817d96920d728896a4510864370c207f99bd4a608ea121700ef01ed879745ee3e4ceef777eda6d9e5e38b90c86ea6fb0b36504ba4a45d22e86f6db5dd43d98a294bebb9125d5b794e9d2a81181066eb954966a487",
+        ];
+
+        for ((msg, short), long) in test_messages().iter().zip(short).zip(long) {
+            assert_eq!(expand_message_xmd::<Sha256>(msg, DST, 0x20), from_hex(short));
+            assert_eq!(expand_message_xmd::<Sha256>(msg, DST, 0x80), from_hex(long));
+        }
+    }
+
+    /// Test vectors of [RFC 9380, appendix K.4](https://datatracker.ietf.org/doc/html/rfc9380#appendix-K.4).
+    #[test]
+    fn expand_message_xof_shake128() {
+        const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHAKE128";
+        let expected = [
+            "86518c9cd86581486e9485aa74ab35ba150d1c75c88e26b7043e44e2acd735a2",
+            "8696af52a4d862417c0763556073f47bc9b9ba43c99b505305cb1ec04a9ab468",
+            "912c58deac4821c3509dbefa094df54b34b8f5d01a191d1d3108a2c89077acca",
+            "1adbcc448aef2a0cebc71dac9f756b22e51839d348e031e63b33ebb50faeaf3f",
+            "df3447cc5f3e9a77da10f819218ddf31342c310778e0e4ef72bbaecee786a4fe",
+        ];
+
+        for (msg, expected) in test_messages().iter().zip(expected) {
+            assert_eq!(expand_message_xof::<Shake128>(msg, DST, 0x20, 128), from_hex(expected));
+        }
+    }
+
+    /// Long domain separation tag test vectors of
+    /// [RFC 9380, appendix K.2](https://datatracker.ietf.org/doc/html/rfc9380#appendix-K.2).
+    #[test]
+    fn expand_message_xmd_sha256_long_dst() {
+        let dst =
+            String::from("QUUX-V01-CS02-with-expander-SHA256-128-long-DST-") + &"1".repeat(208);
+        let short = [
+            "e8dc0c8b686b7ef2074086fbdd2f30e3f8bfbd3bdf177f73f04b97ce618a3ed3",
+            "52dbf4f36cf560fca57dedec2ad924ee9c266341d8f3d6afe5171733b16bbb12",
+            "35387dcf22618f3728e6c686490f8b431f76550b0b2c61cbc1ce7001536f4521",
+            "01b637612bb18e840028be900a833a74414140dde0c4754c198532c3a0ba42bc",
+            "20cce7033cabc5460743180be6fa8aac5a103f56d481cf369a8accc0c374431b",
+        ];
+        let long = [
+            "14604d85432c68b757e485c8894db3117992fc57e0e136f71ad987f789a0abc287c47876978e2388a02af86b1e8d1342e5ce4f7aaa07a87321e691f6fba7e0072eecc1218aebb89fb14a0662322d5edbd873f0eb35260145cd4e64f748c5dfe60567e126604bcab1a3ee2dc0778102ae8a5cfd1429ebc0fa6bf1a53c36f55dfc",
+            "1a30a5e36fbdb87077552b9d18b9f0aee16e80181d5b951d0471d55b66684914aef87dbb3626eaabf5ded8cd0686567e503853e5c84c259ba0efc37f71c839da2129fe81afdaec7fbdc0ccd4c794727a17c0d20ff0ea55e1389d6982d1241cb8d165762dbc39fb0cee4474d2cbbd468a835ae5b2f20e4f959f56ab24cd6fe267",
+            "d2ecef3635d2397f34a9f86438d772db19ffe9924e28a1caf6f1c8f15603d4028f40891044e5c7e39ebb9b31339979ff33a4249206f67d4a1e7c765410bcd249ad78d407e303675918f20f26ce6d7027ed3774512ef5b00d816e51bfcc96c3539601fa48ef1c07e494bdc37054ba96ecb9dbd666417e3de289d4f424f502a982",
+            "ed6e8c036df90111410431431a232d41a32c86e296c05d426e5f44e75b9a50d335b2412bc6c91e0a6dc131de09c43110d9180d0a70f0d6289cb4e43b05f7ee5e9b3f42a1fad0f31bac6a625b3b5c50e3a83316783b649e5ecc9d3b1d9471cb5024b7ccf40d41d1751a04ca0356548bc6e703fca02ab521b505e8e45600508d32",
+            "78b53f2413f3c688f07732c10e5ced29a17c6a16f717179ffbe38d92d6c9ec296502eb9889af83a1928cd162e845b0d3c5424e83280fed3d10cffb2f8431f14e7a23f4c68819d40617589e4c41169d0b56e0e3535be1fd71fbb08bb70c5b5ffed953d6c14bf7618b35fc1f4c4b30538236b4b08c9fbf90462447a8ada60be495",
+        ];
+
+        for ((msg, short), long) in test_messages().iter().zip(short).zip(long) {
+            assert_eq!(expand_message_xmd::<Sha256>(msg, dst.as_bytes(), 0x20), from_hex(short));
+            assert_eq!(expand_message_xmd::<Sha256>(msg, dst.as_bytes(), 0x80), from_hex(long));
+        }
+    }
+
+    /// Long domain separation tag test vectors of
+    /// [RFC 9380, appendix K.5](https://datatracker.ietf.org/doc/html/rfc9380#appendix-K.5).
+    #[test]
+    fn expand_message_xof_shake128_long_dst() {
+        let dst = String::from("QUUX-V01-CS02-with-expander-SHAKE128-long-DST-") + &"1".repeat(210);
+        let expected = [
+            "827c6216330a122352312bccc0c8d6e7a146c5257a776dbd9ad9d75cd880fc53",
+            "690c8d82c7213b4282c6cb41c00e31ea1d3e2005f93ad19bbf6da40f15790c5c",
+            "979e3a15064afbbcf99f62cc09fa9c85028afcf3f825eb0711894dcfc2f57057",
+            "c5a9220962d9edc212c063f4f65b609755a1ed96e62f9db5d1fd6adb5a8dc52b",
+            "f7b96a5901af5d78ce1d071d9c383cac66a1dfadb508300ec6aeaea0d62d5d62",
+        ];
+
+        for (msg, expected) in test_messages().iter().zip(expected) {
+            assert_eq!(
+                expand_message_xof::<Shake128>(msg, dst.as_bytes(), 0x20, 128),
+                from_hex(expected)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic = "expanded length should be at most 255 hash outputs"]
+    fn expand_message_xmd_too_long() {
+        let _ = expand_message_xmd::<Sha256>(b"abc", b"dst", 255 * 32 + 1);
+    }
+}
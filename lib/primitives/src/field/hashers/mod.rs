@@ -0,0 +1,75 @@
+//! Hashing of byte strings to field elements, following
+//! [RFC 9380, section 5](https://datatracker.ietf.org/doc/html/rfc9380#section-5).
+pub mod expander;
+
+use crate::{
+    arithmetic::bigint::BigInteger,
+    field::traits::{Field, PrimeField},
+};
+use expander::Expander;
+
+/// Hashes byte strings to elements of the field `F`.
+pub trait HashToField<F: Field>: Sized {
+    /// Creates a hasher with the domain separation tag `domain`.
+    fn new(domain: &[u8]) -> Self;
+
+    /// Hashes `msg` to `N` field elements.
+    fn hash_to_field<const N: usize>(&self, msg: &[u8]) -> [F; N];
+}
+
+/// The `hash_to_field` function of RFC 9380, for an [`Expander`] `E` and a
+/// target security level of `SEC_PARAM` bits.
+///
+/// Each element of the base prime field is derived from
+/// `L = ceil((ceil(log2(p)) + SEC_PARAM) / 8)` bytes, so that its bias is
+/// negligible. Elements of extension fields consume `L` bytes per
+/// coefficient.
+pub struct DefaultFieldHasher<E, const SEC_PARAM: usize = 128> {
+    expander: E,
+    len_per_base_elem: usize,
+}
+
+impl<F: Field, E: Expander, const SEC_PARAM: usize> HashToField<F>
+    for DefaultFieldHasher<E, SEC_PARAM>
+{
+    fn new(domain: &[u8]) -> Self {
+        let modulus_bits = F::BasePrimeField::MODULUS.num_bits();
+        Self {
+            expander: E::new(domain, SEC_PARAM),
+            len_per_base_elem: (modulus_bits + SEC_PARAM).div_ceil(8),
+        }
+    }
+
+    fn hash_to_field<const N: usize>(&self, msg: &[u8]) -> [F; N] {
+        let m = F::extension_degree();
+        let len = self.len_per_base_elem;
+        let uniform_bytes = self.expander.expand(msg, N * m * len);
+
+        let mut elems = uniform_bytes
+            .chunks_exact(len)
+            .map(<F::BasePrimeField as PrimeField>::from_be_bytes_mod_order);
+        core::array::from_fn(|_| {
+            F::from_base_prime_field_elems(elems.by_ref().take(m))
+                .expect("should have extension degree many coefficients")
+        })
+    }
+}
+
+/// Hashes `msg` to `N` elements of `F` with [`DefaultFieldHasher`].
+#[must_use]
+pub fn hash_to_field<F: Field, E: Expander, const N: usize>(msg: &[u8], dst: &[u8]) -> [F; N] {
+    <DefaultFieldHasher<E> as HashToField<F>>::new(dst).hash_to_field(msg)
+}
+
+/// Messages of the test vectors of
+/// [RFC 9380, appendices J and K](https://datatracker.ietf.org/doc/html/rfc9380#appendix-J).
+#[cfg(test)]
+pub(crate) fn test_messages() -> [Vec<u8>; 5] {
+    [
+        b"".to_vec(),
+        b"abc".to_vec(),
+        b"abcdef0123456789".to_vec(),
+        [b"q128_".as_slice(), &[b'q'; 128]].concat(),
+        [b"a512_".as_slice(), &[b'a'; 512]].concat(),
+    ]
+}
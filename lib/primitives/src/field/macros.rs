@@ -78,3 +78,169 @@ macro_rules! impl_fp_from_signed_int {
         }
     };
 }
+
+/// Implements multiplicative operations by deferring to an implementation on
+/// `&Self`.
+#[macro_export]
+macro_rules! impl_multiplicative_ops_from_ref {
+    ($type:ident, $params:ident) => {
+        #[allow(unused_qualifications)]
+        impl<P: $params> core::ops::Mul<Self> for $type<P> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, other: Self) -> Self {
+                let mut result = self;
+                result *= &other;
+                result
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<'a, P: $params> core::ops::Mul<&'a mut Self> for $type<P> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, other: &'a mut Self) -> Self {
+                let mut result = self;
+                result *= &*other;
+                result
+            }
+        }
+
+        impl<'b, P: $params> core::ops::Mul<$type<P>> for &'b $type<P> {
+            type Output = $type<P>;
+
+            #[inline]
+            fn mul(self, mut other: $type<P>) -> $type<P> {
+                other *= self;
+                other
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<'a, 'b, P: $params> core::ops::Mul<&'a $type<P>> for &'b $type<P> {
+            type Output = $type<P>;
+
+            #[inline]
+            fn mul(self, other: &'a $type<P>) -> $type<P> {
+                let mut result = *self;
+                result *= &*other;
+                result
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<'a, 'b, P: $params> core::ops::Mul<&'a mut $type<P>> for &'b $type<P> {
+            type Output = $type<P>;
+
+            #[inline]
+            fn mul(self, other: &'a mut $type<P>) -> $type<P> {
+                let mut result = *self;
+                result *= &*other;
+                result
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<P: $params> core::ops::Div<Self> for $type<P> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, other: Self) -> Self {
+                let mut result = self;
+                result /= &other;
+                result
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<'a, P: $params> core::ops::Div<&'a mut Self> for $type<P> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, other: &'a mut Self) -> Self {
+                let mut result = self;
+                result /= &*other;
+                result
+            }
+        }
+
+        impl<'b, P: $params> core::ops::Div<$type<P>> for &'b $type<P> {
+            type Output = $type<P>;
+
+            #[inline]
+            fn div(self, other: $type<P>) -> $type<P> {
+                let mut result = *self;
+                result /= &other;
+                result
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<'a, 'b, P: $params> core::ops::Div<&'a $type<P>> for &'b $type<P> {
+            type Output = $type<P>;
+
+            #[inline]
+            fn div(self, other: &'a $type<P>) -> $type<P> {
+                let mut result = *self;
+                result /= &*other;
+                result
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<'a, 'b, P: $params> core::ops::Div<&'a mut $type<P>> for &'b $type<P> {
+            type Output = $type<P>;
+
+            #[inline]
+            fn div(self, other: &'a mut $type<P>) -> $type<P> {
+                let mut result = *self;
+                result /= &*other;
+                result
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<P: $params> core::iter::Product<Self> for $type<P> {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(num_traits::One::one(), core::ops::Mul::mul)
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<'a, P: $params> core::iter::Product<&'a Self> for $type<P> {
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold(num_traits::One::one(), core::ops::Mul::mul)
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<P: $params> core::ops::MulAssign<Self> for $type<P> {
+            fn mul_assign(&mut self, other: Self) {
+                *self *= &other
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<'a, P: $params> core::ops::MulAssign<&'a mut Self> for $type<P> {
+            fn mul_assign(&mut self, other: &'a mut Self) {
+                *self *= &*other
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<P: $params> core::ops::DivAssign<Self> for $type<P> {
+            fn div_assign(&mut self, other: Self) {
+                *self /= &other
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        impl<'a, P: $params> core::ops::DivAssign<&'a mut Self> for $type<P> {
+            fn div_assign(&mut self, other: &'a mut Self) {
+                *self /= &*other
+            }
+        }
+    };
+}
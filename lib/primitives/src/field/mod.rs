@@ -11,15 +11,13 @@
 //! # Examples
 //!
 //! ```rust
-//! use primitives::{
-//!     arithmetic::uint::U64,
+//! use fina_primitives::{
+//!     arithmetic::U64,
 //!     field::{
-//!         fp::{Fp64, FpParams, LIMBS_64},
-//!         group::AdditiveGroup,
-//!         Field,
+//!         Fp64, FpParams, LIMBS_64,
+//!         traits::{AdditiveGroup, Field},
 //!     },
-//!     fp_from_num,
-//!     from_num,
+//!     fp_from_num, from_num,
 //! };
 //!
 //! pub type ExampleField = Fp64<FpParam>;
@@ -27,6 +25,10 @@
 //! impl FpParams<LIMBS_64> for FpParam {
 //!     const MODULUS: U64 = from_num!("17");
 //!     const GENERATOR: Fp64<FpParam> = fp_from_num!("3");
+//!     const ONE: Fp64<FpParam> = fp_from_num!("1");
+//!     const TWO_ADICITY: u32 = 4;
+//!     const TWO_ADIC_ROOT_OF_UNITY: Fp64<FpParam> = fp_from_num!("3");
+//!     const ZERO: Fp64<FpParam> = fp_from_num!("0");
 //! }
 //!
 //! # fn main() {
@@ -44,15 +46,21 @@
 //! ```
 //!
 //! [ark-ff library]: https://github.com/arkworks-rs/algebra/tree/master/ff
+pub mod cubic_extension;
 pub mod fp;
+pub mod fp12;
+pub mod fp2;
+pub mod fp6;
+pub mod hashers;
 pub mod macros;
+pub mod quadratic_extension;
 pub mod traits;
 pub mod vec;
 
 use crate::{
     arithmetic::{U64, U256},
-    declare_fp, fp_from_num, from_num, impl_fp_from_signed_int, impl_fp_from_unsigned_int,
-    impl_int_from_fp,
+    declare_fp, fp_from_hex, fp_from_num, from_num, impl_fp_from_signed_int,
+    impl_fp_from_unsigned_int, impl_int_from_fp,
 };
 pub use fp::FpParams;
 
@@ -99,6 +107,11 @@ impl FpParams<LIMBS_256> for VestaParam {
     const GENERATOR: Fp256<VestaParam> = fp_from_num!("5");
     const MODULUS: U256 =
         from_num!("28948022309329048855892746252171976963363056481941647379679742748393362948097");
+    const ONE: Fp256<VestaParam> = fp_from_num!("1");
+    const TWO_ADICITY: u32 = 32;
+    const TWO_ADIC_ROOT_OF_UNITY: Fp256<VestaParam> =
+        fp_from_hex!("2DE6A9B8746D3F589E5C4DFD492AE26E9BB97EA3C106F049A70E2C1102B6D05F");
+    const ZERO: Fp256<VestaParam> = fp_from_num!("0");
 }
 
 pub type FpBabyBear = Fp64<BabyBearParam>;
@@ -106,6 +119,10 @@ pub struct BabyBearParam;
 impl FpParams<LIMBS_64> for BabyBearParam {
     const GENERATOR: Fp64<BabyBearParam> = fp_from_num!("31");
     const MODULUS: U64 = from_num!("2013265921");
+    const ONE: Fp64<BabyBearParam> = fp_from_num!("1");
+    const TWO_ADICITY: u32 = 27;
+    const TWO_ADIC_ROOT_OF_UNITY: Fp64<BabyBearParam> = fp_from_num!("440564289");
+    const ZERO: Fp64<BabyBearParam> = fp_from_num!("0");
 }
 
 pub type FpBLS12 = Fp256<BLS12Param>;
@@ -114,6 +131,11 @@ impl FpParams<LIMBS_256> for BLS12Param {
     const GENERATOR: Fp256<BLS12Param> = fp_from_num!("7");
     const MODULUS: U256 =
         from_num!("52435875175126190479447740508185965837690552500527637822603658699938581184513");
+    const ONE: Fp256<BLS12Param> = fp_from_num!("1");
+    const TWO_ADICITY: u32 = 32;
+    const TWO_ADIC_ROOT_OF_UNITY: Fp256<BLS12Param> =
+        fp_from_hex!("16A2A19EDFE81F20D09B681922C813B4B63683508C2280B93829971F439F0D2B");
+    const ZERO: Fp256<BLS12Param> = fp_from_num!("0");
 }
 
 pub type FpBN256 = Fp256<BN256Param>;
@@ -122,6 +144,11 @@ impl FpParams<LIMBS_256> for BN256Param {
     const GENERATOR: Fp256<BN256Param> = fp_from_num!("7");
     const MODULUS: U256 =
         from_num!("21888242871839275222246405745257275088548364400416034343698204186575808495617");
+    const ONE: Fp256<BN256Param> = fp_from_num!("1");
+    const TWO_ADICITY: u32 = 28;
+    const TWO_ADIC_ROOT_OF_UNITY: Fp256<BN256Param> =
+        fp_from_hex!("3DDB9F5166D18B798865EA93DD31F743215CF6DD39329C8D34F1ED960C37C9C");
+    const ZERO: Fp256<BN256Param> = fp_from_num!("0");
 }
 
 pub type FpGoldiLocks = Fp64<GoldiLocksParam>;
@@ -129,6 +156,10 @@ pub struct GoldiLocksParam;
 impl FpParams<LIMBS_64> for GoldiLocksParam {
     const GENERATOR: Fp64<GoldiLocksParam> = fp_from_num!("7");
     const MODULUS: U64 = from_num!("18446744069414584321");
+    const ONE: Fp64<GoldiLocksParam> = fp_from_num!("1");
+    const TWO_ADICITY: u32 = 32;
+    const TWO_ADIC_ROOT_OF_UNITY: Fp64<GoldiLocksParam> = fp_from_num!("1753635133440165772");
+    const ZERO: Fp64<GoldiLocksParam> = fp_from_num!("0");
 }
 
 pub type FpPallas = Fp256<PallasParam>;
@@ -137,6 +168,11 @@ impl FpParams<LIMBS_256> for PallasParam {
     const GENERATOR: Fp256<PallasParam> = fp_from_num!("5");
     const MODULUS: U256 =
         from_num!("28948022309329048855892746252171976963363056481941560715954676764349967630337");
+    const ONE: Fp256<PallasParam> = fp_from_num!("1");
+    const TWO_ADICITY: u32 = 32;
+    const TWO_ADIC_ROOT_OF_UNITY: Fp256<PallasParam> =
+        fp_from_hex!("2BCE74DEAC30EBDA362120830561F81AEA322BF2B7BB7584BDAD6FABD87EA32F");
+    const ZERO: Fp256<PallasParam> = fp_from_num!("0");
 }
 
 /// Calculates the k-adicity of n, i.e., the number of trailing 0s in a base-k
@@ -0,0 +1,426 @@
+//! This module contains the implementation of a [quadratic extension]
+//! `F[u] / (u² - β)` of a field `F`, with elements [`QuadExtField`].
+//!
+//! Here `β` is a quadratic non-residue of `F`, given by
+//! [`QuadExtConfig::NONRESIDUE`].
+//!
+//! Note that implementation of `Ord` for [`QuadExtField`] compares the
+//! coefficient of `u` first, and then the constant coefficient.
+//!
+//! [quadratic extension]: https://en.wikipedia.org/wiki/Quadratic_field_extension
+use super::traits::{AdditiveGroup, Field, PrimeField};
+use crate::{UniformRand, impl_additive_ops_from_ref, impl_multiplicative_ops_from_ref};
+use core::{
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+use educe::Educe;
+use fina_common::io::{Read, Write};
+use fina_serialize::{ArkDeserialize, ArkSerialize, Compress, SerializationError, Valid, Validate};
+use num_traits::{One, Zero};
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+
+/// Defines a quadratic extension `F[u] / (u² - β)` of the field `F`.
+pub trait QuadExtConfig: 'static + Send + Sync + Sized {
+    /// Base prime field underlying this extension.
+    type BasePrimeField: PrimeField;
+
+    /// Base field `F` of this extension.
+    type BaseField: Field<BasePrimeField = Self::BasePrimeField>;
+
+    /// Type of the coefficients of the Frobenius map.
+    ///
+    /// These are elements of the smallest subfield containing them, which may
+    /// be smaller than [`Self::BaseField`] for towers of extensions.
+    type FrobCoeff: Field;
+
+    /// Degree of this extension over [`Self::BasePrimeField`].
+    const DEGREE_OVER_BASE_PRIME_FIELD: usize;
+
+    /// Quadratic non-residue `β` of [`Self::BaseField`] defining the
+    /// extension.
+    const NONRESIDUE: Self::BaseField;
+
+    /// Coefficients of the Frobenius map, where `FROBENIUS_COEFF_C1[i]` is
+    /// `β^((q^i - 1) / 2)`, with `q` the characteristic.
+    const FROBENIUS_COEFF_C1: &'static [Self::FrobCoeff];
+
+    /// Helper method for computing `fe * Self::NONRESIDUE`.
+    ///
+    /// The default implementation should be overridden only if the product
+    /// can be computed faster than standard field multiplication.
+    #[inline(always)]
+    fn mul_base_field_by_nonresidue_in_place(fe: &mut Self::BaseField) -> &mut Self::BaseField {
+        *fe *= &Self::NONRESIDUE;
+        fe
+    }
+
+    /// Multiplies `fe` by the Frobenius coefficient of the `power`-th
+    /// Frobenius map.
+    fn mul_base_field_by_frob_coeff(fe: &mut Self::BaseField, power: usize);
+}
+
+/// An element `c0 + c1 * u` of the quadratic extension defined by `P`.
+#[derive(Educe)]
+#[educe(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct QuadExtField<P: QuadExtConfig> {
+    /// Constant coefficient.
+    pub c0: P::BaseField,
+    /// Coefficient of `u`.
+    pub c1: P::BaseField,
+}
+
+impl<P: QuadExtConfig> QuadExtField<P> {
+    /// Creates a new field element `c0 + c1 * u`.
+    pub const fn new(c0: P::BaseField, c1: P::BaseField) -> Self {
+        Self { c0, c1 }
+    }
+
+    /// Sets `self` to its conjugate `c0 - c1 * u`.
+    pub fn conjugate_in_place(&mut self) -> &mut Self {
+        self.c1.neg_in_place();
+        self
+    }
+
+    /// Returns the norm `c0² - β * c1²` of `self` over the base field.
+    #[must_use]
+    pub fn norm(&self) -> P::BaseField {
+        let mut t = self.c1.square();
+        P::mul_base_field_by_nonresidue_in_place(&mut t);
+        self.c0.square() - t
+    }
+
+    /// Multiplies `self` by an element of the base field.
+    pub fn mul_assign_by_base_field(&mut self, value: &P::BaseField) {
+        self.c0 *= value;
+        self.c1 *= value;
+    }
+}
+
+impl<P: QuadExtConfig> Zero for QuadExtField<P> {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+}
+
+impl<P: QuadExtConfig> One for QuadExtField<P> {
+    #[inline]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        self.c0.is_one() && self.c1.is_zero()
+    }
+}
+
+impl<P: QuadExtConfig> AdditiveGroup for QuadExtField<P> {
+    type Scalar = Self;
+
+    const ZERO: Self = Self::new(P::BaseField::ZERO, P::BaseField::ZERO);
+
+    #[inline]
+    fn double_in_place(&mut self) -> &mut Self {
+        self.c0.double_in_place();
+        self.c1.double_in_place();
+        self
+    }
+
+    #[inline]
+    fn neg_in_place(&mut self) -> &mut Self {
+        self.c0.neg_in_place();
+        self.c1.neg_in_place();
+        self
+    }
+}
+
+impl<P: QuadExtConfig> Field for QuadExtField<P> {
+    type BasePrimeField = P::BasePrimeField;
+
+    const ONE: Self = Self::new(P::BaseField::ONE, P::BaseField::ZERO);
+
+    fn extension_degree() -> usize {
+        P::DEGREE_OVER_BASE_PRIME_FIELD
+    }
+
+    #[inline]
+    fn square(&self) -> Self {
+        let mut temp = *self;
+        temp.square_in_place();
+        temp
+    }
+
+    /// Complex squaring: `(c0 - c1)(c0 - β c1) + c0 c1 (1 + β)` is the
+    /// constant coefficient, and `2 c0 c1` the coefficient of `u`.
+    fn square_in_place(&mut self) -> &mut Self {
+        let mut v0 = self.c0 - self.c1;
+        let mut v3 = self.c1;
+        P::mul_base_field_by_nonresidue_in_place(&mut v3);
+        let v3 = self.c0 - v3;
+        let mut v2 = self.c0 * self.c1;
+        v0 *= &v3;
+        v0 += &v2;
+
+        self.c1 = v2.double();
+        P::mul_base_field_by_nonresidue_in_place(&mut v2);
+        self.c0 = v0 + v2;
+        self
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        let norm_inv = self.norm().inverse()?;
+        Some(Self::new(self.c0 * norm_inv, -(self.c1 * norm_inv)))
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        *self = self.inverse()?;
+        Some(self)
+    }
+
+    #[inline]
+    fn ct_select(&self, other: &Self, choice: bool) -> Self {
+        Self::new(self.c0.ct_select(&other.c0, choice), self.c1.ct_select(&other.c1, choice))
+    }
+
+    /// Square root over the base field, in variable time, following
+    /// [Adj and Rodríguez-Henríquez], algorithm 8.
+    ///
+    /// [Adj and Rodríguez-Henríquez]: https://eprint.iacr.org/2012/685.pdf
+    fn sqrt(&self) -> Option<Self> {
+        if self.c1.is_zero() {
+            // `c0` is either a square of the base field, or `β` times one.
+            return if self.c0.is_square() {
+                Some(Self::new(self.c0.sqrt()?, P::BaseField::ZERO))
+            } else {
+                let nonresidue_inv = P::NONRESIDUE.inverse()?;
+                Some(Self::new(P::BaseField::ZERO, (self.c0 * nonresidue_inv).sqrt()?))
+            };
+        }
+
+        // `(x0 + x1 u)² = self` if and only if `x0² = (c0 ± sqrt(norm)) / 2`,
+        // and `x1 = c1 / (2 x0)`.
+        let alpha = self.norm().sqrt()?;
+        let two_inv = P::BaseField::ONE.double().inverse().expect("characteristic should be odd");
+        let mut delta = (alpha + self.c0) * two_inv;
+        if !delta.is_square() {
+            delta -= &alpha;
+        }
+        let x0 = delta.sqrt()?;
+        let x1 = self.c1 * x0.double().inverse()?;
+        let root = Self::new(x0, x1);
+        (root.square() == *self).then_some(root)
+    }
+
+    /// An element is a square if and only if its norm is.
+    fn is_square(&self) -> bool {
+        self.norm().is_square()
+    }
+
+    fn from_base_prime_field_elems(
+        elems: impl IntoIterator<Item = Self::BasePrimeField>,
+    ) -> Option<Self> {
+        let degree = P::BaseField::extension_degree();
+        let mut elems = elems.into_iter();
+        let c0 = P::BaseField::from_base_prime_field_elems(elems.by_ref().take(degree))?;
+        let c1 = P::BaseField::from_base_prime_field_elems(elems.by_ref().take(degree))?;
+        elems.next().is_none().then_some(Self::new(c0, c1))
+    }
+
+    fn to_base_prime_field_elements(&self) -> impl Iterator<Item = Self::BasePrimeField> {
+        self.c0.to_base_prime_field_elements().chain(self.c1.to_base_prime_field_elements())
+    }
+
+    fn frobenius_map_in_place(&mut self, power: usize) {
+        self.c0.frobenius_map_in_place(power);
+        self.c1.frobenius_map_in_place(power);
+        P::mul_base_field_by_frob_coeff(&mut self.c1, power);
+    }
+}
+
+impl<P: QuadExtConfig> Ord for QuadExtField<P> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.c1.cmp(&other.c1).then_with(|| self.c0.cmp(&other.c0))
+    }
+}
+
+impl<P: QuadExtConfig> PartialOrd for QuadExtField<P> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: QuadExtConfig> Display for QuadExtField<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "QuadExtField({} + {} * u)", self.c0, self.c1)
+    }
+}
+
+impl<P: QuadExtConfig> Neg for QuadExtField<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self {
+        self.neg_in_place();
+        self
+    }
+}
+
+impl<P: QuadExtConfig> Add<&Self> for QuadExtField<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: &Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<P: QuadExtConfig> Sub<&Self> for QuadExtField<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, other: &Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<P: QuadExtConfig> Mul<&Self> for QuadExtField<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, other: &Self) -> Self {
+        self *= other;
+        self
+    }
+}
+
+impl<P: QuadExtConfig> Div<&Self> for QuadExtField<P> {
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(mut self, other: &Self) -> Self {
+        self *= &other.inverse().expect("should not divide by zero");
+        self
+    }
+}
+
+impl<P: QuadExtConfig> AddAssign<&Self> for QuadExtField<P> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        self.c0 += &other.c0;
+        self.c1 += &other.c1;
+    }
+}
+
+impl<P: QuadExtConfig> SubAssign<&Self> for QuadExtField<P> {
+    #[inline]
+    fn sub_assign(&mut self, other: &Self) {
+        self.c0 -= &other.c0;
+        self.c1 -= &other.c1;
+    }
+}
+
+impl<P: QuadExtConfig> MulAssign<&Self> for QuadExtField<P> {
+    /// Karatsuba multiplication, with three base field multiplications.
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        let v0 = self.c0 * other.c0;
+        let mut v1 = self.c1 * other.c1;
+        self.c1 = (self.c0 + self.c1) * (other.c0 + other.c1) - v0 - v1;
+        P::mul_base_field_by_nonresidue_in_place(&mut v1);
+        self.c0 = v0 + v1;
+    }
+}
+
+impl<P: QuadExtConfig> DivAssign<&Self> for QuadExtField<P> {
+    #[inline]
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn div_assign(&mut self, other: &Self) {
+        *self *= &other.inverse().expect("should not divide by zero");
+    }
+}
+
+impl_additive_ops_from_ref!(QuadExtField, QuadExtConfig);
+impl_multiplicative_ops_from_ref!(QuadExtField, QuadExtConfig);
+
+macro_rules! impl_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl<P: QuadExtConfig> From<$int> for QuadExtField<P> {
+                fn from(other: $int) -> Self {
+                    Self::new(P::BaseField::from(other), P::BaseField::ZERO)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u128, u64, u32, u16, u8, i128, i64, i32, i16, i8, bool);
+
+impl<P: QuadExtConfig> zeroize::Zeroize for QuadExtField<P> {
+    fn zeroize(&mut self) {
+        self.c0.zeroize();
+        self.c1.zeroize();
+    }
+}
+
+impl<P: QuadExtConfig> Distribution<QuadExtField<P>> for StandardUniform {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> QuadExtField<P> {
+        QuadExtField::new(P::BaseField::rand(rng), P::BaseField::rand(rng))
+    }
+}
+
+impl<P: QuadExtConfig> ArkSerialize for QuadExtField<P> {
+    /// Writes `c0` followed by `c1`.
+    #[inline]
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.c0.serialize_with_mode(&mut writer, compress)?;
+        self.c1.serialize_with_mode(&mut writer, compress)
+    }
+
+    #[inline]
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.c0.serialized_size(compress) + self.c1.serialized_size(compress)
+    }
+}
+
+impl<P: QuadExtConfig> Valid for QuadExtField<P> {
+    #[inline]
+    fn check(&self) -> Result<(), SerializationError> {
+        self.c0.check()?;
+        self.c1.check()
+    }
+}
+
+impl<P: QuadExtConfig> ArkDeserialize for QuadExtField<P> {
+    #[inline]
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let c0 = P::BaseField::deserialize_with_mode(&mut reader, compress, validate)?;
+        let c1 = P::BaseField::deserialize_with_mode(&mut reader, compress, validate)?;
+        Ok(Self::new(c0, c1))
+    }
+}
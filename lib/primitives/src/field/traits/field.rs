@@ -1,11 +1,12 @@
 use super::{AdditiveGroup, k_adicity, prime::PrimeField};
-use crate::{bits::BitIteratorBE, log2};
+use crate::{UniformRand, bits::BitIteratorBE, log2};
 use core::{
     fmt::{Debug, Display},
     hash::Hash,
     iter::Product,
     ops::{Div, DivAssign, Neg},
 };
+use fina_serialize::{ArkDeserialize, ArkSerialize};
 use num_traits::{One, Zero};
 use zeroize::Zeroize;
 
@@ -29,6 +30,9 @@ pub trait Field:
     + Zeroize
     + Sized
     + Hash
+    + UniformRand
+    + ArkSerialize
+    + ArkDeserialize
     + AdditiveGroup<Scalar = Self>
     + Div<Self, Output = Self>
     + DivAssign<Self>
@@ -73,6 +77,45 @@ pub trait Field:
     /// sets `self` to `self.inverse().unwrap()`.
     fn inverse_in_place(&mut self) -> Option<&mut Self>;
 
+    /// Returns `other` if `choice` is set, and `self` otherwise, in constant
+    /// time.
+    #[must_use]
+    fn ct_select(&self, other: &Self, choice: bool) -> Self;
+
+    /// Returns a square root of `self`, if it exists.
+    ///
+    /// Which of the two roots is returned is unspecified.
+    fn sqrt(&self) -> Option<Self>;
+
+    /// Returns `true` if `self` is a quadratic residue, zero included.
+    fn is_square(&self) -> bool;
+
+    /// Constructs a field element from its coefficients over
+    /// [`Self::BasePrimeField`].
+    ///
+    /// Returns `None` if the number of coefficients differs from
+    /// [`Self::extension_degree`].
+    fn from_base_prime_field_elems(
+        elems: impl IntoIterator<Item = Self::BasePrimeField>,
+    ) -> Option<Self>;
+
+    /// Returns the coefficients of `self` over [`Self::BasePrimeField`].
+    fn to_base_prime_field_elements(&self) -> impl Iterator<Item = Self::BasePrimeField>;
+
+    /// Sets `self` to `self^(p^power)`, where `p` is the characteristic of
+    /// the field.
+    ///
+    /// This is the identity on prime fields.
+    fn frobenius_map_in_place(&mut self, power: usize);
+
+    /// Returns `self^(p^power)`, where `p` is the characteristic of the field.
+    #[must_use]
+    fn frobenius_map(&self, power: usize) -> Self {
+        let mut this = *self;
+        this.frobenius_map_in_place(power);
+        this
+    }
+
     /// Returns `self^exp`, where `exp` is an integer.
     ///
     /// NOTE: Consumers should pass `exp`'s type `S` with the least bit size
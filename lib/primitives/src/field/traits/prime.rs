@@ -31,4 +31,33 @@ pub trait PrimeField:
     /// Converts an element of the prime field into an integer less than
     /// [`Self::MODULUS`].
     fn into_bigint(self) -> Self::BigInt;
+
+    /// Reduces a big-endian byte string of any length modulo
+    /// [`Self::MODULUS`].
+    #[must_use]
+    fn from_be_bytes_mod_order(bytes: &[u8]) -> Self {
+        // Horner's rule over 64-bit limbs, most significant first.
+        bytes.rchunks(8).rev().fold(Self::zero(), |acc, chunk| {
+            let mut limb = [0u8; 8];
+            limb[8 - chunk.len()..].copy_from_slice(chunk);
+            acc * limb_base::<Self>() + Self::from(u64::from_be_bytes(limb))
+        })
+    }
+
+    /// Reduces a little-endian byte string of any length modulo
+    /// [`Self::MODULUS`].
+    #[must_use]
+    fn from_le_bytes_mod_order(bytes: &[u8]) -> Self {
+        bytes.chunks(8).rev().fold(Self::zero(), |acc, chunk| {
+            let mut limb = [0u8; 8];
+            limb[..chunk.len()].copy_from_slice(chunk);
+            acc * limb_base::<Self>() + Self::from(u64::from_le_bytes(limb))
+        })
+    }
+}
+
+/// Returns `2^64` as an element of `F`.
+#[inline]
+fn limb_base<F: PrimeField>() -> F {
+    F::from(u64::MAX) + F::ONE
 }
@@ -39,13 +39,13 @@ impl<F: Field> ToFieldVector<F> for () {
 impl<F: PrimeField> ToFieldVector<F> for [u8] {
     #[inline]
     fn to_field_elements(&self) -> Option<Vec<F>> {
-        let max_size = ((F::MODULUS_BIT_SIZE - 1) / 8) as usize;
+        let max_size = (F::MODULUS_BIT_SIZE - 1) / 8;
         let bigint_size = <F as PrimeField>::BigInt::NUM_LIMBS * 8;
         self.chunks(max_size)
             .map(|chunk| {
                 let mut bigint = vec![0u8; bigint_size];
                 bigint.iter_mut().zip(chunk).for_each(|(a, b)| *a = *b);
-                F::from_bigint(BigInteger::from_bytes_le(bigint.as_slice()));
+                Some(F::from_bigint(BigInteger::from_bytes_le(bigint.as_slice())))
             })
             .collect()
     }
@@ -10,6 +10,7 @@ pub mod bits;
 pub mod curve;
 pub mod field;
 pub mod polynomial;
+#[cfg(test)]
+mod test_utils;
 
 use fina_common::*;
-use fina_serialize::*;
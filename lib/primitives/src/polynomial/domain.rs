@@ -1,3 +1,5 @@
+mod bluestein;
+mod ecfft;
 mod evaluation;
 mod general;
 mod mixed_radix;
@@ -13,10 +15,12 @@ use crate::{
     },
     rand::Rng,
 };
+pub use bluestein::{BluesteinEvaluationDomain, chirp_z};
+pub use ecfft::{EcfftConfig, EcfftEvaluationDomain};
 pub use evaluation::{DomainCoeff, EvaluationDomain};
 pub use general::GeneralEvaluationDomain;
 use mixed_radix::MixedRadixEvaluationDomain;
-use radix2::Radix2EvaluationDomain;
+pub use radix2::{Radix2EvaluationDomain, Radix2Twiddles};
 
 /// An iterator over the elements of a domain.
 pub struct Elements<F: FftField> {
@@ -0,0 +1,302 @@
+use super::{
+    DomainCoeff, Elements, EvaluationDomain, FftField, Field, Radix2EvaluationDomain, k_adicity,
+};
+use crate::field::traits::PrimeField;
+use core::fmt;
+
+/// Evaluates the polynomial with coefficients `coeffs` at the `m` points
+/// `a * w^k` of a geometric progression, with the chirp-z transform.
+///
+/// With `T(k) = k * (k - 1) / 2`, `j * k = T(j + k) - T(j) - T(k)`, so that
+/// `p(a * w^k) = w^-T(k) * Σ_j (c_j * a^j * w^-T(j)) * w^T(j + k)`, which is
+/// a correlation of length `coeffs.len() + m - 1` computed with a radix-2 FFT.
+///
+/// # Panics
+///
+/// * If `w` is zero.
+/// * If the field has no radix-2 domain of size at least
+///   `coeffs.len() + m - 1`.
+pub fn chirp_z<F: FftField, T: DomainCoeff<F>>(coeffs: &[T], a: F, w: F, m: usize) -> Vec<T> {
+    let n = coeffs.len();
+    if n == 0 || m == 0 {
+        return vec![T::zero(); m];
+    }
+    let w_inv = w.inverse().expect("ratio of the progression should be non-zero");
+    let domain = Radix2EvaluationDomain::<F>::new(n + m - 1)
+        .expect("radix-2 domain should be large enough for the convolution");
+
+    // `chirp[t] = w^T(t)`, and `chirp_inv[t] = w^-T(t)`.
+    let chirp = chirp_powers(w, n + m - 1);
+    let chirp_inv = chirp_powers(w_inv, n.max(m));
+
+    // `u` is reversed, so that the correlation is a convolution.
+    let mut u = vec![T::zero(); n];
+    let mut a_pow = F::one();
+    for (j, c) in coeffs.iter().enumerate() {
+        let mut u_j = *c;
+        u_j *= a_pow * chirp_inv[j];
+        u[n - 1 - j] = u_j;
+        a_pow *= a;
+    }
+    domain.fft_in_place(&mut u);
+    let chirp_evals = domain.fft(&chirp);
+    u.iter_mut().zip(chirp_evals).for_each(|(u, v)| *u *= v);
+    domain.ifft_in_place(&mut u);
+
+    u.drain(n - 1..n - 1 + m)
+        .zip(chirp_inv)
+        .map(|(mut y, c)| {
+            y *= c;
+            y
+        })
+        .collect()
+}
+
+/// Returns `w^T(t)` for `t < len`, with `T(t) = t * (t - 1) / 2`.
+fn chirp_powers<F: Field>(w: F, len: usize) -> Vec<F> {
+    let mut w_pow = F::one();
+    let mut chirp = F::one();
+    (0..len)
+        .map(|_| {
+            let result = chirp;
+            chirp *= w_pow;
+            w_pow *= w;
+            result
+        })
+        .collect()
+}
+
+/// Returns the quotient of `p - 1` by `n`, if `n` divides `p - 1`.
+fn divide_p_minus_one<F: PrimeField>(n: u64) -> Option<Vec<u64>> {
+    if n == 0 {
+        return None;
+    }
+    // Long division of `p - 1` by `n`, from the most significant limb.
+    let mut limbs = F::MODULUS.as_ref().to_vec();
+    limbs[0] -= 1;
+    let mut remainder = 0u128;
+    for limb in limbs.iter_mut().rev() {
+        let current = (remainder << 64) | u128::from(*limb);
+        *limb = (current / u128::from(n)) as u64;
+        remainder = current % u128::from(n);
+    }
+    (remainder == 0).then_some(limbs)
+}
+
+/// Returns a generator of the subgroup of order `n` of `F^*`, if `n` divides
+/// `p - 1`.
+fn subgroup_generator<F: PrimeField>(n: u64) -> Option<F> {
+    divide_p_minus_one::<F>(n).map(|cofactor| F::GENERATOR.pow(cofactor.as_slice()))
+}
+
+/// Multiplicative subgroup, or coset of one, of any order dividing `p - 1`,
+/// whose FFTs use [`chirp_z`] on top of radix-2 FFTs.
+///
+/// This covers subgroups whose order is not smooth, at the cost of FFTs of
+/// size about `4 * size`.
+#[derive(Copy, Clone, Hash, Eq, PartialEq)]
+pub struct BluesteinEvaluationDomain<F: Field> {
+    pub size: u64,
+    pub log_size_of_group: u32,
+    pub size_as_field_element: F,
+    pub size_inv: F,
+    pub group_gen: F,
+    pub group_gen_inv: F,
+    pub offset: F,
+    pub offset_inv: F,
+    pub offset_pow_size: F,
+}
+
+impl<F: Field> fmt::Debug for BluesteinEvaluationDomain<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Bluestein multiplicative subgroup of size {}", self.size)
+    }
+}
+
+impl<F: PrimeField> EvaluationDomain<F> for BluesteinEvaluationDomain<F> {
+    type Elements = Elements<F>;
+
+    /// Returns the smallest subgroup of order at least `num_coeffs`, i.e. of
+    /// order the smallest divisor of `p - 1` not less than `num_coeffs`,
+    /// provided that the field has radix-2 domains large enough for its FFTs.
+    fn new(num_coeffs: usize) -> Option<Self> {
+        let size = Self::compute_size_of_domain(num_coeffs)? as u64;
+        let group_gen: F = subgroup_generator(size)?;
+        let size_as_field_element = F::from(size);
+
+        Some(Self {
+            size,
+            log_size_of_group: k_adicity(2, size),
+            size_as_field_element,
+            size_inv: size_as_field_element.inverse()?,
+            group_gen,
+            group_gen_inv: group_gen.inverse()?,
+            offset: F::one(),
+            offset_inv: F::one(),
+            offset_pow_size: F::one(),
+        })
+    }
+
+    fn get_coset(&self, offset: F) -> Option<Self> {
+        Some(Self {
+            offset,
+            offset_inv: offset.inverse()?,
+            offset_pow_size: offset.pow([self.size].as_slice()),
+            ..*self
+        })
+    }
+
+    fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
+        // Sizes are tried in increasing order, until one divides `p - 1`, or
+        // is too large for the radix-2 FFTs of size `2 * size - 1`.
+        let mut size = num_coeffs.max(1);
+        loop {
+            Radix2EvaluationDomain::<F>::compute_size_of_domain(size.checked_mul(2)? - 1)?;
+            if divide_p_minus_one::<F>(size as u64).is_some() {
+                return Some(size);
+            }
+            size += 1;
+        }
+    }
+
+    #[inline]
+    fn size(&self) -> usize {
+        self.size.try_into().unwrap()
+    }
+
+    #[inline]
+    fn log_size_of_group(&self) -> u64 {
+        self.log_size_of_group as u64
+    }
+
+    #[inline]
+    fn size_inv(&self) -> F {
+        self.size_inv
+    }
+
+    #[inline]
+    fn group_gen(&self) -> F {
+        self.group_gen
+    }
+
+    #[inline]
+    fn group_gen_inv(&self) -> F {
+        self.group_gen_inv
+    }
+
+    #[inline]
+    fn coset_offset(&self) -> F {
+        self.offset
+    }
+
+    #[inline]
+    fn coset_offset_inv(&self) -> F {
+        self.offset_inv
+    }
+
+    #[inline]
+    fn coset_offset_pow_size(&self) -> F {
+        self.offset_pow_size
+    }
+
+    fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        if !self.offset.is_one() {
+            Self::distribute_powers(coeffs, self.offset);
+        }
+        coeffs.resize(self.size(), T::zero());
+        *coeffs = chirp_z(coeffs, F::one(), self.group_gen, self.size());
+    }
+
+    fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        evals.resize(self.size(), T::zero());
+        *evals = chirp_z(evals, F::one(), self.group_gen_inv, self.size());
+        if self.offset.is_one() {
+            evals.iter_mut().for_each(|val| *val *= self.size_inv);
+        } else {
+            Self::distribute_powers_and_mul_by_const(evals, self.offset_inv, self.size_inv);
+        }
+    }
+
+    fn elements(&self) -> Elements<F> {
+        Elements { cur_elem: self.offset, cur_pow: 0, size: self.size, group_gen: self.group_gen }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{UniformRand, field::FpBN256 as Fr, test_rng};
+
+    fn naive_evaluate(coeffs: &[Fr], x: Fr) -> Fr {
+        coeffs.iter().rev().fold(Fr::ZERO, |acc, c| acc * x + c)
+    }
+
+    #[test]
+    fn chirp_z_transform() {
+        let rng = &mut test_rng();
+        for (n, m) in [(1, 1), (5, 3), (3, 11), (17, 17), (100, 37)] {
+            let coeffs: Vec<_> = (0..n).map(|_| Fr::rand(rng)).collect();
+            let (a, w) = (Fr::rand(rng), Fr::rand(rng));
+            let evals = chirp_z(&coeffs, a, w, m);
+            assert_eq!(evals.len(), m);
+            let mut x = a;
+            for eval in evals {
+                assert_eq!(eval, naive_evaluate(&coeffs, x));
+                x *= w;
+            }
+        }
+        assert_eq!(chirp_z::<Fr, Fr>(&[], Fr::ONE, Fr::ONE, 3), vec![Fr::ZERO; 3]);
+        assert!(chirp_z(&[Fr::ONE], Fr::ONE, Fr::ONE, 0).is_empty());
+    }
+
+    #[test]
+    fn non_smooth_domains() {
+        let rng = &mut test_rng();
+        // `p - 1 = 2^28 * 3^2 * 13 * 29 * 983 * ...` for the BN254 scalar field.
+        for size in [1, 9, 13 * 29, 2 * 983] {
+            let domain = BluesteinEvaluationDomain::<Fr>::new(size).unwrap();
+            assert_eq!(domain.size(), size);
+            assert_eq!(domain.group_gen.pow([size as u64].as_slice()), Fr::ONE);
+            let coset = domain.get_coset(Fr::GENERATOR).unwrap();
+
+            let coeffs: Vec<_> = (0..size).map(|_| Fr::rand(rng)).collect();
+            for domain in [domain, coset] {
+                let evals = domain.fft(&coeffs);
+                for (x, eval) in domain.elements().zip(&evals).step_by(37) {
+                    assert_eq!(*eval, naive_evaluate(&coeffs, x));
+                }
+                assert_eq!(domain.ifft(&evals), coeffs);
+            }
+        }
+        // The generator of order `13 * 29` is not of order `13` or `29`.
+        let generator = subgroup_generator::<Fr>(13 * 29).unwrap();
+        assert_ne!(generator.pow([13u64].as_slice()), Fr::ONE);
+        assert_ne!(generator.pow([29u64].as_slice()), Fr::ONE);
+    }
+
+    #[test]
+    fn smallest_domain_of_at_least_num_coeffs() {
+        for (num_coeffs, size) in
+            [(0, 1), (7, 8), (10, 12), (11, 12), (17, 18), (950, 983), (983, 983), (1000, 1024)]
+        {
+            assert_eq!(
+                BluesteinEvaluationDomain::<Fr>::compute_size_of_domain(num_coeffs),
+                Some(size)
+            );
+        }
+
+        let domain = BluesteinEvaluationDomain::<Fr>::new(7).unwrap();
+        assert_eq!(domain.size(), 8);
+        assert_eq!(domain.group_gen.pow([4u64].as_slice()), -Fr::ONE);
+        let coeffs: Vec<_> = (0..7).map(|_| Fr::rand(&mut test_rng())).collect();
+        let evals = domain.fft(&coeffs);
+        assert_eq!(evals.len(), 8);
+        for (x, eval) in domain.elements().zip(&evals) {
+            assert_eq!(*eval, naive_evaluate(&coeffs, x));
+        }
+
+        // The radix-2 FFTs of the convolution are limited to `2^28` points.
+        assert_eq!(BluesteinEvaluationDomain::<Fr>::compute_size_of_domain(1 << 27), Some(1 << 27));
+        assert_eq!(BluesteinEvaluationDomain::<Fr>::compute_size_of_domain((1 << 27) + 1), None);
+    }
+}
@@ -0,0 +1,595 @@
+//! ECFFT: FFT-like algorithms over fields without smooth multiplicative
+//! subgroups, following [Ben-Sasson, Carmon, Kopparty and Levit][ecfft].
+//!
+//! The evaluation sets are the `x` coordinates of a coset of a subgroup of
+//! order `2^k` of an elliptic curve over the field. A chain of degree-2
+//! isogenies maps each set 2-to-1 onto the `x` coordinates of a coset of
+//! order `2^(k - 1)` of the next curve, which plays the role of squaring in
+//! radix-2 FFTs. The resulting FFT tree supports:
+//!
+//! * EXTEND: from the evaluations of a polynomial of degree less than `n`
+//!   over a set of `n` elements, its evaluations over `n` other elements, in
+//!   `O(n log n)` field operations.
+//! * ENTER and EXIT: conversions between the coefficients and the
+//!   evaluations of a polynomial, in `O(n log² n)` field operations.
+//!
+//! [ecfft]: https://arxiv.org/abs/2107.08473
+use super::Field;
+use crate::curve::batch_inversion;
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt;
+use num_traits::Zero;
+
+/// Elliptic curve `y² = x³ + a * x + b`, with a point of order
+/// `2^LOG_ORDER`, from which [`EcfftEvaluationDomain`]s are derived.
+///
+/// The subgroup generated by [`GENERATOR`](Self::GENERATOR) should be the
+/// `2`-Sylow subgroup of the curve, so that it is cyclic and every
+/// isogeny of the chain has a rational kernel.
+pub trait EcfftConfig: Clone {
+    /// Field over which the curve is defined.
+    type Field: Field;
+
+    /// Coefficient `a` of the curve equation.
+    const COEFF_A: Self::Field;
+
+    /// Coefficient `b` of the curve equation.
+    const COEFF_B: Self::Field;
+
+    /// Affine coordinates of a point of order `2^LOG_ORDER`.
+    const GENERATOR: (Self::Field, Self::Field);
+
+    /// Base-2 logarithm of the order of [`GENERATOR`](Self::GENERATOR).
+    const LOG_ORDER: u32;
+
+    /// Affine coordinates of a point outside of the subgroup generated by
+    /// [`GENERATOR`](Self::GENERATOR), whose coset is the evaluation set.
+    const OFFSET: (Self::Field, Self::Field);
+}
+
+/// Evaluation set of size `2^k` derived from an [`EcfftConfig`] curve, with
+/// FFTs implemented by the ECFFT algorithms.
+///
+/// The domain comes with a second set of the same size, disjoint from it,
+/// over which [`extend`](Self::extend) computes evaluations. Together, they
+/// are the `x` coordinates of a coset of the subgroup of order `2^(k + 1)`,
+/// so domains of size up to `2^(LOG_ORDER - 1)` are supported.
+#[derive(Clone)]
+pub struct EcfftEvaluationDomain<P: EcfftConfig> {
+    elements: Vec<P::Field>,
+    extension: Vec<P::Field>,
+    /// Tree of the union of `elements` and `extension`, interleaved.
+    extend: ExtendTree<P::Field>,
+    /// Tree of `elements`.
+    tree: EnterTree<P::Field>,
+}
+
+impl<P: EcfftConfig> fmt::Debug for EcfftEvaluationDomain<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ECFFT evaluation domain of size {}", self.size())
+    }
+}
+
+impl<P: EcfftConfig> EcfftEvaluationDomain<P> {
+    /// Returns the smallest domain of size at least `num_coeffs`, or `None`
+    /// if the curve of `P` is too small.
+    ///
+    /// Also returns `None` if the `x` coordinates of the coset of
+    /// [`OFFSET`](EcfftConfig::OFFSET) are not distinct and nonzero, which
+    /// would make the matrices of the FFT tree singular. They are distinct
+    /// unless twice the offset is in the subgroup.
+    pub fn new(num_coeffs: usize) -> Option<Self> {
+        let size = Self::compute_size_of_domain(num_coeffs)?;
+        let log_size = size.trailing_zeros() + 1;
+
+        let a = P::COEFF_A;
+        let mut generator = P::GENERATOR;
+        for _ in log_size..P::LOG_ORDER {
+            generator = double(generator, a)?;
+        }
+        let mut point = P::OFFSET;
+        let mut points = Vec::with_capacity(2 * size);
+        for _ in 0..2 * size {
+            points.push(point.0);
+            point = add(point, generator)?;
+        }
+        if points.iter().any(Zero::is_zero) {
+            return None;
+        }
+        let mut sorted = points.clone();
+        sorted.sort_unstable();
+        if sorted.windows(2).any(|pair| pair[0] == pair[1]) {
+            return None;
+        }
+
+        let isogenies = isogeny_chain(generator, log_size, a)?;
+        let elements: Vec<_> = points.iter().step_by(2).copied().collect();
+        Some(Self {
+            tree: EnterTree::new(&elements, &isogenies),
+            extend: ExtendTree::new(&points, &isogenies),
+            extension: points.iter().skip(1).step_by(2).copied().collect(),
+            elements,
+        })
+    }
+
+    /// Returns the size of the smallest domain of size at least
+    /// `num_coeffs`, or `None` if the curve of `P` is too small.
+    pub fn compute_size_of_domain(num_coeffs: usize) -> Option<usize> {
+        let size = num_coeffs.checked_next_power_of_two()?;
+        (size.trailing_zeros() < P::LOG_ORDER).then_some(size)
+    }
+
+    /// Returns the size of the domain.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns the elements of the domain, in the order of evaluations.
+    #[inline]
+    pub fn elements(&self) -> &[P::Field] {
+        &self.elements
+    }
+
+    /// Returns the elements over which [`extend`](Self::extend) evaluates.
+    #[inline]
+    pub fn extension_elements(&self) -> &[P::Field] {
+        &self.extension
+    }
+
+    /// Evaluates the polynomial with coefficients `coeffs` over the domain
+    /// (ENTER).
+    ///
+    /// # Panics
+    ///
+    /// * If there are more coefficients than elements in the domain.
+    pub fn fft(&self, coeffs: &[P::Field]) -> Vec<P::Field> {
+        let mut coeffs = coeffs.to_vec();
+        self.fft_in_place(&mut coeffs);
+        coeffs
+    }
+
+    /// In-place version of [`fft`](Self::fft).
+    ///
+    /// # Panics
+    ///
+    /// * If there are more coefficients than elements in the domain.
+    pub fn fft_in_place(&self, coeffs: &mut Vec<P::Field>) {
+        assert!(coeffs.len() <= self.size(), "there should be at most one coefficient per element");
+        coeffs.resize(self.size(), P::Field::zero());
+        *coeffs = self.tree.enter(coeffs);
+    }
+
+    /// Returns the coefficients of the polynomial of degree less than the
+    /// size of the domain with evaluations `evals` over the domain (EXIT).
+    ///
+    /// # Panics
+    ///
+    /// * If there are more evaluations than elements in the domain.
+    pub fn ifft(&self, evals: &[P::Field]) -> Vec<P::Field> {
+        let mut evals = evals.to_vec();
+        self.ifft_in_place(&mut evals);
+        evals
+    }
+
+    /// In-place version of [`ifft`](Self::ifft).
+    ///
+    /// # Panics
+    ///
+    /// * If there are more evaluations than elements in the domain.
+    pub fn ifft_in_place(&self, evals: &mut Vec<P::Field>) {
+        assert!(evals.len() <= self.size(), "there should be at most one evaluation per element");
+        evals.resize(self.size(), P::Field::zero());
+        *evals = self.tree.exit(evals);
+    }
+
+    /// Returns the evaluations over
+    /// [`extension_elements`](Self::extension_elements) of the polynomial of
+    /// degree less than the size of the domain with evaluations `evals` over
+    /// the domain (EXTEND).
+    ///
+    /// This doubles the number of evaluations of a polynomial, as a
+    /// low-degree extension with blowup `2`, without going through its
+    /// coefficients.
+    ///
+    /// # Panics
+    ///
+    /// * If `evals` and the domain have different lengths.
+    pub fn extend(&self, evals: &[P::Field]) -> Vec<P::Field> {
+        assert_eq!(evals.len(), self.size(), "there should be one evaluation per element");
+        self.extend.extend(evals, 0)
+    }
+}
+
+/// Degree-2 isogeny with kernel `(x_0, 0)`, acting on `x` coordinates as
+/// `ψ(x) = x + t / (x - x_0) = u(x) / v(x)`, with `v(x) = x - x_0`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Isogeny<F: Field> {
+    x0: F,
+    t: F,
+}
+
+/// Returns the `log_order` isogenies of Vélu's formulas, whose composition
+/// has the subgroup generated by `generator`, of order `2^log_order`, as
+/// kernel.
+fn isogeny_chain<F: Field>(
+    mut generator: (F, F),
+    log_order: u32,
+    mut a: F,
+) -> Option<Vec<Isogeny<F>>> {
+    let mut isogenies = Vec::with_capacity(log_order as usize);
+    for i in (0..log_order).rev() {
+        let mut kernel = generator;
+        for _ in 0..i {
+            kernel = double(kernel, a)?;
+        }
+        let x0 = kernel.0;
+        let t = x0.square() * F::from(3u8) + a;
+        isogenies.push(Isogeny { x0, t });
+        if i == 0 {
+            break;
+        }
+
+        // `(x, y) ↦ (x + t / (x - x_0), y * (1 - t / (x - x_0)²))`, onto the
+        // curve of coefficient `a - 5t`.
+        let (x, y) = generator;
+        let d = (x - x0).inverse()?;
+        generator = (x + t * d, y * (F::one() - t * d.square()));
+        a -= t * F::from(5u8);
+    }
+    Some(isogenies)
+}
+
+/// Returns `p + q`, or `None` if `p = ±q`.
+fn add<F: Field>(p: (F, F), q: (F, F)) -> Option<(F, F)> {
+    let lambda = (q.1 - p.1) * (q.0 - p.0).inverse()?;
+    let x = lambda.square() - p.0 - q.0;
+    Some((x, lambda * (p.0 - x) - p.1))
+}
+
+/// Returns `2p`, or `None` if `p` has order `2`.
+fn double<F: Field>(p: (F, F), a: F) -> Option<(F, F)> {
+    let lambda = (p.0.square() * F::from(3u8) + a) * p.1.double().inverse()?;
+    let x = lambda.square() - p.0.double();
+    Some((x, lambda * (p.0 - x) - p.1))
+}
+
+/// Tables to extend evaluations of polynomials of degree less than `m`
+/// between the halves of a set `X` of `2m` elements of the FFT tree:
+/// `X_0`, its elements of even index, and `X_1`, those of odd index.
+///
+/// The isogeny `ψ` maps `X_b[j]` and `X_b[j + m / 2]` to the `j`-th element
+/// of the half `b` of `ψ(X)`. Every `P` of degree less than `m` is
+/// `(P_0(ψ) + x * P_1(ψ)) * v^(m / 2 - 1)` for `P_0` and `P_1` of degree
+/// less than `m / 2`, so that `P` over `X_0` gives `P_0` and `P_1` over
+/// `ψ(X)_0`, which are extended to `ψ(X)_1` recursively, and give `P` over
+/// `X_1`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ExtendTree<F: Field> {
+    /// For each half, the matrices mapping `(P_0, P_1)` at `ψ(X)_b[j]` to
+    /// `P` at `X_b[j]` and `X_b[j + m / 2]`.
+    matrices: [Vec<[F; 4]>; 2],
+    /// Inverses of `matrices`.
+    inverses: [Vec<[F; 4]>; 2],
+    /// Tree of `ψ(X)`, if `m > 1`.
+    image: Option<Box<ExtendTree<F>>>,
+}
+
+impl<F: Field> ExtendTree<F> {
+    fn new(points: &[F], isogenies: &[Isogeny<F>]) -> Self {
+        let m = points.len() / 2;
+        if m <= 1 {
+            return Self { matrices: [vec![], vec![]], inverses: [vec![], vec![]], image: None };
+        }
+        let Isogeny { x0, t } = isogenies[0];
+        let exponent = [(m / 2 - 1) as u64];
+
+        let mut image: Vec<_> = points[..m].iter().map(|x| *x - x0).collect();
+        batch_inversion(&mut image);
+        image.iter_mut().zip(points).for_each(|(d, x)| *d = *x + t * *d);
+
+        let matrices = [0, 1].map(|b| {
+            (0..m / 2)
+                .map(|j| {
+                    let (s0, s1) = (points[2 * j + b], points[2 * j + m + b]);
+                    let w0 = (s0 - x0).pow(exponent.as_slice());
+                    let w1 = (s1 - x0).pow(exponent.as_slice());
+                    [w0, s0 * w0, w1, s1 * w1]
+                })
+                .collect::<Vec<_>>()
+        });
+        let inverses = matrices.clone().map(|matrices| {
+            let mut dets: Vec<_> = matrices.iter().map(|m| m[0] * m[3] - m[1] * m[2]).collect();
+            batch_inversion(&mut dets);
+            matrices
+                .iter()
+                .zip(dets)
+                .map(|(m, det)| [m[3] * det, -m[1] * det, -m[2] * det, m[0] * det])
+                .collect()
+        });
+
+        Self { matrices, inverses, image: Some(Box::new(Self::new(&image, &isogenies[1..]))) }
+    }
+
+    /// Returns the evaluations over `X_(1 - from)` of the polynomial of
+    /// degree less than `m` with evaluations `evals` over `X_from`.
+    fn extend(&self, evals: &[F], from: usize) -> Vec<F> {
+        let Some(image) = &self.image else {
+            return evals.to_vec();
+        };
+        let half = evals.len() / 2;
+        let (low, high) = evals.split_at(half);
+        let (p0, p1): (Vec<_>, Vec<_>) = self.inverses[from]
+            .iter()
+            .zip(low.iter().zip(high))
+            .map(|(m, (e0, e1))| (m[0] * e0 + m[1] * e1, m[2] * e0 + m[3] * e1))
+            .unzip();
+
+        let p0 = image.extend(&p0, from);
+        let p1 = image.extend(&p1, from);
+
+        let mut result = vec![F::zero(); evals.len()];
+        let (low, high) = result.split_at_mut(half);
+        for (((m, (r0, r1)), p0), p1) in
+            self.matrices[1 - from].iter().zip(low.iter_mut().zip(high)).zip(p0).zip(p1)
+        {
+            *r0 = m[0] * p0 + m[1] * p1;
+            *r1 = m[2] * p0 + m[3] * p1;
+        }
+        result
+    }
+}
+
+/// Tables to convert between the coefficients of polynomials of degree less
+/// than `n` and their evaluations over a set `S` of `n` elements of the
+/// bottom layer of the FFT tree, with halves `S_0` and `S_1`.
+///
+/// With `h = n / 2`, ENTER evaluates `P_lo + x^h * P_hi` by evaluating
+/// `P_lo` and `P_hi` over `S_0` recursively, and extending them to `S_1`.
+/// EXIT inverts it, with `P_lo = P mod x^h` computed over `S_0` by two
+/// Montgomery reductions by `x^h` in evaluation form.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct EnterTree<F: Field> {
+    extend: ExtendTree<F>,
+    /// `x^h` over `S`.
+    x_pow: Vec<F>,
+    /// `x^-h` over `S`.
+    x_pow_inv: Vec<F>,
+    /// Inverse of the vanishing polynomial `Z_0` of `S_0` over `S_1`.
+    vanishing_inv: Vec<F>,
+    /// `Z_0² mod x^h` over `S`, to leave the Montgomery form.
+    montgomery_factor: Vec<F>,
+    /// Tree of `S_0`, if `n > 1`.
+    child: Option<Box<EnterTree<F>>>,
+}
+
+impl<F: Field> EnterTree<F> {
+    fn new(points: &[F], isogenies: &[Isogeny<F>]) -> Self {
+        let n = points.len();
+        let mut tree = Self {
+            extend: ExtendTree::new(points, isogenies),
+            x_pow: vec![],
+            x_pow_inv: vec![],
+            vanishing_inv: vec![],
+            montgomery_factor: vec![],
+            child: None,
+        };
+        if n == 1 {
+            return tree;
+        }
+        let h = n / 2;
+        let even: Vec<_> = points.iter().step_by(2).copied().collect();
+        let child = Self::new(&even, isogenies);
+
+        tree.x_pow = points.iter().map(|x| x.pow([h as u64].as_slice())).collect();
+        tree.x_pow_inv = tree.x_pow.clone();
+        batch_inversion(&mut tree.x_pow_inv);
+
+        // `W = Z_0 - x^h` has degree less than `h`, and is `-x^h` over `S_0`.
+        let w_evals: Vec<_> = tree.x_pow.iter().step_by(2).map(|x| -*x).collect();
+        let w = child.exit(&w_evals);
+        // `Z_0 = x^h + W` over `S_1`.
+        let mut vanishing = tree.extend.extend(&w_evals, 0);
+        for (z, x) in vanishing.iter_mut().zip(tree.x_pow.iter().skip(1).step_by(2)) {
+            *z += x;
+        }
+        batch_inversion(&mut vanishing);
+        tree.vanishing_inv = vanishing;
+
+        // `Z_0² mod x^h = W² mod x^h`, with `W = W_lo + x^(h / 2) * W_hi`.
+        let mut factor = vec![w[0].square()];
+        if h > 1 {
+            let (w_lo, w_hi) = w.split_at(h / 2);
+            let mut w_lo = w_lo.to_vec();
+            let mut w_hi = w_hi.to_vec();
+            w_lo.resize(h, F::zero());
+            w_hi.resize(h, F::zero());
+            let w_lo = child.enter(&w_lo);
+            let w_hi = child.enter(&w_hi);
+            let square: Vec<_> = w_lo.iter().map(Field::square).collect();
+            let cross: Vec<_> = w_lo.iter().zip(&w_hi).map(|(l, h)| l.double() * h).collect();
+            factor = child.exit(&square);
+            factor[h / 2..].iter_mut().zip(child.exit(&cross)).for_each(|(f, c)| *f += c);
+        }
+        factor.resize(n, F::zero());
+        tree.child = Some(Box::new(child));
+        tree.montgomery_factor = tree.enter(&factor);
+        tree
+    }
+
+    /// Evaluates the polynomial with the `n` coefficients `coeffs` over `S`.
+    fn enter(&self, coeffs: &[F]) -> Vec<F> {
+        let Some(child) = &self.child else {
+            return coeffs.to_vec();
+        };
+        let h = coeffs.len() / 2;
+        let (low, high) = coeffs.split_at(h);
+        let (low, high) = (child.enter(low), child.enter(high));
+        let (low_odd, high_odd) = (self.extend.extend(&low, 0), self.extend.extend(&high, 0));
+
+        low.into_iter()
+            .zip(high)
+            .zip(low_odd.into_iter().zip(high_odd))
+            .zip(self.x_pow.chunks_exact(2))
+            .flat_map(|(((l0, h0), (l1, h1)), x_pow)| [l0 + h0 * x_pow[0], l1 + h1 * x_pow[1]])
+            .collect()
+    }
+
+    /// Returns the coefficients of the polynomial of degree less than `n`
+    /// with evaluations `evals` over `S`.
+    fn exit(&self, evals: &[F]) -> Vec<F> {
+        let Some(child) = &self.child else {
+            return evals.to_vec();
+        };
+        let mut low = self.reduce(evals);
+        low.iter_mut().zip(&self.montgomery_factor).for_each(|(l, f)| *l *= f);
+        let low: Vec<_> = self.reduce(&low).into_iter().step_by(2).collect();
+
+        let high: Vec<_> = evals
+            .iter()
+            .step_by(2)
+            .zip(&low)
+            .zip(self.x_pow_inv.iter().step_by(2))
+            .map(|((e, l), x_pow_inv)| (*e - l) * x_pow_inv)
+            .collect();
+        let mut coeffs = child.exit(&low);
+        coeffs.extend(child.exit(&high));
+        coeffs
+    }
+
+    /// Returns the evaluations over `S` of `P * Z_0^-1 mod x^h`, for `P` of
+    /// degree less than `n` with evaluations `evals` over `S`.
+    ///
+    /// With `Q = -P * x^-h mod Z_0`, `(P + Q * x^h) / Z_0` is a polynomial
+    /// of degree less than `h`, which is the result.
+    fn reduce(&self, evals: &[F]) -> Vec<F> {
+        let q: Vec<_> = evals
+            .iter()
+            .step_by(2)
+            .zip(self.x_pow_inv.iter().step_by(2))
+            .map(|(e, x_pow_inv)| -*e * x_pow_inv)
+            .collect();
+        let q = self.extend.extend(&q, 0);
+
+        let odd: Vec<_> = evals
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .zip(self.x_pow.iter().skip(1).step_by(2))
+            .zip(q.iter().zip(&self.vanishing_inv))
+            .map(|((e, x_pow), (q, z_inv))| (*e + *q * x_pow) * z_inv)
+            .collect();
+        let even = self.extend.extend(&odd, 1);
+
+        even.into_iter().zip(odd).flat_map(|(e, o)| [e, o]).collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        UniformRand,
+        curve::instances::secp256k1::{Fq, FqEcfftConfig},
+        fp_from_hex, test_rng,
+    };
+
+    type Domain = EcfftEvaluationDomain<FqEcfftConfig>;
+
+    fn naive_evaluate(coeffs: &[Fq], x: Fq) -> Fq {
+        coeffs.iter().rev().fold(Fq::ZERO, |acc, c| acc * x + c)
+    }
+
+    #[test]
+    fn config() {
+        let (a, b) = (FqEcfftConfig::COEFF_A, FqEcfftConfig::COEFF_B);
+        let on_curve = |(x, y): (Fq, Fq)| y.square() == x.square() * x + a * x + b;
+        assert!(on_curve(FqEcfftConfig::GENERATOR));
+        assert!(on_curve(FqEcfftConfig::OFFSET));
+
+        let mut point = FqEcfftConfig::GENERATOR;
+        for _ in 1..FqEcfftConfig::LOG_ORDER {
+            point = double(point, a).unwrap();
+        }
+        assert!(point.1.is_zero());
+    }
+
+    #[test]
+    fn enter_exit() {
+        let rng = &mut test_rng();
+        for size in [1, 2, 4, 8, 64, 256] {
+            let domain = Domain::new(size).unwrap();
+            assert_eq!(domain.size(), size);
+            let mut all = domain.elements().to_vec();
+            all.extend(domain.extension_elements());
+            all.sort();
+            all.dedup();
+            assert_eq!(all.len(), 2 * size);
+
+            let coeffs: Vec<_> = (0..size).map(|_| Fq::rand(rng)).collect();
+            let evals = domain.fft(&coeffs);
+            for (x, eval) in domain.elements().iter().zip(&evals).step_by(7) {
+                assert_eq!(*eval, naive_evaluate(&coeffs, *x));
+            }
+            assert_eq!(domain.ifft(&evals), coeffs);
+        }
+
+        let domain = Domain::new(5).unwrap();
+        assert_eq!(domain.size(), 8);
+        let coeffs: Vec<_> = (0..5).map(|_| Fq::rand(rng)).collect();
+        let mut evals = domain.fft(&coeffs);
+        assert_eq!(evals[3], naive_evaluate(&coeffs, domain.elements()[3]));
+        domain.ifft_in_place(&mut evals);
+        assert_eq!(evals[..5], coeffs);
+        assert!(evals[5..].iter().all(Zero::is_zero));
+    }
+
+    #[test]
+    fn extend() {
+        let rng = &mut test_rng();
+        for size in [1, 2, 16, 128] {
+            let domain = Domain::new(size).unwrap();
+            let coeffs: Vec<_> = (0..size).map(|_| Fq::rand(rng)).collect();
+            let evals: Vec<_> =
+                domain.elements().iter().map(|x| naive_evaluate(&coeffs, *x)).collect();
+            let expected: Vec<_> =
+                domain.extension_elements().iter().map(|x| naive_evaluate(&coeffs, *x)).collect();
+            assert_eq!(domain.extend(&evals), expected);
+        }
+    }
+
+    /// [`FqEcfftConfig`] with an offset of order `16`, so that the `x`
+    /// coordinates of its coset of the subgroup of order `8` collide.
+    #[derive(Clone)]
+    struct CollidingConfig;
+
+    impl EcfftConfig for CollidingConfig {
+        type Field = Fq;
+
+        const COEFF_A: Fq = FqEcfftConfig::COEFF_A;
+        const COEFF_B: Fq = FqEcfftConfig::COEFF_B;
+        const GENERATOR: (Fq, Fq) = FqEcfftConfig::GENERATOR;
+        const LOG_ORDER: u32 = FqEcfftConfig::LOG_ORDER;
+        const OFFSET: (Fq, Fq) = (
+            fp_from_hex!("2C16A2452CE936AABA87466458BAB83ECC4A8A9EDA91D550938059F775B33E7E"),
+            fp_from_hex!("A7FC30DB00DD9ED0C4853156BD90F2D0941317FBACD69B88453B25876E370857"),
+        );
+    }
+
+    #[test]
+    fn colliding_offset() {
+        assert!(EcfftEvaluationDomain::<CollidingConfig>::new(4).is_none());
+        // Twice the offset is not in the subgroup of order `4`.
+        assert!(EcfftEvaluationDomain::<CollidingConfig>::new(2).is_some());
+    }
+
+    #[test]
+    fn domain_size() {
+        let max_size = 1 << (FqEcfftConfig::LOG_ORDER - 1);
+        assert_eq!(Domain::compute_size_of_domain(0), Some(1));
+        assert_eq!(Domain::compute_size_of_domain(3), Some(4));
+        assert_eq!(Domain::compute_size_of_domain(max_size), Some(max_size));
+        assert_eq!(Domain::compute_size_of_domain(max_size + 1), None);
+        assert!(Domain::new(max_size + 1).is_none());
+    }
+}
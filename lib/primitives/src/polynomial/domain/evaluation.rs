@@ -1,4 +1,4 @@
-use super::{DensePolynomial, FftField, Rng, SparsePolynomial};
+use super::{DensePolynomial, FftField, Rng, SparsePolynomial, cfg_iter_mut};
 use core::{
     fmt, hash,
     ops::{Add, AddAssign, MulAssign, Sub, SubAssign},
@@ -79,6 +79,17 @@ pub trait EvaluationDomain<F: FftField>:
         evals
     }
     fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>);
+    /// Same as [`fft_in_place`](Self::fft_in_place) on each of `columns`,
+    /// sharing the precomputations which do not depend on the coefficients,
+    /// eg: the twiddle factors of radix-2 domains.
+    fn batch_fft_in_place<T: DomainCoeff<F>>(&self, columns: &mut [Vec<T>]) {
+        cfg_iter_mut!(columns).for_each(|column| self.fft_in_place(column));
+    }
+    /// Same as [`ifft_in_place`](Self::ifft_in_place) on each of `columns`,
+    /// sharing the precomputations which do not depend on the evaluations.
+    fn batch_ifft_in_place<T: DomainCoeff<F>>(&self, columns: &mut [Vec<T>]) {
+        cfg_iter_mut!(columns).for_each(|column| self.ifft_in_place(column));
+    }
     fn distribute_powers<T: DomainCoeff<F>>(coeffs: &mut [T], g: F) {
         Self::distribute_powers_and_mul_by_const(coeffs, g, F::one());
     }
@@ -131,6 +142,38 @@ pub trait EvaluationDomain<F: FftField>:
     fn evaluate_vanishing_polynomial(&self, tau: F) -> F {
         tau.pow([self.size() as u64].as_slice()) - self.coset_offset_pow_size()
     }
+    /// Returns the coset `shift * H` of the domain `H` of size
+    /// `blowup * self.size()`, over which low-degree extensions of
+    /// evaluations over `self` are computed.
+    ///
+    /// Returns `None` if no domain has exactly that size.
+    fn lde_coset(&self, blowup: usize, shift: F) -> Option<Self> {
+        let size = self.size().checked_mul(blowup)?;
+        let domain = Self::new(size)?;
+        (domain.size() == size).then(|| domain.get_coset(shift))?
+    }
+    /// Returns the inverses of the vanishing polynomial of `self` over the
+    /// elements of `coset`, in order.
+    ///
+    /// When the size of `self` divides the size of `coset`, the vanishing
+    /// polynomial is periodic over `coset`, and only its first
+    /// `coset.size() / self.size()` inverses are returned.
+    ///
+    /// # Panics
+    ///
+    /// * If `coset` intersects `self`.
+    fn vanishing_polynomial_inverses_over(&self, coset: &Self) -> Vec<F> {
+        let period =
+            if coset.size() % self.size() == 0 { coset.size() / self.size() } else { coset.size() };
+        let mut inverses: Vec<F> =
+            coset.elements().take(period).map(|x| self.evaluate_vanishing_polynomial(x)).collect();
+        assert!(
+            inverses.iter().all(|z| !z.is_zero()),
+            "coset should be disjoint from the vanishing domain"
+        );
+        super::batch_inversion(&mut inverses);
+        inverses
+    }
     fn filter_polynomial(&self, subdomain: &Self) -> DensePolynomial<F> {
         use super::DenseOrSparsePolynomial;
         let self_vanishing_poly = DenseOrSparsePolynomial::from(
@@ -95,6 +95,16 @@ impl<F: FftField> EvaluationDomain<F> for GeneralEvaluationDomain<F> {
         map!(self, ifft_in_place, evals)
     }
 
+    #[inline]
+    fn batch_fft_in_place<T: DomainCoeff<F>>(&self, columns: &mut [Vec<T>]) {
+        map!(self, batch_fft_in_place, columns)
+    }
+
+    #[inline]
+    fn batch_ifft_in_place<T: DomainCoeff<F>>(&self, columns: &mut [Vec<T>]) {
+        map!(self, batch_ifft_in_place, columns)
+    }
+
     #[inline]
     fn evaluate_all_lagrange_coefficients(&self, tau: F) -> Vec<F> {
         map!(self, evaluate_all_lagrange_coefficients, tau)
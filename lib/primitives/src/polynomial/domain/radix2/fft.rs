@@ -1,19 +1,40 @@
 use super::{
     DomainCoeff, EvaluationDomain, FftField, Radix2EvaluationDomain, compute_powers_serial,
 };
-use fina_common::{cfg_chunks_mut, cfg_into_iter, cfg_iter, cfg_iter_mut, log2};
+use alloc::borrow::Cow;
+use fina_common::{cfg_chunks_mut, cfg_iter, cfg_iter_mut, log2};
 
-const DEGREE_AWARE_FFT_THRESHOLD_FACTOR: usize = 1 << 2;
-
-#[derive(PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 enum FFTOrder {
     II,
     IO,
     OI,
 }
 
+/// Butterfly network of an FFT, chosen by [`Strategy::for_size`]. All of
+/// them compute exactly the same outputs.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Strategy {
+    /// One pass over the input per layer of radix-2 butterflies.
+    Radix2,
+    /// Pairs of radix-2 layers fused into radix-4 butterflies, which halves
+    /// the number of passes over the input.
+    Radix4,
+}
+
+impl Strategy {
+    /// Picks the strategy of FFTs of size `size`.
+    fn for_size(size: usize) -> Self {
+        if size >= RADIX_4_MIN_SIZE { Self::Radix4 } else { Self::Radix2 }
+    }
+}
+
 impl<F: FftField> Radix2EvaluationDomain<F> {
-    pub(crate) fn degree_aware_fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+    pub(crate) fn degree_aware_fft_in_place<T: DomainCoeff<F>>(
+        &self,
+        coeffs: &mut Vec<T>,
+        roots: &[F],
+    ) {
         if !self.offset.is_one() {
             Self::distribute_powers(&mut *coeffs, self.offset);
         }
@@ -45,19 +66,43 @@ impl<F: FftField> Radix2EvaluationDomain<F> {
         }
 
         let start_gap = duplicity_of_initials;
-        self.oi_helper(&mut *coeffs, self.group_gen, start_gap);
+        Self::oi_helper(&mut *coeffs, roots, start_gap, Strategy::for_size(n));
+    }
+
+    /// Evaluates `x_s` over `self`.
+    pub(crate) fn in_order_fft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T], roots: &[F]) {
+        if !self.offset.is_one() {
+            Self::distribute_powers(x_s, self.offset);
+        }
+        let strategy = Strategy::for_size(x_s.len());
+        Self::fft_helper_in_place(x_s, FFTOrder::II, roots, strategy);
+    }
+
+    /// Interpolates evaluations over `self`.
+    pub(crate) fn in_order_ifft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T], roots: &[F]) {
+        let strategy = Strategy::for_size(x_s.len());
+        Self::ifft_helper_in_place(x_s, FFTOrder::II, roots, strategy);
+        self.scale_ifft_output(x_s);
     }
 
-    #[allow(unused)]
-    pub(crate) fn in_order_fft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
+    /// Evaluates `x_s` over `self`, leaving the evaluations in bit-reversed
+    /// order.
+    pub(crate) fn bit_reversed_fft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T], roots: &[F]) {
         if !self.offset.is_one() {
             Self::distribute_powers(x_s, self.offset);
         }
-        self.fft_helper_in_place(x_s, FFTOrder::II);
+        let strategy = Strategy::for_size(x_s.len());
+        Self::fft_helper_in_place(x_s, FFTOrder::IO, roots, strategy);
     }
 
-    pub(crate) fn in_order_ifft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
-        self.ifft_helper_in_place(x_s, FFTOrder::II);
+    /// Interpolates evaluations over `self` given in bit-reversed order.
+    pub(crate) fn bit_reversed_ifft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T], roots: &[F]) {
+        let strategy = Strategy::for_size(x_s.len());
+        Self::ifft_helper_in_place(x_s, FFTOrder::OI, roots, strategy);
+        self.scale_ifft_output(x_s);
+    }
+
+    fn scale_ifft_output<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
         if self.offset.is_one() {
             cfg_iter_mut!(x_s).for_each(|val| *val *= self.size_inv);
         } else {
@@ -65,13 +110,18 @@ impl<F: FftField> Radix2EvaluationDomain<F> {
         }
     }
 
-    fn fft_helper_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T], ord: FFTOrder) {
+    fn fft_helper_in_place<T: DomainCoeff<F>>(
+        x_s: &mut [T],
+        ord: FFTOrder,
+        roots: &[F],
+        strategy: Strategy,
+    ) {
         let log_len = log2(x_s.len());
 
         if ord == FFTOrder::OI {
-            self.oi_helper(x_s, self.group_gen, 1);
+            Self::oi_helper(x_s, roots, 1, strategy);
         } else {
-            self.io_helper(x_s, self.group_gen);
+            Self::io_helper(x_s, roots, strategy);
         }
 
         if ord == FFTOrder::II {
@@ -79,7 +129,12 @@ impl<F: FftField> Radix2EvaluationDomain<F> {
         }
     }
 
-    fn ifft_helper_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T], ord: FFTOrder) {
+    fn ifft_helper_in_place<T: DomainCoeff<F>>(
+        x_s: &mut [T],
+        ord: FFTOrder,
+        roots: &[F],
+        strategy: Strategy,
+    ) {
         let log_len = log2(x_s.len());
 
         if ord == FFTOrder::II {
@@ -87,9 +142,9 @@ impl<F: FftField> Radix2EvaluationDomain<F> {
         }
 
         if ord == FFTOrder::IO {
-            self.io_helper(x_s, self.group_gen_inv);
+            Self::io_helper(x_s, roots, strategy);
         } else {
-            self.oi_helper(x_s, self.group_gen_inv, 1);
+            Self::oi_helper(x_s, roots, 1, strategy);
         }
     }
 
@@ -148,32 +203,74 @@ impl<F: FftField> Radix2EvaluationDomain<F> {
         }
     }
 
-    fn io_helper<T: DomainCoeff<F>>(&self, xi: &mut [T], root: F) {
-        let mut roots = self.roots_of_unity(root);
-        let mut step = 1;
-        let mut first = true;
+    /// Applies the radix-2 layers of gaps `gap` and `gap / 2` of
+    /// [`io_helper`](Self::io_helper) in a single pass.
+    fn radix_4_io_layers<T: DomainCoeff<F>>(xi: &mut [T], roots_cache: &[F], gap: usize) {
+        let quarter = gap / 2;
+        let roots = compact_roots(roots_cache, xi.len() / (2 * gap), gap);
+        cfg_chunks_mut!(xi, 2 * gap).for_each(|chunk| {
+            let (lo, hi) = chunk.split_at_mut(gap);
+            let ((a_s, b_s), (c_s, d_s)) = (lo.split_at_mut(quarter), hi.split_at_mut(quarter));
+            let quads = a_s.iter_mut().zip(b_s).zip(c_s).zip(d_s);
+            for (j, (((a, b), c), d)) in quads.enumerate() {
+                Self::butterfly_fn_io(((a, c), &roots[j]));
+                Self::butterfly_fn_io(((b, d), &roots[j + quarter]));
+                Self::butterfly_fn_io(((a, b), &roots[2 * j]));
+                Self::butterfly_fn_io(((c, d), &roots[2 * j]));
+            }
+        });
+    }
+
+    /// Applies the radix-2 layers of gaps `gap` and `2 * gap` of
+    /// [`oi_helper`](Self::oi_helper) in a single pass.
+    fn radix_4_oi_layers<T: DomainCoeff<F>>(xi: &mut [T], roots_cache: &[F], gap: usize) {
+        let roots = compact_roots(roots_cache, xi.len() / (4 * gap), 2 * gap);
+        cfg_chunks_mut!(xi, 4 * gap).for_each(|chunk| {
+            let (lo, hi) = chunk.split_at_mut(2 * gap);
+            let ((a_s, b_s), (c_s, d_s)) = (lo.split_at_mut(gap), hi.split_at_mut(gap));
+            let quads = a_s.iter_mut().zip(b_s).zip(c_s).zip(d_s);
+            for (j, (((a, b), c), d)) in quads.enumerate() {
+                Self::butterfly_fn_oi(((a, b), &roots[2 * j]));
+                Self::butterfly_fn_oi(((c, d), &roots[2 * j]));
+                Self::butterfly_fn_oi(((a, c), &roots[j]));
+                Self::butterfly_fn_oi(((b, d), &roots[j + gap]));
+            }
+        });
+    }
+
+    fn io_helper<T: DomainCoeff<F>>(xi: &mut [T], roots_cache: &[F], strategy: Strategy) {
+        let compaction_max_size = core::cmp::min(
+            roots_cache.len() / 2,
+            roots_cache.len() / MIN_NUM_CHUNKS_FOR_COMPACTION,
+        );
+        let mut compacted_roots = vec![F::default(); compaction_max_size];
 
         let max_threads = 1;
 
         let mut gap = xi.len() / 2;
         while gap > 0 {
+            if strategy != Strategy::Radix2 && gap >= 2 {
+                Self::radix_4_io_layers(xi, roots_cache, gap);
+                gap /= 4;
+                continue;
+            }
             let chunk_size = 2 * gap;
             let num_chunks = xi.len() / chunk_size;
-            if num_chunks >= MIN_NUM_CHUNKS_FOR_COMPACTION {
-                if !first {
-                    roots = cfg_into_iter!(roots).step_by(step * 2).collect();
-                }
-                step = 1;
-                roots.shrink_to_fit();
+            let (roots, step) = if num_chunks >= MIN_NUM_CHUNKS_FOR_COMPACTION {
+                cfg_iter!(roots_cache)
+                    .step_by(num_chunks)
+                    .zip(&mut compacted_roots[..gap])
+                    .for_each(|(b, a)| *a = *b);
+
+                (&compacted_roots[..gap], 1)
             } else {
-                step = num_chunks;
-            }
-            first = false;
+                (roots_cache, num_chunks)
+            };
 
             Self::apply_butterfly(
                 Self::butterfly_fn_io,
                 xi,
-                &roots,
+                roots,
                 step,
                 chunk_size,
                 num_chunks,
@@ -185,8 +282,12 @@ impl<F: FftField> Radix2EvaluationDomain<F> {
         }
     }
 
-    fn oi_helper<T: DomainCoeff<F>>(&self, xi: &mut [T], root: F, start_gap: usize) {
-        let roots_cache = self.roots_of_unity(root);
+    fn oi_helper<T: DomainCoeff<F>>(
+        xi: &mut [T],
+        roots_cache: &[F],
+        start_gap: usize,
+        strategy: Strategy,
+    ) {
         let compaction_max_size = core::cmp::min(
             roots_cache.len() / 2,
             roots_cache.len() / MIN_NUM_CHUNKS_FOR_COMPACTION,
@@ -197,6 +298,11 @@ impl<F: FftField> Radix2EvaluationDomain<F> {
 
         let mut gap = start_gap;
         while gap < xi.len() {
+            if strategy != Strategy::Radix2 && 4 * gap <= xi.len() {
+                Self::radix_4_oi_layers(xi, roots_cache, gap);
+                gap *= 4;
+                continue;
+            }
             let chunk_size = 2 * gap;
             let num_chunks = xi.len() / chunk_size;
 
@@ -209,7 +315,7 @@ impl<F: FftField> Radix2EvaluationDomain<F> {
 
                 (&compacted_roots[..gap], 1)
             } else {
-                (&roots_cache[..], num_chunks)
+                (roots_cache, num_chunks)
             };
 
             Self::apply_butterfly(
@@ -229,6 +335,7 @@ impl<F: FftField> Radix2EvaluationDomain<F> {
 }
 
 const MIN_NUM_CHUNKS_FOR_COMPACTION: usize = 1 << 7;
+const RADIX_4_MIN_SIZE: usize = 1 << 6;
 const MIN_GAP_SIZE_FOR_PARALLELIZATION: usize = 1 << 10;
 const MIN_INPUT_SIZE_FOR_PARALLELIZATION: usize = 1 << 10;
 
@@ -245,3 +352,63 @@ fn derange<T>(xi: &mut [T], log_len: u32) {
         }
     }
 }
+
+/// Returns the first `len` elements of `roots` with a stride of `step`,
+/// borrowing them when `step` is one.
+fn compact_roots<F: Copy>(roots: &[F], step: usize, len: usize) -> Cow<'_, [F]> {
+    if step == 1 {
+        Cow::Borrowed(&roots[..len])
+    } else {
+        Cow::Owned(roots.iter().step_by(step).take(len).copied().collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{UniformRand, field::FpBN256 as Fr, test_rng};
+
+    const STRATEGIES: [Strategy; 2] = [Strategy::Radix2, Strategy::Radix4];
+
+    #[test]
+    fn strategies_agree() {
+        let rng = &mut test_rng();
+        for log_size in 0..=11 {
+            let domain = Radix2EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+            let roots = domain.roots_of_unity(domain.group_gen);
+            let roots_inv = domain.roots_of_unity(domain.group_gen_inv);
+            let input: Vec<_> = (0..1 << log_size).map(|_| Fr::rand(rng)).collect();
+
+            let run = |ord, inverse, strategy| {
+                let mut x_s = input.clone();
+                if inverse {
+                    Radix2EvaluationDomain::ifft_helper_in_place(
+                        &mut x_s, ord, &roots_inv, strategy,
+                    );
+                } else {
+                    Radix2EvaluationDomain::fft_helper_in_place(&mut x_s, ord, &roots, strategy);
+                }
+                x_s
+            };
+            for (ord, inverse) in [
+                (FFTOrder::II, false),
+                (FFTOrder::IO, false),
+                (FFTOrder::II, true),
+                (FFTOrder::OI, true),
+            ] {
+                let expected = run(ord, inverse, Strategy::Radix2);
+                for strategy in STRATEGIES {
+                    assert_eq!(run(ord, inverse, strategy), expected, "{strategy:?} {log_size}");
+                }
+            }
+
+            // In-order evaluations at `ω^i`.
+            let expected = run(FFTOrder::II, false, Strategy::Radix4);
+            for (i, e) in expected.iter().enumerate().step_by(97) {
+                let x = domain.element(i);
+                let eval = input.iter().rev().fold(Fr::ZERO, |acc, c| acc * x + c);
+                assert_eq!(*e, eval);
+            }
+        }
+    }
+}
@@ -1,6 +1,10 @@
 mod fft;
-use super::{DomainCoeff, Elements, EvaluationDomain, FftField, Field, compute_powers_serial};
+mod twiddles;
+use super::{
+    DomainCoeff, Elements, EvaluationDomain, FftField, Field, cfg_iter_mut, compute_powers_serial,
+};
 use core::fmt;
+pub use twiddles::Radix2Twiddles;
 const DEGREE_AWARE_FFT_THRESHOLD_FACTOR: usize = 1 << 2;
 
 #[derive(Copy, Clone, Hash, Eq, PartialEq)]
@@ -106,21 +110,43 @@ impl<F: FftField> EvaluationDomain<F> for Radix2EvaluationDomain<F> {
 
     #[inline]
     fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
-        if coeffs.len() * DEGREE_AWARE_FFT_THRESHOLD_FACTOR <= self.size() {
-            self.degree_aware_fft_in_place(coeffs);
-        } else {
-            coeffs.resize(self.size(), T::zero());
-            self.in_order_fft_in_place(coeffs);
-        }
+        let roots = self.roots_of_unity(self.group_gen);
+        self.fft_in_place_with_roots(coeffs, &roots);
     }
 
     #[inline]
     fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
         evals.resize(self.size(), T::zero());
-        self.in_order_ifft_in_place(&mut *evals);
+        let roots = self.roots_of_unity(self.group_gen_inv);
+        self.in_order_ifft_in_place(&mut *evals, &roots);
+    }
+
+    /// Precomputes the twiddle factors once for all columns.
+    fn batch_fft_in_place<T: DomainCoeff<F>>(&self, columns: &mut [Vec<T>]) {
+        let twiddles = self.precompute_twiddles();
+        cfg_iter_mut!(columns)
+            .for_each(|column| self.fft_in_place_with_twiddles(column, &twiddles));
+    }
+
+    /// Precomputes the twiddle factors once for all columns.
+    fn batch_ifft_in_place<T: DomainCoeff<F>>(&self, columns: &mut [Vec<T>]) {
+        let twiddles = self.precompute_twiddles();
+        cfg_iter_mut!(columns)
+            .for_each(|column| self.ifft_in_place_with_twiddles(column, &twiddles));
     }
 
     fn elements(&self) -> Elements<F> {
         Elements { cur_elem: self.offset, cur_pow: 0, size: self.size, group_gen: self.group_gen }
     }
 }
+
+impl<F: FftField> Radix2EvaluationDomain<F> {
+    fn fft_in_place_with_roots<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>, roots: &[F]) {
+        if coeffs.len() * DEGREE_AWARE_FFT_THRESHOLD_FACTOR <= self.size() {
+            self.degree_aware_fft_in_place(coeffs, roots);
+        } else {
+            coeffs.resize(self.size(), T::zero());
+            self.in_order_fft_in_place(coeffs, roots);
+        }
+    }
+}
@@ -3,4 +3,7 @@ mod univariate;
 
 use super::{domain::*, traits::*, univariate::*};
 use crate::{cfg_iter_mut, curve::batch_inversion, field::traits::FftField};
+pub use multivariate::{
+    DenseMultilinearExtension, MultilinearExtension, SparseMultilinearExtension, eq_eval,
+};
 pub use univariate::Evaluations;
@@ -1 +1,258 @@
+mod dense;
+mod sparse;
 
+use super::Polynomial;
+use crate::{field::traits::Field, rand::Rng};
+pub use dense::DenseMultilinearExtension;
+use fina_serialize::{ArkDeserialize, ArkSerialize};
+pub use sparse::SparseMultilinearExtension;
+
+/// A multilinear polynomial in `num_vars` variables, represented by its
+/// evaluations over the boolean hypercube `{0, 1}^num_vars`.
+///
+/// The evaluation at `(x_0, ..., x_{n-1})` is stored at index
+/// `x_0 + 2 * x_1 + ... + 2^{n-1} * x_{n-1}`, i.e. `x_0` is the lowest-order
+/// variable.
+pub trait MultilinearExtension<F: Field>:
+    Polynomial<F, Point = Vec<F>> + ArkSerialize + ArkDeserialize
+{
+    /// Returns the number of variables of `self`.
+    fn num_vars(&self) -> usize;
+
+    /// Returns a random multilinear extension in `num_vars` variables.
+    fn rand<R: Rng>(num_vars: usize, rng: &mut R) -> Self;
+
+    /// Swaps the `k` variables starting at `a` with the `k` variables
+    /// starting at `b`.
+    ///
+    /// # Panics
+    ///
+    /// * If the two windows overlap or exceed the number of variables.
+    fn relabel(&self, a: usize, b: usize, k: usize) -> Self;
+
+    /// Fixes the lowest-order variables `x_0, ..., x_{k-1}` to
+    /// `partial_point`, and returns the extension in the remaining
+    /// variables.
+    ///
+    /// # Panics
+    ///
+    /// * If `partial_point` has more coordinates than there are variables.
+    fn fix_variables(&self, partial_point: &[F]) -> Self;
+
+    /// Fixes the highest-order variables `x_{n-k}, ..., x_{n-1}` to
+    /// `partial_point`, and returns the extension in the remaining
+    /// variables.
+    ///
+    /// # Panics
+    ///
+    /// * If `partial_point` has more coordinates than there are variables.
+    fn fix_high_variables(&self, partial_point: &[F]) -> Self;
+
+    /// Returns the evaluations of `self` over the boolean hypercube.
+    fn to_evaluations(&self) -> Vec<F>;
+}
+
+/// Returns `eq(x, r) = ∏ (x_i * r_i + (1 - x_i) * (1 - r_i))`, the
+/// multilinear polynomial which is `1` when `x = r` over the boolean
+/// hypercube, and `0` elsewhere.
+///
+/// # Panics
+///
+/// * If `x` and `r` have different lengths.
+pub fn eq_eval<F: Field>(x: &[F], r: &[F]) -> F {
+    assert_eq!(x.len(), r.len(), "points should have the same number of variables");
+    x.iter().zip(r).map(|(x, r)| *x * r + (F::ONE - x) * (F::ONE - r)).product()
+}
+
+/// Swaps the `k` bits of `x` starting at `a` with the `k` bits starting at
+/// `b`.
+fn swap_bits(x: usize, a: usize, b: usize, k: usize) -> usize {
+    let mask = (1usize << k) - 1;
+    let xor = ((x >> a) ^ (x >> b)) & mask;
+    x ^ (xor << a) ^ (xor << b)
+}
+
+/// Checks the arguments of [`MultilinearExtension::relabel`], and returns
+/// the windows ordered so that `a <= b`.
+fn relabel_windows(a: usize, b: usize, k: usize, num_vars: usize) -> (usize, usize) {
+    let (a, b) = if a <= b { (a, b) } else { (b, a) };
+    assert!(b + k <= num_vars, "relabeled variables should be less than the number of variables");
+    assert!(a == b || k == 0 || a + k <= b, "relabeled windows should not overlap");
+    (a, b)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{UniformRand, field::FpBN256 as Fr, test_rng};
+    use core::ops::{Add, Mul, Neg};
+    use fina_serialize::{ArkDeserialize, ArkSerialize};
+    use num_traits::Zero;
+
+    fn rand_point(num_vars: usize, rng: &mut impl Rng) -> Vec<Fr> {
+        (0..num_vars).map(|_| Fr::rand(rng)).collect()
+    }
+
+    /// Evaluates `evaluations` at `point` as `Σ_b eq(b, point) * evaluations[b]`.
+    fn naive_evaluate(evaluations: &[Fr], point: &[Fr]) -> Fr {
+        (0..evaluations.len())
+            .map(|index| {
+                let bits: Vec<_> =
+                    (0..point.len()).map(|j| Fr::from(((index >> j) & 1) as u8)).collect();
+                eq_eval(&bits, point) * evaluations[index]
+            })
+            .sum()
+    }
+
+    fn check_extension<M: MultilinearExtension<Fr>>(mle: &M, rng: &mut impl Rng) {
+        let num_vars = mle.num_vars();
+        let evaluations = mle.to_evaluations();
+        let point = rand_point(num_vars, rng);
+        let expected = naive_evaluate(&evaluations, &point);
+        assert_eq!(mle.evaluate(&point), expected);
+
+        for k in 0..=num_vars {
+            let low = mle.fix_variables(&point[..k]);
+            assert_eq!(low.num_vars(), num_vars - k);
+            assert_eq!(low.evaluate(&point[k..].to_vec()), expected);
+
+            let high = mle.fix_high_variables(&point[num_vars - k..]);
+            assert_eq!(high.num_vars(), num_vars - k);
+            assert_eq!(high.evaluate(&point[..num_vars - k].to_vec()), expected);
+        }
+
+        // Swapping `x_0, x_1` with `x_3, x_4`.
+        let relabeled = mle.relabel(3, 0, 2);
+        let mut swapped = point.clone();
+        swapped.swap(0, 3);
+        swapped.swap(1, 4);
+        assert_eq!(relabeled.evaluate(&swapped), expected);
+        assert_eq!(relabeled.relabel(0, 3, 2), *mle);
+        assert_eq!(mle.relabel(1, 1, 2), *mle);
+
+        let mut bytes = Vec::new();
+        mle.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), mle.compressed_size());
+        assert_eq!(M::deserialize_compressed(bytes.as_slice()).unwrap(), *mle);
+    }
+
+    fn check_arithmetic<M>(p: &M, q: &M, rng: &mut impl Rng)
+    where
+        M: MultilinearExtension<Fr> + Add<Output = M> + Neg<Output = M> + Mul<Fr, Output = M>,
+    {
+        let c = Fr::rand(rng);
+        let point = rand_point(p.num_vars(), rng);
+        let (p_eval, q_eval) = (p.evaluate(&point), q.evaluate(&point));
+
+        assert_eq!((p.clone() + q.clone()).evaluate(&point), p_eval + q_eval);
+        assert_eq!((-p.clone()).evaluate(&point), -p_eval);
+        assert_eq!((p.clone() * c).evaluate(&point), p_eval * c);
+
+        let mut r = p.clone();
+        r += (c, q);
+        assert_eq!(r.evaluate(&point), p_eval + c * q_eval);
+        r -= p;
+        assert_eq!(r.evaluate(&point), c * q_eval);
+        r -= &(q.clone() * c);
+        assert!(r.is_zero());
+    }
+
+    #[test]
+    fn dense() {
+        let mut rng = test_rng();
+        for num_vars in [5, 6, 8] {
+            let p = DenseMultilinearExtension::<Fr>::rand(num_vars, &mut rng);
+            let q = DenseMultilinearExtension::<Fr>::rand(num_vars, &mut rng);
+            check_extension(&p, &mut rng);
+            check_arithmetic(&p, &q, &mut rng);
+            assert_eq!(p[3], p.evaluations[3]);
+        }
+    }
+
+    #[test]
+    fn sparse() {
+        let mut rng = test_rng();
+        for num_vars in [5, 6, 10] {
+            let p = SparseMultilinearExtension::<Fr>::rand(num_vars, &mut rng);
+            let q = SparseMultilinearExtension::<Fr>::rand_with_config(num_vars, 7, &mut rng);
+            check_extension(&p, &mut rng);
+            check_arithmetic(&p, &q, &mut rng);
+
+            let dense = p.to_dense();
+            let point = rand_point(num_vars, &mut rng);
+            assert_eq!(dense.evaluate(&point), p.evaluate(&point));
+            assert_eq!(SparseMultilinearExtension::from(dense), p);
+        }
+    }
+
+    #[test]
+    fn sparse_from_evaluations() {
+        let mle = SparseMultilinearExtension::from_evaluations(
+            3,
+            &[(1, Fr::from(2u8)), (6, Fr::zero()), (1, Fr::from(5u8)), (7, Fr::from(1u8))],
+        );
+        assert_eq!(mle.evaluations.len(), 2);
+        assert_eq!(mle.get(1), Fr::from(5u8));
+        assert_eq!(mle.get(6), Fr::zero());
+    }
+
+    #[test]
+    #[should_panic = "index should be less than 2^num_vars"]
+    fn sparse_index_out_of_range() {
+        let _ = SparseMultilinearExtension::from_evaluations(3, &[(8, Fr::from(1u8))]);
+    }
+
+    #[test]
+    #[should_panic = "num_vars should be less than the bit size of usize"]
+    fn sparse_too_many_variables() {
+        let _ = SparseMultilinearExtension::<Fr>::from_evaluations(64, &[]);
+    }
+
+    #[test]
+    #[should_panic = "relabeled windows should not overlap"]
+    fn overlapping_relabel() {
+        let mle = DenseMultilinearExtension::<Fr>::rand(4, &mut test_rng());
+        let _ = mle.relabel(0, 1, 2);
+    }
+
+    #[test]
+    fn eq_polynomial() {
+        let mut rng = test_rng();
+        let r = rand_point(6, &mut rng);
+        let eq = DenseMultilinearExtension::eq(&r);
+        assert_eq!(eq.num_vars, 6);
+        for index in [0, 1, 17, 63] {
+            let bits: Vec<_> = (0..6).map(|j| Fr::from(((index >> j) & 1) as u8)).collect();
+            assert_eq!(eq[index], eq_eval(&bits, &r));
+        }
+        assert_eq!(eq.iter().copied().sum::<Fr>(), Fr::from(1u8));
+
+        let x = rand_point(6, &mut rng);
+        assert_eq!(eq.evaluate(&x), eq_eval(&x, &r));
+        assert_eq!(
+            eq_eval(&r, &r),
+            r.iter().map(|r| *r * r + (Fr::from(1u8) - r).square()).product()
+        );
+    }
+
+    #[test]
+    fn deserialize_invalid() {
+        let mle = DenseMultilinearExtension::<Fr>::rand(3, &mut test_rng());
+        let truncated =
+            DenseMultilinearExtension { evaluations: mle.evaluations[..7].to_vec(), num_vars: 3 };
+        let mut bytes = Vec::new();
+        truncated.serialize_uncompressed(&mut bytes).unwrap();
+        assert!(
+            DenseMultilinearExtension::<Fr>::deserialize_uncompressed(bytes.as_slice()).is_err()
+        );
+
+        let mut evaluations = alloc::collections::BTreeMap::new();
+        evaluations.insert(8, Fr::from(1u8));
+        let out_of_range = SparseMultilinearExtension { evaluations, num_vars: 3 };
+        let mut bytes = Vec::new();
+        out_of_range.serialize_uncompressed(&mut bytes).unwrap();
+        assert!(
+            SparseMultilinearExtension::<Fr>::deserialize_uncompressed(bytes.as_slice()).is_err()
+        );
+    }
+}
@@ -0,0 +1,309 @@
+use super::{MultilinearExtension, Polynomial, relabel_windows, swap_bits};
+use crate::{cfg_iter_mut, field::traits::Field, rand::Rng};
+use alloc::vec::Vec;
+use core::{
+    fmt,
+    ops::{Add, AddAssign, Index, Mul, Neg, Sub, SubAssign},
+};
+use fina_common::io::{Read, Write};
+use fina_serialize::{ArkDeserialize, ArkSerialize, Compress, SerializationError, Valid, Validate};
+use num_traits::Zero;
+
+/// Multilinear extension stored as its `2^num_vars` evaluations over the
+/// boolean hypercube.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct DenseMultilinearExtension<F: Field> {
+    /// Evaluations over the boolean hypercube, indexed as in
+    /// [`MultilinearExtension`].
+    pub evaluations: Vec<F>,
+    /// Number of variables of the extension.
+    pub num_vars: usize,
+}
+
+impl<F: Field> DenseMultilinearExtension<F> {
+    /// Builds an extension from its `2^num_vars` evaluations, copying them.
+    ///
+    /// # Panics
+    ///
+    /// * If there are not exactly `2^num_vars` evaluations.
+    pub fn from_evaluations_slice(num_vars: usize, evaluations: &[F]) -> Self {
+        Self::from_evaluations_vec(num_vars, evaluations.to_vec())
+    }
+
+    /// Builds an extension from its `2^num_vars` evaluations.
+    ///
+    /// # Panics
+    ///
+    /// * If there are not exactly `2^num_vars` evaluations.
+    pub fn from_evaluations_vec(num_vars: usize, evaluations: Vec<F>) -> Self {
+        assert_eq!(evaluations.len(), 1 << num_vars, "number of evaluations should be 2^num_vars");
+        Self { evaluations, num_vars }
+    }
+
+    /// Returns the evaluations of `eq(x, r)` over the boolean hypercube, as
+    /// defined in [`eq_eval`](super::eq_eval).
+    pub fn eq(r: &[F]) -> Self {
+        let mut evaluations = Vec::with_capacity(1 << r.len());
+        evaluations.push(F::ONE);
+        for r_i in r {
+            // Entries with `x_i = 1` go in the upper half.
+            let upper: Vec<_> = evaluations.iter().map(|e| *e * r_i).collect();
+            cfg_iter_mut!(evaluations).zip(&upper).for_each(|(e, u)| *e -= u);
+            evaluations.extend(upper);
+        }
+        Self { evaluations, num_vars: r.len() }
+    }
+
+    /// Returns an iterator over the evaluations, in index order.
+    pub fn iter(&self) -> core::slice::Iter<'_, F> {
+        self.evaluations.iter()
+    }
+
+    /// Checks that `self` and `other` have the same number of variables,
+    /// unless one of them is zero.
+    fn assert_compatible(&self, other: &Self) {
+        assert!(
+            self.is_zero() || other.is_zero() || self.num_vars == other.num_vars,
+            "extensions should have the same number of variables"
+        );
+    }
+}
+
+impl<F: Field> MultilinearExtension<F> for DenseMultilinearExtension<F> {
+    fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    fn rand<R: Rng>(num_vars: usize, rng: &mut R) -> Self {
+        let evaluations = (0..1 << num_vars).map(|_| F::rand(rng)).collect();
+        Self { evaluations, num_vars }
+    }
+
+    fn relabel(&self, a: usize, b: usize, k: usize) -> Self {
+        let (a, b) = relabel_windows(a, b, k, self.num_vars);
+        let mut evaluations = self.evaluations.clone();
+        for i in 0..evaluations.len() {
+            let j = swap_bits(i, a, b, k);
+            if i < j {
+                evaluations.swap(i, j);
+            }
+        }
+        Self { evaluations, num_vars: self.num_vars }
+    }
+
+    fn fix_variables(&self, partial_point: &[F]) -> Self {
+        assert!(
+            partial_point.len() <= self.num_vars,
+            "partial point should have at most num_vars coordinates"
+        );
+        let mut evaluations = self.evaluations.clone();
+        for (i, r) in partial_point.iter().enumerate() {
+            let half = 1 << (self.num_vars - i - 1);
+            for b in 0..half {
+                let (low, high) = (evaluations[2 * b], evaluations[2 * b + 1]);
+                evaluations[b] = low + *r * (high - low);
+            }
+        }
+        let num_vars = self.num_vars - partial_point.len();
+        evaluations.truncate(1 << num_vars);
+        Self { evaluations, num_vars }
+    }
+
+    fn fix_high_variables(&self, partial_point: &[F]) -> Self {
+        assert!(
+            partial_point.len() <= self.num_vars,
+            "partial point should have at most num_vars coordinates"
+        );
+        let mut evaluations = self.evaluations.clone();
+        let mut len = evaluations.len();
+        for r in partial_point.iter().rev() {
+            len /= 2;
+            let (low, high) = evaluations[..2 * len].split_at_mut(len);
+            cfg_iter_mut!(low).zip(high.iter()).for_each(|(l, h)| *l += *r * (*h - *l));
+        }
+        evaluations.truncate(len);
+        Self { evaluations, num_vars: self.num_vars - partial_point.len() }
+    }
+
+    fn to_evaluations(&self) -> Vec<F> {
+        self.evaluations.clone()
+    }
+}
+
+impl<F: Field> Polynomial<F> for DenseMultilinearExtension<F> {
+    type Point = Vec<F>;
+
+    fn degree(&self) -> usize {
+        self.num_vars
+    }
+
+    /// # Panics
+    ///
+    /// * If `point` does not have `num_vars` coordinates.
+    fn evaluate(&self, point: &Vec<F>) -> F {
+        assert_eq!(point.len(), self.num_vars, "point should have num_vars coordinates");
+        self.fix_variables(point).evaluations[0]
+    }
+}
+
+impl<F: Field> Index<usize> for DenseMultilinearExtension<F> {
+    type Output = F;
+
+    fn index(&self, index: usize) -> &F {
+        &self.evaluations[index]
+    }
+}
+
+impl<F: Field> fmt::Debug for DenseMultilinearExtension<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "DenseML(nv = {}, evaluations = [", self.num_vars)?;
+        for (i, e) in self.evaluations.iter().take(4).enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{e}")?;
+        }
+        if self.evaluations.len() > 4 {
+            write!(f, ", ...")?;
+        }
+        write!(f, "])")
+    }
+}
+
+impl<F: Field> Zero for DenseMultilinearExtension<F> {
+    fn zero() -> Self {
+        Self { evaluations: vec![F::zero()], num_vars: 0 }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.evaluations.iter().all(Zero::is_zero)
+    }
+}
+
+impl<'a, F: Field> Add<&'a DenseMultilinearExtension<F>> for &DenseMultilinearExtension<F> {
+    type Output = DenseMultilinearExtension<F>;
+
+    fn add(self, other: &'a DenseMultilinearExtension<F>) -> DenseMultilinearExtension<F> {
+        self.assert_compatible(other);
+        if self.is_zero() {
+            return other.clone();
+        }
+        if other.is_zero() {
+            return self.clone();
+        }
+        let mut result = self.clone();
+        cfg_iter_mut!(result.evaluations).zip(&other.evaluations).for_each(|(a, b)| *a += b);
+        result
+    }
+}
+
+impl<F: Field> Add for DenseMultilinearExtension<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        &self + &other
+    }
+}
+
+impl<'a, F: Field> Sub<&'a DenseMultilinearExtension<F>> for &DenseMultilinearExtension<F> {
+    type Output = DenseMultilinearExtension<F>;
+
+    fn sub(self, other: &'a DenseMultilinearExtension<F>) -> DenseMultilinearExtension<F> {
+        self + &(-other.clone())
+    }
+}
+
+impl<F: Field> Sub for DenseMultilinearExtension<F> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        &self - &other
+    }
+}
+
+impl<'a, F: Field> AddAssign<&'a Self> for DenseMultilinearExtension<F> {
+    fn add_assign(&mut self, other: &'a Self) {
+        *self = &*self + other;
+    }
+}
+
+impl<'a, F: Field> AddAssign<(F, &'a Self)> for DenseMultilinearExtension<F> {
+    fn add_assign(&mut self, (f, other): (F, &'a Self)) {
+        *self = &*self + &(other * f);
+    }
+}
+
+impl<'a, F: Field> SubAssign<&'a Self> for DenseMultilinearExtension<F> {
+    fn sub_assign(&mut self, other: &'a Self) {
+        *self = &*self - other;
+    }
+}
+
+impl<F: Field> Neg for DenseMultilinearExtension<F> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self {
+        cfg_iter_mut!(self.evaluations).for_each(|e| *e = -*e);
+        self
+    }
+}
+
+impl<F: Field> Mul<F> for &DenseMultilinearExtension<F> {
+    type Output = DenseMultilinearExtension<F>;
+
+    fn mul(self, elem: F) -> DenseMultilinearExtension<F> {
+        let mut result = self.clone();
+        cfg_iter_mut!(result.evaluations).for_each(|e| *e *= elem);
+        result
+    }
+}
+
+impl<F: Field> Mul<F> for DenseMultilinearExtension<F> {
+    type Output = Self;
+
+    fn mul(self, elem: F) -> Self {
+        &self * elem
+    }
+}
+
+impl<F: Field> ArkSerialize for DenseMultilinearExtension<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.num_vars.serialize_with_mode(&mut writer, compress)?;
+        self.evaluations.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.num_vars.serialized_size(compress) + self.evaluations.serialized_size(compress)
+    }
+}
+
+impl<F: Field> Valid for DenseMultilinearExtension<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        let well_formed =
+            self.num_vars < usize::BITS as usize && self.evaluations.len() == 1 << self.num_vars;
+        if !well_formed {
+            return Err(SerializationError::InvalidData);
+        }
+        self.evaluations.check()
+    }
+}
+
+impl<F: Field> ArkDeserialize for DenseMultilinearExtension<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let num_vars = usize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let evaluations = Vec::deserialize_with_mode(&mut reader, compress, Validate::No)?;
+        let mle = Self { evaluations, num_vars };
+        if validate == Validate::Yes {
+            mle.check()?;
+        }
+        Ok(mle)
+    }
+}
@@ -0,0 +1,350 @@
+use super::{
+    DenseMultilinearExtension, MultilinearExtension, Polynomial, relabel_windows, swap_bits,
+};
+use crate::{field::traits::Field, rand::Rng};
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+use fina_common::io::{Read, Write};
+use fina_serialize::{ArkDeserialize, ArkSerialize, Compress, SerializationError, Valid, Validate};
+use num_traits::Zero;
+
+/// Multilinear extension stored as its non-zero evaluations over the boolean
+/// hypercube, indexed as in [`MultilinearExtension`].
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct SparseMultilinearExtension<F: Field> {
+    /// Non-zero evaluations, keyed by their index in the hypercube.
+    pub evaluations: BTreeMap<usize, F>,
+    /// Number of variables of the extension.
+    pub num_vars: usize,
+}
+
+impl<F: Field> SparseMultilinearExtension<F> {
+    /// Builds an extension from `(index, evaluation)` pairs. Later pairs
+    /// overwrite earlier ones with the same index, and zero evaluations are
+    /// dropped.
+    ///
+    /// # Panics
+    ///
+    /// * If `num_vars` is not less than the bit size of `usize`.
+    /// * If an index is not less than `2^num_vars`.
+    pub fn from_evaluations<'a>(
+        num_vars: usize,
+        evaluations: impl IntoIterator<Item = &'a (usize, F)>,
+    ) -> Self {
+        assert!(
+            num_vars < usize::BITS as usize,
+            "num_vars should be less than the bit size of usize"
+        );
+        let mut evaluations: BTreeMap<_, _> = evaluations.into_iter().copied().collect();
+        evaluations.retain(|_, e| !e.is_zero());
+        assert!(
+            evaluations.last_key_value().is_none_or(|(i, _)| *i >> num_vars == 0),
+            "index should be less than 2^num_vars"
+        );
+        Self { evaluations, num_vars }
+    }
+
+    /// Returns a random extension in `num_vars` variables with about
+    /// `num_nonzero` non-zero evaluations.
+    pub fn rand_with_config<R: Rng>(num_vars: usize, num_nonzero: usize, rng: &mut R) -> Self {
+        assert!(num_nonzero <= 1 << num_vars, "too many non-zero evaluations");
+        let mut evaluations = BTreeMap::new();
+        while evaluations.len() < num_nonzero {
+            let index = rng.random_range(0..1usize << num_vars);
+            let value = F::rand(rng);
+            if !value.is_zero() {
+                evaluations.insert(index, value);
+            }
+        }
+        Self { evaluations, num_vars }
+    }
+
+    /// Returns the dense representation of `self`.
+    pub fn to_dense(&self) -> DenseMultilinearExtension<F> {
+        DenseMultilinearExtension::from_evaluations_vec(self.num_vars, self.to_evaluations())
+    }
+
+    /// Returns the evaluation at `index` of the boolean hypercube.
+    pub fn get(&self, index: usize) -> F {
+        self.evaluations.get(&index).copied().unwrap_or_default()
+    }
+
+    /// Fixes the variable at `bit` of the indices to `r`, removing that bit
+    /// from the indices.
+    fn fix_bit(evaluations: &BTreeMap<usize, F>, bit: usize, r: F) -> BTreeMap<usize, F> {
+        let low_mask = (1 << bit) - 1;
+        let mut result = BTreeMap::new();
+        for (&index, &value) in evaluations {
+            let weight = if (index >> bit) & 1 == 1 { r } else { F::ONE - r };
+            let new_index = (index & low_mask) | ((index >> (bit + 1)) << bit);
+            *result.entry(new_index).or_insert_with(F::zero) += value * weight;
+        }
+        result.retain(|_, value| !value.is_zero());
+        result
+    }
+
+    /// Checks that `self` and `other` have the same number of variables,
+    /// unless one of them is zero.
+    fn assert_compatible(&self, other: &Self) {
+        assert!(
+            self.is_zero() || other.is_zero() || self.num_vars == other.num_vars,
+            "extensions should have the same number of variables"
+        );
+    }
+}
+
+impl<F: Field> MultilinearExtension<F> for SparseMultilinearExtension<F> {
+    fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Returns a random extension with `2^(num_vars / 2)` non-zero
+    /// evaluations.
+    fn rand<R: Rng>(num_vars: usize, rng: &mut R) -> Self {
+        Self::rand_with_config(num_vars, 1 << (num_vars / 2), rng)
+    }
+
+    fn relabel(&self, a: usize, b: usize, k: usize) -> Self {
+        let (a, b) = relabel_windows(a, b, k, self.num_vars);
+        let evaluations = self
+            .evaluations
+            .iter()
+            .map(|(&index, &value)| (swap_bits(index, a, b, k), value))
+            .collect();
+        Self { evaluations, num_vars: self.num_vars }
+    }
+
+    fn fix_variables(&self, partial_point: &[F]) -> Self {
+        assert!(
+            partial_point.len() <= self.num_vars,
+            "partial point should have at most num_vars coordinates"
+        );
+        let evaluations = partial_point
+            .iter()
+            .fold(self.evaluations.clone(), |evals, r| Self::fix_bit(&evals, 0, *r));
+        Self { evaluations, num_vars: self.num_vars - partial_point.len() }
+    }
+
+    fn fix_high_variables(&self, partial_point: &[F]) -> Self {
+        assert!(
+            partial_point.len() <= self.num_vars,
+            "partial point should have at most num_vars coordinates"
+        );
+        let num_vars = self.num_vars - partial_point.len();
+        // Each fixed variable is removed from the indices, so that the next
+        // one takes its place at bit `num_vars`.
+        let evaluations = partial_point
+            .iter()
+            .fold(self.evaluations.clone(), |evals, r| Self::fix_bit(&evals, num_vars, *r));
+        Self { evaluations, num_vars }
+    }
+
+    fn to_evaluations(&self) -> Vec<F> {
+        let mut evaluations = vec![F::zero(); 1 << self.num_vars];
+        for (&index, &value) in &self.evaluations {
+            evaluations[index] = value;
+        }
+        evaluations
+    }
+}
+
+impl<F: Field> Polynomial<F> for SparseMultilinearExtension<F> {
+    type Point = Vec<F>;
+
+    fn degree(&self) -> usize {
+        self.num_vars
+    }
+
+    /// # Panics
+    ///
+    /// * If `point` does not have `num_vars` coordinates.
+    fn evaluate(&self, point: &Vec<F>) -> F {
+        assert_eq!(point.len(), self.num_vars, "point should have num_vars coordinates");
+        self.fix_variables(point).get(0)
+    }
+}
+
+impl<F: Field> From<SparseMultilinearExtension<F>> for DenseMultilinearExtension<F> {
+    fn from(other: SparseMultilinearExtension<F>) -> Self {
+        other.to_dense()
+    }
+}
+
+impl<F: Field> From<DenseMultilinearExtension<F>> for SparseMultilinearExtension<F> {
+    fn from(other: DenseMultilinearExtension<F>) -> Self {
+        let evaluations = other
+            .evaluations
+            .into_iter()
+            .enumerate()
+            .filter(|(_, value)| !value.is_zero())
+            .collect();
+        Self { evaluations, num_vars: other.num_vars }
+    }
+}
+
+impl<F: Field> fmt::Debug for SparseMultilinearExtension<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(f, "SparseML(nv = {}, evaluations = [", self.num_vars)?;
+        for (i, (index, e)) in self.evaluations.iter().take(4).enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "({index}, {e})")?;
+        }
+        if self.evaluations.len() > 4 {
+            write!(f, ", ...")?;
+        }
+        write!(f, "])")
+    }
+}
+
+impl<F: Field> Zero for SparseMultilinearExtension<F> {
+    fn zero() -> Self {
+        Self { evaluations: BTreeMap::new(), num_vars: 0 }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.evaluations.values().all(Zero::is_zero)
+    }
+}
+
+impl<'a, F: Field> Add<&'a SparseMultilinearExtension<F>> for &SparseMultilinearExtension<F> {
+    type Output = SparseMultilinearExtension<F>;
+
+    fn add(self, other: &'a SparseMultilinearExtension<F>) -> SparseMultilinearExtension<F> {
+        self.assert_compatible(other);
+        if self.is_zero() {
+            return other.clone();
+        }
+        if other.is_zero() {
+            return self.clone();
+        }
+        let mut result = self.clone();
+        for (&index, &value) in &other.evaluations {
+            *result.evaluations.entry(index).or_insert_with(F::zero) += value;
+        }
+        result.evaluations.retain(|_, value| !value.is_zero());
+        result
+    }
+}
+
+impl<F: Field> Add for SparseMultilinearExtension<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        &self + &other
+    }
+}
+
+impl<'a, F: Field> Sub<&'a SparseMultilinearExtension<F>> for &SparseMultilinearExtension<F> {
+    type Output = SparseMultilinearExtension<F>;
+
+    fn sub(self, other: &'a SparseMultilinearExtension<F>) -> SparseMultilinearExtension<F> {
+        self + &(-other.clone())
+    }
+}
+
+impl<F: Field> Sub for SparseMultilinearExtension<F> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        &self - &other
+    }
+}
+
+impl<'a, F: Field> AddAssign<&'a Self> for SparseMultilinearExtension<F> {
+    fn add_assign(&mut self, other: &'a Self) {
+        *self = &*self + other;
+    }
+}
+
+impl<'a, F: Field> AddAssign<(F, &'a Self)> for SparseMultilinearExtension<F> {
+    fn add_assign(&mut self, (f, other): (F, &'a Self)) {
+        *self = &*self + &(other * f);
+    }
+}
+
+impl<'a, F: Field> SubAssign<&'a Self> for SparseMultilinearExtension<F> {
+    fn sub_assign(&mut self, other: &'a Self) {
+        *self = &*self - other;
+    }
+}
+
+impl<F: Field> Neg for SparseMultilinearExtension<F> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self {
+        self.evaluations.values_mut().for_each(|e| *e = -*e);
+        self
+    }
+}
+
+impl<F: Field> Mul<F> for &SparseMultilinearExtension<F> {
+    type Output = SparseMultilinearExtension<F>;
+
+    fn mul(self, elem: F) -> SparseMultilinearExtension<F> {
+        if elem.is_zero() {
+            return SparseMultilinearExtension {
+                evaluations: BTreeMap::new(),
+                num_vars: self.num_vars,
+            };
+        }
+        let mut result = self.clone();
+        result.evaluations.values_mut().for_each(|e| *e *= elem);
+        result
+    }
+}
+
+impl<F: Field> Mul<F> for SparseMultilinearExtension<F> {
+    type Output = Self;
+
+    fn mul(self, elem: F) -> Self {
+        &self * elem
+    }
+}
+
+impl<F: Field> ArkSerialize for SparseMultilinearExtension<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        self.num_vars.serialize_with_mode(&mut writer, compress)?;
+        self.evaluations.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.num_vars.serialized_size(compress) + self.evaluations.serialized_size(compress)
+    }
+}
+
+impl<F: Field> Valid for SparseMultilinearExtension<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        let well_formed = self.num_vars < usize::BITS as usize
+            && self.evaluations.last_key_value().is_none_or(|(i, _)| *i >> self.num_vars == 0)
+            && self.evaluations.values().all(|value| !value.is_zero());
+        if !well_formed {
+            return Err(SerializationError::InvalidData);
+        }
+        self.evaluations.check()
+    }
+}
+
+impl<F: Field> ArkDeserialize for SparseMultilinearExtension<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let num_vars = usize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let evaluations = BTreeMap::deserialize_with_mode(&mut reader, compress, Validate::No)?;
+        let mle = Self { evaluations, num_vars };
+        if validate == Validate::Yes {
+            mle.check()?;
+        }
+        Ok(mle)
+    }
+}
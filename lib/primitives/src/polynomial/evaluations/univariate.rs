@@ -29,6 +29,114 @@ impl<F: FftField, D: EvaluationDomain<F>> Evaluations<F, D> {
     pub const fn domain(&self) -> D {
         self.domain
     }
+
+    /// Evaluates the interpolating polynomial of `self` at `z` with the
+    /// barycentric formula, without interpolating first. `z` may be in the
+    /// domain, and the domain may be a coset.
+    ///
+    /// # Panics
+    ///
+    /// * If there is not one evaluation per element of the domain.
+    pub fn evaluate_at(&self, z: F) -> F {
+        self.assert_size();
+        let lagrange_coefficients = self.domain.evaluate_all_lagrange_coefficients(z);
+        self.evals.iter().zip(&lagrange_coefficients).map(|(e, l)| *e * l).sum()
+    }
+
+    /// Evaluates the interpolating polynomial of `self` at every point of
+    /// `points`, sharing a single batch inversion between all of them.
+    ///
+    /// With `Z` the vanishing polynomial of a domain `{ω_i}` of size `n` and
+    /// offset `c`, `p(z) = Z(z) / (n * c^n) * Σ e_i * ω_i / (z - ω_i)`.
+    ///
+    /// # Panics
+    ///
+    /// * If there is not one evaluation per element of the domain.
+    pub fn evaluate_at_points(&self, points: &[F]) -> Vec<F> {
+        self.assert_size();
+        let elements: Vec<F> = self.domain.elements().collect();
+        let mut denominators: Vec<F> =
+            points.iter().flat_map(|z| elements.iter().map(move |w| *z - w)).collect();
+        batch_inversion(&mut denominators);
+
+        let scale = (self.domain.size_as_field_element() * self.domain.coset_offset_pow_size())
+            .inverse()
+            .unwrap();
+        points
+            .iter()
+            .zip(denominators.chunks(elements.len()))
+            .map(|(z, inverses)| {
+                let vanishing = self.domain.evaluate_vanishing_polynomial(*z);
+                if vanishing.is_zero() {
+                    // `z` is in the domain, and `batch_inversion` left its
+                    // denominator at zero.
+                    let i = inverses.iter().position(|inv| inv.is_zero()).unwrap();
+                    return self.evals[i];
+                }
+                let sum: F = (self.evals.iter().zip(&elements).zip(inverses))
+                    .map(|((e, w), inv)| *e * w * inv)
+                    .sum();
+                sum * vanishing * scale
+            })
+            .collect()
+    }
+
+    /// Returns the evaluations over `self.domain().lde_coset(blowup, shift)`
+    /// of the interpolating polynomial of `self`.
+    ///
+    /// Returns `None` if there is no domain of size
+    /// `blowup * self.domain().size()`.
+    pub fn lde(&self, blowup: usize, shift: F) -> Option<Self> {
+        let coset = self.domain.lde_coset(blowup, shift)?;
+        Some(Self::from_vec_and_domain(coset.fft(&self.domain.ifft(&self.evals)), coset))
+    }
+
+    /// Computes the [`lde`](Self::lde) of every column, all of which should
+    /// be over the same domain. The LDE coset is built once for all columns,
+    /// and so are the twiddle factors of the [batch FFTs] over the domain and
+    /// over the coset.
+    ///
+    /// [batch FFTs]: EvaluationDomain::batch_fft_in_place
+    ///
+    /// # Panics
+    ///
+    /// * If the columns are over different domains.
+    pub fn batch_lde(columns: &[Self], blowup: usize, shift: F) -> Option<Vec<Self>> {
+        let Some(first) = columns.first() else {
+            return Some(Vec::new());
+        };
+        let domain = first.domain;
+        assert!(columns.iter().all(|c| c.domain == domain), "domains are unequal");
+        let coset = domain.lde_coset(blowup, shift)?;
+
+        let mut columns: Vec<_> = columns.iter().map(|c| c.evals.clone()).collect();
+        domain.batch_ifft_in_place(&mut columns);
+        coset.batch_fft_in_place(&mut columns);
+        Some(columns.into_iter().map(|evals| Self::from_vec_and_domain(evals, coset)).collect())
+    }
+
+    /// Divides `self`, over a coset, pointwise by the vanishing polynomial of
+    /// `vanishing_domain`.
+    ///
+    /// # Panics
+    ///
+    /// * If the domain of `self` intersects `vanishing_domain`.
+    pub fn quotient_over_coset(&self, vanishing_domain: &D) -> Self {
+        let inverses = vanishing_domain.vanishing_polynomial_inverses_over(&self.domain);
+        let mut result = self.clone();
+        cfg_iter_mut!(result.evals)
+            .enumerate()
+            .for_each(|(i, e)| *e *= inverses[i % inverses.len()]);
+        result
+    }
+
+    fn assert_size(&self) {
+        assert_eq!(
+            self.evals.len(),
+            self.domain.size(),
+            "there should be one evaluation per element of the domain"
+        );
+    }
 }
 
 impl<F: FftField, D: EvaluationDomain<F>> Index<usize> for Evaluations<F, D> {
@@ -129,3 +237,119 @@ impl<'a, F: FftField, D: EvaluationDomain<F>> DivAssign<&'a Self> for Evaluation
         cfg_iter_mut!(self.evals).zip(&other_copy.evals).for_each(|(a, b)| *a *= b);
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        UniformRand,
+        field::FpBN256 as Fr,
+        polynomial::{domain::Radix2EvaluationDomain, traits::Polynomial},
+        test_rng,
+    };
+
+    fn check_barycentric(domain: GeneralEvaluationDomain<Fr>) {
+        let rng = &mut test_rng();
+        let poly = DensePolynomial::<Fr>::rand(domain.size() - 1, rng);
+        let evals = Evaluations::from_vec_and_domain(domain.fft(&poly.coeffs), domain);
+
+        let mut points: Vec<_> = (0..5).map(|_| Fr::rand(rng)).collect();
+        points.push(domain.element(3));
+        points.push(domain.element(0));
+        let expected: Vec<_> = points.iter().map(|z| poly.evaluate(z)).collect();
+
+        assert_eq!(evals.evaluate_at_points(&points), expected);
+        for (z, expected) in points.iter().zip(&expected) {
+            assert_eq!(evals.evaluate_at(*z), *expected);
+        }
+        assert!(evals.evaluate_at_points(&[]).is_empty());
+    }
+
+    #[test]
+    fn barycentric() {
+        for size in [1, 8, 64] {
+            let domain = GeneralEvaluationDomain::<Fr>::new(size).unwrap();
+            check_barycentric(domain);
+            check_barycentric(domain.get_coset(Fr::GENERATOR).unwrap());
+        }
+    }
+
+    #[test]
+    fn lde() {
+        let rng = &mut test_rng();
+        let domain = GeneralEvaluationDomain::<Fr>::new(16).unwrap();
+        let polys: Vec<_> = (0..3).map(|_| DensePolynomial::<Fr>::rand(15, rng)).collect();
+        let columns: Vec<_> = polys
+            .iter()
+            .map(|p| Evaluations::from_vec_and_domain(domain.fft(&p.coeffs), domain))
+            .collect();
+
+        let ldes = Evaluations::batch_lde(&columns, 4, Fr::GENERATOR).unwrap();
+        for ((poly, column), lde) in polys.iter().zip(&columns).zip(&ldes) {
+            let coset = lde.domain();
+            assert_eq!(coset.size(), 64);
+            assert_eq!(coset.coset_offset(), Fr::GENERATOR);
+            assert_eq!(column.lde(4, Fr::GENERATOR).as_ref(), Some(lde));
+            assert!(coset.elements().zip(&lde.evals).all(|(x, e)| poly.evaluate(&x) == *e));
+            assert_eq!(lde.interpolate_by_ref(), *poly);
+        }
+
+        assert!(columns[0].lde(3, Fr::GENERATOR).is_none());
+        assert_eq!(Evaluations::<Fr>::batch_lde(&[], 4, Fr::GENERATOR), Some(Vec::new()));
+    }
+
+    #[test]
+    fn batch_lde_matches_lde() {
+        let rng = &mut test_rng();
+        let domain = Radix2EvaluationDomain::<Fr>::new(256).unwrap();
+        for domain in [domain, domain.get_coset(Fr::from(7u8)).unwrap()] {
+            let columns: Vec<_> = (0..5)
+                .map(|_| {
+                    let evals = (0..domain.size()).map(|_| Fr::rand(rng)).collect();
+                    Evaluations::from_vec_and_domain(evals, domain)
+                })
+                .collect();
+            for blowup in [1, 2, 8] {
+                let ldes = Evaluations::batch_lde(&columns, blowup, Fr::GENERATOR).unwrap();
+                assert_eq!(ldes.len(), columns.len());
+                for (column, lde) in columns.iter().zip(&ldes) {
+                    assert_eq!(column.lde(blowup, Fr::GENERATOR).as_ref(), Some(lde));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn quotient_over_coset() {
+        let rng = &mut test_rng();
+        let domain = GeneralEvaluationDomain::<Fr>::new(16).unwrap();
+        let coset = domain.lde_coset(4, Fr::GENERATOR).unwrap();
+        let quotient = DensePolynomial::<Fr>::rand(47, rng);
+        let quotient_evals = coset.fft(&quotient.coeffs);
+
+        // Evaluations of `quotient * Z_H` over the coset.
+        let evals = coset
+            .elements()
+            .zip(&quotient_evals)
+            .map(|(x, q)| *q * domain.evaluate_vanishing_polynomial(x))
+            .collect();
+        let evals = Evaluations::from_vec_and_domain(evals, coset);
+        assert_eq!(evals.quotient_over_coset(&domain).evals, quotient_evals);
+        assert_eq!(domain.vanishing_polynomial_inverses_over(&coset).len(), 4);
+    }
+
+    #[test]
+    #[should_panic = "coset should be disjoint from the vanishing domain"]
+    fn quotient_over_intersecting_coset() {
+        let domain = GeneralEvaluationDomain::<Fr>::new(16).unwrap();
+        let coset = domain.lde_coset(2, Fr::ONE).unwrap();
+        let _ = Evaluations::zero(coset).quotient_over_coset(&domain);
+    }
+
+    #[test]
+    #[should_panic = "there should be one evaluation per element of the domain"]
+    fn barycentric_wrong_size() {
+        let domain = GeneralEvaluationDomain::<Fr>::new(8).unwrap();
+        let _ = Evaluations::from_vec_and_domain(vec![Fr::ZERO; 4], domain).evaluate_at(Fr::ONE);
+    }
+}
@@ -0,0 +1,113 @@
+mod sparse;
+
+use super::traits::*;
+use crate::{cfg_into_iter, field::traits::Field};
+use core::{cmp::Ordering, fmt, ops::Deref};
+pub use sparse::SparsePolynomial;
+
+/// A monomial `x_{v_1}^{p_1} * ... * x_{v_k}^{p_k}`, stored as the list of
+/// `(v_i, p_i)` pairs sorted by variable, with repeated variables merged and
+/// zero powers dropped.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct SparseTerm(Vec<(usize, usize)>);
+
+impl SparseTerm {
+    /// Merges the powers of repeated variables of a term sorted by variable.
+    fn combine(term: &[(usize, usize)]) -> Vec<(usize, usize)> {
+        let mut term_dedup: Vec<(usize, usize)> = Vec::new();
+        for &(var, pow) in term {
+            match term_dedup.last_mut() {
+                Some(prev) if prev.0 == var => prev.1 += pow,
+                _ => term_dedup.push((var, pow)),
+            }
+        }
+        term_dedup
+    }
+
+    /// Returns the product of `self` and `other`.
+    pub fn mul(&self, other: &Self) -> Self {
+        Self::new(self.iter().chain(other.iter()).copied().collect())
+    }
+
+    /// Returns the largest variable of the term plus one, i.e. the number of
+    /// variables a polynomial needs to contain it.
+    pub fn num_vars(&self) -> usize {
+        self.last().map_or(0, |(var, _)| var + 1)
+    }
+}
+
+impl Term for SparseTerm {
+    fn new(mut term: Vec<(usize, usize)>) -> Self {
+        term.retain(|(_, pow)| *pow != 0);
+        if term.len() > 1 {
+            term.sort_by(|(v1, _), (v2, _)| v1.cmp(v2));
+            term = Self::combine(&term);
+        }
+        Self(term)
+    }
+
+    fn degree(&self) -> usize {
+        self.iter().map(|(_, pow)| pow).sum()
+    }
+
+    fn vars(&self) -> Vec<usize> {
+        self.iter().map(|(var, _)| *var).collect()
+    }
+
+    fn powers(&self) -> Vec<usize> {
+        self.iter().map(|(_, pow)| *pow).collect()
+    }
+
+    fn is_constant(&self) -> bool {
+        self.is_empty() || self.degree() == 0
+    }
+
+    fn evaluate<F: Field>(&self, point: &[F]) -> F {
+        cfg_into_iter!(self).map(|(var, pow)| point[*var].pow([*pow as u64].as_slice())).product()
+    }
+}
+
+impl fmt::Debug for SparseTerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        for (var, pow) in self.iter() {
+            if *pow == 1 {
+                write!(f, " * x_{var}")?;
+            } else {
+                write!(f, " * x_{var}^{pow}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Deref for SparseTerm {
+    type Target = [(usize, usize)];
+
+    fn deref(&self) -> &[(usize, usize)] {
+        &self.0
+    }
+}
+
+impl PartialOrd for SparseTerm {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Terms are ordered by degree, then lexicographically, so that `x_0 > x_1`.
+impl Ord for SparseTerm {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.degree() != other.degree() {
+            return self.degree().cmp(&other.degree());
+        }
+        for (cur, other) in self.iter().zip(other.iter()) {
+            if cur.0 != other.0 {
+                return other.0.cmp(&cur.0);
+            }
+            if cur.1 != other.1 {
+                return cur.1.cmp(&other.1);
+            }
+        }
+        Ordering::Equal
+    }
+}
@@ -0,0 +1,465 @@
+use super::{DenseMVPolynomial, Polynomial, SparseTerm, Term};
+use crate::{field::traits::Field, rand::Rng};
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+use num_traits::Zero;
+
+/// A multivariate polynomial in `num_vars` variables, stored as a list of
+/// `(coefficient, term)` pairs.
+///
+/// Terms are kept in canonical form: sorted by the order of `T`, with like
+/// terms merged and zero coefficients removed, so that equal polynomials have
+/// equal representations.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct SparsePolynomial<F: Field, T: Term = SparseTerm> {
+    pub num_vars: usize,
+    terms: Vec<(F, T)>,
+}
+
+impl<F: Field, T: Term> SparsePolynomial<F, T> {
+    /// Sorts `terms`, merges like terms and removes zero coefficients.
+    fn canonicalize(mut terms: Vec<(F, T)>) -> Vec<(F, T)> {
+        terms.sort_by(|(_, t1), (_, t2)| t1.cmp(t2));
+        let mut result: Vec<(F, T)> = Vec::with_capacity(terms.len());
+        for (coeff, term) in terms {
+            match result.last_mut() {
+                Some((prev_coeff, prev_term)) if *prev_term == term => *prev_coeff += coeff,
+                _ => {
+                    if result.last().is_some_and(|(c, _)| c.is_zero()) {
+                        result.pop();
+                    }
+                    result.push((coeff, term));
+                },
+            }
+        }
+        if result.last().is_some_and(|(c, _)| c.is_zero()) {
+            result.pop();
+        }
+        result
+    }
+}
+
+impl<F: Field> SparsePolynomial<F, SparseTerm> {
+    /// Evaluates the variables of `assignment`, given as `(variable, value)`
+    /// pairs, and returns the polynomial in the remaining variables.
+    ///
+    /// Variables keep their indices, so the result has the same `num_vars`.
+    ///
+    /// # Panics
+    ///
+    /// * If a variable of `assignment` is not less than `num_vars`.
+    pub fn partial_evaluate(&self, assignment: &[(usize, F)]) -> Self {
+        let mut values = vec![None; self.num_vars];
+        for &(var, value) in assignment {
+            assert!(var < self.num_vars, "variable should be less than the number of variables");
+            values[var] = Some(value);
+        }
+
+        let terms = self
+            .terms
+            .iter()
+            .map(|(coeff, term)| {
+                let mut coeff = *coeff;
+                let mut remaining = Vec::with_capacity(term.len());
+                for &(var, pow) in term.iter() {
+                    match values[var] {
+                        Some(value) => coeff *= value.pow([pow as u64].as_slice()),
+                        None => remaining.push((var, pow)),
+                    }
+                }
+                (coeff, SparseTerm::new(remaining))
+            })
+            .collect();
+        Self::from_coefficients_vec(self.num_vars, terms)
+    }
+
+    /// Fixes the first `values.len()` variables to `values`, and returns the
+    /// polynomial in the remaining `num_vars - values.len()` variables,
+    /// renumbered from `0`.
+    ///
+    /// # Panics
+    ///
+    /// * If more values than variables are given.
+    pub fn fix_variables(&self, values: &[F]) -> Self {
+        assert!(
+            values.len() <= self.num_vars,
+            "number of values should be at most the number of variables"
+        );
+        let k = values.len();
+        let terms = self
+            .terms
+            .iter()
+            .map(|(coeff, term)| {
+                let mut coeff = *coeff;
+                let mut remaining = Vec::with_capacity(term.len());
+                for &(var, pow) in term.iter() {
+                    if var < k {
+                        coeff *= values[var].pow([pow as u64].as_slice());
+                    } else {
+                        remaining.push((var - k, pow));
+                    }
+                }
+                (coeff, SparseTerm::new(remaining))
+            })
+            .collect();
+        Self::from_coefficients_vec(self.num_vars - k, terms)
+    }
+}
+
+impl<F: Field> Polynomial<F> for SparsePolynomial<F, SparseTerm> {
+    type Point = Vec<F>;
+
+    /// Returns the total degree of the polynomial.
+    fn degree(&self) -> usize {
+        self.terms.iter().map(|(_, term)| term.degree()).max().unwrap_or(0)
+    }
+
+    /// Evaluates `self` at `point`.
+    ///
+    /// # Panics
+    ///
+    /// * If `point` has fewer than `num_vars` coordinates.
+    fn evaluate(&self, point: &Vec<F>) -> F {
+        assert!(point.len() >= self.num_vars, "invalid evaluation domain");
+        self.terms.iter().map(|(coeff, term)| *coeff * term.evaluate(point)).sum()
+    }
+}
+
+impl<F: Field> DenseMVPolynomial<F> for SparsePolynomial<F, SparseTerm> {
+    type Term = SparseTerm;
+
+    fn terms(&self) -> &[(F, SparseTerm)] {
+        &self.terms
+    }
+
+    fn num_vars(&self) -> usize {
+        self.num_vars
+    }
+
+    /// Returns a random polynomial of total degree `d` in `num_vars`
+    /// variables, with a random coefficient for every term of degree at most
+    /// `d`.
+    fn rand<R: Rng>(d: usize, num_vars: usize, rng: &mut R) -> Self {
+        let mut random_terms = vec![(F::rand(rng), SparseTerm::new(vec![]))];
+        for var in 0..num_vars {
+            let mut cur_terms = Vec::new();
+            for (_, term) in &random_terms {
+                for pow in 1..=d - term.degree() {
+                    let mut new_term = term.to_vec();
+                    new_term.push((var, pow));
+                    cur_terms.push((F::rand(rng), SparseTerm::new(new_term)));
+                }
+            }
+            random_terms.extend(cur_terms);
+        }
+        Self::from_coefficients_vec(num_vars, random_terms)
+    }
+
+    /// # Panics
+    ///
+    /// * If a term uses a variable that is not less than `num_vars`.
+    fn from_coefficients_vec(num_vars: usize, terms: Vec<(F, SparseTerm)>) -> Self {
+        let terms = Self::canonicalize(terms);
+        assert!(
+            terms.iter().all(|(_, term)| term.num_vars() <= num_vars),
+            "invalid number of variables"
+        );
+        Self { num_vars, terms }
+    }
+}
+
+impl<F: Field, T: Term> fmt::Debug for SparsePolynomial<F, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        for (coeff, term) in self.terms.iter().filter(|(c, _)| !c.is_zero()) {
+            if term.is_constant() {
+                write!(f, "\n{coeff:?}")?;
+            } else {
+                write!(f, " + \n{coeff:?}{term:?}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<F: Field, T: Term> Zero for SparsePolynomial<F, T> {
+    fn zero() -> Self {
+        Self { num_vars: 0, terms: Vec::new() }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.terms.is_empty() || self.terms.iter().all(|(c, _)| c.is_zero())
+    }
+}
+
+impl<'a, F: Field, T: Term> Add<&'a SparsePolynomial<F, T>> for &SparsePolynomial<F, T> {
+    type Output = SparsePolynomial<F, T>;
+
+    fn add(self, other: &'a SparsePolynomial<F, T>) -> SparsePolynomial<F, T> {
+        let mut terms = Vec::with_capacity(self.terms.len() + other.terms.len());
+        let mut self_iter = self.terms.iter().peekable();
+        let mut other_iter = other.terms.iter().peekable();
+        loop {
+            let which = match (self_iter.peek(), other_iter.peek()) {
+                (Some((_, t1)), Some((_, t2))) => t1.cmp(t2),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => break,
+            };
+            let (coeff, term) = match which {
+                Ordering::Less => self_iter.next().unwrap().clone(),
+                Ordering::Greater => other_iter.next().unwrap().clone(),
+                Ordering::Equal => {
+                    let (c1, term) = self_iter.next().unwrap();
+                    let (c2, _) = other_iter.next().unwrap();
+                    (*c1 + c2, term.clone())
+                },
+            };
+            if !coeff.is_zero() {
+                terms.push((coeff, term));
+            }
+        }
+        SparsePolynomial { num_vars: self.num_vars.max(other.num_vars), terms }
+    }
+}
+
+impl<F: Field, T: Term> Add for SparsePolynomial<F, T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        &self + &other
+    }
+}
+
+impl<'a, F: Field, T: Term> Sub<&'a SparsePolynomial<F, T>> for &SparsePolynomial<F, T> {
+    type Output = SparsePolynomial<F, T>;
+
+    fn sub(self, other: &'a SparsePolynomial<F, T>) -> SparsePolynomial<F, T> {
+        self + &(-other.clone())
+    }
+}
+
+impl<F: Field, T: Term> Sub for SparsePolynomial<F, T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        &self - &other
+    }
+}
+
+impl<'a, F: Field, T: Term> AddAssign<&'a Self> for SparsePolynomial<F, T> {
+    fn add_assign(&mut self, other: &'a Self) {
+        *self = &*self + other;
+    }
+}
+
+impl<'a, F: Field, T: Term> AddAssign<(F, &'a Self)> for SparsePolynomial<F, T> {
+    fn add_assign(&mut self, (f, other): (F, &'a Self)) {
+        *self = &*self + &(other * f);
+    }
+}
+
+impl<'a, F: Field, T: Term> SubAssign<&'a Self> for SparsePolynomial<F, T> {
+    fn sub_assign(&mut self, other: &'a Self) {
+        *self = &*self - other;
+    }
+}
+
+impl<F: Field, T: Term> Neg for SparsePolynomial<F, T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self {
+        for (coeff, _) in &mut self.terms {
+            *coeff = -*coeff;
+        }
+        self
+    }
+}
+
+impl<F: Field, T: Term> Mul<F> for &SparsePolynomial<F, T> {
+    type Output = SparsePolynomial<F, T>;
+
+    #[inline]
+    fn mul(self, elem: F) -> SparsePolynomial<F, T> {
+        if self.is_zero() || elem.is_zero() {
+            SparsePolynomial { num_vars: self.num_vars, terms: Vec::new() }
+        } else {
+            let mut result = self.clone();
+            result.terms.iter_mut().for_each(|(coeff, _)| *coeff *= elem);
+            result
+        }
+    }
+}
+
+impl<F: Field, T: Term> Mul<F> for SparsePolynomial<F, T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, elem: F) -> Self {
+        &self * elem
+    }
+}
+
+impl<'a, F: Field> Mul<&'a SparsePolynomial<F, SparseTerm>> for &SparsePolynomial<F, SparseTerm> {
+    type Output = SparsePolynomial<F, SparseTerm>;
+
+    fn mul(self, other: &'a SparsePolynomial<F, SparseTerm>) -> SparsePolynomial<F, SparseTerm> {
+        let num_vars = self.num_vars.max(other.num_vars);
+        if self.is_zero() || other.is_zero() {
+            return SparsePolynomial { num_vars, terms: Vec::new() };
+        }
+
+        let mut result = BTreeMap::new();
+        for (self_coeff, self_term) in &self.terms {
+            for (other_coeff, other_term) in &other.terms {
+                let coeff = *self_coeff * other_coeff;
+                result
+                    .entry(self_term.mul(other_term))
+                    .and_modify(|cur_coeff| *cur_coeff += coeff)
+                    .or_insert(coeff);
+            }
+        }
+        let terms = result
+            .into_iter()
+            .filter(|(_, coeff)| !coeff.is_zero())
+            .map(|(term, coeff)| (coeff, term))
+            .collect();
+        SparsePolynomial { num_vars, terms }
+    }
+}
+
+impl<F: Field> Mul for SparsePolynomial<F, SparseTerm> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        &self * &other
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{UniformRand, field::FpBN256 as Fr, test_rng};
+    use proptest::prelude::*;
+
+    type Poly = SparsePolynomial<Fr, SparseTerm>;
+
+    fn term(vars: &[(usize, usize)]) -> SparseTerm {
+        SparseTerm::new(vars.to_vec())
+    }
+
+    fn rand_point(num_vars: usize, rng: &mut impl Rng) -> Vec<Fr> {
+        (0..num_vars).map(|_| Fr::rand(rng)).collect()
+    }
+
+    #[test]
+    fn canonical_form() {
+        // `x_1 * x_0 * x_0 + 3 - x_0² * x_1 + x_2⁰`
+        let poly = Poly::from_coefficients_vec(
+            3,
+            vec![
+                (Fr::from(1u8), term(&[(1, 1), (0, 1), (0, 1)])),
+                (Fr::from(3u8), term(&[])),
+                (-Fr::from(1u8), term(&[(0, 2), (1, 1)])),
+                (Fr::from(1u8), term(&[(2, 0)])),
+            ],
+        );
+        assert_eq!(poly.terms(), &[(Fr::from(4u8), term(&[]))]);
+
+        let terms = [term(&[(0, 2)]), term(&[]), term(&[(1, 1)]), term(&[(0, 1), (1, 1)])];
+        let poly = Poly::from_coefficients_vec(
+            2,
+            terms.iter().cloned().map(|t| (Fr::from(1u8), t)).collect(),
+        );
+        let sorted: Vec<_> = poly.terms().iter().map(|(_, t)| t.clone()).collect();
+        assert_eq!(sorted, [term(&[]), term(&[(1, 1)]), term(&[(0, 1), (1, 1)]), term(&[(0, 2)])]);
+        assert_eq!(poly.degree(), 2);
+    }
+
+    #[test]
+    #[should_panic = "invalid number of variables"]
+    fn too_few_variables() {
+        let _ = Poly::from_coefficients_vec(2, vec![(Fr::from(1u8), term(&[(2, 1)]))]);
+    }
+
+    #[test]
+    fn rand_degree() {
+        let mut rng = test_rng();
+        for (d, num_vars) in [(0, 3), (1, 4), (3, 2), (4, 3)] {
+            let poly = Poly::rand(d, num_vars, &mut rng);
+            assert_eq!(poly.degree(), d);
+            assert_eq!(poly.num_vars(), num_vars);
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut rng = test_rng();
+        for _ in 0..10 {
+            let p = Poly::rand(3, 4, &mut rng);
+            let q = Poly::rand(2, 3, &mut rng);
+            let c = Fr::rand(&mut rng);
+            let point = rand_point(4, &mut rng);
+            let (p_eval, q_eval) = (p.evaluate(&point), q.evaluate(&point));
+
+            assert_eq!((&p + &q).evaluate(&point), p_eval + q_eval);
+            assert_eq!((&p - &q).evaluate(&point), p_eval - q_eval);
+            assert_eq!((-p.clone()).evaluate(&point), -p_eval);
+            assert_eq!((&p * c).evaluate(&point), p_eval * c);
+            assert_eq!((&p * &q).evaluate(&point), p_eval * q_eval);
+            assert_eq!((&p * &q).degree(), 5);
+            assert_eq!(&p * &q, &q * &p);
+
+            assert!((&p - &p).is_zero());
+            assert_eq!(&(&p + &q) - &q, p);
+
+            let mut r = p.clone();
+            r += (c, &q);
+            assert_eq!(r, &p + &(&q * c));
+            r -= &p;
+            assert_eq!(r.terms(), (&q * c).terms());
+        }
+    }
+
+    #[test]
+    fn partial_evaluation() {
+        let mut rng = test_rng();
+        for _ in 0..10 {
+            let p = Poly::rand(3, 4, &mut rng);
+            let point = rand_point(4, &mut rng);
+            let expected = p.evaluate(&point);
+
+            let partial = p.partial_evaluate(&[(1, point[1]), (3, point[3])]);
+            assert_eq!(partial.num_vars(), 4);
+            assert!(partial.terms().iter().all(|(_, t)| t.vars().iter().all(|v| v % 2 == 0)));
+            assert_eq!(partial.evaluate(&point), expected);
+
+            let fixed = p.fix_variables(&point[..2]);
+            assert_eq!(fixed.num_vars(), 2);
+            assert_eq!(fixed.evaluate(&point[2..].to_vec()), expected);
+            assert_eq!(p.fix_variables(&point).terms(), &[(expected, term(&[]))]);
+        }
+    }
+
+    #[test]
+    fn multiplication_matches_evaluation() {
+        proptest!(|(a: u64, b: u64, x: u64, y: u64)| {
+            // `(a * x_0 + x_1) * (b * x_1 + 1)`
+            let p = Poly::from_coefficients_vec(
+                2,
+                vec![(Fr::from(a), term(&[(0, 1)])), (Fr::from(1u8), term(&[(1, 1)]))],
+            );
+            let q = Poly::from_coefficients_vec(
+                2,
+                vec![(Fr::from(b), term(&[(1, 1)])), (Fr::from(1u8), term(&[]))],
+            );
+            let point = vec![Fr::from(x), Fr::from(y)];
+            let expected = (Fr::from(a) * point[0] + point[1]) * (Fr::from(b) * point[1] + Fr::from(1u8));
+            prop_assert_eq!((&p * &q).evaluate(&point), expected);
+        });
+    }
+}
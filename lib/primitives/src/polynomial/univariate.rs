@@ -1,5 +1,7 @@
 mod dense;
+mod mul;
 mod sparse;
+mod subproduct_tree;
 
 use super::{domain::*, evaluations::*, traits::*};
 use crate::{
@@ -12,6 +14,7 @@ use alloc::borrow::Cow;
 pub use dense::DensePolynomial;
 use num_traits::Zero;
 pub use sparse::SparsePolynomial;
+pub use subproduct_tree::SubproductTree;
 
 #[derive(Clone)]
 pub enum DenseOrSparsePolynomial<'a, F: Field> {
@@ -95,9 +98,38 @@ impl<F: Field> DenseOrSparsePolynomial<'_, F> {
         }
     }
 
+    /// Returns the quotient and remainder of the division of `self` by
+    /// `divisor`.
+    ///
+    /// Sparse divisors with few terms are divided with long division, whose
+    /// cost is linear in their number of terms. Other divisors go through
+    /// [`DensePolynomial::divide_with_q_and_r`], which switches to Newton
+    /// iteration for large degrees.
+    ///
+    /// # Panics
+    ///
+    /// * If `divisor` is zero.
     pub fn divide_with_q_and_r(
         &self,
         divisor: &Self,
+    ) -> Option<(DensePolynomial<F>, DensePolynomial<F>)> {
+        match divisor {
+            SPolynomial(d) if d.len() < dense::FAST_DIVISION_THRESHOLD => {
+                self.naive_divide_with_q_and_r(divisor)
+            },
+            _ => DensePolynomial::from(self.clone())
+                .divide_with_q_and_r(&DensePolynomial::from(divisor.clone())),
+        }
+    }
+
+    /// Returns the quotient and remainder of the division of `self` by
+    /// `divisor`, with schoolbook long division.
+    ///
+    /// Over fields whose products use FFTs, [`Self::divide_with_q_and_r`] is
+    /// asymptotically faster.
+    pub fn naive_divide_with_q_and_r(
+        &self,
+        divisor: &Self,
     ) -> Option<(DensePolynomial<F>, DensePolynomial<F>)> {
         if self.is_zero() {
             Some((DensePolynomial::zero(), DensePolynomial::zero()))
@@ -0,0 +1,531 @@
+//! Multiplication of dense univariate polynomials over any field.
+//!
+//! Products over prime fields with large enough radix-2 subgroups use FFTs.
+//! Otherwise, medium-sized products use Karatsuba's and Toom-3's algorithms,
+//! and large products over prime fields are computed over the integers, with
+//! NTTs modulo 64-bit primes and the Chinese remainder theorem.
+use super::{EvaluationDomain, Field, GeneralEvaluationDomain};
+use crate::{arithmetic::bigint::BigInteger, field::traits::PrimeField};
+use alloc::vec::Vec;
+
+/// Length of the shorter operand below which products use schoolbook
+/// multiplication.
+const NAIVE_THRESHOLD: usize = 32;
+
+/// Length of the shorter operand from which Toom-3 is used instead of
+/// Karatsuba's algorithm.
+const TOOM_3_THRESHOLD: usize = 256;
+
+/// Length of the shorter operand from which products over prime fields
+/// without FFTs use multi-modular NTTs.
+const MULTIMODULAR_THRESHOLD: usize = 256;
+
+/// Returns whether products with `len` coefficients over `F` are computed
+/// with FFTs.
+pub(super) fn uses_fft<F: Field>(len: usize) -> bool {
+    F::extension_degree() == 1 && GeneralEvaluationDomain::<F::BasePrimeField>::new(len).is_some()
+}
+
+/// Returns the coefficients of the product of the polynomials with
+/// coefficients `a` and `b`.
+pub(super) fn mul<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    if F::extension_degree() != 1 {
+        return karatsuba_mul(a, b);
+    }
+
+    let to_prime_field = |coeffs: &[F]| -> Vec<F::BasePrimeField> {
+        coeffs.iter().flat_map(Field::to_base_prime_field_elements).collect()
+    };
+    prime_field_mul(&to_prime_field(a), &to_prime_field(b))
+        .into_iter()
+        .map(|c| F::from_base_prime_field_elems([c]).expect("F should be a prime field"))
+        .collect()
+}
+
+fn prime_field_mul<F: PrimeField>(a: &[F], b: &[F]) -> Vec<F> {
+    let len = a.len() + b.len() - 1;
+    if let Some(domain) = GeneralEvaluationDomain::<F>::new(len) {
+        let mut a_evals = domain.fft(a);
+        let b_evals = domain.fft(b);
+        a_evals.iter_mut().zip(b_evals).for_each(|(a, b)| *a *= b);
+        let mut result = domain.ifft(&a_evals);
+        result.truncate(len);
+        return result;
+    }
+    if a.len().min(b.len()) >= MULTIMODULAR_THRESHOLD {
+        if let Some(result) = multimodular_mul(a, b) {
+            return result;
+        }
+    }
+    karatsuba_mul(a, b)
+}
+
+/// Multiplies `a` and `b` with Karatsuba's algorithm, or Toom-3 for large
+/// enough operands, down to schoolbook multiplication.
+fn karatsuba_mul<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if b.is_empty() {
+        return vec![];
+    }
+    if b.len() < NAIVE_THRESHOLD {
+        return schoolbook_mul(a, b);
+    }
+
+    let mut result = vec![F::zero(); a.len() + b.len() - 1];
+    if a.len() >= 2 * b.len() {
+        // The products of `b` with the chunks of `a` are balanced.
+        for (i, chunk) in a.chunks(b.len()).enumerate() {
+            add_assign(&mut result[i * b.len()..], &karatsuba_mul(chunk, b));
+        }
+        return result;
+    }
+    if b.len() >= TOOM_3_THRESHOLD {
+        if let (Some(half), Some(third)) = (F::from(2u8).inverse(), F::from(3u8).inverse()) {
+            return toom_3_mul(a, b, half, third);
+        }
+    }
+
+    // `(a_0 + x^k a_1) (b_0 + x^k b_1) = z_0 + x^k (z_1 - z_0 - z_2) + x^2k z_2`,
+    // with `z_1 = (a_0 + a_1) (b_0 + b_1)`.
+    let k = a.len().div_ceil(2);
+    let [a0, a1] = split(a, k);
+    let [b0, b1] = split(b, k);
+    let z0 = karatsuba_mul(a0, b0);
+    let z2 = karatsuba_mul(a1, b1);
+    let mut z1 = karatsuba_mul(&sum(a0, a1), &sum(b0, b1));
+    sub_assign(&mut z1, &z0);
+    sub_assign(&mut z1, &z2);
+
+    add_assign(&mut result, &z0);
+    add_assign(&mut result[k..], &z1);
+    add_assign(&mut result[2 * k..], &z2);
+    result
+}
+
+/// Multiplies `a` and `b`, with `b.len() <= a.len() < 2 * b.len()`, with
+/// Toom-3 and Bodrato's interpolation sequence, using the evaluations at `0`,
+/// `1`, `-1`, `-2` and infinity. `half` and `third` are the inverses of `2`
+/// and `3`.
+fn toom_3_mul<F: Field>(a: &[F], b: &[F], half: F, third: F) -> Vec<F> {
+    let k = a.len().div_ceil(3);
+    let evaluate = |p: &[F]| {
+        let [p0, p1, p2] = split(p, k);
+        let p0_plus_p2 = sum(p0, p2);
+        let at_one = sum(&p0_plus_p2, p1);
+        let mut at_minus_one = p0_plus_p2;
+        sub_assign(&mut at_minus_one, p1);
+        // `p(-2) = 2 * (p(-1) + p_2) - p_0`.
+        let mut at_minus_two = sum(&at_minus_one, p2);
+        at_minus_two.iter_mut().for_each(|c| *c = c.double());
+        sub_assign(&mut at_minus_two, p0);
+        [p0.to_vec(), at_one, at_minus_one, at_minus_two, p2.to_vec()]
+    };
+    let [r0, r1, r_minus_1, r_minus_2, r_inf] = {
+        let (a_evals, b_evals) = (evaluate(a), evaluate(b));
+        [0, 1, 2, 3, 4].map(|i| karatsuba_mul(&a_evals[i], &b_evals[i]))
+    };
+
+    let mut c3 = r_minus_2;
+    sub_assign(&mut c3, &r1);
+    c3.iter_mut().for_each(|c| *c *= third);
+    let mut c1 = r1;
+    sub_assign(&mut c1, &r_minus_1);
+    c1.iter_mut().for_each(|c| *c *= half);
+    let mut c2 = r_minus_1;
+    sub_assign(&mut c2, &r0);
+    // `c_3 = (c_2 - c_3) / 2 + 2 r_inf`.
+    let mut c2_minus_c3 = c2.clone();
+    sub_assign(&mut c2_minus_c3, &c3);
+    c3 = c2_minus_c3.into_iter().map(|c| c * half).collect();
+    add_assign_vec(&mut c3, &r_inf.iter().map(|c| c.double()).collect::<Vec<_>>());
+    add_assign_vec(&mut c2, &c1);
+    sub_assign(&mut c2, &r_inf);
+    sub_assign(&mut c1, &c3);
+
+    let len = a.len() + b.len() - 1;
+    let mut result = vec![F::zero(); len];
+    for (i, c) in [r0, c1, c2, c3, r_inf].iter().enumerate() {
+        add_assign(&mut result[(i * k).min(len)..], c);
+    }
+    result
+}
+
+fn schoolbook_mul<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut result = vec![F::zero(); a.len() + b.len() - 1];
+    for (i, a) in a.iter().enumerate() {
+        for (r, b) in result[i..].iter_mut().zip(b) {
+            *r += *a * b;
+        }
+    }
+    result
+}
+
+/// Splits `p` into `N` chunks of length `k`, the last ones being shorter or
+/// empty.
+fn split<F, const N: usize>(p: &[F], k: usize) -> [&[F]; N] {
+    core::array::from_fn(|i| &p[(i * k).min(p.len())..((i + 1) * k).min(p.len())])
+}
+
+fn sum<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    let (mut result, other) = if a.len() >= b.len() { (a.to_vec(), b) } else { (b.to_vec(), a) };
+    add_assign(&mut result, other);
+    result
+}
+
+/// `dst += src`, ignoring the coefficients of `src` past the end of `dst`.
+fn add_assign<F: Field>(dst: &mut [F], src: &[F]) {
+    dst.iter_mut().zip(src).for_each(|(d, s)| *d += s);
+}
+
+/// `dst += src`, extending `dst` with zeros if needed.
+fn add_assign_vec<F: Field>(dst: &mut Vec<F>, src: &[F]) {
+    if dst.len() < src.len() {
+        dst.resize(src.len(), F::zero());
+    }
+    add_assign(dst, src);
+}
+
+/// `dst -= src`, extending `dst` with zeros if needed.
+fn sub_assign<F: Field>(dst: &mut Vec<F>, src: &[F]) {
+    if dst.len() < src.len() {
+        dst.resize(src.len(), F::zero());
+    }
+    dst.iter_mut().zip(src).for_each(|(d, s)| *d -= s);
+}
+
+/// Primes `q = c * 2^32 + 1` between `2^61` and `2^62`, each with an element
+/// of order `2^32`.
+const NTT_PRIMES: [(u64, u64); 24] = [
+    (0x3fffffee00000001, 0xf6ad935336aad2),
+    (0x3fffffb400000001, 0x2efbcbd1f80b862f),
+    (0x3fffffa000000001, 0x2e0d2163d8fd7ce1),
+    (0x3fffff5d00000001, 0x1b941e27c355b864),
+    (0x3fffff4900000001, 0xb6b9de615983e23),
+    (0x3fffff4600000001, 0x22441a8b80b6271d),
+    (0x3fffff3000000001, 0x11d83041a31940a3),
+    (0x3fffff2800000001, 0x28cd1a7cae6682d),
+    (0x3fffff1c00000001, 0x1aab7b48fe1c9d0b),
+    (0x3fffff1800000001, 0x11091e431c76a98d),
+    (0x3ffffed600000001, 0x13b3861d24bfb6e9),
+    (0x3ffffecb00000001, 0x20623f0aeaf8f310),
+    (0x3ffffec700000001, 0x139b0fa1a4f54dc2),
+    (0x3ffffeb800000001, 0x1836bace79641743),
+    (0x3ffffeb300000001, 0x1a42ec6a17b18892),
+    (0x3ffffe6a00000001, 0xf3b19723996d83a),
+    (0x3ffffe4100000001, 0x3885cc4d402cb0fd),
+    (0x3ffffdf900000001, 0x9a282b1c744c6b7),
+    (0x3ffffdd800000001, 0x28c2c7e27b9b223e),
+    (0x3ffffdd700000001, 0xa2f58cae354e251),
+    (0x3ffffdc800000001, 0x270f163b2556628f),
+    (0x3ffffdc300000001, 0x3e057b175cb6960f),
+    (0x3ffffda700000001, 0xb2233551a4afa47),
+    (0x3ffffd8300000001, 0x12f000d9453d49e8),
+];
+
+/// Base-2 logarithm of the order of the roots of [`NTT_PRIMES`].
+const NTT_TWO_ADICITY: u32 = 32;
+
+/// Lower bound on the number of bits of each of the [`NTT_PRIMES`].
+const NTT_PRIME_BITS: usize = 61;
+
+/// Multiplies `a` and `b` over the integers, with NTTs modulo enough of the
+/// [`NTT_PRIMES`] for the coefficients of the product, which are less than
+/// `min(a.len(), b.len()) * p²`, and reduces the result modulo `p`.
+///
+/// Returns `None` if the field is too large, or the product too long, for
+/// the primes.
+fn multimodular_mul<F: PrimeField>(a: &[F], b: &[F]) -> Option<Vec<F>> {
+    let len = a.len() + b.len() - 1;
+    let size = len.checked_next_power_of_two()?;
+    let log_size = size.trailing_zeros();
+    if log_size > NTT_TWO_ADICITY {
+        return None;
+    }
+    let log_terms = a.len().min(b.len()).next_power_of_two().trailing_zeros() as usize;
+    let num_primes = (2 * F::MODULUS_BIT_SIZE + log_terms).div_ceil(NTT_PRIME_BITS);
+    let primes: Vec<_> =
+        NTT_PRIMES.get(..num_primes)?.iter().map(|&(q, root)| NttPrime::new(q, root)).collect();
+
+    let a: Vec<_> = a.iter().map(|c| c.into_bigint()).collect();
+    let b: Vec<_> = b.iter().map(|c| c.into_bigint()).collect();
+    let residues: Vec<_> = primes
+        .iter()
+        .map(|q| {
+            let mut a = q.reduce_all(&a, size);
+            let mut b = q.reduce_all(&b, size);
+            q.ntt(&mut a, log_size, false);
+            q.ntt(&mut b, log_size, false);
+            a.iter_mut().zip(&b).for_each(|(a, b)| *a = q.mul(*a, *b));
+            q.ntt(&mut a, log_size, true);
+            // Scaling by `size^-1` in normal form also leaves the Montgomery
+            // form.
+            let size_inv = q.montgomery_to_normal(q.inverse(q.normal_to_montgomery(size as u64)));
+            a.truncate(len);
+            a.iter_mut().for_each(|a| *a = q.mul(*a, size_inv));
+            a
+        })
+        .collect();
+
+    // Garner's algorithm writes each coefficient in the mixed radix
+    // `v_0 + q_0 (v_1 + q_1 (v_2 + ...))`, with `v_i < q_i`. `radices[i][j]`
+    // is `q_j mod q_i`, and `garner_inverses[i]` the inverse of
+    // `q_0 ... q_(i - 1)` modulo `q_i`, both in Montgomery form, so that
+    // products with them leave the digits in normal form.
+    let radices: Vec<Vec<_>> = primes
+        .iter()
+        .enumerate()
+        .map(|(i, q)| primes[..i].iter().map(|p| q.normal_to_montgomery(p.modulus)).collect())
+        .collect();
+    let garner_inverses: Vec<_> = primes
+        .iter()
+        .zip(&radices)
+        .map(|(q, radices)| {
+            q.inverse(radices.iter().fold(q.normal_to_montgomery(1), |acc, r| q.mul(acc, *r)))
+        })
+        .collect();
+    // Pairs of digits `v_2i + q_2i v_(2i + 1)` fit in a `u128`, and are
+    // multiplied by `q_0 ... q_(2i - 1)`.
+    let mut radix_powers = Vec::with_capacity(num_primes.div_ceil(2));
+    let mut radix_power = F::one();
+    for pair in primes.chunks(2) {
+        radix_powers.push(radix_power);
+        radix_power = pair.iter().fold(radix_power, |acc, q| acc * F::from(q.modulus));
+    }
+
+    let mut digits = vec![0u64; num_primes];
+    let result = (0..len)
+        .map(|j| {
+            for (i, (q, radices)) in primes.iter().zip(&radices).enumerate() {
+                let mut prefix = 0;
+                for (v, r) in digits[..i].iter().zip(radices).rev() {
+                    prefix = q.add(q.mul(prefix, *r), q.reduce_once(*v));
+                }
+                digits[i] = q.mul(q.sub(residues[i][j], prefix), garner_inverses[i]);
+            }
+            digits
+                .chunks(2)
+                .zip(primes.chunks(2))
+                .zip(&radix_powers)
+                .map(|((v, q), r)| {
+                    let pair = u128::from(v[0])
+                        + v.get(1).map_or(0, |v| u128::from(*v) * u128::from(q[0].modulus));
+                    F::from(pair) * r
+                })
+                .sum()
+        })
+        .collect();
+    Some(result)
+}
+
+/// Arithmetic in Montgomery form modulo one of the [`NTT_PRIMES`].
+struct NttPrime {
+    modulus: u64,
+    /// Element of order `2^NTT_TWO_ADICITY`, in Montgomery form.
+    root: u64,
+    /// `-modulus^-1 mod 2^64`.
+    neg_inv: u64,
+    /// `2^128 mod modulus`.
+    r2: u64,
+}
+
+impl NttPrime {
+    fn new(modulus: u64, root: u64) -> Self {
+        let mut inv = 1u64;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(modulus.wrapping_mul(inv)));
+        }
+        let r = ((1u128 << 64) % u128::from(modulus)) as u64;
+        let r2 = ((u128::from(r) * u128::from(r)) % u128::from(modulus)) as u64;
+        let mut prime = Self { modulus, root: 0, neg_inv: inv.wrapping_neg(), r2 };
+        prime.root = prime.normal_to_montgomery(root);
+        prime
+    }
+
+    /// Returns `a * b / 2^64 mod modulus`.
+    #[inline]
+    fn mul(&self, a: u64, b: u64) -> u64 {
+        let t = u128::from(a) * u128::from(b);
+        let m = (t as u64).wrapping_mul(self.neg_inv);
+        let u = ((t + u128::from(m) * u128::from(self.modulus)) >> 64) as u64;
+        self.reduce_once(u)
+    }
+
+    /// Returns `a mod modulus`, for `a < 2 * modulus`. If `a < modulus`,
+    /// `a - modulus` wraps around to a larger value, which makes this
+    /// branchless.
+    #[inline]
+    fn reduce_once(&self, a: u64) -> u64 {
+        a.min(a.wrapping_sub(self.modulus))
+    }
+
+    #[inline]
+    fn add(&self, a: u64, b: u64) -> u64 {
+        self.reduce_once(a + b)
+    }
+
+    #[inline]
+    fn sub(&self, a: u64, b: u64) -> u64 {
+        // If `a < b`, the difference wraps around, and so does adding the
+        // modulus back.
+        let d = a.wrapping_sub(b);
+        d.min(d.wrapping_add(self.modulus))
+    }
+
+    #[inline]
+    fn normal_to_montgomery(&self, a: u64) -> u64 {
+        self.mul(a, self.r2)
+    }
+
+    #[inline]
+    fn montgomery_to_normal(&self, a: u64) -> u64 {
+        self.mul(a, 1)
+    }
+
+    fn pow(&self, base: u64, mut exp: u64) -> u64 {
+        let (mut base, mut result) = (base, self.normal_to_montgomery(1));
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = self.mul(result, base);
+            }
+            base = self.mul(base, base);
+            exp >>= 1;
+        }
+        result
+    }
+
+    fn inverse(&self, a: u64) -> u64 {
+        self.pow(a, self.modulus - 2)
+    }
+
+    /// Reduces the big integers `values` in Montgomery form, padded with
+    /// zeros to `size` elements.
+    fn reduce_all<B: BigInteger>(&self, values: &[B], size: usize) -> Vec<u64> {
+        let mut result: Vec<_> = values
+            .iter()
+            .map(|v| {
+                // Horner's rule in base `2^64`, with `mul(x, r2) = x * 2^64`.
+                let reduced = v
+                    .as_ref()
+                    .iter()
+                    .rev()
+                    .fold(0, |acc, limb| self.add(self.mul(acc, self.r2), limb % self.modulus));
+                self.normal_to_montgomery(reduced)
+            })
+            .collect();
+        result.resize(size, 0);
+        result
+    }
+
+    /// Iterative radix-2 NTT of size `2^log_size`, or its inverse without the
+    /// division by the size.
+    fn ntt(&self, values: &mut [u64], log_size: u32, inverse: bool) {
+        let size = values.len();
+        if size <= 1 {
+            return;
+        }
+        for i in 0..size {
+            let j = i.reverse_bits() >> (usize::BITS - log_size);
+            if i < j {
+                values.swap(i, j);
+            }
+        }
+
+        let mut root = self.pow(self.root, 1 << (NTT_TWO_ADICITY - log_size));
+        if inverse {
+            root = self.inverse(root);
+        }
+        let mut twiddles = Vec::with_capacity(size / 2);
+        let mut twiddle = self.normal_to_montgomery(1);
+        for _ in 0..size / 2 {
+            twiddles.push(twiddle);
+            twiddle = self.mul(twiddle, root);
+        }
+
+        let mut half = 1;
+        while half < size {
+            let step = size / (2 * half);
+            for chunk in values.chunks_exact_mut(2 * half) {
+                let (low, high) = chunk.split_at_mut(half);
+                for (k, (x, y)) in low.iter_mut().zip(high).enumerate() {
+                    let t = self.mul(*y, twiddles[k * step]);
+                    *y = self.sub(*x, t);
+                    *x = self.add(*x, t);
+                }
+            }
+            half *= 2;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        curve::instances::{bls12_381, bn254, secp256k1},
+        field::FpBN256 as Fr,
+        polynomial::univariate::{DensePolynomial, DenseUVPolynomial},
+        test_rng,
+    };
+
+    fn rand_coeffs<F: Field>(len: usize) -> Vec<F> {
+        let rng = &mut test_rng();
+        (0..len).map(|_| F::rand(rng)).collect()
+    }
+
+    const LENGTHS: [(usize, usize); 8] =
+        [(1, 1), (3, 40), (31, 33), (64, 64), (100, 250), (200, 201), (300, 301), (129, 700)];
+
+    fn check_karatsuba<F: Field>() {
+        for (a_len, b_len) in LENGTHS {
+            let (a, b) = (rand_coeffs::<F>(a_len), rand_coeffs::<F>(b_len));
+            let expected = schoolbook_mul(&a, &b);
+            assert_eq!(karatsuba_mul(&a, &b), expected);
+            assert_eq!(karatsuba_mul(&b, &a), expected);
+            assert_eq!(mul(&a, &b), expected);
+        }
+    }
+
+    #[test]
+    fn karatsuba_and_toom_3() {
+        check_karatsuba::<secp256k1::Fq>();
+        check_karatsuba::<bn254::Fq2>();
+        assert!(karatsuba_mul::<Fr>(&[], &[Fr::ONE]).is_empty());
+    }
+
+    fn check_multimodular<F: PrimeField>() {
+        for (a_len, b_len) in [(1, 1), (2, 5), (40, 70), (257, 300), (600, 256)] {
+            let (a, b) = (rand_coeffs::<F>(a_len), rand_coeffs::<F>(b_len));
+            assert_eq!(multimodular_mul(&a, &b).unwrap(), schoolbook_mul(&a, &b));
+        }
+        // The largest coefficients of the integer product need all the primes.
+        let a = vec![-F::ONE; 300];
+        let expected = schoolbook_mul(&a, &a);
+        assert_eq!(multimodular_mul(&a, &a).unwrap(), expected);
+        assert_eq!(mul(&a, &a), expected);
+    }
+
+    #[test]
+    fn multimodular() {
+        check_multimodular::<secp256k1::Fq>();
+        check_multimodular::<bls12_381::Fq>();
+    }
+
+    #[test]
+    fn polynomial_mul() {
+        let rng = &mut test_rng();
+        let a = DensePolynomial::<secp256k1::Fq>::rand(400, rng);
+        let b = DensePolynomial::<secp256k1::Fq>::rand(300, rng);
+        assert_eq!(&a * &b, a.naive_mul(&b));
+
+        let a = DensePolynomial::<bn254::Fq2>::rand(150, rng);
+        let b = DensePolynomial::<bn254::Fq2>::rand(140, rng);
+        assert_eq!(&a * &b, a.naive_mul(&b));
+
+        let a = DensePolynomial::<Fr>::rand(100, rng);
+        let b = DensePolynomial::<Fr>::rand(70, rng);
+        assert_eq!(&a * &b, a.naive_mul(&b));
+    }
+}
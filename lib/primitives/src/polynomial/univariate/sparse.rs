@@ -216,7 +216,7 @@ impl<F: Field> SparsePolynomial<F> {
             coeffs.pop();
         }
         coeffs.sort_by(|(c1, _), (c2, _)| c1.cmp(c2));
-        assert!(coeffs.last().map_or(true, |(_, c)| !c.is_zero()));
+        assert!(coeffs.last().is_none_or(|(_, c)| !c.is_zero()));
 
         Self { coeffs }
     }
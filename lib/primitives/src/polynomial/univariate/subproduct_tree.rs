@@ -0,0 +1,260 @@
+use super::{DensePolynomial, DenseUVPolynomial, FftField, Polynomial};
+use crate::curve::batch_inversion;
+use alloc::{boxed::Box, vec::Vec};
+use num_traits::Zero;
+
+/// Number of points below which nodes of the tree are handled with quadratic
+/// algorithms instead of being split further.
+const NAIVE_THRESHOLD: usize = 16;
+
+/// Degree below which products of polynomials use schoolbook multiplication
+/// rather than FFTs.
+const NAIVE_MUL_THRESHOLD: usize = 32;
+
+/// Binary tree of the products `∏ (x - x_i)` over ranges of a set of points.
+///
+/// The root is the vanishing polynomial of all the points, and each node is
+/// the product of its two children. The tree supports evaluating a
+/// polynomial at all points, and interpolating a polynomial through values at
+/// the points, in `O(n log² n)` field operations. Subtrees of fewer than
+/// [`NAIVE_THRESHOLD`] points are not split, and use quadratic algorithms.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubproductTree<F: FftField> {
+    points: Vec<F>,
+    root: Node<F>,
+}
+
+/// Node of a [`SubproductTree`], for the points in `start..start + len`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Node<F: FftField> {
+    start: usize,
+    len: usize,
+    poly: DensePolynomial<F>,
+    children: Option<Box<(Node<F>, Node<F>)>>,
+}
+
+impl<F: FftField> Node<F> {
+    fn new(points: &[F], start: usize, len: usize) -> Self {
+        if len <= NAIVE_THRESHOLD {
+            let poly = points[start..start + len].iter().fold(
+                DensePolynomial::from_coefficients_vec(vec![F::one()]),
+                |acc, x| {
+                    acc.naive_mul(&DensePolynomial::from_coefficients_vec(vec![-*x, F::one()]))
+                },
+            );
+            return Self { start, len, poly, children: None };
+        }
+
+        let half = len / 2;
+        let left = Self::new(points, start, half);
+        let right = Self::new(points, start + half, len - half);
+        let poly = mul(&left.poly, &right.poly);
+        Self { start, len, poly, children: Some(Box::new((left, right))) }
+    }
+
+    /// Evaluates `remainder`, of degree less than `self.len`, at the points
+    /// of `self`, appending the results to `evals`.
+    fn evaluate(&self, points: &[F], remainder: &DensePolynomial<F>, evals: &mut Vec<F>) {
+        match &self.children {
+            None => {
+                let points = &points[self.start..self.start + self.len];
+                evals.extend(points.iter().map(|x| remainder.evaluate(x)));
+            },
+            Some(children) => {
+                for child in [&children.0, &children.1] {
+                    child.evaluate(points, &rem(remainder, &child.poly), evals);
+                }
+            },
+        }
+    }
+
+    /// Returns `Σ c_i * self.poly / (x - x_i)` over the points of `self`.
+    fn linear_combination(&self, points: &[F], c: &[F]) -> DensePolynomial<F> {
+        match &self.children {
+            None => {
+                let mut result = vec![F::zero(); self.len];
+                for i in self.start..self.start + self.len {
+                    // Synthetic division of `self.poly` by `x - x_i`.
+                    let mut carry = F::zero();
+                    for j in (0..self.len).rev() {
+                        carry = self.poly.coeffs[j + 1] + carry * points[i];
+                        result[j] += carry * c[i];
+                    }
+                }
+                DensePolynomial::from_coefficients_vec(result)
+            },
+            Some(children) => {
+                let (left, right) = (&children.0, &children.1);
+                let left_comb = left.linear_combination(points, c);
+                let right_comb = right.linear_combination(points, c);
+                &mul(&left_comb, &right.poly) + &mul(&right_comb, &left.poly)
+            },
+        }
+    }
+}
+
+impl<F: FftField> SubproductTree<F> {
+    /// Builds the subproduct tree of `points`.
+    pub fn new(points: &[F]) -> Self {
+        let root = Node::new(points, 0, points.len());
+        Self { points: points.to_vec(), root }
+    }
+
+    /// Returns the points of the tree.
+    pub fn points(&self) -> &[F] {
+        &self.points
+    }
+
+    /// Returns the vanishing polynomial `∏ (x - x_i)` of the points.
+    pub fn vanishing_polynomial(&self) -> &DensePolynomial<F> {
+        &self.root.poly
+    }
+
+    /// Evaluates `poly` at every point of the tree.
+    pub fn evaluate(&self, poly: &DensePolynomial<F>) -> Vec<F> {
+        let mut evals = Vec::with_capacity(self.points.len());
+        if !self.points.is_empty() {
+            self.root.evaluate(&self.points, &rem(poly, &self.root.poly), &mut evals);
+        }
+        evals
+    }
+
+    /// Returns the polynomial of degree less than the number of points which
+    /// takes the value `values[i]` at the `i`-th point.
+    ///
+    /// # Panics
+    ///
+    /// * If `values` and the points have different lengths.
+    /// * If the points are not pairwise distinct.
+    pub fn interpolate(&self, values: &[F]) -> DensePolynomial<F> {
+        assert_eq!(values.len(), self.points.len(), "there should be one value per point");
+        if self.points.is_empty() {
+            return DensePolynomial::zero();
+        }
+
+        // The Lagrange basis polynomial of `x_i` is `m / (m'(x_i) * (x - x_i))`.
+        let mut weights = self.evaluate(&derivative(&self.root.poly));
+        assert!(weights.iter().all(|w| !w.is_zero()), "points should be pairwise distinct");
+        batch_inversion(&mut weights);
+        let c: Vec<_> = weights.iter().zip(values).map(|(w, v)| *w * v).collect();
+        self.root.linear_combination(&self.points, &c)
+    }
+
+    /// Returns the polynomial of degree less than `pairs.len()` which goes
+    /// through every `(x, y)` pair.
+    ///
+    /// # Panics
+    ///
+    /// * If the `x` coordinates are not pairwise distinct.
+    pub fn interpolate_pairs(pairs: &[(F, F)]) -> DensePolynomial<F> {
+        let (points, values): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+        Self::new(&points).interpolate(&values)
+    }
+}
+
+/// Multiplies `a` and `b`, with FFTs only for large degrees.
+fn mul<F: FftField>(a: &DensePolynomial<F>, b: &DensePolynomial<F>) -> DensePolynomial<F> {
+    if a.coeffs.len().min(b.coeffs.len()) < NAIVE_MUL_THRESHOLD { a.naive_mul(b) } else { a * b }
+}
+
+/// Returns `a mod b`.
+fn rem<F: FftField>(a: &DensePolynomial<F>, b: &DensePolynomial<F>) -> DensePolynomial<F> {
+    if a.coeffs.len() < b.coeffs.len() {
+        return a.clone();
+    }
+    a.divide_with_q_and_r(b).expect("division failed").1
+}
+
+/// Returns the formal derivative of `poly`.
+fn derivative<F: FftField>(poly: &DensePolynomial<F>) -> DensePolynomial<F> {
+    let coeffs = poly.coeffs.iter().enumerate().skip(1).map(|(i, c)| F::from(i as u64) * c);
+    DensePolynomial::from_coefficients_vec(coeffs.collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        UniformRand,
+        field::{FpBN256 as Fr, traits::Field},
+        test_rng,
+    };
+
+    fn rand_points(n: usize) -> Vec<Fr> {
+        let rng = &mut test_rng();
+        (0..n).map(|_| Fr::rand(rng)).collect()
+    }
+
+    /// Lagrange interpolation in `O(n²)`.
+    fn naive_interpolate(points: &[Fr], values: &[Fr]) -> DensePolynomial<Fr> {
+        let mut result = DensePolynomial::zero();
+        for (i, (x_i, y_i)) in points.iter().zip(values).enumerate() {
+            let mut basis = DensePolynomial::from_coefficients_vec(vec![*y_i]);
+            for (j, x_j) in points.iter().enumerate() {
+                if i != j {
+                    let factor = (*x_i - x_j).inverse().unwrap();
+                    basis = basis.naive_mul(&DensePolynomial::from_coefficients_vec(vec![
+                        -*x_j * factor,
+                        factor,
+                    ]));
+                }
+            }
+            result += &basis;
+        }
+        result
+    }
+
+    #[test]
+    fn evaluate() {
+        let rng = &mut test_rng();
+        for n in [0, 1, 5, NAIVE_THRESHOLD, NAIVE_THRESHOLD + 1, 100, 257] {
+            let points = rand_points(n);
+            let tree = SubproductTree::new(&points);
+            assert_eq!(tree.vanishing_polynomial().degree(), n);
+            assert!(points.iter().all(|x| tree.vanishing_polynomial().evaluate(x).is_zero()));
+
+            for degree in [0, n / 2, 2 * n + 3] {
+                let poly = DensePolynomial::<Fr>::rand(degree, rng);
+                let expected: Vec<_> = points.iter().map(|x| poly.evaluate(x)).collect();
+                assert_eq!(tree.evaluate(&poly), expected);
+            }
+            assert_eq!(tree.evaluate(&DensePolynomial::zero()), vec![Fr::zero(); n]);
+        }
+    }
+
+    #[test]
+    fn interpolate() {
+        let rng = &mut test_rng();
+        for n in [0, 1, 5, NAIVE_THRESHOLD + 1, 100, 257] {
+            let points = rand_points(n);
+            let values: Vec<_> = (0..n).map(|_| Fr::rand(rng)).collect();
+            let tree = SubproductTree::new(&points);
+            let poly = tree.interpolate(&values);
+            assert!(poly.is_zero() || poly.degree() < n);
+            assert_eq!(tree.evaluate(&poly), values);
+            if n <= 20 {
+                assert_eq!(poly, naive_interpolate(&points, &values));
+            }
+
+            let pairs: Vec<_> = points.iter().copied().zip(values.iter().copied()).collect();
+            assert_eq!(SubproductTree::interpolate_pairs(&pairs), poly);
+        }
+    }
+
+    #[test]
+    fn interpolate_round_trip() {
+        let rng = &mut test_rng();
+        let points: Vec<_> = (0..200u64).map(Fr::from).collect();
+        let tree = SubproductTree::new(&points);
+        let poly = DensePolynomial::<Fr>::rand(199, rng);
+        assert_eq!(tree.interpolate(&tree.evaluate(&poly)), poly);
+    }
+
+    #[test]
+    #[should_panic = "points should be pairwise distinct"]
+    fn repeated_points() {
+        let mut points = rand_points(40);
+        points[30] = points[3];
+        let _ = SubproductTree::new(&points).interpolate(&vec![Fr::zero(); 40]);
+    }
+}
@@ -0,0 +1,11 @@
+//! Helpers shared by the test modules of the crate.
+
+/// Decodes the hex string `s`, without a `0x` prefix.
+pub(crate) fn from_hex(s: &str) -> Vec<u8> {
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+}
+
+/// Encodes `bytes` as a lower-case hex string, without a `0x` prefix.
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |hex, byte| hex + &format!("{byte:02x}"))
+}
@@ -17,7 +17,7 @@ use super::{
     traits::{ArkDeserialize, ArkSerialize},
 };
 use core::borrow::Borrow;
-use fina_common::io::{Read, Write};
+use fina_common::io::Write;
 
 #[inline]
 fn serialize_seq<T, B, W>(
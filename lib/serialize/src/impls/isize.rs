@@ -13,7 +13,7 @@ impl ArkSerialize for isize {
 
     #[inline]
     fn serialized_size(&self, _compress: Compress) -> usize {
-        core::mem::size_of::<i64>()
+        size_of::<i64>()
     }
 }
 
@@ -39,7 +39,7 @@ impl ArkDeserialize for isize {
         _compress: Compress,
         _validate: Validate,
     ) -> Result<Self, SerializationError> {
-        let mut bytes = [0u8; core::mem::size_of::<i64>()];
+        let mut bytes = [0u8; size_of::<i64>()];
         reader.read_exact(&mut bytes)?;
         Ok(<i64>::from_le_bytes(bytes) as Self)
     }
@@ -13,7 +13,7 @@ impl ArkSerialize for usize {
 
     #[inline]
     fn serialized_size(&self, _compress: Compress) -> usize {
-        core::mem::size_of::<u64>()
+        size_of::<u64>()
     }
 }
 
@@ -39,7 +39,7 @@ impl ArkDeserialize for usize {
         _compress: Compress,
         _validate: Validate,
     ) -> Result<Self, SerializationError> {
-        let mut bytes = [0u8; core::mem::size_of::<u64>()];
+        let mut bytes = [0u8; size_of::<u64>()];
         reader.read_exact(&mut bytes)?;
         Ok(<u64>::from_le_bytes(bytes) as Self)
     }